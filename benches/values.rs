@@ -0,0 +1,139 @@
+//! Compares reading and writing many values at once to reading and writing them one by one.
+//!
+//! Run with `cargo bench --bench values --features test-util`.
+
+use std::{
+    future::Future,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use open62541::{ua, Node, TestHarness};
+
+/// Number of variable nodes to read and write in each iteration.
+const NODE_COUNT: usize = 500;
+
+/// Number of iterations to measure.
+const ITERATIONS: u32 = 20;
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> anyhow::Result<()> {
+    let mut node_ids = Vec::with_capacity(NODE_COUNT);
+    let harness = TestHarness::start(|server| {
+        for index in 0..NODE_COUNT {
+            let node_id = server
+                .add_node(Node::new(
+                    ua::NodeId::ns0_objects_folder(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, &format!("Value{index}")),
+                    ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(-1)
+                        .with_access_level(
+                            &ua::AccessLevel::NONE
+                                .with_current_read(true)
+                                .with_current_write(true),
+                        ),
+                ))
+                .expect("should add node");
+            node_ids.push(node_id);
+        }
+    })?;
+    let server = harness.server();
+    let client = harness.client();
+
+    let variants: Vec<_> = node_ids
+        .iter()
+        .zip(0..)
+        .map(|(node_id, value)| (node_id.clone(), ua::Variant::scalar(ua::Int32::new(value))))
+        .collect();
+    let data_values: Vec<_> = variants
+        .iter()
+        .map(|(node_id, variant)| (node_id.clone(), ua::DataValue::new(variant.clone())))
+        .collect();
+
+    report("Server::write_value() in loop", || {
+        for (node_id, value) in &variants {
+            server
+                .write_value(node_id, value)
+                .expect("should write value");
+        }
+    });
+    report("Server::write_values()", || {
+        black_box(server.write_values(&variants));
+    });
+    report("Server::read_attribute() in loop", || {
+        for node_id in &node_ids {
+            let value = server.read_attribute(node_id, ua::AttributeId::VALUE_T);
+            black_box(value.expect("should read value"));
+        }
+    });
+    report("Server::read_values()", || {
+        black_box(server.read_values(&node_ids));
+    });
+
+    report_async("AsyncClient::write_value() in loop", || async {
+        for (node_id, value) in &data_values {
+            client
+                .write_value(node_id, value)
+                .await
+                .expect("should write value");
+        }
+    })
+    .await;
+    report_async("AsyncClient::write_values()", || async {
+        black_box(
+            client
+                .write_values(&data_values)
+                .await
+                .expect("should send write request"),
+        );
+    })
+    .await;
+    report_async("AsyncClient::read_value() in loop", || async {
+        for node_id in &node_ids {
+            black_box(client.read_value(node_id).await.expect("should read value"));
+        }
+    })
+    .await;
+    report_async("AsyncClient::read_values()", || async {
+        black_box(
+            client
+                .read_values(&node_ids)
+                .await
+                .expect("should send read request"),
+        );
+    })
+    .await;
+
+    harness.shutdown().await?;
+    Ok(())
+}
+
+fn report(name: &str, mut f: impl FnMut()) {
+    // Warm up caches before measuring.
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    print_result(name, start.elapsed());
+}
+
+async fn report_async<F: Future<Output = ()>>(name: &str, mut f: impl FnMut() -> F) {
+    // Warm up caches before measuring.
+    f().await;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f().await;
+    }
+    print_result(name, start.elapsed());
+}
+
+fn print_result(name: &str, elapsed: Duration) {
+    let per_iteration = elapsed / ITERATIONS;
+    let per_node = per_iteration / u32::try_from(NODE_COUNT).expect("should fit");
+    println!(
+        "{name:<40} {per_iteration:>12.2?} per {NODE_COUNT} nodes, {per_node:>10.2?} per node"
+    );
+}
@@ -5,7 +5,6 @@ use std::{
 
 use anyhow::Context as _;
 use open62541::{ua, AsyncClient, DataType as _, Result};
-use open62541_sys::UA_NS0ID_SERVERTYPE;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -13,7 +12,7 @@ async fn main() -> anyhow::Result<()> {
 
     let client = AsyncClient::new("opc.tcp://opcuademo.sterfive.com:26543").context("connect")?;
 
-    let hierarchy = browse_hierarchy(&client, &ua::NodeId::numeric(0, UA_NS0ID_SERVERTYPE)).await?;
+    let hierarchy = browse_hierarchy(&client, &ua::NodeId::ns0_server_type()).await?;
 
     hierarchy.pretty_print(|name, (node_id, node)| {
         let Some(name) = name else {
@@ -1,6 +1,5 @@
 use anyhow::{anyhow, Context as _};
 use open62541::{ua, AsyncClient, DataValue, ValueType};
-use open62541_sys::{UA_NS0ID_HASPROPERTY, UA_NS0ID_PROPERTYTYPE};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -223,13 +222,13 @@ fn get_arguments(value: &DataValue<ua::Variant>) -> anyhow::Result<Vec<(ua::Stri
 fn property_name(reference: &ua::ReferenceDescription) -> Option<&str> {
     // TODO: Add methods for these checks?
     (reference.node_class() == &ua::NodeClass::VARIABLE
-        && reference.reference_type_id().as_ns0() == Some(UA_NS0ID_HASPROPERTY)
-        && reference.type_definition().node_id().as_ns0() == Some(UA_NS0ID_PROPERTYTYPE))
-    .then(|| {
-        reference
-            .browse_name()
-            .as_ns0()
-            .and_then(|name| name.as_str())
-    })
-    .flatten()
+        && reference.reference_type_id() == &ua::NodeId::ns0_has_property()
+        && reference.type_definition().node_id() == &ua::NodeId::ns0_property_type())
+        .then(|| {
+            reference
+                .browse_name()
+                .as_ns0()
+                .and_then(|name| name.as_str())
+        })
+        .flatten()
 }
@@ -5,13 +5,6 @@ use futures::future;
 use open62541::{
     ua, AsyncClient, ClientBuilder, DataType, MonitoredItemBuilder, SubscriptionBuilder,
 };
-use open62541_sys::{
-    UA_NS0ID_BASEEVENTTYPE, UA_NS0ID_BASEMODELCHANGEEVENTTYPE, UA_NS0ID_SERVER,
-    UA_NS0ID_SERVER_SERVERSTATUS, UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_BUILDDATE,
-    UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_MANUFACTURERNAME,
-    UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME,
-    UA_NS0ID_SERVER_SERVERSTATUS_STARTTIME,
-};
 use tokio::time;
 
 #[tokio::main]
@@ -64,7 +57,7 @@ async fn subscribe_node_value(client: &AsyncClient) -> anyhow::Result<()> {
         .await
         .context("create subscription")?;
 
-    let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    let node_id = ua::NodeId::ns0_server_status_current_time();
 
     let mut monitored_item = subscription
         .create_monitored_item(&node_id)
@@ -96,7 +89,7 @@ async fn subscribe_node_events(client: &AsyncClient) -> anyhow::Result<()> {
         .await
         .context("create subscription")?;
 
-    let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER);
+    let node_id = ua::NodeId::ns0_server();
 
     let results = MonitoredItemBuilder::new([node_id.clone()])
         .attribute_id(ua::AttributeId::EVENTNOTIFIER)
@@ -104,15 +97,15 @@ async fn subscribe_node_events(client: &AsyncClient) -> anyhow::Result<()> {
             ua::EventFilter::init()
                 .with_select_clauses(&[
                     ua::SimpleAttributeOperand::init()
-                        .with_type_definition_id(ua::NodeId::ns0(UA_NS0ID_BASEEVENTTYPE))
+                        .with_type_definition_id(ua::NodeId::ns0_base_event_type())
                         .with_browse_path(&[ua::QualifiedName::new(0, "Change")])
                         .with_attribute_id(&ua::AttributeId::VALUE),
                     ua::SimpleAttributeOperand::init()
-                        .with_type_definition_id(ua::NodeId::ns0(UA_NS0ID_BASEEVENTTYPE))
+                        .with_type_definition_id(ua::NodeId::ns0_base_event_type())
                         .with_browse_path(&[ua::QualifiedName::new(0, "EventType")])
                         .with_attribute_id(&ua::AttributeId::VALUE),
                     ua::SimpleAttributeOperand::init()
-                        .with_type_definition_id(ua::NodeId::ns0(UA_NS0ID_BASEEVENTTYPE))
+                        .with_type_definition_id(ua::NodeId::ns0_base_event_type())
                         .with_browse_path(&[ua::QualifiedName::new(0, "SourceNode")])
                         .with_attribute_id(&ua::AttributeId::VALUE),
                 ])
@@ -120,7 +113,7 @@ async fn subscribe_node_events(client: &AsyncClient) -> anyhow::Result<()> {
                     ua::ContentFilter::init().with_elements(&[ua::ContentFilterElement::init()
                         .with_filter_operator(ua::FilterOperator::OFTYPE)
                         .with_filter_operands(&[ua::LiteralOperand::new(ua::Variant::scalar(
-                            ua::NodeId::ns0(UA_NS0ID_BASEMODELCHANGEEVENTTYPE),
+                            ua::NodeId::ns0_base_model_change_event_type(),
                         ))])]),
                 ),
         )
@@ -177,8 +170,8 @@ async fn subscribe_node_with_options(client: &AsyncClient) -> anyhow::Result<()>
     );
 
     let node_ids = [
-        ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME),
-        ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_STARTTIME),
+        ua::NodeId::ns0_server_status_current_time(),
+        ua::NodeId::ns0_server_status_start_time(),
     ];
 
     let results = MonitoredItemBuilder::new(node_ids.clone())
@@ -226,11 +219,11 @@ async fn subscribe_node_with_options(client: &AsyncClient) -> anyhow::Result<()>
 async fn read_nodes(client: &AsyncClient) -> anyhow::Result<()> {
     println!("Reading some items");
 
-    let builddate = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_BUILDDATE);
-    let manufacturername = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_MANUFACTURERNAME);
-    let productname = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME);
-    let currenttime = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
-    let starttime = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_STARTTIME);
+    let builddate = ua::NodeId::ns0_server_status_build_info_build_date();
+    let manufacturername = ua::NodeId::ns0_server_status_build_info_manufacturer_name();
+    let productname = ua::NodeId::ns0_server_status_build_info_product_name();
+    let currenttime = ua::NodeId::ns0_server_status_current_time();
+    let starttime = ua::NodeId::ns0_server_status_start_time();
 
     let results = future::join_all(vec![
         client.read_value(&builddate),
@@ -250,10 +243,7 @@ async fn browse_node(client: &AsyncClient) -> anyhow::Result<()> {
     println!("Browsing node");
 
     let (references, _) = client
-        .browse(
-            &ua::BrowseDescription::default()
-                .with_node_id(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS)),
-        )
+        .browse(&ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server_status()))
         .await
         .context("browse node")?;
 
@@ -2,7 +2,6 @@ use std::sync::Arc;
 
 use anyhow::Context as _;
 use open62541::{ua, AsyncClient};
-use open62541_sys::{UA_NS0ID_SERVER, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME};
 use tokio::task::JoinSet;
 
 #[tokio::main]
@@ -21,16 +20,13 @@ async fn main() -> anyhow::Result<()> {
 
         tasks.spawn(async move {
             let (references, _) = client
-                .browse(
-                    &ua::BrowseDescription::default()
-                        .with_node_id(&ua::NodeId::ns0(UA_NS0ID_SERVER)),
-                )
+                .browse(&ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server()))
                 .await
                 .context("browse")?;
             println!("References: {}", references.len());
 
             let value = client
-                .read_value(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME))
+                .read_value(&ua::NodeId::ns0_server_status_current_time())
                 .await
                 .context("read")?;
             let value = value.value().to_value();
@@ -2,9 +2,6 @@ use std::{sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use open62541::{ua, AsyncClient, AsyncSubscription};
-use open62541_sys::{
-    UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME,
-};
 use rand::Rng as _;
 use tokio::time::{self, error::Elapsed};
 
@@ -29,9 +26,9 @@ async fn main() -> anyhow::Result<()> {
     // `/Root/Objects/2:DeviceSet/1:CoffeeMachine/1:Espresso/7:BeverageSize`
     let float_node_id = ua::NodeId::numeric(1, 1074);
     // `/Root/Objects/Server/ServerStatus/CurrentTime`
-    let date_time_node_id = ua::NodeId::numeric(0, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    let date_time_node_id = ua::NodeId::ns0_server_status_current_time();
     // `/Root/Objects/Server/ServerStatus/BuildInfo/ProductName`
-    let string_node_id = ua::NodeId::numeric(0, UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME);
+    let string_node_id = ua::NodeId::ns0_server_status_build_info_product_name();
 
     let tasks = vec![
         tokio::spawn(monitor_background(
@@ -3,7 +3,6 @@ use std::{pin::pin, sync::Arc};
 use anyhow::Context as _;
 use futures::StreamExt as _;
 use open62541::{ua, AsyncClient};
-use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME;
 use tokio::task;
 
 #[tokio::main]
@@ -32,7 +31,7 @@ async fn main() -> anyhow::Result<()> {
 }
 
 async fn read_background(client: Arc<AsyncClient>) -> anyhow::Result<()> {
-    let node_id = ua::NodeId::numeric(0, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    let node_id = ua::NodeId::ns0_server_status_current_time();
 
     let value = client.read_value(&node_id).await.context("read value")?;
 
@@ -45,7 +44,7 @@ async fn read_background(client: Arc<AsyncClient>) -> anyhow::Result<()> {
 }
 
 async fn watch_background(client: Arc<AsyncClient>) -> anyhow::Result<()> {
-    let node_id = ua::NodeId::numeric(0, UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME);
+    let node_id = ua::NodeId::ns0_server_status_current_time();
 
     let subscription = client
         .create_subscription()
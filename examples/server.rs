@@ -6,10 +6,6 @@ use std::{
 
 use anyhow::bail;
 use open62541::{ua, Attribute, ObjectNode, Server, VariableNode};
-use open62541_sys::{
-    UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_FOLDERTYPE, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_ORGANIZES,
-    UA_NS0ID_STRING,
-};
 use time::macros::datetime;
 
 fn main() -> anyhow::Result<()> {
@@ -21,10 +17,10 @@ fn main() -> anyhow::Result<()> {
 
     let object_node = ObjectNode {
         requested_new_node_id: Some(ua::NodeId::string(1, "the.folder")),
-        parent_node_id: ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+        parent_node_id: ua::NodeId::ns0_objects_folder(),
+        reference_type_id: ua::NodeId::ns0_organizes(),
         browse_name: ua::QualifiedName::new(1, "the folder"),
-        type_definition: ua::NodeId::ns0(UA_NS0ID_FOLDERTYPE),
+        type_definition: ua::NodeId::ns0_folder_type(),
         attributes: ua::ObjectAttributes::default(),
     };
     let object_node_id = server.add_object_node(object_node)?;
@@ -32,11 +28,10 @@ fn main() -> anyhow::Result<()> {
     let value_node = VariableNode {
         requested_new_node_id: Some(ua::NodeId::string(1, "the.answer")),
         parent_node_id: object_node_id.clone(),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+        reference_type_id: ua::NodeId::ns0_organizes(),
         browse_name: ua::QualifiedName::new(1, "the answer"),
-        type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
-        attributes: ua::VariableAttributes::default()
-            .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING)),
+        type_definition: ua::NodeId::ns0_base_data_variable_type(),
+        attributes: ua::VariableAttributes::default().with_data_type(&ua::NodeId::ns0_string()),
     };
     let value_node_id = server.add_variable_node(value_node)?;
 
@@ -59,11 +54,10 @@ fn main() -> anyhow::Result<()> {
     let data_value_node = VariableNode {
         requested_new_node_id: Some(ua::NodeId::string(1, "the.answer.data.value")),
         parent_node_id: object_node_id.clone(),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+        reference_type_id: ua::NodeId::ns0_organizes(),
         browse_name: ua::QualifiedName::new(1, "the answer.data.value"),
-        type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
-        attributes: ua::VariableAttributes::default()
-            .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING)),
+        type_definition: ua::NodeId::ns0_base_data_variable_type(),
+        attributes: ua::VariableAttributes::default().with_data_type(&ua::NodeId::ns0_string()),
     };
 
     let data_value_node_id = server.add_variable_node(data_value_node)?;
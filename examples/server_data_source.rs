@@ -5,10 +5,6 @@ use open62541::{
     ua, DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
     DataSourceWriteContext, ObjectNode, Server, VariableNode,
 };
-use open62541_sys::{
-    UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_FOLDERTYPE, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_ORGANIZES,
-    UA_NS0ID_STRING,
-};
 
 struct DynamicDataSource {
     current_value: String,
@@ -70,10 +66,10 @@ fn main() -> anyhow::Result<()> {
 
     let object_node = ObjectNode {
         requested_new_node_id: Some(ua::NodeId::string(1, "the.folder")),
-        parent_node_id: ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+        parent_node_id: ua::NodeId::ns0_objects_folder(),
+        reference_type_id: ua::NodeId::ns0_organizes(),
         browse_name: ua::QualifiedName::new(1, "the folder"),
-        type_definition: ua::NodeId::ns0(UA_NS0ID_FOLDERTYPE),
+        type_definition: ua::NodeId::ns0_folder_type(),
         attributes: ua::ObjectAttributes::default(),
     };
     let object_node_id = server
@@ -83,11 +79,11 @@ fn main() -> anyhow::Result<()> {
     let variable_node = VariableNode {
         requested_new_node_id: Some(ua::NodeId::string(1, "the.answer")),
         parent_node_id: object_node_id.clone(),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+        reference_type_id: ua::NodeId::ns0_organizes(),
         browse_name: ua::QualifiedName::new(1, "the answer"),
-        type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
+        type_definition: ua::NodeId::ns0_base_data_variable_type(),
         attributes: ua::VariableAttributes::default()
-            .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING))
+            .with_data_type(&ua::NodeId::ns0_string())
             .with_access_level(
                 &ua::AccessLevel::NONE
                     .with_current_read(true)
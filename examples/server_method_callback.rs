@@ -5,7 +5,6 @@ use open62541::{
     ua, Attributes, DataType, MethodCallback, MethodCallbackContext, MethodCallbackError,
     MethodCallbackResult, MethodNode, Server,
 };
-use open62541_sys::{UA_NS0ID_HASCOMPONENT, UA_NS0ID_OBJECTSFOLDER};
 
 struct ExampleCallback {}
 
@@ -51,21 +50,21 @@ fn main() -> anyhow::Result<()> {
     println!("Adding server nodes");
 
     let input_argument = ua::Argument::init()
-        .with_data_type(&ua::NodeId::numeric(0, 12))
+        .with_data_type(&ua::NodeId::ns0_string())
         .with_name(&ua::String::new("MyInput")?)
         .with_description(&ua::LocalizedText::new("en-US", "A String")?)
         .with_value_rank(-1);
 
     let output_argument = ua::Argument::init()
-        .with_data_type(&ua::NodeId::numeric(0, 12))
+        .with_data_type(&ua::NodeId::ns0_string())
         .with_name(&ua::String::new("MyOutput")?)
         .with_description(&ua::LocalizedText::new("en-US", "A String")?)
         .with_value_rank(-1);
 
     let method_node = MethodNode {
         requested_new_node_id: Some(ua::NodeId::numeric(1, 62541)),
-        parent_node_id: ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-        reference_type_id: ua::NodeId::ns0(UA_NS0ID_HASCOMPONENT),
+        parent_node_id: ua::NodeId::ns0_objects_folder(),
+        reference_type_id: ua::NodeId::ns0_has_component(),
         browse_name: ua::QualifiedName::new(1, "hello world"),
         attributes: ua::MethodAttributes::init()
             .with_display_name(&ua::LocalizedText::new("en-US", "Hello World")?)
@@ -1,23 +1,31 @@
 use std::{
+    collections::HashMap,
     ffi::c_void,
+    future::Future,
+    num::NonZeroUsize,
+    pin::{pin, Pin},
     ptr, slice,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
     },
+    task::{ready, Context, Poll, Wake, Waker},
     thread::{self, JoinHandle},
     time::Duration,
 };
 
+use futures_core::Stream;
 use open62541_sys::{
-    UA_Client, UA_Client_disconnectAsync, UA_Client_run_iterate, UA_UInt32,
+    UA_Client, UA_Client_connectAsync, UA_Client_disconnectAsync, UA_Client_run_iterate, UA_UInt32,
     __UA_Client_AsyncService, UA_STATUSCODE_BADCONNECTIONCLOSED, UA_STATUSCODE_BADDISCONNECT,
 };
 use tokio::{sync::oneshot, task, time::Instant};
 
 use crate::{
-    ua, AsyncSubscription, Attribute, BrowseResult, CallbackOnce, DataType, DataValue, Error,
-    Result, ServiceRequest, ServiceResponse, SubscriptionBuilder,
+    client::{ClientContext, Reconnect},
+    ua, AsyncSubscription, Attribute, BrowseResult, CallError, CallbackOnce, CallbackStream,
+    DataType, DataValue, DiagnosticInfo, Error, Result, ServiceRequest, ServiceResponse,
+    StreamOverflow, SubscriptionBuilder, TypeMismatchError, ValueType, WriteError,
 };
 
 /// Timeout for `UA_Client_run_iterate()`.
@@ -31,6 +39,23 @@ use crate::{
 /// repeatedly calling `poll()`/`select()` inside open62541's event loop implementation.
 const RUN_ITERATE_TIMEOUT: Duration = Duration::from_millis(200);
 
+/// Interval for checking connection progress in [`AsyncClient::connect()`].
+///
+/// The connection is established by the background task. We only check its state regularly, which
+/// is cheap and keeps the delay after activating the session short.
+const CONNECT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+tokio::task_local! {
+    /// Timeout of service requests, set by [`AsyncClient::with_timeout()`].
+    static REQUEST_TIMEOUT: Duration;
+}
+
+/// Number of state changes buffered in [`AsyncClient::state_stream()`].
+const STATE_STREAM_CAPACITY: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(capacity) => capacity,
+    None => unreachable!(),
+};
+
 /// Connected OPC UA client (with asynchronous API).
 ///
 /// To disconnect, prefer method [`disconnect()`](Self::disconnect) over simply dropping the client:
@@ -44,6 +69,17 @@ pub struct AsyncClient {
     client: Arc<ua::Client>,
     background_cancelled: Arc<AtomicBool>,
     background_handle: Option<JoinHandle<()>>,
+    /// Cached attributes of nodes, for [`write_value_checked()`](Self::write_value_checked).
+    write_targets: Mutex<HashMap<ua::NodeId, WriteTarget>>,
+    /// Incremented by background task whenever session is activated again after reconnect.
+    session_generation: Arc<AtomicU64>,
+    /// Cached values of nodes, for [`read_value_cached()`](Self::read_value_cached).
+    value_cache: Mutex<ValueCache>,
+    /// Cached names of data types, for [`read_option_set_names()`](Self::read_option_set_names).
+    option_set_names: Mutex<HashMap<ua::NodeId, Vec<ua::LocalizedText>>>,
+    /// Cached namespace array with its session generation, for
+    /// [`namespace_index()`](Self::namespace_index).
+    namespace_array: Mutex<Option<(u64, Vec<String>)>>,
 }
 
 impl AsyncClient {
@@ -57,20 +93,77 @@ impl AsyncClient {
     ///
     /// See [`ClientBuilder::connect()`] and [`Client::into_async()`](crate::Client::into_async).
     ///
-    /// # Panics
-    ///
-    /// See [`ClientBuilder::connect()`].
-    ///
     /// [`ClientBuilder`]: crate::ClientBuilder
     /// [`ClientBuilder::connect()`]: crate::ClientBuilder::connect
     pub fn new(endpoint_url: &str) -> Result<Self> {
         Ok(crate::Client::new(endpoint_url)?.into_async())
     }
 
+    /// Creates default client connected to endpoint, without blocking.
+    ///
+    /// Unlike [`new()`](Self::new), this does not block the current thread while the connection is
+    /// established. If you need more control over the initialization, use [`ClientBuilder`] and
+    /// call [`connect_async()`](crate::ClientBuilder::connect_async) instead.
+    ///
+    /// # Errors
+    ///
+    /// See [`ClientBuilder::connect_async()`].
+    ///
+    /// [`ClientBuilder`]: crate::ClientBuilder
+    /// [`ClientBuilder::connect_async()`]: crate::ClientBuilder::connect_async
+    pub async fn connect(endpoint_url: &str) -> Result<Self> {
+        crate::ClientBuilder::default()
+            .connect_async(endpoint_url)
+            .await
+    }
+
+    /// Connects client to endpoint, without blocking.
+    pub(crate) async fn connect_with(
+        mut client: ua::Client,
+        endpoint_url: &str,
+        timeout: Duration,
+    ) -> Result<Self> {
+        log::info!("Connecting asynchronously to endpoint {endpoint_url}");
+
+        let endpoint_url = Error::c_string(endpoint_url, "endpoint URL")?;
+
+        let status_code = ua::StatusCode::new(unsafe {
+            // SAFETY: The method does not take ownership of `client`.
+            UA_Client_connectAsync(client.as_mut_ptr(), endpoint_url.as_ptr())
+        });
+        Error::verify_good(&status_code)?;
+
+        // The connection is established by the background task while it runs the event loop. When
+        // we return early (or the future is dropped), dropping `client` cancels the background task
+        // and cleans up the connection.
+        let client = Self::from_sync(client);
+
+        let connected = tokio::time::timeout(timeout, async {
+            loop {
+                if client.client.is_session_activated() {
+                    return Ok(());
+                }
+                let connect_status = client.client.connect_status();
+                if !connect_status.is_good() {
+                    return Err(Error::new(connect_status));
+                }
+                tokio::time::sleep(CONNECT_POLL_INTERVAL).await;
+            }
+        })
+        .await;
+
+        match connected {
+            Ok(Ok(())) => Ok(client),
+            Ok(Err(error)) => Err(error),
+            Err(_) => Err(Error::new(ua::StatusCode::BADTIMEOUT)),
+        }
+    }
+
     pub(crate) fn from_sync(client: ua::Client) -> Self {
         let client = Arc::new(client);
 
         let background_cancelled = Arc::new(AtomicBool::new(false));
+        let session_generation = Arc::new(AtomicU64::new(0));
 
         // Run the event loop concurrently. We do so on a thread where we may block: we need to call
         // `UA_Client_run_iterate()` and this method blocks for up to `RUN_ITERATE_TIMEOUT`.
@@ -81,13 +174,19 @@ impl AsyncClient {
         let background_handle = {
             let client = Arc::clone(&client);
             let cancelled = Arc::clone(&background_cancelled);
-            thread::spawn(move || background_task(&client, &cancelled))
+            let session_generation = Arc::clone(&session_generation);
+            thread::spawn(move || background_task(&client, &cancelled, &session_generation))
         };
 
         Self {
             client,
             background_cancelled,
             background_handle: Some(background_handle),
+            write_targets: Mutex::default(),
+            session_generation,
+            value_cache: Mutex::default(),
+            option_set_names: Mutex::default(),
+            namespace_array: Mutex::default(),
         }
     }
 
@@ -124,14 +223,281 @@ impl AsyncClient {
         self.client.state()
     }
 
+    /// Gets stream of state changes.
+    ///
+    /// The stream yields the channel and session state, and connect status, whenever any of them is
+    /// changed by the client, e.g. when the session is lost or the secure channel is renewed. Only
+    /// changes after this call are included: use [`state()`](Self::state) to get the current state.
+    ///
+    /// Up to 100 changes are buffered. When the consumer falls behind further than that, the oldest
+    /// changes are discarded, so the most recent state is always received.
+    ///
+    /// The stream ends when the client is dropped or disconnected.
+    pub fn state_stream(&self) -> impl Stream<Item = ua::ClientState> + Send + Sync + 'static {
+        let (tx, rx) =
+            CallbackStream::channel(STATE_STREAM_CAPACITY, StreamOverflow::DiscardOldest);
+        ClientContext::get(&self.client).add_state_sender(tx);
+        rx
+    }
+
+    /// Sets default options for request headers.
+    ///
+    /// The audit entry ID and the diagnostics to return are copied from `options` into the request
+    /// header of every service request sent afterwards by methods such as [`read_value()`] or
+    /// [`write_value()`]. Other fields of `options` are ignored: they are set by the client for each
+    /// request.
+    ///
+    /// ```no_run
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # fn example(client: &AsyncClient) {
+    /// client.set_default_request_header_options(
+    ///     ua::RequestHeader::init()
+    ///         .with_audit_entry_id(&ua::String::new("operator-42").unwrap())
+    ///         .with_return_diagnostics(0x3ff),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [`read_value()`]: Self::read_value
+    /// [`write_value()`]: Self::write_value
+    pub fn set_default_request_header_options(&self, options: ua::RequestHeader) {
+        ClientContext::get(&self.client).set_request_header_options(options);
+    }
+
+    /// Runs future with different timeout for service requests.
+    ///
+    /// Service requests that are sent while running `future`, e.g. by [`read_value()`] or
+    /// [`browse()`], use `timeout` instead of the [timeout] that has been configured for the client.
+    /// This allows long-running requests such as history reads to take longer, and quick checks to
+    /// fail early.
+    ///
+    /// The timeout is passed to the server in the request header and the request fails locally with
+    /// `BadTimeout` when it elapses. Requests that are sent by tasks spawned from `future` use the
+    /// default timeout.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(client: &AsyncClient) -> open62541::Result<()> {
+    /// let value = AsyncClient::with_timeout(
+    ///     Duration::from_secs(1),
+    ///     client.read_value(&ua::NodeId::ns0_server_status_state()),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`read_value()`]: Self::read_value
+    /// [`browse()`]: Self::browse
+    /// [timeout]: crate::ClientBuilder::timeout
+    pub async fn with_timeout<F: Future>(timeout: Duration, future: F) -> F::Output {
+        REQUEST_TIMEOUT.scope(timeout, future).await
+    }
+
+    /// Sends browse request without waiting for the response.
+    ///
+    /// This is a lower-level alternative to [`browse_many()`](Self::browse_many) for requests that
+    /// may take long on the server. The returned [`ServiceCall`] resolves to the response and allows
+    /// cancelling the request on the server with [`ServiceCall::cancel()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request cannot be sent, e.g. when the client is not connected.
+    pub fn send_browse_request(
+        &self,
+        request: ua::BrowseRequest,
+    ) -> Result<ServiceCall<'_, ua::BrowseResponse>> {
+        send_service_request(&self.client, request)
+    }
+
+    /// Sends history read request without waiting for the response.
+    ///
+    /// This is a lower-level alternative to [`history_read_raw()`](Self::history_read_raw), see
+    /// [`send_browse_request()`](Self::send_browse_request).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request cannot be sent, e.g. when the client is not connected.
+    pub fn send_history_read_request(
+        &self,
+        request: ua::HistoryReadRequest,
+    ) -> Result<ServiceCall<'_, ua::HistoryReadResponse>> {
+        send_service_request(&self.client, request)
+    }
+
+    /// Sends call request without waiting for the response.
+    ///
+    /// This is a lower-level alternative to [`call_methods()`](Self::call_methods), see
+    /// [`send_browse_request()`](Self::send_browse_request).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request cannot be sent, e.g. when the client is not connected.
+    pub fn send_call_request(
+        &self,
+        request: ua::CallRequest,
+    ) -> Result<ServiceCall<'_, ua::CallResponse>> {
+        send_service_request(&self.client, request)
+    }
+
+    /// Gets security settings of current connection.
+    ///
+    /// This returns the negotiated security policy and message security mode, which may be used to
+    /// audit the connection.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    pub fn security_info(&self) -> Result<ua::SecurityInfo> {
+        self.client.security_info()
+    }
+
+    /// Gets session settings of current connection.
+    ///
+    /// This returns the endpoint URL and the server's application description, which may be used
+    /// to log which server the client ended up with. Unlike [`state()`](Self::state), this refers
+    /// to the current session and changes when the session is re-created after reconnecting.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client has no current session.
+    pub fn session_info(&self) -> Result<ua::SessionInfo> {
+        self.client.session_info()
+    }
+
+    /// Changes user identity of current session.
+    ///
+    /// This activates the current session again with the given user identity token, e.g. when an
+    /// operator logs in or out. The secure channel, the session and its subscriptions are kept. The
+    /// token is also used when the session is activated again after reconnecting.
+    ///
+    /// Note: When the server rejects the new identity, `open62541` closes the session and the
+    /// connection. The previous token is restored in the client config but the client must be
+    /// connected again.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the session is not activated or when the server rejects the new identity.
+    /// The error carries the status code from the server, e.g. `BadIdentityTokenRejected`.
+    ///
+    /// This fails with `BadSessionIdInvalid` when the server no longer knew the session. Then
+    /// `open62541` has created a new session with the new identity instead, so the subscriptions
+    /// of the previous session may have been lost.
+    pub async fn change_identity(&self, user_identity_token: &ua::UserIdentityToken) -> Result<()> {
+        use futures_util::{
+            future::{self, Either},
+            StreamExt as _,
+        };
+
+        if !self.client.is_session_activated() {
+            return Err(Error::new(ua::StatusCode::BADSESSIONNOTACTIVATED));
+        }
+        let (authentication_token, _) = self.client.session_authentication_token()?;
+
+        // Subscribe before activating to not miss any state changes.
+        let mut state_stream = self.state_stream();
+
+        let connection_lost = |state: Option<ua::ClientState>| {
+            Error::new(state.map_or(ua::StatusCode::BADCONNECTIONCLOSED, |state| {
+                state.connect_status
+            }))
+        };
+
+        // The request is sent from the event loop. When the connection is lost before that, we stop
+        // waiting. Dropping the future then makes sure that the request is not sent later on.
+        let mut activate = pin!(self
+            .client
+            .activate_current_session_with(user_identity_token.to_extension_object()));
+        loop {
+            match future::select(activate.as_mut(), state_stream.next()).await {
+                Either::Left((result, _)) => break result?,
+                Either::Right((Some(state), _)) if state.connect_status.is_good() => {}
+                Either::Right((state, _)) => return Err(connection_lost(state)),
+            }
+        }
+
+        // Sending the request has moved the session out of the activated state. So the session is
+        // activated again when the server has accepted the new identity. When the server rejects
+        // it, the previous token is restored by the state callback before the status is reported.
+        while !self.client.is_session_activated() {
+            match state_stream.next().await {
+                Some(state) if state.connect_status.is_good() => {}
+                state => return Err(connection_lost(state)),
+            }
+        }
+
+        let (new_authentication_token, _) = self.client.session_authentication_token()?;
+        if new_authentication_token != authentication_token {
+            log::warn!("Session has been replaced while changing user identity");
+            return Err(Error::new(ua::StatusCode::BADSESSIONIDINVALID));
+        }
+
+        Ok(())
+    }
+
     /// Disconnects from endpoint.
     ///
     /// This consumes the client and handles the graceful shutdown of the connection. This should be
     /// preferred over simply dropping the instance to give the server a chance to clean up and also
     /// to avoid blocking unexpectedly when the client is being dropped without calling this method.
     pub async fn disconnect(mut self) {
+        self.start_disconnect();
+
+        // Wait for background task to complete. Since `join_background_task()` blocks, we must wait
+        // in a separate tokio task. We ignore the result (since we do not care if the task panicked
+        // and there is nothing else it returns).
+        //
+        // Note: We do _not_ cancel the background task before blocking: we require the asynchronous
+        // handling to keep on running until the connection has been taken down which then makes the
+        // task finish by itself.
+        let _unused = task::spawn_blocking(move || self.join_background_task(false)).await;
+    }
+
+    /// Disconnects from endpoint, with timeout.
+    ///
+    /// This is like [`disconnect()`](Self::disconnect) but gives up waiting for the graceful
+    /// shutdown of the connection after `timeout`, e.g. when the server does not respond to closing
+    /// the session. The background task is then cancelled and the connection is closed without
+    /// waiting for the server, as when dropping the client.
+    ///
+    /// Returns `true` when the connection was shut down gracefully, and `false` when the shutdown
+    /// had to be forced.
+    pub async fn disconnect_timeout(mut self, timeout: Duration) -> bool {
+        self.start_disconnect();
+
+        let background_cancelled = Arc::clone(&self.background_cancelled);
+        let mut join_handle = task::spawn_blocking(move || self.join_background_task(false));
+
+        if tokio::time::timeout(timeout, &mut join_handle)
+            .await
+            .is_ok()
+        {
+            return true;
+        }
+
+        log::warn!("Disconnecting timed out, cancelling background task");
+
+        // The background task checks the cancellation token after each iteration of the event loop,
+        // so it finishes shortly.
+        background_cancelled.store(true, Ordering::Relaxed);
+        let _unused = join_handle.await;
+
+        false
+    }
+
+    /// Starts graceful shutdown of the connection.
+    ///
+    /// The connection is taken down by the background task while it runs the event loop.
+    fn start_disconnect(&self) {
         log::info!("Disconnecting from endpoint");
 
+        if let Some(reconnect) = ClientContext::get(&self.client).reconnect() {
+            reconnect.set_disconnecting();
+        }
+
         let status_code = ua::StatusCode::new(unsafe {
             UA_Client_disconnectAsync(
                 // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
@@ -141,15 +507,6 @@ impl AsyncClient {
         if let Err(error) = Error::verify_good(&status_code) {
             log::warn!("Error while disconnecting client: {error}");
         }
-
-        // Wait for background task to complete. Since `join_background_task()` blocks, we must wait
-        // in a separate tokio task. We ignore the result (since we do not care if the task panicked
-        // and there is nothing else it returns).
-        //
-        // Note: We do _not_ cancel the background task before blocking: we require the asynchronous
-        // handling to keep on running until the connection has been taken down which then makes the
-        // task finish by itself.
-        let _unused = task::spawn_blocking(move || self.join_background_task(false)).await;
     }
 
     /// Reads node value.
@@ -168,6 +525,214 @@ impl AsyncClient {
         self.read_attribute(node_id, ua::AttributeId::VALUE_T).await
     }
 
+    /// Reads node value as scalar of specific data type.
+    ///
+    /// This is like [`read_value()`](Self::read_value) but also converts the value. This works with
+    /// any [`DataType`], e.g. built-in types such as [`ua::Int32`] or [`ua::String`]:
+    ///
+    /// ```no_run
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(client: &AsyncClient) -> open62541::Result<()> {
+    /// let current_time: ua::DateTime = client
+    ///     .read_value_as(&ua::NodeId::ns0_server_status_current_time())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read. When the value
+    /// is not a scalar of the requested type, [`Error::TypeMismatch`] is returned with the type of
+    /// the actual value.
+    pub async fn read_value_as<T: DataType>(&self, node_id: &ua::NodeId) -> Result<T> {
+        let value = self.read_value(node_id).await?;
+        value.value().to_scalar::<T>().ok_or_else(|| {
+            let error = TypeMismatchError::new::<T>(node_id.clone(), value.value(), false);
+            Error::TypeMismatch(Box::new(error))
+        })
+    }
+
+    /// Reads node value as array of specific data type.
+    ///
+    /// This is like [`read_value_as()`](Self::read_value_as) but for array values.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read. When the value
+    /// is not an array of the requested type, [`Error::TypeMismatch`] is returned with the type of
+    /// the actual value.
+    pub async fn read_array_value_as<T: DataType>(&self, node_id: &ua::NodeId) -> Result<Vec<T>> {
+        let value = self.read_value(node_id).await?;
+        let Some(array) = value.value().to_array::<T>() else {
+            let error = TypeMismatchError::new::<T>(node_id.clone(), value.value(), true);
+            return Err(Error::TypeMismatch(Box::new(error)));
+        };
+        Ok(array.into_vec())
+    }
+
+    /// Reads values of several nodes.
+    ///
+    /// This issues only a single request to the OPC UA server (and should be preferred over several
+    /// individual requests with [`read_value()`] when reading multiple nodes).
+    ///
+    /// The size and order of the result list matches the size and order of the given node ID list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or its value
+    /// attribute cannot be read, an inner `Err` is returned.
+    ///
+    /// [`read_value()`]: Self::read_value
+    pub async fn read_values(
+        &self,
+        node_ids: &[ua::NodeId],
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        // TODO: Avoid cloning, use `AsRef` in `read_many_attributes()`?
+        self.read_many_attributes(
+            &node_ids
+                .iter()
+                .map(|node_id| (node_id.clone(), ua::AttributeId::VALUE))
+                .collect::<Vec<_>>(),
+        )
+        .await
+    }
+
+    /// Reads node value, with options.
+    ///
+    /// This is like [`read_value()`](Self::read_value) but allows the server to return a cached
+    /// value and selects the timestamps to return, see [`ReadOptions`].
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read.
+    pub async fn read_value_with_options(
+        &self,
+        node_id: &ua::NodeId,
+        options: &ReadOptions,
+    ) -> Result<DataValue<ua::Variant>> {
+        let mut values = self
+            .read_many_attributes_with_options(
+                &[(node_id.clone(), ua::AttributeId::VALUE)],
+                options,
+            )
+            .await?;
+
+        // ERROR: We give a slice with one item to `read_many_attributes_with_options()` and expect
+        // a single result value.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("should contain exactly one attribute"));
+        };
+        value
+    }
+
+    /// Reads part of node value.
+    ///
+    /// This reads only the elements of the node's array value that are selected by `index_range`,
+    /// e.g. `2:5` for the elements at indices 2 to 5 (inclusive). Multi-dimensional ranges select
+    /// elements from each dimension of the array value.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read. When the value
+    /// is not an array or the range lies outside of its bounds, the server returns an error such as
+    /// [`ua::StatusCode::BADINDEXRANGENODATA`].
+    pub async fn read_value_range(
+        &self,
+        node_id: &ua::NodeId,
+        index_range: &ua::NumericRange,
+    ) -> Result<DataValue<ua::Variant>> {
+        let request = ua::ReadRequest::init()
+            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
+            .with_nodes_to_read(&[ua::ReadValueId::init()
+                .with_node_id(node_id)
+                .with_attribute_id(&ua::AttributeId::VALUE)
+                .with_index_range(&index_range.to_ua_string())]);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("read should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("read should return a result"));
+        };
+
+        result.to_generic::<ua::Variant>()
+    }
+
+    /// Reads node value, using local cache.
+    ///
+    /// This returns the cached value when its server timestamp is not older than `max_staleness`.
+    /// Otherwise, the value is read with the request's `maxAge` set to `max_staleness`, i.e. the
+    /// server may in turn return a value from its own cache, and the local cache is updated.
+    ///
+    /// The age of cached values is measured with the local system clock, so the clocks of client
+    /// and server should be synchronized. Values without server timestamp are never served from
+    /// the cache. The cache is cleared when the session is re-established after reconnecting, and
+    /// entries are removed when the value is written with [`write_value()`].
+    ///
+    /// Use [`read_value()`] to bypass the cache.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be read.
+    ///
+    /// [`read_value()`]: Self::read_value
+    /// [`write_value()`]: Self::write_value
+    pub async fn read_value_cached(
+        &self,
+        node_id: &ua::NodeId,
+        max_staleness: Duration,
+    ) -> Result<DataValue<ua::Variant>> {
+        let session_generation = self.session_generation.load(Ordering::Relaxed);
+
+        let write_epoch = {
+            // PANIC: We never panic while holding the lock.
+            let mut value_cache = self.value_cache.lock().unwrap();
+            if let Some(value) = value_cache.get(session_generation, node_id, max_staleness) {
+                log::debug!("Serving value of {node_id} from cache");
+                return Ok(value.clone());
+            }
+            // Remember epoch before reading to detect writes that happen while reading.
+            value_cache.write_epoch()
+        };
+
+        log::debug!("Reading value of {node_id} (not cached or stale)");
+
+        let mut values = read_many_attributes_with_options(
+            &self.client,
+            &[(node_id.clone(), ua::AttributeId::VALUE)],
+            &ReadOptions {
+                max_age: Some(max_staleness),
+                ..ReadOptions::default()
+            },
+        )
+        .await?;
+
+        // ERROR: We give a slice with one item to `read_many_attributes_with_options()` and expect
+        // a single result value.
+        let Some(value) = values.pop() else {
+            return Err(Error::internal("should contain exactly one attribute"));
+        };
+        let value = value?;
+
+        // Do not cache value from previous session when we have reconnected while reading.
+        if self.session_generation.load(Ordering::Relaxed) == session_generation {
+            // PANIC: We never panic while holding the lock.
+            self.value_cache.lock().unwrap().insert(
+                session_generation,
+                write_epoch,
+                node_id.clone(),
+                value.clone(),
+            );
+        }
+
+        Ok(value)
+    }
+
     /// Reads node attribute.
     ///
     /// To read only the value attribute, you can also use [`read_value()`].
@@ -239,353 +804,1961 @@ impl AsyncClient {
         &self,
         node_attributes: &[(ua::NodeId, ua::AttributeId)],
     ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
-        let nodes_to_read: Vec<_> = node_attributes
-            .iter()
-            .map(|(node_id, attribute_id)| {
-                ua::ReadValueId::init()
-                    .with_node_id(node_id)
-                    .with_attribute_id(attribute_id)
-            })
-            .collect();
-
-        let request = ua::ReadRequest::init()
-            // TODO: Add method argument for this? We return timestamps in `DataValue` and they
-            // should not end up always being `None` by default.
-            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
-            .with_nodes_to_read(&nodes_to_read);
-
-        let response = service_request(&self.client, request).await?;
-
-        let Some(results) = response.results() else {
-            return Err(Error::internal("read should return results"));
-        };
+        read_many_attributes(&self.client, node_attributes).await
+    }
 
-        let results: Vec<_> = results
-            .iter()
-            .map(ua::DataValue::to_generic::<ua::Variant>)
-            .collect();
+    /// Reads a combination of node attributes, with options.
+    ///
+    /// This is like [`read_many_attributes()`](Self::read_many_attributes) but allows the server to
+    /// return cached values and selects the timestamps to return, see [`ReadOptions`].
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or one of the
+    /// attributes cannot be read, an inner `Err` is returned.
+    pub async fn read_many_attributes_with_options(
+        &self,
+        node_attributes: &[(ua::NodeId, ua::AttributeId)],
+        options: &ReadOptions,
+    ) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+        read_many_attributes_with_options(&self.client, node_attributes, options).await
+    }
 
-        // The OPC UA specification state that the resulting list has the same number of elements as
-        // the request list. If not, we would not be able to match elements in the two lists anyway.
-        if results.len() != node_attributes.len() {
-            return Err(Error::internal("unexpected number of read results"));
-        }
+    /// Reads minimum sampling interval of variable node.
+    ///
+    /// This is the fastest rate at which the server can sample the node's value. Requesting shorter
+    /// sampling intervals for monitored items has no effect, see
+    /// [`MonitoredItemBuilder::clamp_to_minimum_sampling_interval()`].
+    ///
+    /// Returns `None` when the server does not know the minimum sampling interval. A value of zero
+    /// indicates that the server monitors the value continuously.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or is not a variable node.
+    ///
+    /// [`MonitoredItemBuilder::clamp_to_minimum_sampling_interval()`]: crate::MonitoredItemBuilder::clamp_to_minimum_sampling_interval
+    pub async fn read_minimum_sampling_interval(
+        &self,
+        node_id: &ua::NodeId,
+    ) -> Result<Option<Duration>> {
+        let value = self
+            .read_attribute(node_id, ua::AttributeId::MINIMUMSAMPLINGINTERVAL_T)
+            .await?;
 
-        Ok(results)
+        Ok(minimum_sampling_interval(value.value()))
     }
 
-    /// Writes node value.
+    /// Reads names of option set bits.
+    ///
+    /// This reads the `OptionSetValues` property of the given data type (or `EnumStrings` when it
+    /// does not exist). The name at index `i` belongs to bit `i`, see
+    /// [`ua::OptionSet::set_names()`].
+    ///
+    /// The names are cached per data type, i.e. only the first call for a data type sends requests
+    /// to the server.
     ///
     /// # Errors
     ///
-    /// This fails when the node does not exist or its value attribute cannot be written.
-    pub async fn write_value(&self, node_id: &ua::NodeId, value: &ua::DataValue) -> Result<()> {
-        let attribute_id = ua::AttributeId::VALUE;
-
-        let request = ua::WriteRequest::init().with_nodes_to_write(&[ua::WriteValue::init()
-            .with_node_id(node_id)
-            .with_attribute_id(&attribute_id)
-            .with_value(value)]);
+    /// This fails when the data type does not exist or has neither of the properties.
+    pub async fn read_option_set_names(
+        &self,
+        data_type_id: &ua::NodeId,
+    ) -> Result<Vec<ua::LocalizedText>> {
+        // PANIC: We never panic while holding the lock.
+        if let Some(names) = self.option_set_names.lock().unwrap().get(data_type_id) {
+            return Ok(names.clone());
+        }
 
-        let response = service_request(&self.client, request).await?;
+        let (references, _) = self
+            .browse(
+                &ua::BrowseDescription::default()
+                    .with_node_id(data_type_id)
+                    .with_reference_type_id(&ua::NodeId::ns0_has_property()),
+            )
+            .await?;
 
-        let Some(results) = response.results() else {
-            return Err(Error::internal("write should return results"));
+        let Some(property) = ["OptionSetValues", "EnumStrings"].iter().find_map(|name| {
+            let browse_name = ua::QualifiedName::ns0(name);
+            references
+                .iter()
+                .find(|reference| reference.browse_name() == &browse_name)
+        }) else {
+            return Err(Error::new(ua::StatusCode::BADNOTFOUND));
         };
 
-        let Some(result) = results.as_slice().first() else {
-            return Err(Error::internal("write should return a result"));
+        let value = self.read_value(property.node_id().node_id()).await?;
+        let Some(names) = value.value().to_array::<ua::LocalizedText>() else {
+            return Err(Error::internal("names should be array of localized texts"));
         };
+        let names = names.into_vec();
 
-        Error::verify_good(result)?;
+        // PANIC: We never panic while holding the lock.
+        self.option_set_names
+            .lock()
+            .unwrap()
+            .insert(data_type_id.clone(), names.clone());
 
-        Ok(())
+        Ok(names)
     }
 
-    /// Calls specific method node at object node.
+    /// Reads namespace array of server.
+    ///
+    /// The namespace URI at index `i` belongs to namespace index `i`. Since the server may add
+    /// namespaces at runtime, this always reads the current array. It also refreshes the cache that
+    /// is used by [`namespace_index()`](Self::namespace_index).
     ///
     /// # Errors
     ///
-    /// This fails when the object or method node does not exist, the method cannot be called, or
-    /// the input arguments are unexpected.
-    pub async fn call_method(
-        &self,
-        object_id: &ua::NodeId,
-        method_id: &ua::NodeId,
-        input_arguments: &[ua::Variant],
-    ) -> Result<Vec<ua::Variant>> {
-        let request =
-            ua::CallRequest::init().with_methods_to_call(&[ua::CallMethodRequest::init()
-                .with_object_id(object_id)
-                .with_method_id(method_id)
-                .with_input_arguments(input_arguments)]);
+    /// This fails when the namespace array cannot be read.
+    pub async fn namespace_array(&self) -> Result<Vec<String>> {
+        let session_generation = self.session_generation.load(Ordering::Relaxed);
 
-        let response = service_request(&self.client, request).await?;
-
-        let Some(results) = response.results() else {
-            return Err(Error::internal("call should return results"));
+        let value = self
+            .read_value(&ua::NodeId::ns0_server_namespace_array())
+            .await?;
+        let Some(namespace_array) = value.value().to_array::<ua::String>() else {
+            return Err(Error::internal(
+                "namespace array should be array of strings",
+            ));
         };
+        let namespace_array: Vec<_> = namespace_array
+            .iter()
+            .map(|namespace_uri| namespace_uri.as_str().unwrap_or_default().to_owned())
+            .collect();
 
-        let Some(result) = results.as_slice().first() else {
-            return Err(Error::internal("call should return a result"));
-        };
+        // PANIC: We never panic while holding the lock.
+        *self.namespace_array.lock().unwrap() = Some((session_generation, namespace_array.clone()));
 
-        Error::verify_good(&result.status_code())?;
+        Ok(namespace_array)
+    }
 
-        let output_arguments = if let Some(output_arguments) = result.output_arguments() {
-            output_arguments.into_vec()
-        } else {
-            log::debug!("Calling {method_id} returned unset output arguments, assuming none exist");
-            Vec::new()
+    /// Gets namespace index of namespace URI.
+    ///
+    /// Namespace indices are specific to the server and may change when the server is restarted, so
+    /// they should not be hard-coded. This looks up the namespace URI in the cached namespace
+    /// array. When it is not found there, or the session has been re-established since, the
+    /// namespace array is read again.
+    ///
+    /// Returns `None` when the server does not know the namespace URI.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the namespace array cannot be read.
+    pub async fn namespace_index(&self, namespace_uri: &str) -> Result<Option<u16>> {
+        let session_generation = self.session_generation.load(Ordering::Relaxed);
+
+        let cached = {
+            // PANIC: We never panic while holding the lock.
+            let namespace_array = self.namespace_array.lock().unwrap();
+            namespace_array
+                .as_ref()
+                .filter(|(generation, _)| *generation == session_generation)
+                .and_then(|(_, namespace_array)| find_namespace(namespace_array, namespace_uri))
         };
+        if let Some(namespace_index) = cached {
+            return Ok(Some(namespace_index));
+        }
 
-        Ok(output_arguments)
+        let namespace_array = self.namespace_array().await?;
+        Ok(find_namespace(&namespace_array, namespace_uri))
     }
 
-    /// Browses specific node.
-    ///
-    /// Use [`ua::BrowseDescription::default()`](ua::BrowseDescription) to set sensible defaults to
-    /// browse a specific node's children (forward references of the `HierarchicalReferences` type)
-    /// like this:
+    /// Gets node ID from namespace URI and identifier.
     ///
-    /// ```
-    /// # use open62541::{AsyncClient, Result, ua};
-    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS;
+    /// The identifier uses the format of node IDs without namespace, e.g. `i=1234` or `s=Machine`.
+    /// The namespace URI is resolved to the namespace index on the server with
+    /// [`namespace_index()`](Self::namespace_index).
     ///
-    /// # async fn example(client: &AsyncClient) -> Result<()> {
-    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS);
-    /// let browse_description = ua::BrowseDescription::default().with_node_id(&node_id);
-    /// let (references, continuation_point) = client.browse(&browse_description).await?;
+    /// ```no_run
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(client: &AsyncClient) -> open62541::Result<()> {
+    /// let node_id = client
+    ///     .node_id_from_uri("http://example.com/machines/", "s=Machine1")
+    ///     .await?
+    ///     .expect("namespace should exist");
     /// # Ok(())
     /// # }
     /// ```
     ///
+    /// Returns `None` when the server does not know the namespace URI.
+    ///
     /// # Errors
     ///
-    /// This fails when the node does not exist or it cannot be browsed.
-    pub async fn browse(&self, browse_description: &ua::BrowseDescription) -> BrowseResult {
-        let request =
-            ua::BrowseRequest::init().with_nodes_to_browse(slice::from_ref(browse_description));
-
-        let response = service_request(&self.client, request).await?;
-
-        let Some(results) = response.results() else {
-            return Err(Error::internal("browse should return results"));
-        };
-
-        let Some(result) = results.as_slice().first() else {
-            return Err(Error::internal("browse should return a result"));
+    /// This fails when the namespace array cannot be read or the identifier is invalid.
+    pub async fn node_id_from_uri(
+        &self,
+        namespace_uri: &str,
+        identifier: &str,
+    ) -> Result<Option<ua::NodeId>> {
+        let Some(namespace_index) = self.namespace_index(namespace_uri).await? else {
+            return Ok(None);
         };
-
-        to_browse_result(result, Some(browse_description.node_id()))
+        let node_id = format!("ns={namespace_index};{identifier}").parse()?;
+        Ok(Some(node_id))
     }
 
-    /// Browses several nodes at once.
-    ///
-    /// This issues only a single request to the OPC UA server (and should be preferred over several
-    /// individual requests with [`browse()`] when browsing multiple nodes).
+    /// Reads raw or modified historical values.
     ///
-    /// The size and order of the result list matches the size and order of the given node ID list.
+    /// The size and order of the result list matches the size and order of the given node list.
+    /// Each result holds the values of the node and, when not all values could be returned at once,
+    /// a continuation point. Pass it to [`ua::HistoryReadValueId::with_continuation_point()`] in a
+    /// follow-up call (with the same `details`) to get the next values.
     ///
     /// # Errors
     ///
-    /// This fails only when the entire request fails. When a node does not exist or cannot be
-    /// browsed, an inner `Err` is returned.
-    ///
-    /// [`browse()`]: Self::browse
-    pub async fn browse_many(
+    /// This fails only when the entire request fails. When a node does not exist or has no history,
+    /// an inner `Err` is returned.
+    pub async fn history_read_raw(
         &self,
-        browse_descriptions: &[ua::BrowseDescription],
-    ) -> Result<Vec<BrowseResult>> {
-        let request = ua::BrowseRequest::init().with_nodes_to_browse(browse_descriptions);
+        details: &ua::ReadRawModifiedDetails,
+        nodes_to_read: &[ua::HistoryReadValueId],
+    ) -> Result<Vec<Result<(ua::HistoryData, Option<ua::ContinuationPoint>)>>> {
+        let request = ua::HistoryReadRequest::init()
+            .with_read_raw_modified_details(details)
+            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
+            .with_nodes_to_read(nodes_to_read);
 
         let response = service_request(&self.client, request).await?;
 
         let Some(results) = response.results() else {
-            return Err(Error::internal("browse should return results"));
+            return Err(Error::internal("history read should return results"));
         };
 
-        // The OPC UA specification state that the resulting list has the same number of elements as
-        // the request list. If not, we would not be able to match elements in the two lists anyway.
-        if results.len() != browse_descriptions.len() {
-            return Err(Error::internal("unexpected number of browse results"));
-        }
+        verify_result_count(
+            results.len(),
+            nodes_to_read.len(),
+            "unexpected number of history read results",
+        )?;
 
-        let results: Vec<_> = results
+        let results = results
             .iter()
-            .zip(browse_descriptions)
-            .map(|(result, browse_description)| {
-                to_browse_result(result, Some(browse_description.node_id()))
+            .map(|result| {
+                Error::verify_good(&result.status_code())?;
+                let Some(history_data) = result.history_data() else {
+                    return Err(Error::internal("history read should return history data"));
+                };
+                Ok((history_data.clone(), result.continuation_point()))
             })
             .collect();
 
         Ok(results)
     }
 
-    /// Browses continuation points for more references.
+    /// Reads aggregated historical values.
     ///
-    /// This uses continuation points returned from [`browse()`] and [`browse_many()`] whenever not
-    /// all references were returned (due to client or server limits).
+    /// The server computes the aggregates given in `details` over consecutive intervals of the
+    /// processing interval. [`ua::ReadProcessedDetails::with_aggregate_type()`] must have one entry
+    /// per node. The size and order of the result list matches the size and order of the given node
+    /// list. Each result holds one value per interval.
     ///
-    /// The size and order of the result list matches the size and order of the given continuation
-    /// point list.
+    /// Values are returned as received, with their status intact: intervals for which the aggregate
+    /// cannot be computed (or only with uncertainty) have a bad or uncertain status, see
+    /// [`ua::DataValue::status()`]. These do not fail the node.
     ///
     /// # Errors
     ///
-    /// This fails only when the entire request fails. When a continuation point is invalid, an
-    /// inner `Err` is returned.
-    ///
-    /// [`browse()`]: Self::browse
-    /// [`browse_many()`]: Self::browse_many
-    pub async fn browse_next(
+    /// This fails only when the entire request fails. When a node does not exist, has no history or
+    /// does not support the aggregate, an inner `Err` is returned. An inner `Err` is also returned
+    /// when the server cannot return all values at once, i.e. when the time range should be split.
+    pub async fn history_read_processed(
         &self,
-        continuation_points: &[ua::ContinuationPoint],
-    ) -> Result<Vec<BrowseResult>> {
-        let request = ua::BrowseNextRequest::init().with_continuation_points(continuation_points);
+        details: &ua::ReadProcessedDetails,
+        node_ids: &[ua::NodeId],
+    ) -> Result<Vec<Result<Vec<ua::DataValue>>>> {
+        let nodes_to_read: Vec<_> = node_ids
+            .iter()
+            .map(|node_id| ua::HistoryReadValueId::init().with_node_id(node_id))
+            .collect();
+
+        let request = ua::HistoryReadRequest::init()
+            .with_read_processed_details(details)
+            .with_timestamps_to_return(&ua::TimestampsToReturn::BOTH)
+            .with_nodes_to_read(&nodes_to_read);
 
         let response = service_request(&self.client, request).await?;
 
         let Some(results) = response.results() else {
-            return Err(Error::internal("browse should return results"));
+            return Err(Error::internal("history read should return results"));
         };
 
-        // The OPC UA specification state that the resulting list has the same number of elements as
-        // the request list. If not, we would not be able to match elements in the two lists anyway.
-        if results.len() != continuation_points.len() {
-            return Err(Error::Internal("unexpected number of browse results"));
+        verify_result_count(
+            results.len(),
+            nodes_to_read.len(),
+            "unexpected number of history read results",
+        )?;
+
+        // We do not follow continuation points. Release them to free resources on the server.
+        let continued_nodes: Vec<_> = nodes_to_read
+            .iter()
+            .zip(results.iter())
+            .filter_map(|(node_to_read, result)| {
+                let continuation_point = result.continuation_point()?;
+                Some(
+                    node_to_read
+                        .clone()
+                        .with_continuation_point(&continuation_point),
+                )
+            })
+            .collect();
+        if !continued_nodes.is_empty() {
+            let request = ua::HistoryReadRequest::init()
+                .with_read_processed_details(details)
+                .with_release_continuation_points(true)
+                .with_nodes_to_read(&continued_nodes);
+            if let Err(error) = service_request(&self.client, request).await {
+                log::warn!("Unable to release history continuation points: {error}");
+            }
         }
 
-        let results: Vec<_> = results
+        let results = results
             .iter()
-            .map(|result| to_browse_result(result, None))
+            .map(|result| {
+                Error::verify_good(&result.status_code())?;
+                if result.continuation_point().is_some() {
+                    return Err(Error::internal(
+                        "processed history read should return all values",
+                    ));
+                }
+                let Some(history_data) = result.history_data() else {
+                    return Err(Error::internal("history read should return history data"));
+                };
+                Ok(history_data.data_values().unwrap_or_default().to_vec())
+            })
             .collect();
 
         Ok(results)
     }
 
-    /// Creates new [subscription](AsyncSubscription).
+    /// Inserts historical values.
+    ///
+    /// Values are only inserted when the node's history has no value at the same source timestamp
+    /// yet. The size and order of the result list matches the size and order of the given values.
     ///
     /// # Errors
     ///
-    /// This fails when the client is not connected.
-    pub async fn create_subscription(&self) -> Result<AsyncSubscription> {
-        let (_, subscription) = SubscriptionBuilder::default().create(self).await?;
-
-        Ok(subscription)
-    }
+    /// This fails when the entire request fails, e.g. when the node does not exist or has no
+    /// history. When the server rejects individual values, an inner `Err` is returned, e.g. with
+    /// [`ua::StatusCode::BADENTRYEXISTS`].
+    pub async fn history_update_insert(
+        &self,
+        node_id: &ua::NodeId,
+        values: &[ua::DataValue],
+    ) -> Result<Vec<Result<()>>> {
+        let details = ua::UpdateDataDetails::init()
+            .with_node_id(node_id)
+            .with_perform_insert_replace(&ua::PerformUpdateType::INSERT)
+            .with_update_values(values);
 
-    pub(crate) const fn client(&self) -> &Arc<ua::Client> {
-        &self.client
-    }
-}
+        let result = self
+            .history_update(ua::ExtensionObject::new(&details))
+            .await?;
 
-impl Drop for AsyncClient {
-    fn drop(&mut self) {
-        // We need to wait for the task to finish and must do so blockingly. `UA_Client_delete()` is
-        // not safe run concurrently while `UA_Client_run_iterate()` is still running.
-        //
-        // Notify background task to cancel itself, even when [`UA_Client_run_iterate()`] would want
-        // to keep on running. This is okay: we are not issuing asynchronous requests anymore anyway
-        // (the only other call will be `UA_Client_delete()` when inner client drops).
-        self.join_background_task(true);
+        to_status_results(
+            result.operation_results(),
+            values.len(),
+            "unexpected number of history update operation results",
+        )
     }
-}
-
-/// Background task for [`ua::Client`].
-///
-/// This runs [`UA_Client_run_iterate()`] in a loop, blocking for up to `RUN_ITERATE_TIMEOUT` during
-/// each iteration. In case the loop does not finish by itself (which happens in case of disconnects
-/// and for final connection failures), the cancellation token `cancel` can be used to stop the task
-/// from the outside before the next loop iteration.
-fn background_task(client: &ua::Client, cancelled: &AtomicBool) {
-    log::info!("Starting background task");
-
-    // `UA_Client_run_iterate()` expects the timeout to be given in milliseconds.
-    let timeout_millis = u32::try_from(RUN_ITERATE_TIMEOUT.as_millis()).unwrap_or(u32::MAX);
 
-    // Run until cancelled. The only other way to exit is when `UA_Client_run_iterate()` fails which
-    // happens when the connection is broken and the client instance cannot be used anymore.
-    while !cancelled.load(Ordering::Relaxed) {
-        // Track time of iteration start to report iteration times below.
-        let start_of_iteration = Instant::now();
+    /// Deletes historical values.
+    ///
+    /// This deletes all raw values of the node with source timestamps from `start` (inclusive) to
+    /// `end` (exclusive).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist, has no history or when the values cannot be
+    /// deleted.
+    pub async fn history_delete_raw(
+        &self,
+        node_id: &ua::NodeId,
+        start: ua::DateTime,
+        end: ua::DateTime,
+    ) -> Result<()> {
+        let details = ua::DeleteRawModifiedDetails::init()
+            .with_node_id(node_id)
+            .with_start_time(&start)
+            .with_end_time(&end);
 
-        let status_code = ua::StatusCode::new({
-            log::trace!("Running iterate");
-
-            // This returns after the timeout even when nothing was processed. The internal mutex is
-            // _not_ held for the entire time though, so we can send out requests concurrently while
-            // the client is running the iteration.
-            unsafe {
-                UA_Client_run_iterate(
-                    // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
-                    client.as_ptr().cast_mut(),
-                    timeout_millis,
-                )
-            }
-        });
-        if let Err(error) = Error::verify_good(&status_code) {
-            // Context-sensitive handling of bad status codes.
-            match status_code.into_raw() {
-                UA_STATUSCODE_BADDISCONNECT => {
-                    // Not an error.
-                    log::info!("Terminating background task after disconnect");
-                }
-                UA_STATUSCODE_BADCONNECTIONCLOSED => {
-                    // Not an error.
-                    log::info!("Terminating background task after connection closed");
-                }
-                _ => {
-                    // Unexpected error.
-                    log::error!("Terminating background task: run failed with {error}");
-                }
-            }
-            return;
-        }
+        self.history_update(ua::ExtensionObject::new(&details))
+            .await?;
 
-        let time_taken = start_of_iteration.elapsed();
-        log::trace!("Iterate run took {time_taken:?}");
+        Ok(())
     }
 
-    log::info!("Terminating cancelled background task");
-}
-
-async fn service_request<R: ServiceRequest>(
-    client: &ua::Client,
-    request: R,
-) -> Result<R::Response> {
-    type Cb<R> = CallbackOnce<std::result::Result<<R as ServiceRequest>::Response, ua::StatusCode>>;
+    /// Sends single history update.
+    async fn history_update(
+        &self,
+        details: ua::ExtensionObject,
+    ) -> Result<ua::HistoryUpdateResult> {
+        let request = ua::HistoryUpdateRequest::init().with_history_update_details(&[details]);
 
-    unsafe extern "C" fn callback_c<R: ServiceRequest>(
-        _client: *mut UA_Client,
-        userdata: *mut c_void,
-        request_id: UA_UInt32,
-        response: *mut c_void,
-    ) {
-        log::trace!(
-            "Request ID {request_id} finished, received {}",
-            R::Response::type_name(),
-        );
+        let response = service_request(&self.client, request).await?;
 
-        // SAFETY: Incoming pointer is valid for access.
-        // PANIC: We expect pointer to be valid when good.
-        let response = unsafe { response.cast::<<R::Response as DataType>::Inner>().as_ref() }
-            .expect("response should be set");
-        let response = R::Response::clone_raw(response);
+        let Some(results) = response.results() else {
+            return Err(Error::internal("history update should return results"));
+        };
 
-        let status_code = response.service_result();
-        let result = if status_code.is_good() {
-            Ok(response)
-        } else {
-            Err(status_code)
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("history update should return a result"));
         };
 
-        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
-        unsafe {
-            Cb::<R>::execute(userdata, result);
-        }
-    }
+        Error::verify_good(&result.status_code())?;
 
-    let (tx, rx) = oneshot::channel::<Result<R::Response>>();
+        Ok(result.clone())
+    }
+
+    /// Writes node value.
+    ///
+    /// To check the value against the node's data type and value rank before sending the request,
+    /// use [`write_value_checked()`](Self::write_value_checked).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be written. When the
+    /// server rejects the value, [`Error::Write`] is returned with the server's status code, e.g.
+    /// [`ua::StatusCode::BADTYPEMISMATCH`] or [`ua::StatusCode::BADNOTWRITABLE`].
+    pub async fn write_value(&self, node_id: &ua::NodeId, value: &ua::DataValue) -> Result<()> {
+        self.write_attribute(node_id, &ua::AttributeId::VALUE, value)
+            .await
+    }
+
+    /// Writes part of node value.
+    ///
+    /// This writes only the elements of the node's array value that are selected by `index_range`,
+    /// e.g. `2:5` for the elements at indices 2 to 5 (inclusive). The given value must be an array
+    /// with matching dimensions, i.e. it holds exactly the elements to replace.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be written. When the
+    /// server rejects the value, [`Error::Write`] is returned with the server's status code, e.g.
+    /// [`ua::StatusCode::BADINDEXRANGENODATA`] when the range lies outside of the array value.
+    pub async fn write_value_range(
+        &self,
+        node_id: &ua::NodeId,
+        index_range: &ua::NumericRange,
+        value: &ua::DataValue,
+    ) -> Result<()> {
+        let request = ua::WriteRequest::init().with_nodes_to_write(&[ua::WriteValue::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE)
+            .with_index_range(&index_range.to_ua_string())
+            .with_value(value)]);
+
+        let response = service_request(&self.client, request).await;
+
+        // Whether or not the write succeeded, the cached value may be outdated now.
+        // PANIC: We never panic while holding the lock.
+        self.value_cache.lock().unwrap().remove(node_id);
+
+        let response = response?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("write should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("write should return a result"));
+        };
+
+        if !result.is_good() {
+            let error = write_error(node_id, value, result.clone());
+            let diagnostic_info = result_diagnostic_info(&response, response.diagnostic_infos(), 0);
+            return Err(Error::Write(Box::new(error)).with_diagnostic_info(diagnostic_info));
+        }
+
+        Ok(())
+    }
+
+    /// Writes node attribute.
+    ///
+    /// To write only the value attribute, you can also use [`write_value()`](Self::write_value). To
+    /// check the value type at compile time, use [`write_typed_attribute()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or the attribute cannot be written. When the server
+    /// rejects the value, [`Error::Write`] is returned with the server's status code, e.g.
+    /// [`ua::StatusCode::BADNOTWRITABLE`] for read-only attributes.
+    ///
+    /// [`write_typed_attribute()`]: Self::write_typed_attribute
+    pub async fn write_attribute(
+        &self,
+        node_id: &ua::NodeId,
+        attribute_id: &ua::AttributeId,
+        value: &ua::DataValue,
+    ) -> Result<()> {
+        let request = ua::WriteRequest::init().with_nodes_to_write(&[ua::WriteValue::init()
+            .with_node_id(node_id)
+            .with_attribute_id(attribute_id)
+            .with_value(value)]);
+
+        let response = service_request(&self.client, request).await;
+
+        if attribute_id == &ua::AttributeId::VALUE {
+            // Whether or not the write succeeded, the cached value may be outdated now.
+            // PANIC: We never panic while holding the lock.
+            self.value_cache.lock().unwrap().remove(node_id);
+        }
+
+        let response = response?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("write should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("write should return a result"));
+        };
+
+        if !result.is_good() {
+            let error = write_error(node_id, value, result.clone());
+            let diagnostic_info = result_diagnostic_info(&response, response.diagnostic_infos(), 0);
+            return Err(Error::Write(Box::new(error)).with_diagnostic_info(diagnostic_info));
+        }
+
+        Ok(())
+    }
+
+    /// Writes node attribute, with type of value checked at compile time.
+    ///
+    /// This is the counterpart to [`read_attribute()`](Self::read_attribute). Use the `_T` variants
+    /// of attribute IDs to have the value type match the attribute:
+    ///
+    /// ```no_run
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(client: &AsyncClient, node_id: &ua::NodeId) -> anyhow::Result<()> {
+    /// client
+    ///     .write_typed_attribute(
+    ///         node_id,
+    ///         ua::AttributeId::HISTORIZING_T,
+    ///         &ua::Boolean::new(true),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`write_attribute()`](Self::write_attribute).
+    pub async fn write_typed_attribute<T: Attribute>(
+        &self,
+        node_id: &ua::NodeId,
+        attribute: T,
+        value: &T::Value,
+    ) -> Result<()> {
+        let variant = ua::Variant::scalar(value.clone());
+        // Variants cannot contain other variants. Pass on untyped values (e.g. from `VALUE_T`) as
+        // they are.
+        let variant = if T::Value::data_type() == ua::Variant::data_type() {
+            variant.to_scalar::<ua::Variant>().unwrap_or(variant)
+        } else {
+            variant
+        };
+        let value = ua::DataValue::new(variant);
+        self.write_attribute(node_id, &attribute.id(), &value).await
+    }
+
+    /// Writes several node values.
+    ///
+    /// This sends a single request for all values. The size and order of the result list matches
+    /// the size and order of the given list.
+    ///
+    /// To write only a single value, you can also use [`write_value()`](Self::write_value).
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or its value
+    /// attribute cannot be written, an inner `Err` is returned, as described in
+    /// [`write_value()`](Self::write_value).
+    pub async fn write_values(
+        &self,
+        node_values: &[(ua::NodeId, ua::DataValue)],
+    ) -> Result<Vec<Result<()>>> {
+        let attribute_id = ua::AttributeId::VALUE;
+
+        let nodes_to_write: Vec<_> = node_values
+            .iter()
+            .map(|(node_id, value)| {
+                ua::WriteValue::init()
+                    .with_node_id(node_id)
+                    .with_attribute_id(&attribute_id)
+                    .with_value(value)
+            })
+            .collect();
+
+        let request = ua::WriteRequest::init().with_nodes_to_write(&nodes_to_write);
+
+        let response = service_request(&self.client, request).await;
+
+        // Whether or not the writes succeeded, the cached values may be outdated now.
+        {
+            // PANIC: We never panic while holding the lock.
+            let mut value_cache = self.value_cache.lock().unwrap();
+            for (node_id, _) in node_values {
+                value_cache.remove(node_id);
+            }
+        }
+
+        let response = response?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("write should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            node_values.len(),
+            "unexpected number of write results",
+        )?;
+
+        let diagnostic_infos = response.diagnostic_infos();
+        let results = results
+            .into_iter()
+            .zip(node_values)
+            .enumerate()
+            .map(|(index, (result, (node_id, value)))| {
+                if result.is_good() {
+                    Ok(())
+                } else {
+                    let error = Error::Write(Box::new(write_error(node_id, value, result)));
+                    let diagnostic_info =
+                        result_diagnostic_info(&response, diagnostic_infos, index);
+                    Err(error.with_diagnostic_info(diagnostic_info))
+                }
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Writes node value after checking data type and value rank.
+    ///
+    /// This reads the `DataType` and `ValueRank` attributes of the node first and verifies that the
+    /// value matches them, failing with a descriptive error before sending a request that would be
+    /// rejected anyway. The attributes are cached per node, so repeated writes to the same node do
+    /// not cause additional reads.
+    ///
+    /// Only built-in data types are checked. Values for nodes with abstract or structured data
+    /// types are passed on to the server as they are.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its attributes cannot be read, or as described in
+    /// [`write_value()`](Self::write_value). When the value does not match, [`Error::Write`] is
+    /// returned with [`ua::StatusCode::BADTYPEMISMATCH`] and a [reason](WriteError::reason).
+    pub async fn write_value_checked(
+        &self,
+        node_id: &ua::NodeId,
+        value: &ua::DataValue,
+    ) -> Result<()> {
+        let write_target = self.write_target(node_id).await?;
+
+        if let Some(reason) = write_target.check(value.value()) {
+            let error =
+                write_error(node_id, value, ua::StatusCode::BADTYPEMISMATCH).with_reason(reason);
+            return Err(Error::Write(Box::new(error)));
+        }
+
+        self.write_value(node_id, value).await
+    }
+
+    async fn write_target(&self, node_id: &ua::NodeId) -> Result<WriteTarget> {
+        // PANIC: We never panic while holding the lock.
+        if let Some(write_target) = self.write_targets.lock().unwrap().get(node_id) {
+            return Ok(write_target.clone());
+        }
+
+        let mut results = self
+            .read_attributes(
+                node_id,
+                &[ua::AttributeId::DATATYPE, ua::AttributeId::VALUERANK],
+            )
+            .await?
+            .into_iter();
+        let (Some(data_type), Some(value_rank)) = (results.next(), results.next()) else {
+            return Err(Error::internal("should contain exactly two attributes"));
+        };
+
+        let write_target = WriteTarget {
+            data_type: data_type?.into_scalar::<ua::NodeId>()?.into_value(),
+            // The value rank is an `Int32` with special negative values.
+            value_rank: value_rank?.into_scalar::<ua::Int32>()?.into_value().value(),
+        };
+
+        // PANIC: We never panic while holding the lock.
+        self.write_targets
+            .lock()
+            .unwrap()
+            .insert(node_id.clone(), write_target.clone());
+
+        Ok(write_target)
+    }
+
+    /// Calls specific method node at object node.
+    ///
+    /// To call several methods at once, use [`call_methods()`](Self::call_methods).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the object or method node does not exist, the method cannot be called, or
+    /// the input arguments are unexpected. When the server rejects the call, [`Error::Call`] is
+    /// returned with the server's status code and the results of the individual input arguments.
+    pub async fn call_method(
+        &self,
+        object_id: &ua::NodeId,
+        method_id: &ua::NodeId,
+        input_arguments: &[ua::Variant],
+    ) -> Result<Vec<ua::Variant>> {
+        call_method(&self.client, object_id, method_id, input_arguments).await
+    }
+
+    /// Calls specific method node at object node, with typed arguments.
+    ///
+    /// This is like [`call_method()`](Self::call_method) but converts input arguments from Rust
+    /// values, e.g. `bool`, integers, floats, and string slices, and output arguments into the
+    /// requested type, e.g. `Vec<ua::Variant>` or an array of fixed size:
+    ///
+    /// ```no_run
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(
+    /// #     client: &AsyncClient,
+    /// #     object_id: &ua::NodeId,
+    /// #     method_id: &ua::NodeId,
+    /// # ) -> anyhow::Result<()> {
+    /// let [output]: [ua::Variant; 1] = client
+    ///     .call_method_typed(object_id, method_id, [1_i32, 2, 3])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when an input argument cannot be converted, e.g. a string slice with NUL bytes,
+    /// or as described in [`call_method()`](Self::call_method). When the server rejects an input
+    /// argument, e.g. because of a type mismatch, [`Error::Call`] is returned and the index of the
+    /// argument is available from [`CallError::argument_index()`]. When the output arguments cannot
+    /// be converted, an internal error is returned.
+    pub async fn call_method_typed<I, O>(
+        &self,
+        object_id: &ua::NodeId,
+        method_id: &ua::NodeId,
+        input_arguments: I,
+    ) -> Result<O>
+    where
+        I: IntoIterator,
+        I::Item: TryInto<ua::Variant>,
+        Error: From<<I::Item as TryInto<ua::Variant>>::Error>,
+        O: TryFrom<Vec<ua::Variant>>,
+    {
+        let input_arguments = input_arguments
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let output_arguments = self
+            .call_method(object_id, method_id, &input_arguments)
+            .await?;
+
+        O::try_from(output_arguments).map_err(|_| Error::internal("unexpected output arguments"))
+    }
+
+    /// Calls several methods at once.
+    ///
+    /// Each call is given as object node, method node, and input arguments. This issues only a
+    /// single request to the OPC UA server (and should be preferred over several individual
+    /// requests with [`call_method()`] when calling multiple methods).
+    ///
+    /// The size and order of the result list matches the size and order of the given call list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a method cannot be called, an inner
+    /// [`Error::Call`] is returned with the server's status code and the results of the individual
+    /// input arguments.
+    ///
+    /// [`call_method()`]: Self::call_method
+    pub async fn call_methods(
+        &self,
+        method_calls: &[(ua::NodeId, ua::NodeId, Vec<ua::Variant>)],
+    ) -> Result<Vec<Result<Vec<ua::Variant>>>> {
+        let methods_to_call: Vec<_> = method_calls
+            .iter()
+            .map(|(object_id, method_id, input_arguments)| {
+                ua::CallMethodRequest::init()
+                    .with_object_id(object_id)
+                    .with_method_id(method_id)
+                    .with_input_arguments(input_arguments)
+            })
+            .collect();
+
+        let request = ua::CallRequest::init().with_methods_to_call(&methods_to_call);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("call should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            method_calls.len(),
+            "unexpected number of call results",
+        )?;
+
+        let diagnostic_infos = response.diagnostic_infos();
+        let results = results
+            .iter()
+            .zip(method_calls)
+            .enumerate()
+            .map(|(index, (result, (_, method_id, _)))| {
+                to_call_result(result, method_id).map_err(|error| {
+                    error.with_diagnostic_info(result_diagnostic_info(
+                        &response,
+                        diagnostic_infos,
+                        index,
+                    ))
+                })
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Browses specific node.
+    ///
+    /// Use [`ua::BrowseDescription::default()`](ua::BrowseDescription) to set sensible defaults to
+    /// browse a specific node's children (forward references of the `HierarchicalReferences` type)
+    /// like this:
+    ///
+    /// ```
+    /// # use open62541::{AsyncClient, Result, ua};
+    ///
+    /// # async fn example(client: &AsyncClient) -> Result<()> {
+    /// let node_id = ua::NodeId::ns0_server_status();
+    /// let browse_description = ua::BrowseDescription::default().with_node_id(&node_id);
+    /// let (references, continuation_point) = client.browse(&browse_description).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The browse description may restrict the browse direction, reference type, and node classes.
+    /// For example, to find the supertype of a data type, browse its inverse `HasSubtype` reference:
+    ///
+    /// ```
+    /// # use open62541::{AsyncClient, Result, ua};
+    ///
+    /// # async fn example(client: &AsyncClient) -> Result<()> {
+    /// let browse_description = ua::BrowseDescription::default()
+    ///     .with_node_id(&ua::NodeId::ns0_int32())
+    ///     .with_browse_direction(&ua::BrowseDirection::INVERSE)
+    ///     .with_reference_type_id(&ua::NodeId::ns0_has_subtype());
+    /// let (references, _) = client.browse(&browse_description).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or it cannot be browsed.
+    pub async fn browse(&self, browse_description: &ua::BrowseDescription) -> BrowseResult {
+        let request =
+            ua::BrowseRequest::init().with_nodes_to_browse(slice::from_ref(browse_description));
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("browse should return a result"));
+        };
+
+        to_browse_result(result, Some(browse_description.node_id()))
+    }
+
+    /// Browses specific node within view.
+    ///
+    /// This is like [`browse()`](Self::browse) but only returns references that are part of the
+    /// given view. Servers that do not support views reject the request with
+    /// [`ua::StatusCode::BADVIEWIDUNKNOWN`]; this includes servers based on `open62541`.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the view or the node does not exist or the node cannot be browsed.
+    pub async fn browse_in_view(
+        &self,
+        view: &ua::ViewDescription,
+        browse_description: &ua::BrowseDescription,
+    ) -> BrowseResult {
+        let request = ua::BrowseRequest::init()
+            .with_view(view)
+            .with_nodes_to_browse(slice::from_ref(browse_description));
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        let Some(result) = results.as_slice().first() else {
+            return Err(Error::internal("browse should return a result"));
+        };
+
+        to_browse_result(result, Some(browse_description.node_id()))
+    }
+
+    /// Browses several nodes at once.
+    ///
+    /// This issues only a single request to the OPC UA server (and should be preferred over several
+    /// individual requests with [`browse()`] when browsing multiple nodes).
+    ///
+    /// The size and order of the result list matches the size and order of the given list of browse
+    /// descriptions. Each description may use its own browse direction, reference type, and node
+    /// class mask.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or cannot be
+    /// browsed, an inner `Err` is returned.
+    ///
+    /// [`browse()`]: Self::browse
+    pub async fn browse_many(
+        &self,
+        browse_descriptions: &[ua::BrowseDescription],
+    ) -> Result<Vec<BrowseResult>> {
+        let request = ua::BrowseRequest::init().with_nodes_to_browse(browse_descriptions);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            browse_descriptions.len(),
+            "unexpected number of browse results",
+        )?;
+
+        let diagnostic_infos = response.diagnostic_infos();
+        let results: Vec<_> = results
+            .iter()
+            .zip(browse_descriptions)
+            .enumerate()
+            .map(|(index, (result, browse_description))| {
+                to_browse_result(result, Some(browse_description.node_id())).map_err(|error| {
+                    error.with_diagnostic_info(result_diagnostic_info(
+                        &response,
+                        diagnostic_infos,
+                        index,
+                    ))
+                })
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Browses continuation points for more references.
+    ///
+    /// This uses continuation points returned from [`browse()`] and [`browse_many()`] whenever not
+    /// all references were returned (due to client or server limits).
+    ///
+    /// The size and order of the result list matches the size and order of the given continuation
+    /// point list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a continuation point is invalid, an
+    /// inner `Err` is returned.
+    ///
+    /// [`browse()`]: Self::browse
+    /// [`browse_many()`]: Self::browse_many
+    pub async fn browse_next(
+        &self,
+        continuation_points: &[ua::ContinuationPoint],
+    ) -> Result<Vec<BrowseResult>> {
+        let request = ua::BrowseNextRequest::init().with_continuation_points(continuation_points);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            continuation_points.len(),
+            "unexpected number of browse results",
+        )?;
+
+        let results: Vec<_> = results
+            .iter()
+            .map(|result| to_browse_result(result, None))
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Releases continuation points without browsing them.
+    ///
+    /// Continuation points returned from [`browse()`], [`browse_many()`] and [`browse_next()`] keep
+    /// resources allocated on the server until they are browsed to the end or the session is
+    /// closed. Release them when the remaining references are not needed.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails, or when one of the continuation points is invalid, e.g.
+    /// because it has already been released. The server attempts to release all continuation points
+    /// even when one of them is invalid.
+    ///
+    /// [`browse()`]: Self::browse
+    /// [`browse_many()`]: Self::browse_many
+    /// [`browse_next()`]: Self::browse_next
+    pub async fn release_continuation_points(
+        &self,
+        continuation_points: &[ua::ContinuationPoint],
+    ) -> Result<()> {
+        if continuation_points.is_empty() {
+            return Ok(());
+        }
+
+        let request = ua::BrowseNextRequest::init()
+            .with_continuation_points(continuation_points)
+            .with_release_continuation_points(true);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("browse should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            continuation_points.len(),
+            "unexpected number of browse results",
+        )?;
+
+        for result in results.iter() {
+            Error::verify_good(&result.status_code())?;
+        }
+
+        Ok(())
+    }
+
+    /// Registers nodes for repeated access.
+    ///
+    /// The server may return optimized node IDs that are faster to access, e.g. in cyclic reads
+    /// and writes. These can be used in place of the original node IDs in all services, such as
+    /// [`read_value()`] and [`write_value()`]. The size and order of the result list matches the
+    /// size and order of the given node list.
+    ///
+    /// Registered node IDs are only valid within the current session. Unregister them with
+    /// [`unregister_nodes()`] when they are no longer needed.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// #
+    /// # use open62541::{ua, AsyncClient};
+    /// #
+    /// # async fn example(client: &AsyncClient, node_id: &ua::NodeId) -> anyhow::Result<()> {
+    /// let registered_node_ids = client.register_nodes(&[node_id.clone()]).await?;
+    ///
+    /// for _ in 0..100 {
+    ///     let value = client.read_value(&registered_node_ids[0]).await?;
+    ///     println!("{:?}", value.value());
+    ///     tokio::time::sleep(Duration::from_millis(100)).await;
+    /// }
+    ///
+    /// client.unregister_nodes(&registered_node_ids).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails, e.g. when one of the node IDs is invalid.
+    ///
+    /// [`read_value()`]: Self::read_value
+    /// [`write_value()`]: Self::write_value
+    /// [`unregister_nodes()`]: Self::unregister_nodes
+    pub async fn register_nodes(&self, node_ids: &[ua::NodeId]) -> Result<Vec<ua::NodeId>> {
+        let request = ua::RegisterNodesRequest::init().with_nodes_to_register(node_ids);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(registered_node_ids) = response.registered_node_ids() else {
+            return Err(Error::internal("register nodes should return node IDs"));
+        };
+
+        verify_result_count(
+            registered_node_ids.len(),
+            node_ids.len(),
+            "unexpected number of registered node IDs",
+        )?;
+
+        Ok(registered_node_ids.into_vec())
+    }
+
+    /// Unregisters nodes.
+    ///
+    /// This releases node IDs returned by [`register_nodes()`](Self::register_nodes).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails.
+    pub async fn unregister_nodes(&self, node_ids: &[ua::NodeId]) -> Result<()> {
+        let request = ua::UnregisterNodesRequest::init().with_nodes_to_unregister(node_ids);
+
+        service_request(&self.client, request).await?;
+
+        Ok(())
+    }
+
+    /// Adds references between nodes.
+    ///
+    /// The size and order of the result list matches the size and order of the given list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a reference cannot be added, an inner
+    /// `Err` is returned, e.g. with [`ua::StatusCode::BADDUPLICATEREFERENCENOTALLOWED`] when the
+    /// reference exists already (see [`Server::add_reference()`]).
+    ///
+    /// [`Server::add_reference()`]: crate::Server::add_reference
+    pub async fn add_references(
+        &self,
+        references: &[ua::AddReferencesItem],
+    ) -> Result<Vec<Result<()>>> {
+        let request = ua::AddReferencesRequest::init().with_references_to_add(references);
+
+        let response = service_request(&self.client, request).await?;
+
+        to_status_results(
+            response.results(),
+            references.len(),
+            "unexpected number of add references results",
+        )
+    }
+
+    /// Deletes nodes.
+    ///
+    /// Each node is given along with a flag whether references that point to the node from other
+    /// nodes are deleted too. The size and order of the result list matches the size and order of
+    /// the given list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a node does not exist or cannot be
+    /// deleted, an inner `Err` is returned, e.g. with [`ua::StatusCode::BADNODEIDUNKNOWN`].
+    pub async fn delete_nodes(&self, nodes: &[(ua::NodeId, bool)]) -> Result<Vec<Result<()>>> {
+        let nodes_to_delete: Vec<_> = nodes
+            .iter()
+            .map(|(node_id, delete_target_references)| {
+                ua::DeleteNodesItem::init()
+                    .with_node_id(node_id)
+                    .with_delete_target_references(*delete_target_references)
+            })
+            .collect();
+
+        let request = ua::DeleteNodesRequest::init().with_nodes_to_delete(&nodes_to_delete);
+
+        let response = service_request(&self.client, request).await?;
+
+        to_status_results(
+            response.results(),
+            nodes.len(),
+            "unexpected number of delete nodes results",
+        )
+    }
+
+    /// Deletes references between nodes.
+    ///
+    /// The size and order of the result list matches the size and order of the given list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a reference does not exist or cannot be
+    /// deleted, an inner `Err` is returned.
+    pub async fn delete_references(
+        &self,
+        references: &[ua::DeleteReferencesItem],
+    ) -> Result<Vec<Result<()>>> {
+        let request = ua::DeleteReferencesRequest::init().with_references_to_delete(references);
+
+        let response = service_request(&self.client, request).await?;
+
+        to_status_results(
+            response.results(),
+            references.len(),
+            "unexpected number of delete references results",
+        )
+    }
+
+    /// Finds servers known to server.
+    ///
+    /// When connected to a Local Discovery Server, this returns the servers that have registered
+    /// with it. Other servers return only their own description. Use the discovery URLs of each
+    /// server to get its endpoints, see [`ClientBuilder::get_endpoints()`].
+    ///
+    /// `endpoint_url` is the URL that was used to connect to the server. When `server_uris` is not
+    /// empty, only servers with matching application URIs are returned. `locale_ids` select the
+    /// preferred locales of application names.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails or when the endpoint URL contains NUL bytes.
+    ///
+    /// [`ClientBuilder::get_endpoints()`]: crate::ClientBuilder::get_endpoints
+    pub async fn find_servers(
+        &self,
+        endpoint_url: &str,
+        server_uris: &[ua::String],
+        locale_ids: &[ua::String],
+    ) -> Result<ua::Array<ua::ApplicationDescription>> {
+        let request = ua::FindServersRequest::init()
+            .with_endpoint_url(&ua::String::new(endpoint_url)?)
+            .with_server_uris(server_uris)
+            .with_locale_ids(locale_ids);
+
+        let response = service_request(&self.client, request).await?;
+
+        // An empty list of servers may be returned as null array.
+        Ok(response
+            .servers()
+            .unwrap_or_else(|| ua::Array::from_slice(&[])))
+    }
+
+    /// Finds servers on network.
+    ///
+    /// This is supported by Local Discovery Servers with multicast extension (LDS-ME). It returns
+    /// the time when record IDs were last reset and the servers with record IDs of at least
+    /// `starting_record_id`, up to `max_records` (or all with `0`). When `capability_filter` is not
+    /// empty, only servers with all of the given capabilities are returned.
+    ///
+    /// To poll incrementally, pass the highest record ID seen so far plus one in the next call. When
+    /// the returned reset time differs from the previous one, record IDs have been reset and polling
+    /// must start over with record ID `0`.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails, e.g. with [`ua::StatusCode::BADSERVICEUNSUPPORTED`] when
+    /// the server does not support multicast discovery.
+    pub async fn find_servers_on_network(
+        &self,
+        starting_record_id: u32,
+        max_records: u32,
+        capability_filter: &[ua::String],
+    ) -> Result<(ua::DateTime, ua::Array<ua::ServerOnNetwork>)> {
+        let request = ua::FindServersOnNetworkRequest::init()
+            .with_starting_record_id(starting_record_id)
+            .with_max_records_to_return(max_records)
+            .with_server_capability_filter(capability_filter);
+
+        let response = service_request(&self.client, request).await?;
+
+        // An empty list of servers may be returned as null array.
+        let servers = response
+            .servers()
+            .unwrap_or_else(|| ua::Array::from_slice(&[]));
+
+        Ok((response.last_counter_reset_time().clone(), servers))
+    }
+
+    /// Creates new [subscription](AsyncSubscription).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected.
+    pub async fn create_subscription(&self) -> Result<AsyncSubscription> {
+        let (_, subscription) = SubscriptionBuilder::default().create(self).await?;
+
+        Ok(subscription)
+    }
+
+    /// Adopts existing [subscription](AsyncSubscription).
+    ///
+    /// This wraps a subscription that exists on the server, e.g. after it has been moved to this
+    /// client's session with the `TransferSubscriptions` service, into an [`AsyncSubscription`]. As
+    /// with subscriptions created by this client, the subscription is deleted on the server when the
+    /// returned value is dropped.
+    ///
+    /// Note that the underlying client does not track adopted subscriptions: creating monitored
+    /// items on the subscription fails, and notifications are not delivered to
+    /// [`AsyncMonitoredItem`] values. Use [`AsyncSubscription::notifications()`] to receive the
+    /// notification messages of the adopted subscription instead.
+    ///
+    /// Adopted and regular subscriptions are mutually exclusive within a session: the server answers
+    /// publish requests with notifications of any subscription. While notifications of adopted
+    /// subscriptions are received, creating subscriptions fails, and while this client has
+    /// subscriptions of its own, [`AsyncSubscription::notifications()`] fails.
+    #[must_use]
+    pub fn adopt_subscription(&self, subscription_id: ua::SubscriptionId) -> AsyncSubscription {
+        AsyncSubscription::new_adopted(&self.client, subscription_id)
+    }
+
+    /// Transfers subscriptions to this client's session.
+    ///
+    /// This moves subscriptions that have been created in another session, e.g. before reconnecting
+    /// or by another client, to the current session. When `send_initial_values` is set, the server
+    /// sends the current values of all monitored items of the transferred subscriptions.
+    ///
+    /// This returns one result per subscription, in the order given. Each result holds the status
+    /// of the individual transfer and the sequence numbers of notifications that are available for
+    /// retransmission. Transferred subscriptions should be wrapped with
+    /// [`adopt_subscription()`](Self::adopt_subscription) to manage their lifetime and to receive
+    /// their notifications with [`AsyncSubscription::notifications()`].
+    ///
+    /// Note that transferred subscriptions are not bound to the underlying client's subscription
+    /// state. Existing [`AsyncSubscription`] and [`AsyncMonitoredItem`] values of the previous
+    /// session keep referring to that session: their streams end when it is lost. Notifications of
+    /// transferred subscriptions cannot be received while this client has subscriptions of its own
+    /// in the session, see [`adopt_subscription()`](Self::adopt_subscription).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails as a whole, e.g. with [`ua::StatusCode::BADNOTHINGTODO`]
+    /// when no subscription IDs are given. Failures of individual subscriptions are reported in the
+    /// returned results instead.
+    pub async fn transfer_subscriptions(
+        &self,
+        subscription_ids: &[ua::SubscriptionId],
+        send_initial_values: bool,
+    ) -> Result<Vec<ua::TransferResult>> {
+        let request = ua::TransferSubscriptionsRequest::init()
+            .with_subscription_ids(subscription_ids)
+            .with_send_initial_values(send_initial_values);
+
+        let response = service_request(&self.client, request).await?;
+
+        let Some(results) = response.results() else {
+            return Err(Error::internal("transfer should return results"));
+        };
+
+        verify_result_count(
+            results.len(),
+            subscription_ids.len(),
+            "unexpected number of transfer results",
+        )?;
+
+        Ok(results.to_vec())
+    }
+
+    pub(crate) const fn client(&self) -> &Arc<ua::Client> {
+        &self.client
+    }
+}
+
+impl Drop for AsyncClient {
+    fn drop(&mut self) {
+        // We need to wait for the task to finish and must do so blockingly. `UA_Client_delete()` is
+        // not safe run concurrently while `UA_Client_run_iterate()` is still running.
+        //
+        // Notify background task to cancel itself, even when [`UA_Client_run_iterate()`] would want
+        // to keep on running. This is okay: we are not issuing asynchronous requests anymore anyway
+        // (the only other call will be `UA_Client_delete()` when inner client drops).
+        self.join_background_task(true);
+    }
+}
+
+/// Background task for [`ua::Client`].
+///
+/// This runs [`UA_Client_run_iterate()`] in a loop, blocking for up to `RUN_ITERATE_TIMEOUT` during
+/// each iteration. In case the loop does not finish by itself (which happens in case of disconnects
+/// and for final connection failures), the cancellation token `cancel` can be used to stop the task
+/// from the outside before the next loop iteration.
+///
+/// With [`ClientBuilder::auto_reconnect()`], the task reconnects after final connection failures
+/// instead, and recreates lost subscriptions after the session has been activated again.
+///
+/// [`ClientBuilder::auto_reconnect()`]: crate::ClientBuilder::auto_reconnect
+fn background_task(client: &ua::Client, cancelled: &AtomicBool, session_generation: &AtomicU64) {
+    log::info!("Starting background task");
+
+    let reconnect = ClientContext::get(client).reconnect();
+
+    // Track session state to notice when the session has been re-activated after reconnecting.
+    let mut session_activated = client.is_session_activated();
+    // Only reconnect after having been connected. Initial connection failures are reported by
+    // `AsyncClient::connect_with()`.
+    let mut has_been_activated = session_activated;
+    // Number of attempts to reconnect since the connection has been lost.
+    let mut reconnect_attempt = 0;
+    // Recreation of lost subscriptions. The requests are processed by the event loop below, so we
+    // poll the future here instead of handing it to an executor.
+    let mut restore: Option<Pin<Box<dyn Future<Output = ()> + '_>>> = None;
+    // The future is woken by callbacks of its requests, while running the event loop.
+    let restore_wake = Arc::new(WakeFlag::default());
+    let restore_waker = Waker::from(Arc::clone(&restore_wake));
+
+    // Run until cancelled. The only other way to exit is when `UA_Client_run_iterate()` fails which
+    // happens when the connection is broken and the client instance cannot be used anymore.
+    while !cancelled.load(Ordering::Relaxed) {
+        // Track time of iteration start to report iteration times below.
+        let start_of_iteration = Instant::now();
+
+        let status_code = run_iterate(client, RUN_ITERATE_TIMEOUT);
+        if let Err(error) = Error::verify_good(&status_code) {
+            if let Some(reconnect) =
+                reconnect.filter(|reconnect| has_been_activated && !reconnect.is_disconnecting())
+            {
+                log::warn!("Connection has been lost: {error}");
+                // Requests of pending recreation have failed with the connection.
+                restore = None;
+                if wait_and_reconnect(client, cancelled, reconnect, &mut reconnect_attempt) {
+                    continue;
+                }
+            }
+
+            // Context-sensitive handling of bad status codes.
+            match status_code.into_raw() {
+                UA_STATUSCODE_BADDISCONNECT => {
+                    // Not an error.
+                    log::info!("Terminating background task after disconnect");
+                }
+                UA_STATUSCODE_BADCONNECTIONCLOSED => {
+                    // Not an error.
+                    log::info!("Terminating background task after connection closed");
+                }
+                _ => {
+                    // Unexpected error.
+                    log::error!("Terminating background task: run failed with {error}");
+                }
+            }
+            return;
+        }
+
+        let time_taken = start_of_iteration.elapsed();
+        log::trace!("Iterate run took {time_taken:?}");
+
+        let context = ClientContext::get(client);
+        // Notifications of each publish response have been delivered within the iteration.
+        context.publish_batches().flush();
+        context.check_subscription_watchdogs();
+
+        let was_session_activated = session_activated;
+        session_activated = client.is_session_activated();
+        if session_activated && !was_session_activated {
+            log::info!("Session has been activated again");
+            session_generation.fetch_add(1, Ordering::Relaxed);
+            has_been_activated = true;
+            reconnect_attempt = 0;
+            if let Some(reconnect) = reconnect {
+                // Retry subscriptions that could not be recreated before.
+                reconnect.schedule_restore();
+            }
+            if let Some(keep_alive) = context.session_keep_alive() {
+                keep_alive.record_activity();
+            }
+        }
+
+        if session_activated {
+            if let Some(keep_alive) = context.session_keep_alive() {
+                keep_alive.check(client);
+            }
+        }
+
+        if let Some(reconnect) = reconnect {
+            if session_activated && restore.is_none() && reconnect.take_restore_pending() {
+                restore = Some(Box::pin(reconnect.restore_subscriptions(client)));
+                // Poll new future once to send its first requests.
+                restore_wake.wake_by_ref();
+            }
+            if let Some(future) = restore.as_mut() {
+                // Only poll again after responses have been received.
+                if restore_wake.take() {
+                    let mut cx = Context::from_waker(&restore_waker);
+                    if future.as_mut().poll(&mut cx).is_ready() {
+                        restore = None;
+                    }
+                }
+            }
+        }
+    }
+
+    log::info!("Terminating cancelled background task");
+}
+
+/// Waker that only records that it has been woken.
+///
+/// This lets [`background_task()`] poll futures on its own thread when they can make progress.
+#[derive(Debug, Default)]
+struct WakeFlag(AtomicBool);
+
+impl WakeFlag {
+    /// Checks if woken since the last call, and resets flag.
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::Relaxed)
+    }
+}
+
+impl Wake for WakeFlag {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Runs single iteration of event loop.
+///
+/// This returns the connect status of the client.
+fn run_iterate(client: &ua::Client, timeout: Duration) -> ua::StatusCode {
+    log::trace!("Running iterate");
+
+    // `UA_Client_run_iterate()` expects the timeout to be given in milliseconds.
+    let timeout_millis = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+
+    // This returns after the timeout even when nothing was processed. The internal mutex is _not_
+    // held for the entire time though, so we can send out requests concurrently while the client
+    // is running the iteration.
+    ua::StatusCode::new(unsafe {
+        UA_Client_run_iterate(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            timeout_millis,
+        )
+    })
+}
+
+/// Waits before connecting again, according to reconnect policy.
+///
+/// This keeps running the event loop while waiting, so that the previous connection is closed
+/// properly. Returns `false` when the background task should terminate instead, i.e. when it has
+/// been cancelled, when the client is being disconnected, or when the policy gives up.
+fn wait_and_reconnect(
+    client: &ua::Client,
+    cancelled: &AtomicBool,
+    reconnect: &Reconnect,
+    attempt: &mut u32,
+) -> bool {
+    let Some(delay) = reconnect.policy().delay(*attempt) else {
+        log::error!("Giving up reconnecting after {attempt} attempts");
+        return false;
+    };
+    *attempt = attempt.saturating_add(1);
+
+    log::info!("Reconnecting in {delay:?} (attempt {attempt})");
+
+    let deadline = Instant::now() + delay;
+    loop {
+        if cancelled.load(Ordering::Relaxed) || reconnect.is_disconnecting() {
+            return false;
+        }
+
+        // Connecting requires the previous secure channel to be closed.
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() && client.state().channel_state.is_closed() {
+            break;
+        }
+
+        // The connect status stays bad until we connect again below. Ignore it while waiting.
+        let timeout = if remaining.is_zero() {
+            RUN_ITERATE_TIMEOUT
+        } else {
+            remaining.min(RUN_ITERATE_TIMEOUT)
+        };
+        let _unused = run_iterate(client, timeout);
+    }
+
+    // When this fails, the next iteration of the event loop fails too and we try again.
+    if let Err(error) = client.reconnect() {
+        log::warn!("Reconnecting failed: {error}");
+    }
+
+    true
+}
+
+/// Options for reading attributes.
+///
+/// See [`AsyncClient::read_value_with_options()`] and
+/// [`AsyncClient::read_many_attributes_with_options()`].
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// Maximum age of values that the server may return from its cache.
+    ///
+    /// With `None` (the default), the server reads current values from the source.
+    pub max_age: Option<Duration>,
+    /// Timestamps to return with values.
+    ///
+    /// Default value is [`ua::TimestampsToReturn::BOTH`].
+    pub timestamps: ua::TimestampsToReturn,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            max_age: None,
+            timestamps: ua::TimestampsToReturn::BOTH,
+        }
+    }
+}
+
+/// Data type and value rank of variable node.
+#[derive(Debug, Clone)]
+struct WriteTarget {
+    data_type: ua::NodeId,
+    value_rank: i32,
+}
+
+impl WriteTarget {
+    /// Value rank for scalar values.
+    const SCALAR: i32 = -1;
+    /// Value rank for any values, scalar or array.
+    const ANY: i32 = -2;
+    /// Value rank for scalar values or one-dimensional arrays.
+    const SCALAR_OR_ONE_DIMENSION: i32 = -3;
+
+    /// Checks if value may be written.
+    ///
+    /// Returns description of mismatch, if any.
+    fn check(&self, value: Option<&ua::Variant>) -> Option<String> {
+        let Some(value) = value.filter(|value| !value.is_empty()) else {
+            // Empty values are allowed by some servers to reset a value. Leave it to the server.
+            return None;
+        };
+
+        let expected_value_type = ValueType::from_data_type(&self.data_type);
+        if let Some(value_type) = value.value_type() {
+            if expected_value_type != ValueType::Unsupported
+                && value_type != ValueType::Unsupported
+                && value_type != expected_value_type
+            {
+                return Some(format!(
+                    "node expects {expected_value_type:?} value (data type {})",
+                    self.data_type
+                ));
+            }
+        }
+
+        match self.value_rank {
+            Self::ANY | Self::SCALAR_OR_ONE_DIMENSION => None,
+            Self::SCALAR if !value.is_scalar() => {
+                Some("node expects scalar value but got array".to_owned())
+            }
+            value_rank if value_rank >= 0 && value.is_scalar() => Some(format!(
+                "node expects array value (value rank {value_rank}) but got scalar"
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Creates write error with type of attempted value.
+fn write_error(
+    node_id: &ua::NodeId,
+    value: &ua::DataValue,
+    status_code: ua::StatusCode,
+) -> WriteError {
+    WriteError::new(
+        node_id.clone(),
+        value.value().and_then(ua::Variant::value_type),
+        status_code,
+    )
+}
+
+/// Calls specific method node at object node.
+///
+/// See [`AsyncClient::call_method()`].
+pub(crate) async fn call_method(
+    client: &ua::Client,
+    object_id: &ua::NodeId,
+    method_id: &ua::NodeId,
+    input_arguments: &[ua::Variant],
+) -> Result<Vec<ua::Variant>> {
+    let request = ua::CallRequest::init().with_methods_to_call(&[ua::CallMethodRequest::init()
+        .with_object_id(object_id)
+        .with_method_id(method_id)
+        .with_input_arguments(input_arguments)]);
+
+    let response = service_request(client, request).await?;
+
+    let Some(results) = response.results() else {
+        return Err(Error::internal("call should return results"));
+    };
+
+    let Some(result) = results.as_slice().first() else {
+        return Err(Error::internal("call should return a result"));
+    };
+
+    to_call_result(result, method_id).map_err(|error| {
+        error.with_diagnostic_info(result_diagnostic_info(
+            &response,
+            response.diagnostic_infos(),
+            0,
+        ))
+    })
+}
+
+/// Converts result of method call into output arguments.
+fn to_call_result(
+    result: &ua::CallMethodResult,
+    method_id: &ua::NodeId,
+) -> Result<Vec<ua::Variant>> {
+    let status_code = result.status_code();
+    if !status_code.is_good() {
+        let input_argument_results = result
+            .input_argument_results()
+            .map(ua::Array::into_vec)
+            .unwrap_or_default();
+        let error = CallError::new(method_id.clone(), status_code, input_argument_results);
+        return Err(Error::Call(Box::new(error)));
+    }
+
+    let output_arguments = if let Some(output_arguments) = result.output_arguments() {
+        output_arguments.into_vec()
+    } else {
+        log::debug!("Calling {method_id} returned unset output arguments, assuming none exist");
+        Vec::new()
+    };
+
+    Ok(output_arguments)
+}
+
+/// Finds index of namespace URI in namespace array.
+fn find_namespace(namespace_array: &[String], namespace_uri: &str) -> Option<u16> {
+    let index = namespace_array
+        .iter()
+        .position(|candidate| candidate == namespace_uri)?;
+    u16::try_from(index).ok()
+}
+
+/// Cached node values with their session generation.
+///
+/// See [`AsyncClient::read_value_cached()`].
+#[derive(Debug, Default)]
+struct ValueCache {
+    /// Session generation that the cached values belong to.
+    session_generation: u64,
+    /// Incremented whenever cached values are removed because of writes.
+    write_epoch: u64,
+    values: HashMap<ua::NodeId, DataValue<ua::Variant>>,
+}
+
+impl ValueCache {
+    /// Gets cached value if it is fresh enough.
+    ///
+    /// This clears the cache when the session generation has changed.
+    fn get(
+        &mut self,
+        session_generation: u64,
+        node_id: &ua::NodeId,
+        max_staleness: Duration,
+    ) -> Option<&DataValue<ua::Variant>> {
+        self.invalidate_outdated(session_generation);
+
+        self.values.get(node_id).filter(|value| {
+            value
+                .server_timestamp()
+                .and_then(ua::DateTime::elapsed)
+                .is_some_and(|age| age <= max_staleness)
+        })
+    }
+
+    /// Gets current write epoch.
+    ///
+    /// This must be taken before reading a value and passed to [`insert()`](Self::insert).
+    const fn write_epoch(&self) -> u64 {
+        self.write_epoch
+    }
+
+    /// Inserts value that has been read.
+    ///
+    /// The value is discarded when it may be outdated, i.e. when the session generation or the
+    /// write epoch have changed since the read has started.
+    fn insert(
+        &mut self,
+        session_generation: u64,
+        write_epoch: u64,
+        node_id: ua::NodeId,
+        value: DataValue<ua::Variant>,
+    ) {
+        self.invalidate_outdated(session_generation);
+
+        if self.session_generation != session_generation || self.write_epoch != write_epoch {
+            log::debug!("Not caching value of {node_id} read before write or reconnect");
+            return;
+        }
+
+        // Values without server timestamp would never be served from the cache.
+        if value.server_timestamp().is_some() {
+            self.values.insert(node_id, value);
+        }
+    }
+
+    fn remove(&mut self, node_id: &ua::NodeId) {
+        self.values.remove(node_id);
+        self.write_epoch = self.write_epoch.wrapping_add(1);
+    }
+
+    fn invalidate_outdated(&mut self, session_generation: u64) {
+        // Never go back to previous generation, e.g. for reads that started before reconnect.
+        if self.session_generation < session_generation {
+            log::debug!("Clearing value cache after reconnect");
+            self.values.clear();
+            self.session_generation = session_generation;
+        }
+    }
+}
+
+/// Reads a combination of node attributes.
+///
+/// See [`AsyncClient::read_many_attributes()`].
+pub(crate) async fn read_many_attributes(
+    client: &ua::Client,
+    node_attributes: &[(ua::NodeId, ua::AttributeId)],
+) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+    read_many_attributes_with_options(client, node_attributes, &ReadOptions::default()).await
+}
+
+async fn read_many_attributes_with_options(
+    client: &ua::Client,
+    node_attributes: &[(ua::NodeId, ua::AttributeId)],
+    options: &ReadOptions,
+) -> Result<Vec<Result<DataValue<ua::Variant>>>> {
+    let nodes_to_read: Vec<_> = node_attributes
+        .iter()
+        .map(|(node_id, attribute_id)| {
+            ua::ReadValueId::init()
+                .with_node_id(node_id)
+                .with_attribute_id(attribute_id)
+        })
+        .collect();
+
+    let request = ua::ReadRequest::init()
+        .with_timestamps_to_return(&options.timestamps)
+        .with_max_age(options.max_age.unwrap_or(Duration::ZERO))
+        .with_nodes_to_read(&nodes_to_read);
+
+    let response = service_request(client, request).await?;
+
+    let Some(results) = response.results() else {
+        return Err(Error::internal("read should return results"));
+    };
+
+    let diagnostic_infos = response.diagnostic_infos();
+    let results: Vec<_> = results
+        .iter()
+        .enumerate()
+        .map(|(index, result)| {
+            result.to_generic::<ua::Variant>().map_err(|error| {
+                error.with_diagnostic_info(result_diagnostic_info(
+                    &response,
+                    diagnostic_infos,
+                    index,
+                ))
+            })
+        })
+        .collect();
+
+    verify_result_count(
+        results.len(),
+        node_attributes.len(),
+        "unexpected number of read results",
+    )?;
+
+    Ok(results)
+}
+
+/// Converts value of `MinimumSamplingInterval` attribute.
+///
+/// This returns `None` for negative values (indeterminate).
+pub(crate) fn minimum_sampling_interval(value: &ua::Double) -> Option<Duration> {
+    Duration::try_from_secs_f64(value.value() / 1e3).ok()
+}
+
+pub(crate) async fn service_request<R: ServiceRequest>(
+    client: &ua::Client,
+    mut request: R,
+) -> Result<R::Response> {
+    ClientContext::get(client).apply_request_header_options(request.request_header_mut());
+
+    let Ok(timeout) = REQUEST_TIMEOUT.try_with(|&timeout| timeout) else {
+        return dispatch_service_request(client, request)?.await;
+    };
+
+    // `open62541` uses the timeout hint for the request on the client side too: when no response
+    // arrives in time, it releases the request and calls our callback with `BadTimeout`. Bounding
+    // the local wait ensures that we also return in time when the event loop runs late.
+    request.request_header_mut().set_timeout_hint(timeout);
+    let call = dispatch_service_request(client, request)?;
+    tokio::time::timeout(timeout, call)
+        .await
+        .unwrap_or(Err(Error::new(ua::StatusCode::BADTIMEOUT)))
+}
+
+/// Sends service request with default request header options without waiting for the response.
+///
+/// Unlike [`service_request()`], this does not apply the timeout set with
+/// [`AsyncClient::with_timeout()`]: the returned [`ServiceCall`] may outlive that scope.
+fn send_service_request<R: ServiceRequest>(
+    client: &ua::Client,
+    mut request: R,
+) -> Result<ServiceCall<'_, R::Response>> {
+    ClientContext::get(client).apply_request_header_options(request.request_header_mut());
+    dispatch_service_request(client, request)
+}
+
+/// Sends service request without waiting for the response.
+///
+/// The returned [`ServiceCall`] resolves to the response. This fails when the request cannot be
+/// sent.
+fn dispatch_service_request<R: ServiceRequest>(
+    client: &ua::Client,
+    request: R,
+) -> Result<ServiceCall<'_, R::Response>> {
+    type Cb<R> = CallbackOnce<std::result::Result<<R as ServiceRequest>::Response, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c<R: ServiceRequest>(
+        client: *mut UA_Client,
+        userdata: *mut c_void,
+        request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::trace!(
+            "Request ID {request_id} finished, received {}",
+            R::Response::type_name(),
+        );
+
+        // SAFETY: The client is valid for the duration of the callback.
+        let keep_alive = unsafe { ClientContext::from_client(client) }
+            .and_then(ClientContext::session_keep_alive);
+        if let Some(keep_alive) = keep_alive {
+            // The session is not idle, postpone keep-alive.
+            keep_alive.record_activity();
+        }
+
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.cast::<<R::Response as DataType>::Inner>().as_ref() }
+            .expect("response should be set");
+        let response = R::Response::clone_raw(response);
+
+        let status_code = response.service_result();
+        let result = if status_code.is_good() {
+            Ok(response)
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::<R>::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<R::Response>>();
 
     let callback = |result: std::result::Result<R::Response, _>| {
         // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
@@ -594,58 +2767,1900 @@ async fn service_request<R: ServiceRequest>(
         let _unused = tx.send(result.map_err(Error::new));
     };
 
-    log::debug!("Running {}", R::type_name());
+    log::debug!("Running {}", R::type_name());
+
+    let mut request_id: UA_UInt32 = 0;
+    let status_code = ua::StatusCode::new(unsafe {
+        __UA_Client_AsyncService(
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            client.as_ptr().cast_mut(),
+            request.as_ptr().cast::<c_void>(),
+            R::data_type(),
+            Some(callback_c::<R>),
+            R::Response::data_type(),
+            Cb::<R>::prepare(callback),
+            ptr::addr_of_mut!(request_id),
+        )
+    });
+    // The request itself fails when the client is not connected (or the secure session has not been
+    // established). In all other cases, `open62541` processes the request first and then may reject
+    // it only through the response when executing our callback above.
+    Error::verify_good(&status_code).inspect_err(|_| {
+        log::warn!("{} failed: {status_code:?}", R::type_name());
+    })?;
+
+    // The request handle has been assigned by `open62541` when sending the request (unless it was
+    // set explicitly).
+    let request_handle = request.request_header().request_handle();
+
+    log::trace!(
+        "Assigned ID {request_id} and handle {request_handle} to {}",
+        R::type_name()
+    );
+
+    Ok(ServiceCall {
+        client,
+        request_id,
+        request_handle,
+        rx,
+        finished: false,
+    })
+}
+
+/// Pending service request.
+///
+/// This resolves to the response of the request, see [`AsyncClient::send_browse_request()`]. The
+/// request may be cancelled on the server with [`cancel()`](Self::cancel). When dropped before the
+/// response has been received, the request is cancelled on the server if enabled with
+/// [`ClientBuilder::cancel_dropped_requests()`](crate::ClientBuilder::cancel_dropped_requests).
+#[derive(Debug)]
+#[must_use]
+pub struct ServiceCall<'a, T> {
+    client: &'a ua::Client,
+    request_id: UA_UInt32,
+    request_handle: UA_UInt32,
+    rx: oneshot::Receiver<Result<T>>,
+    /// Whether the response has been received or the request has been cancelled already.
+    finished: bool,
+}
+
+impl<T> ServiceCall<'_, T> {
+    /// Gets request ID.
+    ///
+    /// This is the ID that `open62541` assigned to the request locally, e.g. for logging.
+    #[must_use]
+    pub const fn request_id(&self) -> u32 {
+        self.request_id
+    }
+
+    /// Gets request handle.
+    ///
+    /// This is the handle that is sent to the server in the request header and that identifies the
+    /// request in `Cancel` requests.
+    #[must_use]
+    pub const fn request_handle(&self) -> u32 {
+        self.request_handle
+    }
+
+    /// Cancels request on the server.
+    ///
+    /// This sends a `Cancel` request without waiting for its response. Servers are not required to
+    /// abort the operation. When they do, the request finishes with an error, usually
+    /// [`ua::StatusCode::BADREQUESTCANCELLEDBYCLIENT`]. The call should still be awaited to get the
+    /// result.
+    pub fn cancel(&mut self) {
+        if !self.finished {
+            self.send_cancel();
+            // Do not send another `Cancel` request when dropping.
+            self.finished = true;
+        }
+    }
+
+    fn send_cancel(&self) {
+        log::debug!(
+            "Cancelling request ID {} with handle {}",
+            self.request_id,
+            self.request_handle
+        );
+
+        let request = ua::CancelRequest::init().with_request_handle(self.request_handle);
+        match dispatch_service_request(self.client, request) {
+            // We do not care about the number of cancelled requests. Make sure that we do not
+            // cancel the `Cancel` request itself when dropping it.
+            Ok(mut call) => call.finished = true,
+            Err(error) => log::warn!("Cancelling request failed: {error}"),
+        }
+    }
+}
+
+impl<T> Future for ServiceCall<'_, T> {
+    type Output = Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = ready!(Pin::new(&mut self.rx).poll(cx));
+        self.finished = true;
+        // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the
+        // sender is only dropped after placing a value into the channel and `rx` always finds this
+        // value there.
+        Poll::Ready(result.unwrap_or(Err(Error::internal("callback should send result"))))
+    }
+}
+
+impl<T> Drop for ServiceCall<'_, T> {
+    fn drop(&mut self) {
+        if !self.finished && ClientContext::get(self.client).cancel_dropped_requests() {
+            self.send_cancel();
+        }
+    }
+}
+
+/// Verifies that service returned one result per item of request.
+///
+/// The OPC UA specification state that the resulting list has the same number of elements as the
+/// request list. If not, we would not be able to match elements in the two lists anyway.
+pub(crate) fn verify_result_count(
+    result_count: usize,
+    request_count: usize,
+    message: &'static str,
+) -> Result<()> {
+    if result_count != request_count {
+        return Err(Error::internal(message));
+    }
+    Ok(())
+}
+
+/// Converts status codes of individual items from response.
+///
+/// Servers may leave out the result list when the request list is empty. Otherwise, this fails
+/// like [`verify_result_count()`].
+pub(crate) fn to_status_results(
+    results: Option<Vec<ua::StatusCode>>,
+    request_count: usize,
+    message: &'static str,
+) -> Result<Vec<Result<()>>> {
+    let results = results.unwrap_or_default();
+    verify_result_count(results.len(), request_count, message)?;
+    Ok(results.iter().map(Error::verify_good).collect())
+}
+
+/// Gets diagnostic info of individual result from response.
+///
+/// This returns `None` when the server did not return diagnostic info for the result.
+fn result_diagnostic_info(
+    response: &impl ServiceResponse,
+    diagnostic_infos: Option<&[ua::DiagnosticInfo]>,
+    index: usize,
+) -> Option<DiagnosticInfo> {
+    let diagnostic_info = diagnostic_infos?.get(index)?;
+    let string_table = response
+        .response_header()
+        .string_table()
+        .unwrap_or_default();
+    DiagnosticInfo::new(diagnostic_info, string_table)
+}
+
+/// Converts [`ua::BrowseResult`] to our public result type.
+fn to_browse_result(result: &ua::BrowseResult, node_id: Option<&ua::NodeId>) -> BrowseResult {
+    // Make sure to verify the inner status code inside `BrowseResult`. The service request finishes
+    // without error, even when browsing the node has failed.
+    Error::verify_good(&result.status_code())?;
+
+    let references = if let Some(references) = result.references() {
+        references.into_vec()
+    } else {
+        // When no references exist, some OPC UA servers do not return an empty references array but
+        // an invalid (unset) one instead, e.g. Siemens SIMOTION. We treat it as an empty array, and
+        // continue without error.
+        if let Some(node_id) = node_id {
+            log::debug!("Browsing {node_id} returned unset references, assuming none exist");
+        } else {
+            log::debug!(
+                "Browsing continuation point returned unset references, assuming none exist",
+            );
+        }
+        Vec::new()
+    };
+
+    Ok((references, result.continuation_point()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ua, AsyncClient, ClientBuilder, DataType as _, DataValue, Error, Node, TestHarness,
+        ValueType,
+    };
+
+    use super::{ValueCache, WriteTarget};
+
+    #[test]
+    fn check_write_target() {
+        let write_target = WriteTarget {
+            data_type: ua::NodeId::ns0_int32(),
+            value_rank: WriteTarget::SCALAR,
+        };
+
+        assert!(write_target
+            .check(Some(&ua::Variant::scalar(ua::Int32::new(1))))
+            .is_none());
+        // Wrong data type is rejected.
+        assert!(write_target
+            .check(Some(&ua::Variant::scalar(ua::Double::new(1.0))))
+            .is_some());
+        // Array is rejected for scalar node.
+        assert!(write_target
+            .check(Some(&ua::Variant::array(ua::Array::from_slice(&[
+                ua::Int32::new(1)
+            ]))))
+            .is_some());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn value_cache_staleness() {
+        use std::time::Duration;
+
+        let node_id = ua::NodeId::numeric(1, 1234);
+        let value_at = |timestamp: time::OffsetDateTime| {
+            ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(1)))
+                .with_server_timestamp(&ua::DateTime::try_from(timestamp).unwrap())
+                .to_generic::<ua::Variant>()
+                .unwrap()
+        };
+        let now = time::OffsetDateTime::now_utc();
+
+        let mut cache = ValueCache::default();
+        let epoch = cache.write_epoch();
+        cache.insert(
+            0,
+            epoch,
+            node_id.clone(),
+            value_at(now - Duration::from_secs(10)),
+        );
+        // Value is served only when it is fresh enough.
+        assert!(cache.get(0, &node_id, Duration::from_secs(60)).is_some());
+        assert!(cache.get(0, &node_id, Duration::from_secs(1)).is_none());
+        // Cache is cleared when session generation changes.
+        assert!(cache.get(1, &node_id, Duration::from_secs(60)).is_none());
+
+        // Values read before reconnect are not cached, generation is not reset.
+        let epoch = cache.write_epoch();
+        cache.insert(0, epoch, node_id.clone(), value_at(now));
+        assert!(cache.get(1, &node_id, Duration::MAX).is_none());
+
+        // Values read before concurrent write are not cached.
+        let epoch = cache.write_epoch();
+        cache.remove(&node_id);
+        cache.insert(1, epoch, node_id.clone(), value_at(now));
+        assert!(cache.get(1, &node_id, Duration::MAX).is_none());
+
+        // Values without server timestamp are not cached.
+        let value = ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(1)))
+            .to_generic::<ua::Variant>()
+            .unwrap();
+        let epoch = cache.write_epoch();
+        cache.insert(1, epoch, node_id.clone(), value);
+        assert!(cache.get(1, &node_id, Duration::MAX).is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn write_values() {
+        let mut node_ids = Vec::new();
+        let harness = TestHarness::start(|server| {
+            for (name, writable) in [("Writable", true), ("ReadOnly", false)] {
+                let node_id = server
+                    .add_node(Node::new(
+                        ua::NodeId::ns0_objects_folder(),
+                        ua::NodeId::ns0_organizes(),
+                        ua::QualifiedName::new(1, name),
+                        ua::VariableAttributes::init()
+                            .with_data_type(&ua::NodeId::ns0_int32())
+                            .with_value_rank(-1)
+                            .with_access_level(
+                                &ua::AccessLevel::NONE
+                                    .with_current_read(true)
+                                    .with_current_write(writable),
+                            ),
+                    ))
+                    .expect("should add node");
+                node_ids.push(node_id);
+            }
+        })
+        .expect("should start harness");
+
+        let value = ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(42)));
+        let results = harness
+            .client()
+            .write_values(&[
+                (node_ids[0].clone(), value.clone()),
+                (node_ids[1].clone(), value.clone()),
+                (ua::NodeId::numeric(1, 123_456_789), value),
+            ])
+            .await
+            .expect("should send write request");
+
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [
+                Ok(()),
+                Err(ua::StatusCode::BADNOTWRITABLE),
+                Err(ua::StatusCode::BADNODEIDUNKNOWN),
+            ]
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn value_range() {
+        let mut node_id = None;
+        let harness = TestHarness::start(|server| {
+            node_id = Some(
+                server
+                    .add_node(Node::new(
+                        ua::NodeId::ns0_objects_folder(),
+                        ua::NodeId::ns0_organizes(),
+                        ua::QualifiedName::new(1, "Array"),
+                        ua::VariableAttributes::init()
+                            .with_data_type(&ua::NodeId::ns0_int32())
+                            .with_value_rank(1)
+                            .with_access_level(
+                                &ua::AccessLevel::NONE
+                                    .with_current_read(true)
+                                    .with_current_write(true),
+                            ),
+                    ))
+                    .expect("should add node"),
+            );
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let node_id = node_id.unwrap();
+
+        let int32_array = |values: &[i32]| {
+            ua::Array::from_slice(
+                &values
+                    .iter()
+                    .copied()
+                    .map(ua::Int32::new)
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let values = |value: &DataValue<ua::Variant>| -> Vec<i32> {
+            value
+                .value()
+                .to_array::<ua::Int32>()
+                .expect("should be array")
+                .iter()
+                .map(ua::Int32::value)
+                .collect()
+        };
+
+        client
+            .write_value(
+                &node_id,
+                &ua::DataValue::new(ua::Variant::array(int32_array(&[0, 1, 2, 3, 4, 5]))),
+            )
+            .await
+            .expect("should write value");
+
+        let range: ua::NumericRange = "2:4".parse().unwrap();
+        client
+            .write_value_range(
+                &node_id,
+                &range,
+                &ua::DataValue::new(ua::Variant::array(int32_array(&[20, 30, 40]))),
+            )
+            .await
+            .expect("should write value range");
+
+        let value = client
+            .read_value_range(&node_id, &"1:3".parse().unwrap())
+            .await
+            .expect("should read value range");
+        assert_eq!(values(&value), [1, 20, 30]);
+
+        let value = client
+            .read_value(&node_id)
+            .await
+            .expect("should read value");
+        assert_eq!(values(&value), [0, 1, 20, 30, 40, 5]);
+
+        let result = client
+            .read_value_range(&node_id, &"10:12".parse().unwrap())
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADINDEXRANGENODATA)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn read_values() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let results = harness
+            .client()
+            .read_values(&[
+                ua::NodeId::ns0_server_status_state(),
+                ua::NodeId::numeric(1, 123_456_789),
+                ua::NodeId::ns0_server_namespace_array(),
+            ])
+            .await
+            .expect("should send read request");
+        assert_eq!(results.len(), 3);
+        let mut results = results.into_iter();
+
+        let state = results.next().unwrap().expect("should read server state");
+        assert!(state.value().is_scalar());
+        let result = results.next().unwrap();
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNODEIDUNKNOWN)
+        );
+        let namespaces = results.next().unwrap().expect("should read namespaces");
+        assert!(namespaces.value().to_array::<ua::String>().is_some());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn write_typed_attribute() {
+        let mut node_ids = Vec::new();
+        let harness = TestHarness::start(|server| {
+            for (name, writable) in [("Writable", true), ("ReadOnly", false)] {
+                let node_id = server
+                    .add_node(Node::new(
+                        ua::NodeId::ns0_objects_folder(),
+                        ua::NodeId::ns0_organizes(),
+                        ua::QualifiedName::new(1, name),
+                        ua::VariableAttributes::init()
+                            .with_data_type(&ua::NodeId::ns0_int32())
+                            .with_value_rank(-1)
+                            .with_access_level(
+                                &ua::AccessLevel::NONE
+                                    .with_current_read(true)
+                                    .with_current_write(writable),
+                            ),
+                    ))
+                    .expect("should add node");
+                node_ids.push(node_id);
+            }
+        })
+        .expect("should start harness");
+        let client = harness.client();
+
+        let value = ua::Variant::scalar(ua::Int32::new(42));
+        client
+            .write_typed_attribute(&node_ids[0], ua::AttributeId::VALUE_T, &value)
+            .await
+            .expect("should write value");
+        let read_value = client
+            .read_attribute(&node_ids[0], ua::AttributeId::VALUE_T)
+            .await
+            .expect("should read value");
+        assert_eq!(
+            read_value.value().as_scalar::<ua::Int32>(),
+            Some(&ua::Int32::new(42))
+        );
+
+        let result = client
+            .write_typed_attribute(&node_ids[1], ua::AttributeId::VALUE_T, &value)
+            .await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNOTWRITABLE)
+        );
+
+        // Browse name cannot be written in open62541.
+        let result = client
+            .write_typed_attribute(
+                &node_ids[0],
+                ua::AttributeId::BROWSENAME_T,
+                &ua::QualifiedName::new(1, "Renamed"),
+            )
+            .await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADWRITENOTSUPPORTED)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn browse_inverse() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let supertype_description = |node_id: &ua::NodeId| {
+            ua::BrowseDescription::default()
+                .with_node_id(node_id)
+                .with_browse_direction(&ua::BrowseDirection::INVERSE)
+                .with_reference_type_id(&ua::NodeId::ns0_has_subtype())
+        };
+
+        let (references, continuation_point) = client
+            .browse(&supertype_description(&ua::NodeId::ns0_int32()))
+            .await
+            .expect("should browse node");
+        assert!(continuation_point.is_none());
+        let supertypes: Vec<_> = references
+            .iter()
+            .map(|reference| reference.node_id().node_id().clone())
+            .collect();
+        assert_eq!(supertypes, [ua::NodeId::ns0_integer()]);
+
+        let results = client
+            .browse_many(&[
+                supertype_description(&ua::NodeId::ns0_integer()),
+                supertype_description(&ua::NodeId::ns0_number()),
+            ])
+            .await
+            .expect("should browse nodes");
+        let supertypes: Vec<_> = results
+            .into_iter()
+            .map(|result| {
+                let (references, _) = result.expect("should browse node");
+                references
+                    .iter()
+                    .map(|reference| reference.node_id().node_id().clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(
+            supertypes,
+            [
+                [ua::NodeId::ns0_number()],
+                [ua::NodeId::ns0_base_data_type()]
+            ]
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn browse_view() {
+        let mut view_id = None;
+        let harness = TestHarness::start(|server| {
+            let id = server
+                .add_view_node(
+                    &ua::NodeId::ns0_views_folder(),
+                    ua::QualifiedName::new(1, "OperatorView"),
+                    ua::ViewAttributes::default().with_contains_no_loops(true),
+                )
+                .expect("should add view node");
+            server
+                .add_reference(
+                    &id,
+                    &ua::NodeId::ns0_organizes(),
+                    &ua::NodeId::ns0_server_status().into_expanded_node_id(),
+                    true,
+                )
+                .expect("should add reference");
+            view_id = Some(id);
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let view_id = view_id.unwrap();
+
+        // Members of the view are found by browsing the view node.
+        let (references, _) = client
+            .browse(&ua::BrowseDescription::default().with_node_id(&view_id))
+            .await
+            .expect("should browse view node");
+        let members: Vec<_> = references
+            .iter()
+            .map(|reference| reference.node_id().node_id().clone())
+            .collect();
+        assert_eq!(members, [ua::NodeId::ns0_server_status()]);
+
+        // The server does not support browsing restricted to views.
+        let result = client
+            .browse_in_view(
+                &ua::ViewDescription::init().with_view_id(&view_id),
+                &ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server()),
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADVIEWIDUNKNOWN)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn release_continuation_points() {
+        use std::slice;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        // Limit number of references to get continuation point for the well-populated node.
+        let request = ua::BrowseRequest::init()
+            .with_requested_max_references_per_node(1)
+            .with_nodes_to_browse(&[
+                ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server())
+            ]);
+        let response = super::service_request(&client.client, request)
+            .await
+            .expect("should browse node");
+        let continuation_point = response
+            .results()
+            .and_then(|results| results.as_slice().first()?.continuation_point())
+            .expect("should return continuation point");
+
+        client
+            .release_continuation_points(slice::from_ref(&continuation_point))
+            .await
+            .expect("should release continuation point");
+
+        // Released continuation points can neither be browsed nor released again.
+        let results = client
+            .browse_next(slice::from_ref(&continuation_point))
+            .await
+            .expect("should send browse request");
+        assert_eq!(
+            results
+                .into_iter()
+                .map(|result| result.map(|_| ()).map_err(|error| error.status_code()))
+                .collect::<Vec<_>>(),
+            [Err(ua::StatusCode::BADCONTINUATIONPOINTINVALID)]
+        );
+        let result = client
+            .release_continuation_points(slice::from_ref(&continuation_point))
+            .await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADCONTINUATIONPOINTINVALID)
+        );
+
+        client
+            .release_continuation_points(&[])
+            .await
+            .expect("should release nothing");
+
+        harness.shutdown().await.unwrap();
+    }
+
+    /// Starts test harness with method that doubles its `Int32` argument.
+    fn start_with_double_method() -> (TestHarness, ua::NodeId) {
+        use crate::{MethodCallback, MethodCallbackContext, MethodCallbackResult, MethodNode};
+
+        struct Double;
+
+        impl MethodCallback for Double {
+            fn call(&mut self, context: &mut MethodCallbackContext) -> MethodCallbackResult {
+                let input = context.input_arguments()[0]
+                    .to_scalar::<ua::Int32>()
+                    .expect("should be checked by server")
+                    .value();
+                context.output_arguments_mut()[0] = ua::Variant::scalar(ua::Int32::new(input * 2));
+                Ok(())
+            }
+        }
+
+        let argument = ua::Argument::init()
+            .with_data_type(&ua::NodeId::ns0_int32())
+            .with_value_rank(-1);
+
+        let mut method_id = None;
+        let harness = TestHarness::start(|server| {
+            let (node_id, _) = server
+                .add_method_node(
+                    MethodNode {
+                        requested_new_node_id: None,
+                        parent_node_id: ua::NodeId::ns0_objects_folder(),
+                        reference_type_id: ua::NodeId::ns0_has_component(),
+                        browse_name: ua::QualifiedName::new(1, "Double"),
+                        attributes: ua::MethodAttributes::init()
+                            .with_executable(true)
+                            .with_user_executable(true),
+                        input_arguments: ua::Array::from_slice(&[argument.clone()]),
+                        input_arguments_requested_new_node_id: None,
+                        output_arguments: ua::Array::from_slice(&[argument]),
+                        output_arguments_requested_new_node_id: None,
+                    },
+                    Double,
+                )
+                .expect("should add method node");
+            method_id = Some(node_id);
+        })
+        .expect("should start harness");
+
+        (harness, method_id.unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn call_methods() {
+        let (harness, method_id) = start_with_double_method();
+        let client = harness.client();
+        let object_id = ua::NodeId::ns0_objects_folder();
+
+        let results = client
+            .call_methods(&[
+                (
+                    object_id.clone(),
+                    method_id.clone(),
+                    vec![ua::Variant::scalar(ua::Int32::new(21))],
+                ),
+                (
+                    object_id.clone(),
+                    method_id.clone(),
+                    vec![ua::Variant::scalar(ua::String::new("21").unwrap())],
+                ),
+                (
+                    object_id.clone(),
+                    ua::NodeId::numeric(1, 123_456_789),
+                    Vec::new(),
+                ),
+                (
+                    object_id,
+                    method_id.clone(),
+                    vec![ua::Variant::scalar(ua::Int32::new(-5))],
+                ),
+            ])
+            .await
+            .expect("should send call request");
+        assert_eq!(results.len(), 4);
+        let mut results = results.into_iter();
+
+        let output_arguments = results.next().unwrap().expect("should call method");
+        assert_eq!(
+            output_arguments[0].as_scalar::<ua::Int32>(),
+            Some(&ua::Int32::new(42))
+        );
+
+        let Err(Error::Call(error)) = results.next().unwrap() else {
+            panic!("should reject input argument");
+        };
+        assert_eq!(error.method_id(), &method_id);
+        assert_eq!(error.status_code(), &ua::StatusCode::BADINVALIDARGUMENT);
+        assert_eq!(
+            error.rejected_arguments().collect::<Vec<_>>(),
+            [(0, &ua::StatusCode::BADTYPEMISMATCH)]
+        );
+
+        let result = results.next().unwrap();
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADMETHODINVALID)
+        );
+
+        let output_arguments = results.next().unwrap().expect("should call method");
+        assert_eq!(
+            output_arguments[0].as_scalar::<ua::Int32>(),
+            Some(&ua::Int32::new(-10))
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn call_method_typed() {
+        let (harness, method_id) = start_with_double_method();
+        let client = harness.client();
+        let object_id = ua::NodeId::ns0_objects_folder();
+
+        let [output]: [ua::Variant; 1] = client
+            .call_method_typed(&object_id, &method_id, [21])
+            .await
+            .expect("should call method");
+        assert_eq!(output.as_scalar::<ua::Int32>(), Some(&ua::Int32::new(42)));
+
+        let result = client
+            .call_method_typed::<_, Vec<ua::Variant>>(&object_id, &method_id, ["21"])
+            .await;
+        let Err(Error::Call(error)) = result else {
+            panic!("should reject input argument");
+        };
+        assert_eq!(error.status_code(), &ua::StatusCode::BADINVALIDARGUMENT);
+        assert_eq!(error.argument_index(), Some(0));
+
+        let result = client
+            .call_method_typed::<_, Vec<ua::Variant>>(&object_id, &method_id, ["2\01"])
+            .await;
+        assert!(matches!(result, Err(Error::NulByte("string"))));
+
+        let result = client
+            .call_method_typed::<_, [ua::Variant; 2]>(&object_id, &method_id, [21])
+            .await;
+        assert!(matches!(result, Err(Error::Internal(_))));
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn history_read_raw_without_history() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        // The test server has no history database, so the request is rejected entirely.
+        let result = harness
+            .client()
+            .history_read_raw(
+                &ua::ReadRawModifiedDetails::init().with_num_values_per_node(10),
+                &[ua::HistoryReadValueId::init()
+                    .with_node_id(&ua::NodeId::ns0_server_status_current_time())],
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNOTSUPPORTED)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn history_read_processed_without_history() {
+        use std::time::Duration;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        // The test server has no history database, so the request is rejected entirely.
+        let result = harness
+            .client()
+            .history_read_processed(
+                &ua::ReadProcessedDetails::init()
+                    .with_processing_interval(Duration::from_secs(60))
+                    .with_aggregate_type(&[ua::NodeId::ns0_aggregate_function_average()]),
+                &[ua::NodeId::ns0_server_status_current_time()],
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNOTSUPPORTED)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn history_update_without_history() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let node_id = ua::NodeId::ns0_server_status_current_time();
+
+        // The test server has no history database, so updates are rejected entirely.
+        let result = harness
+            .client()
+            .history_update_insert(
+                &node_id,
+                &[ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(1)))],
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNOTSUPPORTED)
+        );
+
+        let result = harness
+            .client()
+            .history_delete_raw(&node_id, ua::DateTime::init(), ua::DateTime::init())
+            .await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNOTSUPPORTED)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn register_nodes() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let registered_node_ids = client
+            .register_nodes(&[ua::NodeId::ns0_server_status_current_time()])
+            .await
+            .expect("should register nodes");
+        assert_eq!(registered_node_ids.len(), 1);
+
+        let value = client
+            .read_value(&registered_node_ids[0])
+            .await
+            .expect("should read registered node");
+        assert!(value.value().as_scalar::<ua::DateTime>().is_some());
+
+        client
+            .unregister_nodes(&registered_node_ids)
+            .await
+            .expect("should unregister nodes");
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn add_references() {
+        let mut node_ids = Vec::new();
+        let harness = TestHarness::start(|server| {
+            for name in ["Folder", "Variable"] {
+                let node_id = server
+                    .add_node(Node::new(
+                        ua::NodeId::ns0_objects_folder(),
+                        ua::NodeId::ns0_organizes(),
+                        ua::QualifiedName::new(1, name),
+                        ua::ObjectAttributes::init(),
+                    ))
+                    .expect("should add node");
+                node_ids.push(node_id);
+            }
+        })
+        .expect("should start harness");
 
-    let mut request_id: UA_UInt32 = 0;
-    let status_code = ua::StatusCode::new(unsafe {
-        __UA_Client_AsyncService(
-            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
-            client.as_ptr().cast_mut(),
-            request.as_ptr().cast::<c_void>(),
-            R::data_type(),
-            Some(callback_c::<R>),
-            R::Response::data_type(),
-            Cb::<R>::prepare(callback),
-            ptr::addr_of_mut!(request_id),
+        let reference = |source_node_id: &ua::NodeId, target_node_id: &ua::NodeId| {
+            ua::AddReferencesItem::init()
+                .with_source_node_id(source_node_id)
+                .with_reference_type_id(&ua::NodeId::ns0_organizes())
+                .with_is_forward(true)
+                .with_target_node_id(&target_node_id.clone().into_expanded_node_id())
+                .with_target_node_class(&ua::NodeClass::OBJECT)
+        };
+
+        let results = harness
+            .client()
+            .add_references(&[
+                reference(&node_ids[0], &node_ids[1]),
+                // Duplicating an existing reference is not allowed.
+                reference(&ua::NodeId::ns0_objects_folder(), &node_ids[1]),
+                reference(&node_ids[0], &ua::NodeId::numeric(1, 123_456_789)),
+            ])
+            .await
+            .expect("should send add references request");
+
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [
+                Ok(()),
+                Err(ua::StatusCode::BADDUPLICATEREFERENCENOTALLOWED),
+                Err(ua::StatusCode::BADTARGETNODEIDINVALID),
+            ]
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete_nodes_and_references() {
+        let mut node_ids = Vec::new();
+        let harness = TestHarness::start(|server| {
+            for name in ["First", "Second"] {
+                let node_id = server
+                    .add_node(Node::new(
+                        ua::NodeId::ns0_objects_folder(),
+                        ua::NodeId::ns0_organizes(),
+                        ua::QualifiedName::new(1, name),
+                        ua::ObjectAttributes::init(),
+                    ))
+                    .expect("should add node");
+                node_ids.push(node_id);
+            }
+        })
+        .expect("should start harness");
+        let client = harness.client();
+
+        let reference = ua::DeleteReferencesItem::init()
+            .with_source_node_id(&ua::NodeId::ns0_objects_folder())
+            .with_reference_type_id(&ua::NodeId::ns0_organizes())
+            .with_is_forward(true)
+            .with_target_node_id(&node_ids[1].clone().into_expanded_node_id())
+            .with_delete_bidirectional(true);
+        let results = client
+            .delete_references(&[reference.clone(), reference])
+            .await
+            .expect("should send delete references request");
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        // The reference is gone after it has been deleted once.
+        assert_eq!(status_codes.len(), 2);
+        assert_eq!(status_codes.first(), Some(&Ok(())));
+        assert!(matches!(status_codes.get(1), Some(Err(_))));
+
+        let results = client
+            .delete_nodes(&[
+                (node_ids[0].clone(), true),
+                (ua::NodeId::numeric(1, 123_456_789), true),
+            ])
+            .await
+            .expect("should send delete nodes request");
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [Ok(()), Err(ua::StatusCode::BADNODEIDUNKNOWN)]
+        );
+
+        let error = client
+            .read_value(&node_ids[0])
+            .await
+            .expect_err("node should have been deleted");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODEIDUNKNOWN);
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_servers() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        // Without discovery server, the server returns only its own description.
+        let servers = harness
+            .client()
+            .find_servers(&harness.endpoint_url(), &[], &[])
+            .await
+            .expect("should find servers");
+        assert_eq!(servers.len(), 1);
+        let server = &servers.as_slice()[0];
+        assert_eq!(server.application_type(), &ua::ApplicationType::SERVER);
+        assert!(server.application_uri().as_str().is_some());
+
+        // Unknown application URIs yield no servers.
+        let servers = harness
+            .client()
+            .find_servers(
+                &harness.endpoint_url(),
+                &[ua::String::new("urn:unknown").unwrap()],
+                &[],
+            )
+            .await
+            .expect("should find servers");
+        assert!(servers.is_empty());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn find_servers_on_network_without_multicast() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let result = harness.client().find_servers_on_network(0, 0, &[]).await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADSERVICEUNSUPPORTED)
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn connect_without_blocking() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        assert!(client.state().session_state.is_activated());
+        client
+            .read_value(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .expect("should read value");
+        client.disconnect().await;
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn connect_without_blocking_fails() {
+        use std::{
+            net::{Ipv4Addr, TcpListener},
+            time::Duration,
+        };
+
+        // Find port that nobody listens on.
+        let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .and_then(|listener| listener.local_addr())
+            .unwrap()
+            .port();
+
+        let result = ClientBuilder::default()
+            .timeout(Duration::from_secs(2))
+            .connect_async(&format!("opc.tcp://localhost:{port}"))
+            .await;
+        assert!(result.is_err());
+
+        let result = AsyncClient::connect("opc.tcp://localhost\0:4840").await;
+        assert!(matches!(result, Err(Error::NulByte("endpoint URL"))));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn state_stream() {
+        use std::time::Duration;
+
+        use futures::StreamExt as _;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        let stream = client.state_stream();
+
+        // Disconnecting changes the session state. The stream ends when the client is gone.
+        client.disconnect().await;
+        let states: Vec<_> = tokio::time::timeout(Duration::from_secs(10), stream.collect())
+            .await
+            .expect("stream should end");
+        let last_state: &ua::ClientState = states.last().expect("should receive state changes");
+        assert!(!last_state.session_state.is_activated());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn inactivity_callbacks() {
+        use std::{
+            num::NonZeroU32,
+            sync::{
+                atomic::{AtomicUsize, Ordering},
+                Arc,
+            },
+            thread,
+            time::Duration,
+        };
+
+        use crate::{ServerBuilder, SubscriptionBuilder};
+
+        let session_inactivity_count = Arc::new(AtomicUsize::new(0));
+        let subscription_inactivity_count = Arc::new(AtomicUsize::new(0));
+        let client_builder = ClientBuilder::default()
+            .timeout(Duration::from_millis(200))
+            .connectivity_check_interval(Some(Duration::from_millis(100)))
+            .on_session_inactivity({
+                let session_inactivity_count = Arc::clone(&session_inactivity_count);
+                move || {
+                    session_inactivity_count.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+            .on_subscription_inactivity({
+                let subscription_inactivity_count = Arc::clone(&subscription_inactivity_count);
+                move |_| {
+                    subscription_inactivity_count.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        let harness =
+            TestHarness::start_with(ServerBuilder::minimal(0, None), client_builder, |_| {})
+                .expect("should start harness");
+
+        let (_, _subscription) = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_millis(100)))
+            .requested_max_keep_alive_count(NonZeroU32::new(1))
+            .create(harness.client())
+            .await
+            .unwrap();
+
+        // Healthy connections and subscriptions never trigger the callbacks.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(session_inactivity_count.load(Ordering::Relaxed), 0);
+        assert_eq!(subscription_inactivity_count.load(Ordering::Relaxed), 0);
+
+        // Block the server's event loop. Connectivity checks and publish requests are no longer
+        // answered in time.
+        let blocked = harness
+            .server()
+            .execute(|_| thread::sleep(Duration::from_millis(1500)));
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        blocked.await.expect("should block server");
+        assert!(session_inactivity_count.load(Ordering::Relaxed) > 0);
+        assert!(subscription_inactivity_count.load(Ordering::Relaxed) > 0);
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn session_keep_alive() {
+        use std::time::Duration;
+
+        use crate::ServerBuilder;
+
+        for session_keep_alive in [true, false] {
+            let client_builder = ClientBuilder::default()
+                .requested_session_timeout(Duration::from_secs(1))
+                .session_keep_alive(session_keep_alive);
+            let harness =
+                TestHarness::start_with(ServerBuilder::minimal(0, None), client_builder, |_| {})
+                    .expect("should start harness");
+
+            // Idle sessions time out on the server unless they are kept alive.
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            let metrics = harness.server().metrics().await.unwrap();
+            assert_eq!(metrics.session_timeout_count() == 0, session_keep_alive);
+
+            harness.shutdown().await.unwrap();
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn read_value_with_options() {
+        use std::time::Duration;
+
+        use crate::ReadOptions;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let node_id = ua::NodeId::ns0_server_status_current_time();
+
+        let value = harness
+            .client()
+            .read_value_with_options(
+                &node_id,
+                &ReadOptions {
+                    max_age: Some(Duration::from_secs(1)),
+                    timestamps: ua::TimestampsToReturn::SERVER,
+                },
+            )
+            .await
+            .expect("should read value");
+        assert!(value.server_timestamp().is_some());
+        assert!(value.source_timestamp().is_none());
+
+        let value = harness
+            .client()
+            .read_value_with_options(
+                &node_id,
+                &ReadOptions {
+                    timestamps: ua::TimestampsToReturn::NEITHER,
+                    ..ReadOptions::default()
+                },
+            )
+            .await
+            .expect("should read value");
+        assert!(value.server_timestamp().is_none());
+        assert!(value.source_timestamp().is_none());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancel_dropped_requests() {
+        use std::time::Duration;
+
+        use crate::ServerBuilder;
+
+        let client_builder = ClientBuilder::default().cancel_dropped_requests(true);
+        let harness =
+            TestHarness::start_with(ServerBuilder::minimal(0, None), client_builder, |_| {})
+                .expect("should start harness");
+        let client = harness.client();
+
+        // Drop pending requests. This sends `Cancel` requests, which do not affect the connection.
+        for _ in 0..10 {
+            let _unused = tokio::time::timeout(
+                Duration::ZERO,
+                client.browse(
+                    &ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server()),
+                ),
+            )
+            .await;
+        }
+
+        let value = client
+            .read_value(&ua::NodeId::ns0_server_status_state())
+            .await
+            .expect("should read value after cancelling");
+        assert!(value.value().is_scalar());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancel_request() {
+        use std::{
+            sync::{Arc, Mutex},
+            time::Duration,
+        };
+
+        use crate::{
+            AsyncMethodCallback, AsyncMethodCallbackResult, AsyncOperationHandle,
+            MethodCallbackContext, MethodNode,
+        };
+
+        /// Never completes calls. Handles are kept to prevent calls from timing out.
+        struct Never(Arc<Mutex<Vec<AsyncOperationHandle>>>);
+
+        impl AsyncMethodCallback for Never {
+            fn call(
+                &mut self,
+                _context: &mut MethodCallbackContext,
+                handle: AsyncOperationHandle,
+            ) -> AsyncMethodCallbackResult {
+                self.0.lock().unwrap().push(handle);
+                AsyncMethodCallbackResult::Pending
+            }
+        }
+
+        let handles = Arc::new(Mutex::new(Vec::new()));
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let (method_id, _) = harness
+            .server()
+            .add_async_method_node(
+                MethodNode {
+                    requested_new_node_id: None,
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_has_component(),
+                    browse_name: ua::QualifiedName::new(1, "Never"),
+                    attributes: ua::MethodAttributes::init()
+                        .with_executable(true)
+                        .with_user_executable(true),
+                    input_arguments: ua::Array::new(0),
+                    input_arguments_requested_new_node_id: None,
+                    output_arguments: ua::Array::new(0),
+                    output_arguments_requested_new_node_id: None,
+                },
+                Never(Arc::clone(&handles)),
+            )
+            .expect("should add method node");
+
+        let request = ua::CallRequest::init()
+            .with_methods_to_call(&[ua::CallMethodRequest::init()
+                .with_object_id(&ua::NodeId::ns0_objects_folder())
+                .with_method_id(&method_id)]);
+        let mut call = harness
+            .client()
+            .send_call_request(request)
+            .expect("should send call request");
+
+        // Wait for the server to hand the call to the method callback.
+        for _ in 0..50 {
+            if !handles.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(handles.lock().unwrap().len(), 1);
+
+        call.cancel();
+
+        // This status code is set by the server only when it has received the `Cancel` request and
+        // aborted the pending call.
+        let error = call.await.expect_err("should cancel call");
+        assert_eq!(
+            error.status_code(),
+            ua::StatusCode::BADREQUESTCANCELLEDBYCLIENT
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn with_timeout() {
+        use std::time::Duration;
+
+        use crate::{MethodCallback, MethodCallbackContext, MethodCallbackResult, MethodNode};
+
+        struct Sleep;
+
+        impl MethodCallback for Sleep {
+            fn call(&mut self, _context: &mut MethodCallbackContext) -> MethodCallbackResult {
+                std::thread::sleep(Duration::from_millis(500));
+                Ok(())
+            }
+        }
+
+        let mut method_id = None;
+        let harness = TestHarness::start(|server| {
+            let (node_id, _) = server
+                .add_method_node(
+                    MethodNode {
+                        requested_new_node_id: None,
+                        parent_node_id: ua::NodeId::ns0_objects_folder(),
+                        reference_type_id: ua::NodeId::ns0_has_component(),
+                        browse_name: ua::QualifiedName::new(1, "Sleep"),
+                        attributes: ua::MethodAttributes::init()
+                            .with_executable(true)
+                            .with_user_executable(true),
+                        input_arguments: ua::Array::new(0),
+                        input_arguments_requested_new_node_id: None,
+                        output_arguments: ua::Array::new(0),
+                        output_arguments_requested_new_node_id: None,
+                    },
+                    Sleep,
+                )
+                .expect("should add method node");
+            method_id = Some(node_id);
+        })
+        .expect("should start harness");
+        let method_id = method_id.expect("should have method ID");
+        let client = harness.client();
+
+        let value = AsyncClient::with_timeout(
+            Duration::from_secs(10),
+            client.read_value(&ua::NodeId::ns0_server_status_state()),
         )
-    });
-    // The request itself fails when the client is not connected (or the secure session has not been
-    // established). In all other cases, `open62541` processes the request first and then may reject
-    // it only through the response when executing our callback above.
-    Error::verify_good(&status_code).inspect_err(|_| {
-        log::warn!("{} failed: {status_code:?}", R::type_name());
-    })?;
+        .await
+        .expect("should read value within timeout");
+        assert!(value.value().is_scalar());
 
-    log::trace!("Assigned ID {request_id} to {}", R::type_name());
+        let error = AsyncClient::with_timeout(
+            Duration::from_millis(100),
+            client.call_method(&ua::NodeId::ns0_objects_folder(), &method_id, &[]),
+        )
+        .await
+        .expect_err("should time out");
+        assert_eq!(error.status_code(), ua::StatusCode::BADTIMEOUT);
 
-    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
-    // is only dropped after placing a value into the channel and `rx.await` always finds this value
-    // there.
-    rx.await
-        .unwrap_or(Err(Error::internal("callback should send result")))
-}
+        harness.shutdown().await.unwrap();
+    }
 
-/// Converts [`ua::BrowseResult`] to our public result type.
-fn to_browse_result(result: &ua::BrowseResult, node_id: Option<&ua::NodeId>) -> BrowseResult {
-    // Make sure to verify the inner status code inside `BrowseResult`. The service request finishes
-    // without error, even when browsing the node has failed.
-    Error::verify_good(&result.status_code())?;
+    #[tokio::test(flavor = "multi_thread")]
+    async fn default_request_header_options() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
 
-    let references = if let Some(references) = result.references() {
-        references.into_vec()
-    } else {
-        // When no references exist, some OPC UA servers do not return an empty references array but
-        // an invalid (unset) one instead, e.g. Siemens SIMOTION. We treat it as an empty array, and
-        // continue without error.
-        if let Some(node_id) = node_id {
-            log::debug!("Browsing {node_id} returned unset references, assuming none exist");
-        } else {
-            log::debug!(
-                "Browsing continuation point returned unset references, assuming none exist",
-            );
+        let options = ua::RequestHeader::init()
+            .with_audit_entry_id(&ua::String::new("operator").unwrap())
+            .with_return_diagnostics(0x3ff);
+        assert_eq!(options.audit_entry_id().as_str(), Some("operator"));
+        assert_eq!(options.return_diagnostics(), 0x3ff);
+        client.set_default_request_header_options(options);
+
+        // Servers accept requests with these options. Failures may include diagnostics.
+        let value = client
+            .read_value(&ua::NodeId::ns0_server_status_state())
+            .await
+            .expect("should read value with request header options");
+        assert!(value.value().is_scalar());
+        let error = client
+            .read_value(&ua::NodeId::numeric(1, 123_456_789))
+            .await
+            .expect_err("should fail for unknown node");
+        assert_eq!(error.status_code(), ua::StatusCode::BADNODEIDUNKNOWN);
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn request_diagnostics() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        client.set_default_request_header_options(
+            ua::RequestHeader::init().with_return_diagnostics(0x3ff),
+        );
+
+        let results = client
+            .read_many_attributes(&[(ua::NodeId::numeric(1, 123_456_789), ua::AttributeId::VALUE)])
+            .await
+            .expect("should send read request");
+        let error = results
+            .into_iter()
+            .next()
+            .expect("should have result")
+            .expect_err("should fail for unknown node");
+
+        // `open62541` servers do not return diagnostic info, so none is attached here.
+        let Error::Server(status_code) = &error else {
+            panic!("should be server error");
+        };
+        assert_eq!(status_code, &ua::StatusCode::BADNODEIDUNKNOWN);
+        assert!(error.diagnostic_info().is_none());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn namespace_index() {
+        use crate::ObjectNode;
+
+        let mut namespace_index = 0;
+        let harness = TestHarness::start(|server| {
+            namespace_index = server.add_namespace("http://example.com/first/");
+            server
+                .add_object_node(ObjectNode {
+                    requested_new_node_id: Some(ua::NodeId::string(namespace_index, "Machine")),
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(namespace_index, "Machine"),
+                    type_definition: ua::NodeId::ns0_base_object_type(),
+                    attributes: ua::ObjectAttributes::default(),
+                })
+                .expect("should add object node");
+        })
+        .expect("should start harness");
+        let client = harness.client();
+
+        let namespace_array = client
+            .namespace_array()
+            .await
+            .expect("should read namespace array");
+        assert_eq!(namespace_array[0], "http://opcfoundation.org/UA/");
+        assert_eq!(
+            namespace_array[usize::from(namespace_index)],
+            "http://example.com/first/"
+        );
+
+        assert_eq!(
+            client
+                .namespace_index("http://example.com/first/")
+                .await
+                .unwrap(),
+            Some(namespace_index)
+        );
+        assert_eq!(
+            client
+                .namespace_index("http://example.com/unknown/")
+                .await
+                .unwrap(),
+            None
+        );
+
+        // Namespaces added at runtime are found without refreshing explicitly.
+        let second_index = harness.server().add_namespace("http://example.com/second/");
+        assert_eq!(
+            client
+                .namespace_index("http://example.com/second/")
+                .await
+                .unwrap(),
+            Some(second_index)
+        );
+
+        let node_id = client
+            .node_id_from_uri("http://example.com/first/", "s=Machine")
+            .await
+            .expect("should resolve node ID")
+            .expect("namespace should exist");
+        assert_eq!(node_id, ua::NodeId::string(namespace_index, "Machine"));
+        let browse_name = client
+            .read_attribute(&node_id, ua::AttributeId::BROWSENAME_T)
+            .await
+            .expect("should read browse name of node");
+        assert_eq!(browse_name.value().name().as_str(), Some("Machine"));
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn read_value_as() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let _current_time: ua::DateTime = client
+            .read_value_as(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .expect("should read current time");
+
+        let namespaces: Vec<ua::String> = client
+            .read_array_value_as(&ua::NodeId::ns0_server_namespace_array())
+            .await
+            .expect("should read namespace array");
+        assert_eq!(namespaces[0].as_str(), Some("http://opcfoundation.org/UA/"));
+
+        let Err(Error::TypeMismatch(error)) = client
+            .read_value_as::<ua::Int32>(&ua::NodeId::ns0_server_namespace_array())
+            .await
+        else {
+            panic!("should reject array of strings");
+        };
+        assert_eq!(error.node_id(), &ua::NodeId::ns0_server_namespace_array());
+        assert_eq!(error.expected_type_id(), &ua::NodeId::ns0_int32());
+        assert!(!error.expected_array());
+        assert_eq!(error.value_type(), Some(&ValueType::String));
+        assert!(error.is_array());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn disconnect_timeout() {
+        use std::time::Duration;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        assert!(client.disconnect_timeout(Duration::from_secs(10)).await);
+
+        // The server cannot respond in no time, so disconnecting is forced.
+        let client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        assert!(!client.disconnect_timeout(Duration::ZERO).await);
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn connect_to_endpoint() {
+        use std::net::{Ipv4Addr, TcpListener};
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let endpoint_url = harness.endpoint_url();
+
+        let endpoints = tokio::task::spawn_blocking({
+            let endpoint_url = endpoint_url.clone();
+            move || ClientBuilder::default().get_endpoints(&endpoint_url)
+        })
+        .await
+        .unwrap()
+        .expect("should get endpoints");
+        let endpoint = endpoints
+            .iter()
+            .find(|endpoint| endpoint.security_mode() == &ua::MessageSecurityMode::NONE)
+            .expect("should have endpoint without security");
+
+        // Pretend that the server advertises an address that is not reachable. The client must use
+        // the given endpoint and fall back to the URL that it connects to.
+        let port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .and_then(|listener| listener.local_addr())
+            .unwrap()
+            .port();
+        let unreachable_url = ua::String::new(&format!("opc.tcp://localhost:{port}")).unwrap();
+        let endpoint = endpoint.clone().with_endpoint_url(&unreachable_url);
+
+        let client = ClientBuilder::default()
+            .endpoint(endpoint)
+            .connect_async(&endpoint_url)
+            .await
+            .expect("should connect");
+        let value = client
+            .read_value(&ua::NodeId::numeric(0, 2256))
+            .await
+            .expect("should read server status");
+        assert!(!value.value().is_empty());
+        client.disconnect().await;
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn change_identity() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+
+        client
+            .change_identity(&ua::UserIdentityToken::Anonymous(
+                ua::AnonymousIdentityToken::init(),
+            ))
+            .await
+            .expect("should change identity");
+
+        // The session has been kept along with its subscriptions.
+        assert!(client.state().session_state.is_activated());
+        let _monitored_item = subscription
+            .create_monitored_item(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .expect("should keep subscription");
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn change_identity_rejected() {
+        use std::time::Duration;
+
+        use futures::StreamExt as _;
+
+        use crate::{DefaultAccessControlWithLoginCallback, Login, ReconnectPolicy, ServerBuilder};
+
+        let login_callback = |user_name: &ua::String, password: &ua::ByteString| {
+            if user_name.as_str() == Some("operator") && password.as_bytes() == Some(b"secret") {
+                Login::accept()
+            } else {
+                Login::reject(ua::StatusCode::BADUSERACCESSDENIED)
+            }
+        };
+        let mut server_builder = ServerBuilder::minimal(0, None)
+            .access_control(DefaultAccessControlWithLoginCallback::new(
+                false,
+                login_callback,
+            ))
+            .expect("should set access control");
+        // The test client connects without encryption.
+        server_builder.config_mut().allowNonePolicyPassword = true;
+
+        let harness = TestHarness::start_with(
+            server_builder,
+            ClientBuilder::default()
+                .username_password("operator", "secret")
+                .auto_reconnect(
+                    ReconnectPolicy::default().initial_delay(Duration::from_millis(100)),
+                ),
+            |_| {},
+        )
+        .expect("should start harness");
+        let client = harness.client();
+        let mut state_stream = client.state_stream();
+
+        let result = client
+            .change_identity(&ua::UserIdentityToken::UserName(
+                ua::UserNameIdentityToken::new("intruder", "wrong"),
+            ))
+            .await;
+        assert!(result.is_err());
+
+        // The previous identity has been restored, so reconnecting succeeds.
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !client.state().session_state.is_activated() {
+                state_stream
+                    .next()
+                    .await
+                    .expect("should receive state changes");
+            }
+        })
+        .await
+        .expect("should reconnect with previous identity");
+        client
+            .read_value(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .expect("should read value");
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn auto_reconnect_restores_subscriptions() {
+        use std::time::Duration;
+
+        use futures::StreamExt as _;
+
+        use crate::{ReconnectPolicy, ServerBuilder, SubscriptionEvent};
+
+        let mut harness = TestHarness::start_with(
+            ServerBuilder::minimal(0, None),
+            ClientBuilder::default().auto_reconnect(
+                ReconnectPolicy::default().initial_delay(Duration::from_millis(100)),
+            ),
+            |_| {},
+        )
+        .expect("should start harness");
+
+        let subscription = harness.client().create_subscription().await.unwrap();
+        let mut events = subscription.events();
+        let mut monitored_item = subscription
+            .create_monitored_item(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .unwrap();
+        let value = tokio::time::timeout(Duration::from_secs(10), monitored_item.next())
+            .await
+            .expect("should receive value")
+            .expect("should not end");
+        assert!(value.value().is_some());
+
+        harness
+            .restart_server(ServerBuilder::minimal(0, None), |_| {})
+            .expect("should restart server");
+
+        // The lost subscription is reported, and the gap is marked in the value stream.
+        let event = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let event = events.next().await.expect("should not end");
+                if let SubscriptionEvent::StatusChange(status_code) = event {
+                    break status_code;
+                }
+            }
+        })
+        .await
+        .expect("should receive status change");
+        assert_eq!(event, ua::StatusCode::BADNOCOMMUNICATION);
+        let value = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                // Values may still arrive until the connection loss has been noticed.
+                let value = monitored_item.next().await.expect("should not end");
+                if value.value().is_none() {
+                    break value;
+                }
+            }
+        })
+        .await
+        .expect("should receive gap marker");
+        assert_eq!(value.status(), Some(ua::StatusCode::BADNOCOMMUNICATION));
+
+        // Values of the recreated monitored item follow in the same stream.
+        let value = tokio::time::timeout(Duration::from_secs(30), monitored_item.next())
+            .await
+            .expect("should resume after reconnecting")
+            .expect("should not end");
+        assert!(value.value().is_some());
+        assert!(harness.client().state().session_state.is_activated());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn transfer_subscriptions() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+
+        let results = client
+            .transfer_subscriptions(
+                &[
+                    subscription.subscription_id(),
+                    ua::SubscriptionId::new(u32::MAX),
+                ],
+                false,
+            )
+            .await
+            .expect("should transfer subscriptions");
+        assert_eq!(results.len(), 2);
+        let mut results = results.into_iter();
+
+        // Transferring to the same session is accepted without moving anything.
+        let result = results.next().unwrap();
+        assert_eq!(result.status_code(), ua::StatusCode::GOOD);
+        let result = results.next().unwrap();
+        assert_eq!(
+            result.status_code(),
+            ua::StatusCode::BADSUBSCRIPTIONIDINVALID
+        );
+        assert!(result.available_sequence_numbers().is_empty());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn transfer_subscriptions_notifications() {
+        use std::time::Duration;
+
+        use futures::StreamExt as _;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let _monitored_item = subscription
+            .create_monitored_item(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .unwrap();
+        let subscription_id = subscription.subscription_id();
+        // Only adopted subscriptions have their notifications published by us.
+        assert!(subscription.notifications().is_err());
+
+        let other_client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        let results = other_client
+            .transfer_subscriptions(&[subscription_id], true)
+            .await
+            .expect("should transfer subscription");
+        assert_eq!(results[0].status_code(), ua::StatusCode::GOOD);
+
+        let adopted = other_client.adopt_subscription(subscription_id);
+        let (_, client_handles) = adopted.get_monitored_items().await.unwrap();
+        let mut notifications = adopted.notifications().expect("should publish");
+
+        // Subscriptions of its own cannot be published for alongside.
+        let error = other_client.create_subscription().await.unwrap_err();
+        assert_eq!(error.status_code(), ua::StatusCode::BADINVALIDSTATE);
+
+        // The server sends initial values after the transfer, then changes of the current time.
+        for _ in 0..2 {
+            let message = tokio::time::timeout(Duration::from_secs(10), notifications.next())
+                .await
+                .expect("should receive notification")
+                .expect("should not end");
+            let data_changes = message.data_change_notifications();
+            assert_eq!(data_changes.len(), 1);
+            let items = data_changes[0].monitored_items().unwrap();
+            assert!(items
+                .iter()
+                .all(|item| client_handles.contains(&item.client_handle())));
         }
-        Vec::new()
-    };
 
-    Ok((references, result.continuation_point()))
+        // The stream ends when the adopted subscription is deleted.
+        let response = adopted.delete().await.unwrap();
+        assert_eq!(response.results().unwrap()[0], ua::StatusCode::GOOD);
+        assert!(notifications.next().await.is_none());
+
+        drop(other_client);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
 }
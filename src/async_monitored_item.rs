@@ -1,8 +1,13 @@
 use std::{
     ffi::c_void,
+    mem,
+    num::NonZeroUsize,
     pin::Pin,
     ptr,
-    sync::{Arc, Weak},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Weak,
+    },
     task::{self, Poll},
     time::Duration,
 };
@@ -12,26 +17,36 @@ use futures_core::Stream;
 use futures_util::stream;
 use open62541_sys::{
     UA_Client, UA_Client_DataChangeNotificationCallback, UA_Client_DeleteMonitoredItemCallback,
-    UA_Client_MonitoredItems_createDataChanges_async, UA_Client_MonitoredItems_delete_async,
-    UA_CreateMonitoredItemsResponse, UA_DataValue, UA_DeleteMonitoredItemsResponse, UA_UInt32,
+    UA_Client_EventNotificationCallback, UA_Client_MonitoredItems_createDataChanges_async,
+    UA_Client_MonitoredItems_delete_async, UA_CreateMonitoredItemsResponse, UA_DataValue,
+    UA_DeleteMonitoredItemsResponse, UA_UInt32, UA_Variant,
+    UA_NS0ID_SERVER_SERVERCAPABILITIES_OPERATIONLIMITS_MAXMONITOREDITEMSPERCALL,
 };
-use tokio::sync::mpsc;
 
 use crate::{
-    ua, AsyncSubscription, CallbackOnce, CallbackStream, DataType as _, Error, MonitoringFilter,
-    Result,
+    async_client::{minimum_sampling_interval, read_many_attributes},
+    async_subscription::{modify_monitored_items, set_monitoring_mode},
+    client::ClientContext,
+    ua, AsyncSubscription, CallbackOnce, CallbackStream, CallbackStreamReceiver,
+    CallbackStreamSender, DataType as _, DataValue, Error, EventValue, MonitoringFilter, Result,
+    StreamOverflow, Userdata,
 };
 
 #[derive(Debug)]
 pub struct MonitoredItemBuilder {
     node_ids: Vec<ua::NodeId>,
     attribute_id: Option<ua::AttributeId>,
+    index_range: Option<ua::NumericRange>,
     monitoring_mode: Option<ua::MonitoringMode>,
     #[allow(clippy::option_option)]
     sampling_interval: Option<Option<Duration>>,
     filter: Option<Box<dyn MonitoringFilter>>,
     queue_size: Option<u32>,
     discard_oldest: Option<bool>,
+    timestamps_to_return: Option<ua::TimestampsToReturn>,
+    clamp_to_minimum_sampling_interval: bool,
+    stream_buffer_size: Option<NonZeroUsize>,
+    stream_overflow: Option<StreamOverflow>,
 }
 
 // Note: The default values in the docs below come from `UA_MonitoredItemCreateRequest_default()`.
@@ -40,11 +55,16 @@ impl MonitoredItemBuilder {
         Self {
             node_ids: node_ids.into_iter().collect(),
             attribute_id: None,
+            index_range: None,
             monitoring_mode: None,
             sampling_interval: None,
             filter: None,
             queue_size: None,
             discard_oldest: None,
+            timestamps_to_return: None,
+            clamp_to_minimum_sampling_interval: false,
+            stream_buffer_size: None,
+            stream_overflow: None,
         }
     }
 
@@ -59,6 +79,35 @@ impl MonitoredItemBuilder {
         self
     }
 
+    /// Sets index range.
+    ///
+    /// This monitors only part of array values, e.g. `0:9` for the first ten elements. Received
+    /// values then hold only the selected elements. When the server rejects the range, the result
+    /// of the individual monitored item holds an error such as
+    /// [`ua::StatusCode::BADINDEXRANGEINVALID`].
+    ///
+    /// Default value is no index range, i.e. the entire value.
+    ///
+    /// See [`ua::MonitoredItemCreateRequest::with_index_range()`].
+    #[must_use]
+    pub fn index_range(mut self, index_range: ua::NumericRange) -> Self {
+        self.index_range = Some(index_range);
+        self
+    }
+
+    /// Sets timestamps to return with each value.
+    ///
+    /// This applies to all monitored items created with this builder.
+    ///
+    /// Default value is [`ua::TimestampsToReturn::SOURCE`].
+    ///
+    /// See [`ua::CreateMonitoredItemsRequest::with_timestamps_to_return()`].
+    #[must_use]
+    pub fn timestamps_to_return(mut self, timestamps_to_return: ua::TimestampsToReturn) -> Self {
+        self.timestamps_to_return = Some(timestamps_to_return);
+        self
+    }
+
     /// Sets monitoring mode.
     ///
     /// Default value is [`ua::MonitoringMode::REPORTING`].
@@ -94,7 +143,8 @@ impl MonitoredItemBuilder {
 
     /// Sets requested size of the monitored item queue.
     ///
-    /// Default value is 1.
+    /// Default value is 1. In [durable](crate::SubscriptionBuilder::durable) subscriptions, the
+    /// default value covers the buffer duration at the publishing interval.
     ///
     /// See [`ua::MonitoringParameters::with_queue_size()`].
     #[must_use]
@@ -107,6 +157,10 @@ impl MonitoredItemBuilder {
     ///
     /// Default value is `true`.
     ///
+    /// This also applies to the local buffer of received values, when values are received faster
+    /// than they are being consumed from [`AsyncMonitoredItem`]. Use
+    /// [`stream_overflow()`](Self::stream_overflow) to set a different policy for the local buffer.
+    ///
     /// See [`ua::MonitoringParameters::with_discard_oldest()`].
     #[must_use]
     pub const fn discard_oldest(mut self, discard_oldest: bool) -> Self {
@@ -114,6 +168,54 @@ impl MonitoredItemBuilder {
         self
     }
 
+    /// Enables clamping of sampling interval to minimum sampling interval of nodes.
+    ///
+    /// Servers revise requested sampling intervals that are shorter than the node's minimum sampling
+    /// interval. When enabled, the `MinimumSamplingInterval` attribute of all nodes is read (in a
+    /// single request) before creating the monitored items, and shorter sampling intervals are
+    /// raised to the minimum upfront. This only applies when monitoring the value attribute.
+    ///
+    /// The sampling interval granted by the server is still returned in
+    /// [`ua::MonitoredItemCreateResult::revised_sampling_interval()`].
+    ///
+    /// Default value is `false`.
+    ///
+    /// See [`AsyncClient::read_minimum_sampling_interval()`].
+    ///
+    /// [`AsyncClient::read_minimum_sampling_interval()`]: crate::AsyncClient::read_minimum_sampling_interval
+    #[must_use]
+    pub const fn clamp_to_minimum_sampling_interval(
+        mut self,
+        clamp_to_minimum_sampling_interval: bool,
+    ) -> Self {
+        self.clamp_to_minimum_sampling_interval = clamp_to_minimum_sampling_interval;
+        self
+    }
+
+    /// Sets size of the local buffer of received values.
+    ///
+    /// Values are buffered when they are received faster than they are being consumed from
+    /// [`AsyncMonitoredItem`]. Increase this for servers that send values in bursts. With a size of
+    /// 1 and [`StreamOverflow::DiscardOldest`], only the latest value is kept.
+    ///
+    /// Default value is 3.
+    #[must_use]
+    pub const fn stream_buffer_size(mut self, stream_buffer_size: NonZeroUsize) -> Self {
+        self.stream_buffer_size = Some(stream_buffer_size);
+        self
+    }
+
+    /// Sets policy for full local buffer of received values.
+    ///
+    /// Discarded values are counted, see [`AsyncMonitoredItem::discarded_values()`].
+    ///
+    /// Default value matches [`discard_oldest()`](Self::discard_oldest).
+    #[must_use]
+    pub const fn stream_overflow(mut self, stream_overflow: StreamOverflow) -> Self {
+        self.stream_overflow = Some(stream_overflow);
+        self
+    }
+
     /// Creates monitored items.
     ///
     /// This creates one or more new monitored items. Returns one result for each node ID.
@@ -129,73 +231,163 @@ impl MonitoredItemBuilder {
         let Some(client) = &subscription.client().upgrade() else {
             return Err(Error::internal("client should not be dropped"));
         };
+        let subscription_id = subscription.shared_subscription_id();
+        let stream_options = self.stream_options();
+
+        let request = self.prepare_request(client, subscription).await?;
+
+        create_items(client, subscription_id, &request, stream_options).await
+    }
+
+    /// Creates monitored items in chunks.
+    ///
+    /// This splits the monitored items into chunks of at most `chunk_size` items and creates each
+    /// chunk with a separate request. When the server announces a lower limit of monitored items
+    /// per call in its operation limits, that limit is used instead.
+    ///
+    /// When given, `progress` is called after each chunk with the number of monitored items that
+    /// have been processed so far and the total number of monitored items.
+    ///
+    /// # Errors
+    ///
+    /// This fails when any of the requests is not successful as a whole. Monitored items that have
+    /// been created by earlier chunks are deleted again in this case. Errors for individual node
+    /// IDs are returned in [`MonitoredItemsSummary::failed()`].
+    pub async fn create_chunked(
+        self,
+        subscription: &AsyncSubscription,
+        chunk_size: NonZeroUsize,
+        mut progress: Option<&mut (dyn FnMut(usize, usize) + Send)>,
+    ) -> Result<MonitoredItemsSummary> {
+        let Some(client) = &subscription.client().upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+        let shared_subscription_id = subscription.shared_subscription_id();
         let subscription_id = subscription.subscription_id();
+        let stream_options = self.stream_options();
 
-        let request = self.into_request(subscription_id);
-        let result_count = request.items_to_create().map_or(0, <[_]>::len);
-        let (response, rxs) = create_monitored_items(client, &request).await?;
+        let request = self.prepare_request(client, subscription).await?;
+        let items_to_create = request.items_to_create().unwrap_or_default();
 
-        let Some(mut results) = response.into_results() else {
-            return Err(Error::internal("expected monitoring item results"));
+        let chunk_size = match read_max_monitored_items_per_call(client).await {
+            Some(max_monitored_items_per_call) => chunk_size.min(max_monitored_items_per_call),
+            None => chunk_size,
         };
 
-        if results.len() != result_count || rxs.len() != result_count {
-            // This should not happen. In any case, we cannot associate returned items with their
-            // incoming node IDs. Clean up the items that we received to not leave them dangling.
-            //
-            let monitored_item_ids = results
-                .iter()
-                .filter(|result| result.status_code().is_good())
-                .map(ua::MonitoredItemCreateResult::monitored_item_id)
-                .collect::<Vec<_>>();
-            let request = ua::DeleteMonitoredItemsRequest::init()
+        let mut created: Vec<(_, AsyncMonitoredItem)> = Vec::with_capacity(items_to_create.len());
+        let mut failed = Vec::new();
+
+        for chunk in items_to_create.chunks(chunk_size.get()) {
+            let chunk_request = ua::CreateMonitoredItemsRequest::init()
                 .with_subscription_id(subscription_id)
-                .with_monitored_item_ids(&monitored_item_ids);
-            // This request is processed asynchronously. Errors are logged asynchronously too.
-            delete_monitored_items(client, &request);
+                .with_timestamps_to_return(request.timestamps_to_return())
+                .with_items_to_create(chunk);
+
+            let results = match create_items(
+                client,
+                shared_subscription_id,
+                &chunk_request,
+                stream_options,
+            )
+            .await
+            {
+                Ok(results) => results,
+                Err(error) => {
+                    // Clean up the items of earlier chunks to not leave them dangling. We delete
+                    // them in a single request instead of dropping them one by one.
+                    let monitored_item_ids = created
+                        .drain(..)
+                        .map(|(_, monitored_item)| monitored_item.into_monitored_item_id())
+                        .collect::<Vec<_>>();
+                    delete_monitored_item_ids(client, subscription_id, &monitored_item_ids);
+
+                    return Err(error);
+                }
+            };
 
-            return Err(Error::internal("unexpected number of monitored items"));
+            for (item_to_create, result) in chunk.iter().zip(results) {
+                match result {
+                    Ok(result) => created.push(result),
+                    Err(error) => failed.push(MonitoredItemCreateFailure {
+                        request: item_to_create.clone(),
+                        status_code: error.status_code(),
+                    }),
+                }
+            }
+
+            if let Some(progress) = progress.as_mut() {
+                progress(created.len() + failed.len(), items_to_create.len());
+            }
         }
 
-        let results = results
-            .drain_all()
-            .zip(rxs)
-            .map(|(result, rx)| {
-                Error::verify_good(&result.status_code())?;
+        Ok(MonitoredItemsSummary { created, failed })
+    }
 
-                let monitored_item = AsyncMonitoredItem {
-                    client: Arc::downgrade(client),
-                    subscription_id,
-                    monitored_item_id: result.monitored_item_id(),
-                    rx,
-                };
+    const fn stream_options(&self) -> StreamOptions {
+        StreamOptions {
+            buffer_size: self.stream_buffer_size,
+            overflow: self.stream_overflow,
+        }
+    }
 
-                Ok((result, monitored_item))
-            })
-            .collect();
+    async fn prepare_request(
+        mut self,
+        client: &ua::Client,
+        subscription: &AsyncSubscription,
+    ) -> Result<ua::CreateMonitoredItemsRequest> {
+        if self.queue_size.is_none() {
+            self.queue_size = subscription.default_queue_size();
+        }
 
-        Ok(results)
+        let minimum_sampling_intervals = if self.clamp_to_minimum_sampling_interval
+            && self
+                .attribute_id
+                .as_ref()
+                .map_or(true, |attribute_id| attribute_id == &ua::AttributeId::VALUE)
+        {
+            Some(read_minimum_sampling_intervals(client, &self.node_ids).await?)
+        } else {
+            None
+        };
+
+        Ok(self.into_request(
+            subscription.subscription_id(),
+            minimum_sampling_intervals.as_deref(),
+        ))
     }
 
-    fn into_request(self, subscription_id: ua::SubscriptionId) -> ua::CreateMonitoredItemsRequest {
+    fn into_request(
+        self,
+        subscription_id: ua::SubscriptionId,
+        minimum_sampling_intervals: Option<&[Option<Duration>]>,
+    ) -> ua::CreateMonitoredItemsRequest {
         let Self {
             node_ids,
             attribute_id,
+            index_range,
             monitoring_mode,
             sampling_interval,
             filter,
             queue_size,
             discard_oldest,
+            timestamps_to_return,
+            clamp_to_minimum_sampling_interval: _,
+            stream_buffer_size: _,
+            stream_overflow: _,
         } = self;
 
         let items_to_create = node_ids
             .into_iter()
-            .map(|node_id| {
+            .enumerate()
+            .map(|(index, node_id)| {
                 let mut request = ua::MonitoredItemCreateRequest::default().with_node_id(&node_id);
 
                 if let Some(attribute_id) = attribute_id.as_ref() {
                     request = request.with_attribute_id(attribute_id);
                 }
+                if let Some(index_range) = index_range.as_ref() {
+                    request = request.with_index_range(&index_range.to_ua_string());
+                }
                 if let Some(monitoring_mode) = monitoring_mode.as_ref() {
                     request = request.with_monitoring_mode(monitoring_mode);
                 }
@@ -211,14 +403,84 @@ impl MonitoredItemBuilder {
                 if let Some(&discard_oldest) = discard_oldest.as_ref() {
                     request = request.with_discard_oldest(discard_oldest);
                 }
+                if let Some(&Some(minimum_sampling_interval)) = minimum_sampling_intervals
+                    .and_then(|minimum_sampling_intervals| minimum_sampling_intervals.get(index))
+                {
+                    request = clamp_sampling_interval(request, &node_id, minimum_sampling_interval);
+                }
 
                 request
             })
             .collect::<Vec<_>>();
 
-        ua::CreateMonitoredItemsRequest::init()
+        let mut request = ua::CreateMonitoredItemsRequest::init()
             .with_subscription_id(subscription_id)
-            .with_items_to_create(&items_to_create)
+            .with_items_to_create(&items_to_create);
+
+        if let Some(timestamps_to_return) = timestamps_to_return.as_ref() {
+            request = request.with_timestamps_to_return(timestamps_to_return);
+        }
+
+        request
+    }
+}
+
+/// Result of [`MonitoredItemBuilder::create_chunked()`].
+#[derive(Debug)]
+pub struct MonitoredItemsSummary {
+    created: Vec<(ua::MonitoredItemCreateResult, AsyncMonitoredItem)>,
+    failed: Vec<MonitoredItemCreateFailure>,
+}
+
+impl MonitoredItemsSummary {
+    /// Gets monitored items that have been created.
+    #[must_use]
+    pub fn created(&self) -> &[(ua::MonitoredItemCreateResult, AsyncMonitoredItem)] {
+        &self.created
+    }
+
+    /// Gets monitored items that could not be created.
+    #[must_use]
+    pub fn failed(&self) -> &[MonitoredItemCreateFailure] {
+        &self.failed
+    }
+
+    /// Splits summary into created and failed monitored items.
+    #[must_use]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<(ua::MonitoredItemCreateResult, AsyncMonitoredItem)>,
+        Vec<MonitoredItemCreateFailure>,
+    ) {
+        (self.created, self.failed)
+    }
+}
+
+/// Monitored item that could not be created.
+#[derive(Debug, Clone)]
+pub struct MonitoredItemCreateFailure {
+    request: ua::MonitoredItemCreateRequest,
+    status_code: ua::StatusCode,
+}
+
+impl MonitoredItemCreateFailure {
+    /// Gets node ID of monitored item.
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        self.request.node_id()
+    }
+
+    /// Gets requested parameters of monitored item.
+    #[must_use]
+    pub const fn request(&self) -> &ua::MonitoredItemCreateRequest {
+        &self.request
+    }
+
+    /// Gets status code returned by the server.
+    #[must_use]
+    pub const fn status_code(&self) -> &ua::StatusCode {
+        &self.status_code
     }
 }
 
@@ -226,9 +488,16 @@ impl MonitoredItemBuilder {
 #[derive(Debug)]
 pub struct AsyncMonitoredItem {
     client: Weak<ua::Client>,
-    subscription_id: ua::SubscriptionId,
-    monitored_item_id: ua::MonitoredItemId,
-    rx: mpsc::Receiver<ua::DataValue>,
+    /// Server-assigned IDs. These change when the monitored item is recreated after reconnecting.
+    subscription_id: Arc<AtomicU32>,
+    monitored_item_id: Arc<AtomicU32>,
+    /// Monitored node, as requested when creating the monitored item.
+    node_id: ua::NodeId,
+    rx: CallbackStreamReceiver<ua::DataValue>,
+    /// Names of select clauses, for monitored items of events.
+    event_field_names: Option<Arc<[String]>>,
+    /// Timestamps to return, as requested when creating or modifying the monitored item.
+    timestamps_to_return: ua::TimestampsToReturn,
 }
 
 impl AsyncMonitoredItem {
@@ -236,11 +505,33 @@ impl AsyncMonitoredItem {
     ///
     /// This waits for the next value received for this monitored item. Returns `None` when item has
     /// been closed and no more updates will be received.
+    ///
+    /// For monitored items of events, the value holds the event fields as array of [`ua::Variant`].
+    /// Use [`next_event()`](Self::next_event) to access them by name instead.
     pub async fn next(&mut self) -> Option<ua::DataValue> {
         // This mirrors `<Self as Stream>::poll_next()` but does not require `self` to be pinned.
         self.rx.recv().await
     }
 
+    /// Waits for next event from server.
+    ///
+    /// This applies to monitored items of events, i.e. with attribute
+    /// [`ua::AttributeId::EVENTNOTIFIER`] and an [`ua::EventFilter`]. The fields of the event can be
+    /// accessed by the names of the select clauses of the filter.
+    ///
+    /// Returns `None` when item has been closed and no more events will be received, and right away
+    /// when the item does not monitor events.
+    pub async fn next_event(&mut self) -> Option<EventValue> {
+        let names = Arc::clone(self.event_field_names.as_ref()?);
+        let value = self.next().await?;
+        let fields = value
+            .value()
+            .and_then(ua::Variant::to_array::<ua::Variant>)
+            .map(ua::Array::into_vec)
+            .unwrap_or_default();
+        Some(EventValue::new(names, fields))
+    }
+
     /// Turns monitored item into stream.
     ///
     /// The stream will emit all value updates as they are being received. If the client disconnects
@@ -250,6 +541,156 @@ impl AsyncMonitoredItem {
             this.next().await.map(|value| (value, this))
         })
     }
+
+    /// Gets ID of subscription that the monitored item belongs to.
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        ua::SubscriptionId::new(self.subscription_id.load(Ordering::Relaxed))
+    }
+
+    /// Gets server-assigned monitored item ID.
+    ///
+    /// When the monitored item is recreated after reconnecting, the server assigns a new ID. See
+    /// [`ClientBuilder::auto_reconnect()`](crate::ClientBuilder::auto_reconnect).
+    #[must_use]
+    pub fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        ua::MonitoredItemId::new(self.monitored_item_id.load(Ordering::Relaxed))
+    }
+
+    /// Gets ID of monitored node.
+    #[must_use]
+    pub const fn node_id(&self) -> &ua::NodeId {
+        &self.node_id
+    }
+
+    /// Gets number of discarded values.
+    ///
+    /// This counts values that have been discarded because the local buffer of received values was
+    /// full, i.e. when values have been received faster than they have been consumed. See
+    /// [`MonitoredItemBuilder::stream_buffer_size()`] and [`MonitoredItemBuilder::stream_overflow()`].
+    ///
+    /// Values discarded by the server (when its queue overflows) are not counted here.
+    #[must_use]
+    pub fn discarded_values(&self) -> u64 {
+        self.rx.discarded()
+    }
+
+    /// Sets monitoring mode of monitored item.
+    ///
+    /// See [`AsyncSubscription::set_monitoring_mode()`] to set the monitoring mode of several
+    /// monitored items at once.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the monitoring mode cannot be set.
+    pub async fn set_monitoring_mode(&self, monitoring_mode: ua::MonitoringMode) -> Result<()> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let results = set_monitoring_mode(
+            client,
+            &self.subscription_id,
+            &[self.monitored_item_id()],
+            &monitoring_mode,
+        )
+        .await?;
+
+        // We expect exactly one result for the single monitored item in the request.
+        let Ok::<[_; 1], _>([result]) = results.try_into() else {
+            return Err(Error::internal("expected exactly one result"));
+        };
+
+        result
+    }
+
+    /// Modifies parameters of monitored item.
+    ///
+    /// This changes the sampling interval, queue size, discard policy and filter of the monitored
+    /// item, without recreating it. Parameters are replaced as a whole, so all of them should be set
+    /// in `requested_parameters`. Values that have been queued before are kept, and this monitored
+    /// item continues to receive values afterwards. For monitored items of events, changed select
+    /// clauses apply to [`next_event()`](Self::next_event) right away.
+    ///
+    /// The local buffer of received values keeps the discard policy that the monitored item has
+    /// been created with.
+    ///
+    /// The server may revise the requested values. See [`AsyncSubscription::modify_monitored_items()`]
+    /// to modify several monitored items at once.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the monitored item cannot be modified, e.g. when the server does not support
+    /// the filter.
+    pub async fn modify(
+        &mut self,
+        requested_parameters: &ua::MonitoringParameters,
+    ) -> Result<ua::MonitoredItemModifyResult> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let results = modify_monitored_items(
+            client,
+            &self.subscription_id,
+            &[ua::MonitoredItemModifyRequest::init()
+                .with_monitored_item_id(self.monitored_item_id())
+                .with_requested_parameters(requested_parameters)],
+            &self.timestamps_to_return,
+        )
+        .await?;
+
+        // We expect exactly one result for the single monitored item in the request.
+        let Ok::<[_; 1], _>([result]) = results.try_into() else {
+            return Err(Error::internal("expected exactly one result"));
+        };
+        let result = result?;
+
+        if self.event_field_names.is_some() {
+            if let Some(filter) = requested_parameters
+                .filter()
+                .decoded_content::<ua::EventFilter>()
+            {
+                self.event_field_names = Some(EventValue::field_names(filter));
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Gets monitored item ID without deleting monitored item when dropped.
+    fn into_monitored_item_id(mut self) -> ua::MonitoredItemId {
+        if let Some(client) = self.client.upgrade() {
+            self.untrack(&client);
+        }
+        // Without client, `Drop` does not delete the monitored item.
+        self.client = Weak::new();
+        self.monitored_item_id()
+    }
+
+    /// Gets shared monitored item ID, to be used by subscription watchdog.
+    #[must_use]
+    pub(crate) const fn shared_monitored_item_id(&self) -> &Arc<AtomicU32> {
+        &self.monitored_item_id
+    }
+
+    /// Turns monitored item into receiver of its values.
+    ///
+    /// Other than dropping, this keeps the monitored item on the server until its subscription is
+    /// deleted. It is still recreated after reconnecting.
+    pub(crate) fn into_receiver(mut self) -> CallbackStreamReceiver<ua::DataValue> {
+        // Without client, `Drop` does not delete the monitored item.
+        self.client = Weak::new();
+        let (_, rx) = CallbackStream::channel(NonZeroUsize::MIN, StreamOverflow::DiscardOldest);
+        mem::replace(&mut self.rx, rx)
+    }
+
+    /// Stops tracking monitored item for automatic reconnecting.
+    fn untrack(&self, client: &ua::Client) {
+        if let Some(reconnect) = ClientContext::get(client).reconnect() {
+            reconnect.untrack_monitored_item(&self.subscription_id, &self.monitored_item_id);
+        }
+    }
 }
 
 impl Drop for AsyncMonitoredItem {
@@ -258,9 +699,11 @@ impl Drop for AsyncMonitoredItem {
             return;
         };
 
+        self.untrack(&client);
+
         let request = ua::DeleteMonitoredItemsRequest::init()
-            .with_subscription_id(self.subscription_id)
-            .with_monitored_item_ids(&[self.monitored_item_id]);
+            .with_subscription_id(self.subscription_id())
+            .with_monitored_item_ids(&[self.monitored_item_id()]);
 
         delete_monitored_items(&client, &request);
     }
@@ -275,17 +718,226 @@ impl Stream for AsyncMonitoredItem {
     }
 }
 
-/// Maximum number of buffered values.
-const MONITORED_ITEM_BUFFER_SIZE: usize = 3;
+/// Reads minimum sampling intervals of nodes.
+///
+/// This returns `None` for nodes whose minimum sampling interval is unknown or cannot be read.
+async fn read_minimum_sampling_intervals(
+    client: &ua::Client,
+    node_ids: &[ua::NodeId],
+) -> Result<Vec<Option<Duration>>> {
+    let node_attributes = node_ids
+        .iter()
+        .map(|node_id| (node_id.clone(), ua::AttributeId::MINIMUMSAMPLINGINTERVAL))
+        .collect::<Vec<_>>();
+
+    let results = read_many_attributes(client, &node_attributes).await?;
+
+    Ok(results
+        .into_iter()
+        .zip(node_ids)
+        .map(
+            |(result, node_id)| match result.and_then(DataValue::into_scalar::<ua::Double>) {
+                Ok(value) => minimum_sampling_interval(value.value()),
+                Err(error) => {
+                    log::debug!("Cannot read minimum sampling interval of {node_id}: {error}");
+                    None
+                }
+            },
+        )
+        .collect())
+}
+
+/// Raises requested sampling interval to minimum sampling interval.
+fn clamp_sampling_interval(
+    request: ua::MonitoredItemCreateRequest,
+    node_id: &ua::NodeId,
+    minimum_sampling_interval: Duration,
+) -> ua::MonitoredItemCreateRequest {
+    // When no sampling interval is given, the publishing interval of the subscription is used. We do
+    // not know this here, so we leave it to the server.
+    let Some(sampling_interval) = request.sampling_interval() else {
+        return request;
+    };
+    if sampling_interval >= minimum_sampling_interval {
+        return request;
+    }
+
+    log::info!(
+        "Raising sampling interval of {node_id} from {sampling_interval:?} to minimum sampling \
+        interval {minimum_sampling_interval:?}"
+    );
+
+    request.with_sampling_interval(Some(minimum_sampling_interval))
+}
+
+/// Reads server's maximum number of monitored items per call.
+///
+/// This returns `None` when the server does not announce a limit or it cannot be read.
+async fn read_max_monitored_items_per_call(client: &ua::Client) -> Option<NonZeroUsize> {
+    let node_attributes = [(
+        ua::NodeId::ns0(
+            UA_NS0ID_SERVER_SERVERCAPABILITIES_OPERATIONLIMITS_MAXMONITOREDITEMSPERCALL,
+        ),
+        ua::AttributeId::VALUE,
+    )];
+
+    let result = read_many_attributes(client, &node_attributes)
+        .await
+        .and_then(|results| {
+            results
+                .into_iter()
+                .next()
+                .ok_or(Error::internal("expected exactly one result"))?
+        })
+        .and_then(DataValue::into_scalar::<ua::UInt32>);
+
+    match result {
+        // The value `0` indicates that there is no limit.
+        Ok(value) => NonZeroUsize::new(usize::try_from(value.value().value()).ok()?),
+        Err(error) => {
+            log::debug!("Cannot read maximum number of monitored items per call: {error}");
+            None
+        }
+    }
+}
+
+/// Creates monitored items and associates results with local handles.
+async fn create_items(
+    client: &Arc<ua::Client>,
+    shared_subscription_id: &Arc<AtomicU32>,
+    request: &ua::CreateMonitoredItemsRequest,
+    stream_options: StreamOptions,
+) -> Result<Vec<Result<(ua::MonitoredItemCreateResult, AsyncMonitoredItem)>>> {
+    let subscription_id = ua::SubscriptionId::new(shared_subscription_id.load(Ordering::Relaxed));
+    let items_to_create = request.items_to_create().unwrap_or_default();
+    let result_count = items_to_create.len();
+
+    let (txs, rxs): (Vec<_>, Vec<_>) = items_to_create
+        .iter()
+        .map(|item| {
+            // When the buffer is full, discard values in the same way that the server does, unless
+            // the caller decides otherwise.
+            let (tx, rx) = CallbackStream::channel(
+                stream_options
+                    .buffer_size
+                    .unwrap_or(MONITORED_ITEM_BUFFER_SIZE),
+                stream_options
+                    .overflow
+                    .unwrap_or(StreamOverflow::from_discard_oldest(item.discard_oldest())),
+            );
+            (Arc::new(tx), rx)
+        })
+        .unzip();
+
+    let response = create_monitored_items(client, request, &txs).await?;
+
+    let Some(mut results) = response.into_results() else {
+        return Err(Error::internal("expected monitoring item results"));
+    };
+
+    if results.len() != result_count {
+        // This should not happen. In any case, we cannot associate returned items with their
+        // incoming node IDs. Clean up the items that we received to not leave them dangling.
+        //
+        let monitored_item_ids = results
+            .iter()
+            .filter(|result| result.status_code().is_good())
+            .map(ua::MonitoredItemCreateResult::monitored_item_id)
+            .collect::<Vec<_>>();
+        delete_monitored_item_ids(client, subscription_id, &monitored_item_ids);
+
+        return Err(Error::internal("unexpected number of monitored items"));
+    }
+
+    let reconnect = ClientContext::get(client).reconnect();
+
+    let results = results
+        .drain_all()
+        .zip(items_to_create.iter().zip(txs).zip(rxs))
+        .map(|(result, ((item, tx), rx))| {
+            Error::verify_good(&result.status_code())?;
+
+            let monitored_item_id = Arc::new(AtomicU32::new(result.monitored_item_id().as_u32()));
+
+            if let Some(reconnect) = reconnect {
+                reconnect.track_monitored_item(
+                    shared_subscription_id,
+                    &monitored_item_id,
+                    item.clone(),
+                    request.timestamps_to_return().clone(),
+                    tx,
+                );
+            }
+
+            let event_field_names = is_event_item(item).then(|| {
+                item.requested_parameters()
+                    .filter()
+                    .decoded_content::<ua::EventFilter>()
+                    .map_or_else(|| Arc::from([]), EventValue::field_names)
+            });
+
+            let monitored_item = AsyncMonitoredItem {
+                client: Arc::downgrade(client),
+                subscription_id: Arc::clone(shared_subscription_id),
+                monitored_item_id,
+                node_id: item.node_id().clone(),
+                rx,
+                event_field_names,
+                timestamps_to_return: request.timestamps_to_return().clone(),
+            };
+
+            Ok((result, monitored_item))
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Deletes monitored items in a single request.
+fn delete_monitored_item_ids(
+    client: &ua::Client,
+    subscription_id: ua::SubscriptionId,
+    monitored_item_ids: &[ua::MonitoredItemId],
+) {
+    if monitored_item_ids.is_empty() {
+        return;
+    }
+
+    let request = ua::DeleteMonitoredItemsRequest::init()
+        .with_subscription_id(subscription_id)
+        .with_monitored_item_ids(monitored_item_ids);
+    // This request is processed asynchronously. Errors are logged asynchronously too.
+    delete_monitored_items(client, &request);
+}
+
+/// Checks whether monitored item is for events instead of data changes.
+fn is_event_item(item: &ua::MonitoredItemCreateRequest) -> bool {
+    item.item_to_monitor().attribute_id() == ua::AttributeId::EVENTNOTIFIER
+}
+
+/// Options for local buffer of received values.
+#[derive(Debug, Clone, Copy)]
+struct StreamOptions {
+    buffer_size: Option<NonZeroUsize>,
+    overflow: Option<StreamOverflow>,
+}
 
-async fn create_monitored_items(
+/// Default maximum number of buffered values.
+const MONITORED_ITEM_BUFFER_SIZE: NonZeroUsize = match NonZeroUsize::new(3) {
+    Some(size) => size,
+    None => unreachable!(),
+};
+
+/// Creates monitored items that send their values to the given senders.
+///
+/// There must be one sender for each monitored item in the request. `open62541` holds a reference
+/// to each sender until the monitored item is removed from the client.
+pub(crate) async fn create_monitored_items(
     client: &ua::Client,
     request: &ua::CreateMonitoredItemsRequest,
-) -> Result<(
-    ua::CreateMonitoredItemsResponse,
-    Vec<mpsc::Receiver<ua::DataValue>>,
-)> {
-    type St = CallbackStream<ua::DataValue>;
+    txs: &[Arc<CallbackStreamSender<ua::DataValue>>],
+) -> Result<ua::CreateMonitoredItemsResponse> {
+    type St = Userdata<Arc<CallbackStreamSender<ua::DataValue>>>;
     type Cb = CallbackOnce<std::result::Result<ua::CreateMonitoredItemsResponse, ua::StatusCode>>;
 
     // Wrapper type so that we can mark `*mut c_void` for callbacks as safe to send. Otherwise, this
@@ -293,13 +945,13 @@ async fn create_monitored_items(
     #[repr(transparent)]
     struct Context(*mut c_void);
     // SAFETY: As long as the payload is `Send`, context is also `Send`.
-    unsafe impl Send for Context where St: Send + Sync {}
+    unsafe impl Send for Context where Arc<CallbackStreamSender<ua::DataValue>>: Send + Sync {}
 
     unsafe extern "C" fn notification_callback_c(
-        _client: *mut UA_Client,
-        _sub_id: UA_UInt32,
+        client: *mut UA_Client,
+        sub_id: UA_UInt32,
         _sub_context: *mut c_void,
-        _mon_id: UA_UInt32,
+        mon_id: UA_UInt32,
         mon_context: *mut c_void,
         value: *mut UA_DataValue,
     ) {
@@ -310,10 +962,49 @@ async fn create_monitored_items(
         let value = unsafe { value.as_ref() }.expect("value should be set");
         let value = ua::DataValue::clone_raw(value);
 
-        // SAFETY: `userdata` is the result of `St::prepare()` and is used only before `delete()`.
-        unsafe {
-            St::notify(mon_context, value);
+        // SAFETY: The client is valid for the duration of the callback.
+        if let Some(context) = unsafe { ClientContext::from_client(client) } {
+            context.publish_batches().collect(
+                ua::SubscriptionId::new(sub_id),
+                ua::MonitoredItemId::new(mon_id),
+                &value,
+            );
         }
+
+        // SAFETY: `userdata` is the result of `St::prepare()` and is used only before `consume()`.
+        let tx = unsafe { St::peek_at(mon_context) };
+        tx.send(value);
+    }
+
+    unsafe extern "C" fn event_notification_callback_c(
+        client: *mut UA_Client,
+        sub_id: UA_UInt32,
+        _sub_context: *mut c_void,
+        mon_id: UA_UInt32,
+        mon_context: *mut c_void,
+        n_event_fields: usize,
+        event_fields: *mut UA_Variant,
+    ) {
+        log::debug!("EventNotificationCallback() was called");
+
+        // Event fields are sent as array in a data value, so that monitored items of events share
+        // their stream type with monitored items of data changes.
+        let event_fields = ua::Array::<ua::Variant>::from_raw_parts(n_event_fields, event_fields)
+            .unwrap_or_else(|| ua::Array::new(0));
+        let value = ua::DataValue::new(ua::Variant::array(event_fields));
+
+        // SAFETY: The client is valid for the duration of the callback.
+        if let Some(context) = unsafe { ClientContext::from_client(client) } {
+            context.publish_batches().collect(
+                ua::SubscriptionId::new(sub_id),
+                ua::MonitoredItemId::new(mon_id),
+                &value,
+            );
+        }
+
+        // SAFETY: `userdata` is the result of `St::prepare()` and is used only before `consume()`.
+        let tx = unsafe { St::peek_at(mon_context) };
+        tx.send(value);
     }
 
     unsafe extern "C" fn delete_callback_c(
@@ -325,10 +1016,9 @@ async fn create_monitored_items(
     ) {
         log::debug!("DeleteMonitoredItemCallback() was called");
 
-        // SAFETY: `userdata` is the result of `St::prepare()` and is deleted only once.
-        unsafe {
-            St::delete(mon_context);
-        }
+        // SAFETY: `userdata` is the result of `St::prepare()` and is consumed only once. When this
+        // was the last reference to the sender, this closes the stream.
+        let _unused = unsafe { St::consume(mon_context) };
     }
 
     unsafe extern "C" fn callback_c(
@@ -366,32 +1056,40 @@ async fn create_monitored_items(
         let _unused = tx.send(result.map_err(Error::new));
     };
 
-    let items_to_create = request
-        .items_to_create()
-        .map_or(0, <[ua::MonitoredItemCreateRequest]>::len);
+    let items_to_create = request.items_to_create().unwrap_or_default();
+    if txs.len() != items_to_create.len() {
+        return Err(Error::internal("expected one sender per monitored item"));
+    }
 
     let mut notification_callbacks: Vec<UA_Client_DataChangeNotificationCallback> =
-        Vec::with_capacity(items_to_create);
+        Vec::with_capacity(items_to_create.len());
     let mut delete_callbacks: Vec<UA_Client_DeleteMonitoredItemCallback> =
-        Vec::with_capacity(items_to_create);
-    let mut contexts = Vec::with_capacity(items_to_create);
-    let mut st_rxs = Vec::with_capacity(items_to_create);
-
-    for _ in 0..items_to_create {
-        // TODO: Think about appropriate buffer size or let the caller decide.
-        let (st_tx, st_rx) = mpsc::channel::<ua::DataValue>(MONITORED_ITEM_BUFFER_SIZE);
+        Vec::with_capacity(items_to_create.len());
+    let mut contexts = Vec::with_capacity(items_to_create.len());
 
+    for (item, tx) in items_to_create.iter().zip(txs) {
         // `open62541` requires one set of notification/delete callback and context per monitored
         // item in the request.
-        let notification_callback: UA_Client_DataChangeNotificationCallback =
-            Some(notification_callback_c);
+        let notification_callback: UA_Client_DataChangeNotificationCallback = if is_event_item(item)
+        {
+            // SAFETY: `open62541` stores notification callbacks of both kinds alike. It calls them
+            // with the signature that matches the monitored attribute: monitored items of events
+            // are those of the attribute `EventNotifier`, as checked by `is_event_item()`.
+            unsafe {
+                mem::transmute::<
+                    UA_Client_EventNotificationCallback,
+                    UA_Client_DataChangeNotificationCallback,
+                >(Some(event_notification_callback_c))
+            }
+        } else {
+            Some(notification_callback_c)
+        };
         let delete_callback: UA_Client_DeleteMonitoredItemCallback = Some(delete_callback_c);
-        let context = Context(St::prepare(st_tx));
+        let context = Context(St::prepare(Arc::clone(tx)));
 
         notification_callbacks.push(notification_callback);
         delete_callbacks.push(delete_callback);
         contexts.push(context);
-        st_rxs.push(st_rx);
     }
 
     let status_code = ua::StatusCode::new({
@@ -425,7 +1123,6 @@ async fn create_monitored_items(
     // there.
     rx.await
         .unwrap_or(Err(Error::internal("callback should send result")))
-        .map(|response| (response, st_rxs))
 }
 
 fn delete_monitored_items(client: &ua::Client, request: &ua::DeleteMonitoredItemsRequest) {
@@ -470,3 +1167,569 @@ fn delete_monitored_items(client: &ua::Client, request: &ua::DeleteMonitoredItem
         log::warn!("Error in request when deleting monitored items: {error}");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroUsize, time::Duration};
+
+    use crate::{
+        ua, ClientBuilder, DataType as _, MonitoredItemBuilder, Node, ServerBuilder,
+        StreamOverflow, TestHarness,
+    };
+
+    use super::clamp_sampling_interval;
+
+    #[test]
+    fn clamp_to_minimum_sampling_interval() {
+        let node_id = ua::NodeId::ns0_server_status_current_time();
+        let minimum = Duration::from_millis(1000);
+
+        // Shorter sampling interval is raised to minimum.
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_sampling_interval(Some(Duration::from_millis(100)));
+        let request = clamp_sampling_interval(request, &node_id, minimum);
+        assert_eq!(request.sampling_interval(), Some(minimum));
+
+        // Longer sampling interval is kept.
+        let request = ua::MonitoredItemCreateRequest::default()
+            .with_sampling_interval(Some(Duration::from_millis(5000)));
+        let request = clamp_sampling_interval(request, &node_id, minimum);
+        assert_eq!(
+            request.sampling_interval(),
+            Some(Duration::from_millis(5000))
+        );
+
+        // Publishing interval of subscription is not touched.
+        let request = ua::MonitoredItemCreateRequest::default().with_sampling_interval(None);
+        let request = clamp_sampling_interval(request, &node_id, minimum);
+        assert_eq!(request.sampling_interval(), None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn timestamps_to_return_both() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .timestamps_to_return(ua::TimestampsToReturn::BOTH)
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        let value = monitored_item.next().await.unwrap();
+        assert!(value.source_timestamp().is_some());
+        assert!(value.server_timestamp().is_some());
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn too_many_publish_requests() {
+        use open62541_sys::UA_Client_getConfig;
+
+        // Client queues up more publish requests than the server allows. The client must reduce
+        // the number of requests and continue to receive notifications.
+        let harness = TestHarness::start_with(
+            ServerBuilder::minimal(0, None).max_publish_requests_per_session(1),
+            ClientBuilder::default().max_outstanding_publish_requests(5),
+            |_| {},
+        )
+        .expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .sampling_interval(Some(Duration::from_millis(100)))
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        for _ in 0..3 {
+            let value = monitored_item.next().await.unwrap();
+            assert!(value.value().is_some());
+        }
+
+        // SAFETY: We only read a single number from the config. The client's background task only
+        // ever decreases it, and never below 1.
+        let outstanding_publish_requests = unsafe {
+            UA_Client_getConfig(client.client().as_ptr().cast_mut())
+                .as_ref()
+                .unwrap()
+                .outStandingPublishRequests
+        };
+        assert!((1..5).contains(&outstanding_publish_requests));
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn set_monitoring_mode() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .monitoring_mode(ua::MonitoringMode::SAMPLING)
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (result, mut monitored_item) = results.pop().unwrap().unwrap();
+        assert_eq!(
+            monitored_item.monitored_item_id(),
+            result.monitored_item_id()
+        );
+        assert_eq!(
+            monitored_item.node_id(),
+            &ua::NodeId::ns0_server_status_current_time()
+        );
+
+        // Sampled values are queued on the server but not reported.
+        let next = tokio::time::timeout(Duration::from_millis(1500), monitored_item.next()).await;
+        assert!(next.is_err(), "should not report values while sampling");
+
+        let results = subscription
+            .set_monitoring_mode(
+                &[
+                    monitored_item.monitored_item_id(),
+                    ua::MonitoredItemId::new(u32::MAX),
+                ],
+                ua::MonitoringMode::REPORTING,
+            )
+            .await
+            .expect("should set monitoring mode");
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [Ok(()), Err(ua::StatusCode::BADMONITOREDITEMIDINVALID)]
+        );
+
+        let value = monitored_item.next().await.unwrap();
+        assert!(value.value().is_some());
+
+        monitored_item
+            .set_monitoring_mode(ua::MonitoringMode::DISABLED)
+            .await
+            .expect("should disable monitored item");
+
+        // Skip values that have been sent before the monitored item was disabled.
+        for _ in 0..10 {
+            let next =
+                tokio::time::timeout(Duration::from_millis(500), monitored_item.next()).await;
+            if next.is_err() {
+                break;
+            }
+        }
+
+        // Disabled monitored items neither sample nor report values.
+        let next = tokio::time::timeout(Duration::from_millis(1500), monitored_item.next()).await;
+        assert!(next.is_err(), "should not report values while disabled");
+
+        monitored_item
+            .set_monitoring_mode(ua::MonitoringMode::REPORTING)
+            .await
+            .expect("should enable monitored item");
+
+        // Reporting resumes.
+        let value = tokio::time::timeout(Duration::from_secs(5), monitored_item.next())
+            .await
+            .expect("should report values again")
+            .unwrap();
+        assert!(value.value().is_some());
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn stream_buffer_size() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .sampling_interval(Some(Duration::from_millis(100)))
+            .stream_buffer_size(NonZeroUsize::MIN)
+            .stream_overflow(StreamOverflow::DiscardOldest)
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+        assert_eq!(monitored_item.discarded_values(), 0);
+
+        // Values are received while we do not consume them. Only the latest one is kept.
+        tokio::time::sleep(Duration::from_millis(2000)).await;
+        assert!(monitored_item.discarded_values() > 0);
+
+        let value = monitored_item.next().await.unwrap();
+        assert!(value.value().is_some());
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn set_triggering() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, triggering_item) = results.pop().unwrap().unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .monitoring_mode(ua::MonitoringMode::SAMPLING)
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut triggered_item) = results.pop().unwrap().unwrap();
+
+        let (add_results, remove_results) = subscription
+            .set_triggering(
+                triggering_item.monitored_item_id(),
+                &[
+                    triggered_item.monitored_item_id(),
+                    ua::MonitoredItemId::new(u32::MAX),
+                ],
+                &[],
+            )
+            .await
+            .expect("should set triggering");
+        let status_codes: Vec<_> = add_results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [Ok(()), Err(ua::StatusCode::BADMONITOREDITEMIDINVALID)]
+        );
+        assert!(remove_results.is_empty());
+
+        // Sampled values are reported along with notifications of the triggering item.
+        let value = triggered_item.next().await.unwrap();
+        assert!(value.value().is_some());
+
+        let (add_results, remove_results) = subscription
+            .set_triggering(
+                triggering_item.monitored_item_id(),
+                &[],
+                &[triggered_item.monitored_item_id()],
+            )
+            .await
+            .expect("should remove triggering link");
+        assert!(add_results.is_empty());
+        assert_eq!(remove_results.len(), 1);
+        assert!(remove_results[0].is_ok());
+
+        let result = subscription
+            .set_triggering(
+                ua::MonitoredItemId::new(u32::MAX),
+                &[triggered_item.monitored_item_id()],
+                &[],
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADMONITOREDITEMIDINVALID)
+        );
+
+        drop(triggered_item);
+        drop(triggering_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn index_range() {
+        let mut node_id = None;
+        let harness = TestHarness::start(|server| {
+            let id = server
+                .add_node(Node::new(
+                    ua::NodeId::ns0_objects_folder(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, "Array"),
+                    ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(1)
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+                ))
+                .expect("should add node");
+            let values = (0..10).map(ua::Int32::new).collect::<Vec<_>>();
+            server
+                .write_value(&id, &ua::Variant::array(ua::Array::from_slice(&values)))
+                .expect("should write value");
+            node_id = Some(id);
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let node_id = node_id.unwrap();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([node_id])
+            .index_range("2:4".parse().unwrap())
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        let value = monitored_item.next().await.unwrap();
+        let values: Vec<_> = value
+            .value()
+            .and_then(ua::Variant::to_array::<ua::Int32>)
+            .expect("should be array")
+            .iter()
+            .map(ua::Int32::value)
+            .collect();
+        assert_eq!(values, [2, 3, 4]);
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn index_range_invalid() {
+        let mut node_id = None;
+        let harness = TestHarness::start(|server| {
+            let id = server
+                .add_node(Node::new(
+                    ua::NodeId::ns0_objects_folder(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, "Matrix"),
+                    ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(2)
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+                ))
+                .expect("should add node");
+            let values = (0..10).map(ua::Int32::new).collect::<Vec<_>>();
+            let mut value = ua::Variant::array(ua::Array::from_slice(&values));
+            let dimensions = ua::Array::from_slice(&[ua::UInt32::new(2), ua::UInt32::new(5)]);
+            // SAFETY: The variant has no array dimensions yet, so nothing is leaked.
+            let raw = unsafe { value.as_mut() };
+            dimensions.move_into_raw(&mut raw.arrayDimensionsSize, &mut raw.arrayDimensions);
+            server.write_value(&id, &value).expect("should write value");
+            node_id = Some(id);
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let node_id = node_id.unwrap();
+
+        let subscription = client.create_subscription().await.unwrap();
+        // The range does not cover both dimensions of the value. The server rejects the monitored
+        // item, but not the request.
+        let mut results = MonitoredItemBuilder::new([node_id])
+            .index_range("0:1".parse().unwrap())
+            .create(&subscription)
+            .await
+            .expect("should create monitored items");
+        assert_eq!(results.len(), 1);
+        let result = results.pop().unwrap();
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADINDEXRANGEINVALID)
+        );
+
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn data_change_filter_deadband() {
+        let mut node_id = None;
+        let harness = TestHarness::start(|server| {
+            let id = server
+                .add_node(Node::new(
+                    ua::NodeId::ns0_objects_folder(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, "Analog"),
+                    ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_double())
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+                ))
+                .expect("should add node");
+            server
+                .write_value(&id, &ua::Variant::scalar(ua::Double::new(0.0)))
+                .expect("should write value");
+            node_id = Some(id);
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let node_id = node_id.unwrap();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([node_id.clone()])
+            .sampling_interval(Some(Duration::from_millis(100)))
+            .filter(
+                ua::DataChangeFilter::init()
+                    .with_trigger(&ua::DataChangeTrigger::STATUSVALUE)
+                    .with_deadband_absolute(1.0),
+            )
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        let value = |value: ua::DataValue| {
+            value
+                .value()
+                .and_then(ua::Variant::to_scalar::<ua::Double>)
+                .map(|value| value.value())
+        };
+
+        // Initial value is always reported.
+        assert_eq!(value(monitored_item.next().await.unwrap()), Some(0.0));
+
+        // Changes within the deadband are suppressed.
+        harness
+            .server()
+            .write_value(&node_id, &ua::Variant::scalar(ua::Double::new(0.5)))
+            .expect("should write value");
+        let next = tokio::time::timeout(Duration::from_millis(500), monitored_item.next()).await;
+        assert!(next.is_err(), "should not report change within deadband");
+
+        // Changes beyond the deadband are reported.
+        harness
+            .server()
+            .write_value(&node_id, &ua::Variant::scalar(ua::Double::new(2.0)))
+            .expect("should write value");
+        assert_eq!(value(monitored_item.next().await.unwrap()), Some(2.0));
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn next_event() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server()])
+            .attribute_id(ua::AttributeId::EVENTNOTIFIER)
+            .filter(
+                ua::EventFilter::init()
+                    .with_select_simple("EventType")
+                    .with_select_simple("SourceNode")
+                    .with_where_clause(ua::ContentFilter::of_type(
+                        &ua::NodeId::ns0_base_model_change_event_type(),
+                    )),
+            )
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        harness
+            .server()
+            .emit_model_change_event()
+            .expect("should emit event");
+
+        let event = monitored_item
+            .next_event()
+            .await
+            .expect("should receive event");
+        assert_eq!(event.fields().len(), 2);
+        assert_eq!(
+            event.field_as::<ua::NodeId>("EventType"),
+            Some(&ua::NodeId::ns0_base_model_change_event_type())
+        );
+        assert_eq!(
+            event.field_as::<ua::NodeId>("SourceNode"),
+            Some(&ua::NodeId::ns0_server())
+        );
+        assert!(event.field("Message/Text").is_none());
+
+        // Monitored items of values do not yield events.
+        let mut monitored_item = subscription
+            .create_monitored_item(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .unwrap();
+        assert!(monitored_item.next_event().await.is_none());
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn modify() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+            .sampling_interval(Some(Duration::from_millis(100)))
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+        assert!(monitored_item.next().await.is_some());
+
+        let result = monitored_item
+            .modify(
+                &ua::MonitoringParameters::init()
+                    .with_sampling_interval(Some(Duration::from_millis(500)))
+                    .with_queue_size(1)
+                    .with_discard_oldest(true),
+            )
+            .await
+            .expect("should modify monitored item");
+        assert_eq!(
+            result.revised_sampling_interval().unwrap(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(result.revised_queue_size(), 1);
+
+        // Values are still received after modifying.
+        let value = monitored_item.next().await.unwrap();
+        assert!(value.value().is_some());
+
+        let results = subscription
+            .modify_monitored_items(
+                &[
+                    ua::MonitoredItemModifyRequest::init()
+                        .with_monitored_item_id(monitored_item.monitored_item_id())
+                        .with_requested_parameters(
+                            &ua::MonitoringParameters::init()
+                                .with_sampling_interval(Some(Duration::from_millis(200)))
+                                .with_queue_size(1),
+                        ),
+                    ua::MonitoredItemModifyRequest::init()
+                        .with_monitored_item_id(ua::MonitoredItemId::new(u32::MAX)),
+                ],
+                ua::TimestampsToReturn::SOURCE,
+            )
+            .await
+            .expect("should modify monitored items");
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map(|_| ()).map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [Ok(()), Err(ua::StatusCode::BADMONITOREDITEMIDINVALID)]
+        );
+
+        let value = monitored_item.next().await.unwrap();
+        assert!(value.value().is_some());
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+}
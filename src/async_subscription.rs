@@ -1,20 +1,49 @@
 use std::{
     ffi::c_void,
-    num::NonZeroU32,
+    num::{NonZeroU32, NonZeroUsize},
     ptr,
-    sync::{Arc, Weak},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Weak,
+    },
     time::Duration,
 };
 
 use futures_channel::oneshot;
+use futures_core::Stream;
 use open62541_sys::{
-    UA_Client, UA_Client_Subscriptions_create_async, UA_Client_Subscriptions_delete_async,
-    UA_CreateSubscriptionResponse, UA_DeleteSubscriptionsResponse, UA_UInt32,
+    UA_Client, UA_Client_MonitoredItems_modify_async, UA_Client_Subscriptions_create_async,
+    UA_Client_Subscriptions_delete_async, UA_Client_Subscriptions_modify_async,
+    UA_CreateSubscriptionResponse, UA_DeleteSubscriptionsResponse, UA_ModifyMonitoredItemsResponse,
+    UA_ModifySubscriptionResponse, UA_UInt32,
 };
 
 use crate::{
-    ua, AsyncClient, AsyncMonitoredItem, CallbackOnce, DataType as _, Error, MonitoredItemBuilder,
-    Result,
+    async_client::{call_method, service_request, to_status_results, verify_result_count},
+    client::{
+        start_publishing, subscription_delete_callback_c, subscription_status_change_callback_c,
+        ClientContext,
+    },
+    ua, AsyncClient, AsyncMonitoredItem, CallbackOnce, CallbackStream, DataType as _, Error,
+    MonitoredItemBuilder, Result, StreamOverflow,
+};
+
+/// Number of events buffered in [`AsyncSubscription::events()`].
+const EVENT_STREAM_CAPACITY: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(capacity) => capacity,
+    None => unreachable!(),
+};
+
+/// Number of batches buffered in [`AsyncSubscription::publish_batches()`].
+const PUBLISH_BATCH_STREAM_CAPACITY: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(capacity) => capacity,
+    None => unreachable!(),
+};
+
+/// Number of notification messages buffered in [`AsyncSubscription::notifications()`].
+const NOTIFICATION_STREAM_CAPACITY: NonZeroUsize = match NonZeroUsize::new(100) {
+    Some(capacity) => capacity,
+    None => unreachable!(),
 };
 
 #[derive(Debug, Default)]
@@ -28,8 +57,14 @@ pub struct SubscriptionBuilder {
     max_notifications_per_publish: Option<Option<NonZeroU32>>,
     publishing_enabled: Option<bool>,
     priority: Option<u8>,
+    durable_buffer: Option<Duration>,
+    require_durable_buffer: bool,
+    watchdog_timeout: Option<Duration>,
 }
 
+/// Publishing interval assumed for durable subscriptions when none is requested.
+const DEFAULT_PUBLISHING_INTERVAL: Duration = Duration::from_millis(500);
+
 // Note: The default values in the docs below come from `UA_CreateSubscriptionRequest_default()`.
 impl SubscriptionBuilder {
     /// Sets requested publishing interval.
@@ -108,23 +143,138 @@ impl SubscriptionBuilder {
         self
     }
 
+    /// Makes subscription durable for the given buffer duration.
+    ///
+    /// This derives the requested lifetime count from the publishing interval such that the
+    /// subscription outlives an interruption of the connection for up to `buffer`. When
+    /// reconnecting within this time, the client may resume the subscription and receive the
+    /// notifications that were queued in the meantime.
+    ///
+    /// Unless requested explicitly, the maximum keep-alive count is derived from the publishing
+    /// interval as well, such that the server sends keep-alive messages three times within `buffer`
+    /// when there are no notifications. This is the longest keep-alive interval that the
+    /// specification allows for the given lifetime. The lifetime count is raised to at least three
+    /// times the maximum keep-alive count, and is never lowered below the requested (or default)
+    /// lifetime count.
+    ///
+    /// The server retains only as many notifications as the queues of the monitored items can
+    /// hold. Monitored items created in a durable subscription therefore default to a queue size
+    /// that covers `buffer` at the revised publishing interval, unless set explicitly with
+    /// [`MonitoredItemBuilder::queue_size()`]. Note that servers may limit queue sizes too.
+    ///
+    /// The server may revise the requested values. When the revised values cover less than
+    /// `buffer`, a warning is logged. Use [`require_durable_buffer()`](Self::require_durable_buffer)
+    /// to fail instead.
+    #[must_use]
+    pub const fn durable(mut self, buffer: Duration) -> Self {
+        self.durable_buffer = Some(buffer);
+        self
+    }
+
+    /// Requires server to accept durable buffer.
+    ///
+    /// When enabled, [`create()`](Self::create) fails when the server revises the subscription
+    /// parameters to cover less than the buffer duration set with [`durable()`](Self::durable).
+    /// The subscription is deleted again in this case.
+    ///
+    /// Default value is `false`.
+    #[must_use]
+    pub const fn require_durable_buffer(mut self, require_durable_buffer: bool) -> Self {
+        self.require_durable_buffer = require_durable_buffer;
+        self
+    }
+
+    /// Enables watchdog that detects stalled subscription.
+    ///
+    /// The watchdog yields [`SubscriptionEvent::Stalled`] in [`AsyncSubscription::events()`] when
+    /// the server has not answered publish requests for the subscription within `timeout`, e.g.
+    /// when the network connection has silently died. It yields [`SubscriptionEvent::Resumed`] when
+    /// publish responses arrive again.
+    ///
+    /// Keep-alive messages are handled internally by `open62541` and are not visible to the
+    /// watchdog. Instead, the watchdog adds a heartbeat monitored item for the server's current
+    /// time, sampled at half the timeout, so that publish responses with notifications arrive at
+    /// least that often. The heartbeat monitored item is left out from
+    /// [`AsyncSubscription::get_monitored_items()`] and [`AsyncSubscription::publish_batches()`].
+    /// The timeout should be at least twice the publishing interval. Subscriptions with publishing
+    /// disabled are reported as stalled.
+    ///
+    /// Default value is `None` (no watchdog).
+    #[must_use]
+    pub const fn watchdog_timeout(mut self, timeout: Duration) -> Self {
+        self.watchdog_timeout = Some(timeout);
+        self
+    }
+
     /// Creates subscription.
     ///
     /// # Errors
     ///
-    /// This fails when the client is not connected.
+    /// This fails when the client is not connected, or when the server does not accept the buffer
+    /// duration of a durable subscription (see [`require_durable_buffer()`]). With
+    /// [`watchdog_timeout()`](Self::watchdog_timeout), this also fails when the heartbeat monitored
+    /// item cannot be created.
+    ///
+    /// [`require_durable_buffer()`]: Self::require_durable_buffer
     pub async fn create(
         self,
         client: &AsyncClient,
     ) -> Result<(ua::CreateSubscriptionResponse, AsyncSubscription)> {
         let client = client.client();
 
-        let response = create_subscription(client, &self.into_request()).await?;
+        let durable_buffer = self.durable_buffer;
+        let require_durable_buffer = self.require_durable_buffer;
+        let watchdog_timeout = self.watchdog_timeout;
 
-        let subscription = AsyncSubscription {
-            client: Arc::downgrade(client),
-            subscription_id: response.subscription_id(),
-        };
+        let request = self.into_request();
+        let response = create_subscription(client, &request).await?;
+
+        let mut subscription = AsyncSubscription::new(client, response.subscription_id());
+        subscription.revised_publishing_interval = response.revised_publishing_interval().ok();
+        subscription.revised_lifetime_count = Some(response.revised_lifetime_count());
+        subscription.revised_max_keep_alive_count = Some(response.revised_max_keep_alive_count());
+
+        if let Some(durable_buffer) = durable_buffer {
+            let revised_publishing_interval = response.revised_publishing_interval()?;
+
+            // Dropping the subscription on error deletes it on the server.
+            check_durable_buffer(
+                subscription.subscription_id(),
+                durable_buffer,
+                require_durable_buffer,
+                revised_publishing_interval,
+                response.revised_lifetime_count(),
+            )?;
+
+            subscription.default_queue_size =
+                Some(covering_count(durable_buffer, revised_publishing_interval));
+        }
+
+        if let Some(reconnect) = ClientContext::get(client).reconnect() {
+            reconnect.track_subscription(&subscription.subscription_id, request);
+        }
+
+        if let Some(timeout) = watchdog_timeout {
+            // The heartbeat is created like other monitored items, so that it is recreated along
+            // with the subscription after reconnecting.
+            let mut results =
+                MonitoredItemBuilder::new([ua::NodeId::ns0_server_status_current_time()])
+                    .sampling_interval(Some(timeout / 2))
+                    .stream_buffer_size(NonZeroUsize::MIN)
+                    .stream_overflow(StreamOverflow::DiscardOldest)
+                    .create(&subscription)
+                    .await?;
+            let (_, heartbeat) = results
+                .pop()
+                .ok_or(Error::internal("heartbeat should be created"))??;
+            let heartbeat_id = Arc::clone(heartbeat.shared_monitored_item_id());
+            ClientContext::get(client).subscription_watchdogs().add(
+                &subscription.subscription_id,
+                timeout,
+                &heartbeat_id,
+                heartbeat.into_receiver(),
+            );
+        }
 
         Ok((response, subscription))
     }
@@ -137,6 +287,9 @@ impl SubscriptionBuilder {
             max_notifications_per_publish,
             publishing_enabled,
             priority,
+            durable_buffer,
+            require_durable_buffer: _,
+            watchdog_timeout: _,
         } = self;
 
         let mut request = ua::CreateSubscriptionRequest::default();
@@ -159,19 +312,166 @@ impl SubscriptionBuilder {
         if let Some(priority) = priority {
             request = request.with_priority(priority);
         }
+        if let Some(durable_buffer) = durable_buffer {
+            // The server may revise with a faster publishing interval when none is requested. The
+            // server-revised values are checked again when creating the subscription.
+            let publishing_interval = request
+                .requested_publishing_interval()
+                .unwrap_or(DEFAULT_PUBLISHING_INTERVAL);
+            let buffer_count = covering_count(durable_buffer, publishing_interval);
+            // Unless requested explicitly, send keep-alive messages three times within the buffer.
+            let max_keep_alive_count = requested_max_keep_alive_count
+                .unwrap_or_else(|| NonZeroU32::new((buffer_count / 3).max(1)));
+            // Lifetime count must be at least three times the maximum keep-alive count.
+            let lifetime_count = buffer_count
+                .max(
+                    max_keep_alive_count
+                        .map_or(1, NonZeroU32::get)
+                        .saturating_mul(3),
+                )
+                .max(request.requested_lifetime_count());
+            request = request
+                .with_requested_lifetime_count(lifetime_count)
+                .with_requested_max_keep_alive_count(max_keep_alive_count);
+        }
 
         request
     }
 }
 
 /// Subscription (with asynchronous API).
+///
+/// Values of monitored items are delivered individually to each [`AsyncMonitoredItem`], even when
+/// the server sends them in the same publish response. Use
+/// [`publish_batches()`](Self::publish_batches) to receive them grouped instead.
 #[derive(Debug)]
 pub struct AsyncSubscription {
     client: Weak<ua::Client>,
-    subscription_id: ua::SubscriptionId,
+    /// Server-assigned ID. This changes when the subscription is recreated after reconnecting.
+    subscription_id: Arc<AtomicU32>,
+    /// Queue size for monitored items that do not set their own, in durable subscriptions.
+    default_queue_size: Option<u32>,
+    /// Revised parameters, as returned by the server. These are unknown for adopted subscriptions.
+    revised_publishing_interval: Option<Duration>,
+    revised_lifetime_count: Option<u32>,
+    revised_max_keep_alive_count: Option<u32>,
+    /// Whether the subscription has been adopted instead of created by `open62541`.
+    adopted: bool,
 }
 
 impl AsyncSubscription {
+    pub(crate) fn new(client: &Arc<ua::Client>, subscription_id: ua::SubscriptionId) -> Self {
+        Self {
+            client: Arc::downgrade(client),
+            subscription_id: Arc::new(AtomicU32::new(subscription_id.as_u32())),
+            default_queue_size: None,
+            revised_publishing_interval: None,
+            revised_lifetime_count: None,
+            revised_max_keep_alive_count: None,
+            adopted: false,
+        }
+    }
+
+    pub(crate) fn new_adopted(
+        client: &Arc<ua::Client>,
+        subscription_id: ua::SubscriptionId,
+    ) -> Self {
+        Self {
+            adopted: true,
+            ..Self::new(client, subscription_id)
+        }
+    }
+
+    /// Modifies parameters of subscription.
+    ///
+    /// This sends the parameters set in `builder` to the server, without recreating the
+    /// subscription or its monitored items. Parameters that are not set in `builder` are reset to
+    /// their default values. Note that publishing cannot be enabled or disabled this way: the value
+    /// of [`SubscriptionBuilder::publishing_enabled()`] is ignored.
+    ///
+    /// The server may revise the requested values. The revised values are returned and are also
+    /// available from [`revised_publishing_interval()`](Self::revised_publishing_interval) and
+    /// related methods afterwards. For durable subscriptions, monitored items that are created
+    /// afterwards default to a queue size that covers the buffer at the revised publishing
+    /// interval.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the client is not connected or when the server rejects the request. It also
+    /// fails when the server does not accept the buffer duration of a durable subscription (see
+    /// [`SubscriptionBuilder::require_durable_buffer()`]). The subscription has been modified in
+    /// this case nonetheless.
+    pub async fn modify(
+        &mut self,
+        builder: SubscriptionBuilder,
+    ) -> Result<ua::ModifySubscriptionResponse> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let durable_buffer = builder.durable_buffer;
+        let require_durable_buffer = builder.require_durable_buffer;
+
+        let request = builder.into_request();
+        let modify_request = ua::ModifySubscriptionRequest::init()
+            .with_subscription_id(self.subscription_id())
+            .with_requested_publishing_interval(request.requested_publishing_interval())
+            .with_requested_lifetime_count(request.requested_lifetime_count())
+            .with_requested_max_keep_alive_count(request.requested_max_keep_alive_count())
+            .with_max_notifications_per_publish(request.max_notifications_per_publish())
+            .with_priority(request.priority());
+
+        let response = modify_subscription(client, &modify_request).await?;
+
+        // Recreate subscription with the modified parameters after reconnecting.
+        if let Some(reconnect) = ClientContext::get(client).reconnect() {
+            reconnect.update_subscription(&self.subscription_id, request);
+        }
+
+        let revised_publishing_interval = response.revised_publishing_interval()?;
+        self.revised_publishing_interval = Some(revised_publishing_interval);
+        self.revised_lifetime_count = Some(response.revised_lifetime_count());
+        self.revised_max_keep_alive_count = Some(response.revised_max_keep_alive_count());
+        self.default_queue_size = durable_buffer
+            .map(|durable_buffer| covering_count(durable_buffer, revised_publishing_interval));
+
+        if let Some(durable_buffer) = durable_buffer {
+            check_durable_buffer(
+                self.subscription_id(),
+                durable_buffer,
+                require_durable_buffer,
+                revised_publishing_interval,
+                response.revised_lifetime_count(),
+            )?;
+        }
+
+        Ok(response)
+    }
+
+    /// Gets publishing interval, as revised by the server.
+    ///
+    /// This returns `None` for adopted subscriptions, see [`AsyncClient::adopt_subscription()`].
+    #[must_use]
+    pub const fn revised_publishing_interval(&self) -> Option<Duration> {
+        self.revised_publishing_interval
+    }
+
+    /// Gets lifetime count, as revised by the server.
+    ///
+    /// This returns `None` for adopted subscriptions, see [`AsyncClient::adopt_subscription()`].
+    #[must_use]
+    pub const fn revised_lifetime_count(&self) -> Option<u32> {
+        self.revised_lifetime_count
+    }
+
+    /// Gets maximum keep-alive count, as revised by the server.
+    ///
+    /// This returns `None` for adopted subscriptions, see [`AsyncClient::adopt_subscription()`].
+    #[must_use]
+    pub const fn revised_max_keep_alive_count(&self) -> Option<u32> {
+        self.revised_max_keep_alive_count
+    }
+
     /// Creates [monitored item](AsyncMonitoredItem).
     ///
     /// This creates a new monitored item for the given node.
@@ -195,14 +495,398 @@ impl AsyncSubscription {
         Ok(monitored_item)
     }
 
+    /// Sets monitoring mode of monitored items.
+    ///
+    /// This switches monitored items of this subscription in bulk, e.g. from
+    /// [`ua::MonitoringMode::SAMPLING`] to [`ua::MonitoringMode::REPORTING`] after their queues have
+    /// been filled. Disabled monitored items neither sample nor report values, so their streams do
+    /// not receive values until they are enabled again.
+    ///
+    /// The size and order of the result list matches the size and order of the given list.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a monitored item does not exist, an
+    /// inner `Err` is returned with [`ua::StatusCode::BADMONITOREDITEMIDINVALID`].
+    pub async fn set_monitoring_mode(
+        &self,
+        monitored_item_ids: &[ua::MonitoredItemId],
+        monitoring_mode: ua::MonitoringMode,
+    ) -> Result<Vec<Result<()>>> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        set_monitoring_mode(
+            client,
+            &self.subscription_id,
+            monitored_item_ids,
+            &monitoring_mode,
+        )
+        .await
+    }
+
+    /// Modifies parameters of monitored items.
+    ///
+    /// This changes the sampling interval, queue size, discard policy and filter of monitored items
+    /// of this subscription in bulk, without recreating them. Parameters are replaced as a whole,
+    /// so all of them should be set in the requested parameters of each item. The streams of the
+    /// monitored items continue to receive values afterwards.
+    ///
+    /// The server may revise the requested values. The size and order of the result list matches
+    /// the size and order of the given list.
+    ///
+    /// See [`AsyncMonitoredItem::modify()`] to modify a single monitored item. Unlike this method,
+    /// it also picks up changed select clauses of event filters for
+    /// [`AsyncMonitoredItem::next_event()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails. When a monitored item does not exist, an
+    /// inner `Err` is returned with [`ua::StatusCode::BADMONITOREDITEMIDINVALID`].
+    pub async fn modify_monitored_items(
+        &self,
+        items_to_modify: &[ua::MonitoredItemModifyRequest],
+        timestamps_to_return: ua::TimestampsToReturn,
+    ) -> Result<Vec<Result<ua::MonitoredItemModifyResult>>> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        modify_monitored_items(
+            client,
+            &self.subscription_id,
+            items_to_modify,
+            &timestamps_to_return,
+        )
+        .await
+    }
+
+    /// Sets triggering links of monitored item.
+    ///
+    /// This adds and removes links from the given triggering monitored item to other monitored
+    /// items of this subscription. Linked items are usually in [`ua::MonitoringMode::SAMPLING`]:
+    /// they sample values but only report them when the triggering item reports a notification.
+    ///
+    /// The size and order of the two result lists match the size and order of the given lists of
+    /// links to add and to remove, respectively.
+    ///
+    /// Note that triggering links are not restored when monitored items are recreated after
+    /// reconnecting. Call this method again when needed.
+    ///
+    /// # Errors
+    ///
+    /// This fails only when the entire request fails, e.g. with
+    /// [`ua::StatusCode::BADMONITOREDITEMIDINVALID`] when the triggering monitored item does not
+    /// exist. When a linked monitored item does not exist, an inner `Err` is returned with
+    /// [`ua::StatusCode::BADMONITOREDITEMIDINVALID`].
+    pub async fn set_triggering(
+        &self,
+        triggering_item_id: ua::MonitoredItemId,
+        links_to_add: &[ua::MonitoredItemId],
+        links_to_remove: &[ua::MonitoredItemId],
+    ) -> Result<(Vec<Result<()>>, Vec<Result<()>>)> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        set_triggering(
+            client,
+            &self.subscription_id,
+            triggering_item_id,
+            links_to_add,
+            links_to_remove,
+        )
+        .await
+    }
+
+    /// Gets monitored items of subscription from server.
+    ///
+    /// This calls the standard method `GetMonitoredItems` of the server object and returns the
+    /// server handles (i.e. the monitored item IDs) and the client handles of all monitored items
+    /// that the server holds for this subscription. This is useful for finding monitored items that
+    /// have not been deleted. The heartbeat monitored item of the watchdog is left out, see
+    /// [`SubscriptionBuilder::watchdog_timeout()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`Error::Unsupported`] when the server does not implement the method, and
+    /// when the method call fails otherwise.
+    pub async fn get_monitored_items(&self) -> Result<(Vec<u32>, Vec<u32>)> {
+        let Some(client) = &self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let output_arguments = call_method(
+            client,
+            &ua::NodeId::ns0_server(),
+            &ua::NodeId::ns0_server_get_monitored_items(),
+            &[ua::Variant::scalar(ua::UInt32::new(
+                self.subscription_id().as_u32(),
+            ))],
+        )
+        .await
+        .map_err(|error| {
+            let status_code = error.status_code();
+            if status_code == ua::StatusCode::BADMETHODINVALID
+                || status_code == ua::StatusCode::BADNOTIMPLEMENTED
+            {
+                Error::Unsupported("GetMonitoredItems")
+            } else {
+                error
+            }
+        })?;
+
+        let [server_handles, client_handles] = output_arguments
+            .try_into()
+            .map_err(|_| Error::internal("expected two output arguments"))?;
+        let to_handles = |variant: ua::Variant| {
+            let Some(array) = variant.to_array::<ua::UInt32>() else {
+                return Err(Error::internal("expected array of handles"));
+            };
+            Ok(array.iter().map(ua::UInt32::value).collect::<Vec<_>>())
+        };
+
+        let mut server_handles = to_handles(server_handles)?;
+        let mut client_handles = to_handles(client_handles)?;
+
+        let heartbeat_id = ClientContext::get(client)
+            .subscription_watchdogs()
+            .heartbeat_id(&self.subscription_id);
+        if let Some(heartbeat_id) = heartbeat_id {
+            let heartbeat_id = heartbeat_id.load(Ordering::Relaxed);
+            if let Some(index) = server_handles.iter().position(|&id| id == heartbeat_id) {
+                server_handles.remove(index);
+                if index < client_handles.len() {
+                    client_handles.remove(index);
+                }
+            }
+        }
+
+        Ok((server_handles, client_handles))
+    }
+
     #[must_use]
     pub(crate) const fn client(&self) -> &Weak<ua::Client> {
         &self.client
     }
 
     #[must_use]
-    pub(crate) const fn subscription_id(&self) -> ua::SubscriptionId {
-        self.subscription_id
+    pub(crate) const fn default_queue_size(&self) -> Option<u32> {
+        self.default_queue_size
+    }
+
+    /// Gets server-assigned subscription ID.
+    ///
+    /// This may be persisted to resume management of the subscription later, see
+    /// [`AsyncClient::adopt_subscription()`].
+    ///
+    /// When the subscription is recreated after reconnecting, the server assigns a new ID. See
+    /// [`ClientBuilder::auto_reconnect()`](crate::ClientBuilder::auto_reconnect).
+    #[must_use]
+    pub fn subscription_id(&self) -> ua::SubscriptionId {
+        ua::SubscriptionId::new(self.subscription_id.load(Ordering::Relaxed))
+    }
+
+    /// Deletes subscription.
+    ///
+    /// Unlike dropping the subscription, this waits for the server to respond. The response holds
+    /// the status code of the deletion in [`ua::DeleteSubscriptionsResponse::results()`], e.g.
+    /// [`ua::StatusCode::BADSUBSCRIPTIONIDINVALID`] when the subscription does not exist anymore.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the request fails as a whole, e.g. when the client is not connected. The
+    /// subscription is then deleted in the background when it is dropped, as usual. The same
+    /// happens when the server responds but has not deleted the subscription.
+    pub async fn delete(mut self) -> Result<ua::DeleteSubscriptionsResponse> {
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        self.untrack(&client);
+
+        let request =
+            ua::DeleteSubscriptionsRequest::init().with_subscription_ids(&[self.subscription_id()]);
+
+        let response = delete_subscriptions_async(&client, &request).await?;
+
+        // Keep streams of subscription that still exists on the server.
+        let deleted = response.results().is_some_and(|results| {
+            results.first().is_some_and(|status_code| {
+                status_code.is_good() || *status_code == ua::StatusCode::BADSUBSCRIPTIONIDINVALID
+            })
+        });
+        if deleted {
+            self.close_streams(&client);
+            // Without client, `Drop` does not delete the subscription again.
+            self.client = Weak::new();
+        }
+
+        Ok(response)
+    }
+
+    /// Stops tracking subscription for automatic reconnecting.
+    ///
+    /// This must happen before deleting the subscription: `open62541` notifies the tracking when it
+    /// removes the subscription.
+    fn untrack(&self, client: &ua::Client) {
+        if let Some(reconnect) = ClientContext::get(client).reconnect() {
+            reconnect.untrack_subscription(&self.subscription_id);
+        }
+    }
+
+    /// Ends streams of subscription and stops its watchdog.
+    ///
+    /// `open62541` ends the streams of its own subscriptions when it removes them, i.e. after
+    /// they have been deleted on the server. Adopted subscriptions are not known to `open62541`.
+    fn close_streams(&self, client: &ua::Client) {
+        let context = ClientContext::get(client);
+        context.remove_subscription_event_senders(&self.subscription_id);
+        context
+            .publish_batches()
+            .remove_senders(&self.subscription_id);
+        context
+            .subscription_watchdogs()
+            .remove(&self.subscription_id);
+        context
+            .adopted_subscriptions()
+            .remove_senders(&self.subscription_id);
+    }
+
+    /// Gets stream of subscription events.
+    ///
+    /// The stream yields status changes that the server reports for the subscription, e.g.
+    /// [`ua::StatusCode::BADTIMEOUT`] when the subscription has expired on the server. Only events
+    /// after this call are included. Up to 100 events are buffered; when the consumer falls behind
+    /// further than that, the oldest events are discarded.
+    ///
+    /// Keep-alive messages are handled internally by `open62541` and are not included (except for
+    /// adopted subscriptions, see below). Use [`SubscriptionBuilder::watchdog_timeout()`] to be
+    /// notified with [`SubscriptionEvent::Stalled`] and [`SubscriptionEvent::Resumed`] when publish
+    /// responses stop and resume arriving.
+    ///
+    /// When the subscription is lost and recreated after reconnecting, the stream yields
+    /// [`ua::StatusCode::BADNOCOMMUNICATION`] and continues with events of the recreated
+    /// subscription (see [`ClientBuilder::auto_reconnect()`]). The stream ends when the
+    /// subscription has been deleted, or when it is dropped.
+    ///
+    /// Adopted subscriptions (see [`AsyncClient::adopt_subscription()`]) are not known to the
+    /// client's publishing machinery. Their stream only yields events while their notifications
+    /// are received with [`notifications()`](Self::notifications), including
+    /// [`SubscriptionEvent::KeepAlive`].
+    ///
+    /// [`ClientBuilder::auto_reconnect()`]: crate::ClientBuilder::auto_reconnect
+    pub fn events(&self) -> impl Stream<Item = SubscriptionEvent> + Send + Sync + 'static {
+        let (tx, rx) =
+            CallbackStream::channel(EVENT_STREAM_CAPACITY, StreamOverflow::DiscardOldest);
+        if let Some(client) = self.client.upgrade() {
+            ClientContext::get(&client).add_subscription_event_sender(&self.subscription_id, tx);
+        }
+        // Without client, the sender has been dropped and the stream ends right away.
+        rx
+    }
+
+    /// Gets stream of notification messages of adopted subscription.
+    ///
+    /// `open62541` only delivers notifications of subscriptions that it has created itself. For
+    /// subscriptions adopted with [`AsyncClient::adopt_subscription()`], e.g. after transferring
+    /// them with [`AsyncClient::transfer_subscriptions()`], the client sends publish requests of its
+    /// own while there are streams, and acknowledges the received notification messages.
+    ///
+    /// The stream yields the notification messages of this subscription as sent by the server. Data
+    /// change notifications refer to monitored items by client handle, which may be looked up with
+    /// [`get_monitored_items()`](Self::get_monitored_items). Only notification messages after this
+    /// call are included. Up to 100 messages are buffered; when the consumer falls behind further
+    /// than that, the oldest messages are discarded.
+    ///
+    /// The stream ends when the subscription is deleted or dropped, and when the server stops
+    /// answering publish requests, e.g. when the session has been lost.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADINVALIDSTATE`] when the subscription has not been
+    /// adopted, or when the client has created subscriptions of its own: the server answers publish
+    /// requests with notifications of any subscription in the session, so they cannot be shared.
+    /// Conversely, creating subscriptions fails while publishing for adopted subscriptions.
+    pub fn notifications(
+        &self,
+    ) -> Result<impl Stream<Item = ua::NotificationMessage> + Send + Sync + 'static> {
+        if !self.adopted {
+            return Err(Error::new(ua::StatusCode::BADINVALIDSTATE));
+        }
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let (tx, rx) =
+            CallbackStream::channel(NOTIFICATION_STREAM_CAPACITY, StreamOverflow::DiscardOldest);
+        let start = ClientContext::get(&client)
+            .adopted_subscriptions()
+            .add_sender(&self.subscription_id, tx)?;
+        if start {
+            start_publishing(&client);
+        }
+
+        Ok(rx)
+    }
+
+    /// Gets stream of values of monitored items, grouped by publish response.
+    ///
+    /// The stream yields the values that the server sends in one publish response as one batch,
+    /// with the ID of their monitored item. This includes event fields of monitored items of events,
+    /// see [`AsyncMonitoredItem`]. The values are also delivered to the monitored items themselves
+    /// as before.
+    ///
+    /// `open62541` does not pass on publish responses but only the values of monitored items. So
+    /// the batches hold the values that have been received within a single iteration of the
+    /// client's event loop: a publish response is never split, but in rare cases two responses may
+    /// be merged into one batch. The publish time of the notification message is not available. Use
+    /// source or server timestamps of values instead (see
+    /// [`MonitoredItemBuilder::timestamps_to_return()`]). Each batch is marked with the iteration
+    /// it has been received in, see [`PublishBatch::iteration()`].
+    ///
+    /// Values of the heartbeat monitored item of the watchdog are left out, see
+    /// [`SubscriptionBuilder::watchdog_timeout()`]. Only values after this call are included. Up to
+    /// 100 batches are buffered; when the consumer falls behind further than that, the oldest
+    /// batches are discarded. The stream ends when the subscription has been deleted, or when it is
+    /// dropped. It continues after the subscription has been recreated (see
+    /// [`ClientBuilder::auto_reconnect()`]).
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADINVALIDSTATE`] for adopted subscriptions. Their
+    /// notification messages, including the publish time, are available from
+    /// [`notifications()`](Self::notifications).
+    ///
+    /// [`ClientBuilder::auto_reconnect()`]: crate::ClientBuilder::auto_reconnect
+    pub fn publish_batches(
+        &self,
+    ) -> Result<impl Stream<Item = PublishBatch> + Send + Sync + 'static> {
+        if self.adopted {
+            return Err(Error::new(ua::StatusCode::BADINVALIDSTATE));
+        }
+        let Some(client) = self.client.upgrade() else {
+            return Err(Error::internal("client should not be dropped"));
+        };
+
+        let (tx, rx) =
+            CallbackStream::channel(PUBLISH_BATCH_STREAM_CAPACITY, StreamOverflow::DiscardOldest);
+        let context = ClientContext::get(&client);
+        let heartbeat_id = context
+            .subscription_watchdogs()
+            .heartbeat_id(&self.subscription_id);
+        context
+            .publish_batches()
+            .add_sender(&self.subscription_id, heartbeat_id, tx);
+
+        Ok(rx)
+    }
+
+    /// Gets shared subscription ID, to be used by monitored items.
+    #[must_use]
+    pub(crate) const fn shared_subscription_id(&self) -> &Arc<AtomicU32> {
+        &self.subscription_id
     }
 }
 
@@ -212,21 +896,331 @@ impl Drop for AsyncSubscription {
             return;
         };
 
+        self.untrack(&client);
+        // The subscription cannot be used anymore. We do not learn whether it is deleted.
+        if self.adopted {
+            self.close_streams(&client);
+        }
+
         let request =
-            ua::DeleteSubscriptionsRequest::init().with_subscription_ids(&[self.subscription_id]);
+            ua::DeleteSubscriptionsRequest::init().with_subscription_ids(&[self.subscription_id()]);
 
         delete_subscriptions(&client, &request);
     }
 }
 
-async fn create_subscription(
+/// Event of [`AsyncSubscription`].
+///
+/// See [`AsyncSubscription::events()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubscriptionEvent {
+    /// Status of subscription has changed.
+    ///
+    /// This holds the status reported by the server in a `StatusChangeNotification`, e.g.
+    /// [`ua::StatusCode::BADTIMEOUT`] when the subscription has expired.
+    StatusChange(ua::StatusCode),
+    /// Subscription has been transferred to another session.
+    ///
+    /// The server does not send notifications for this subscription to this session anymore.
+    TransferInitiated,
+    /// Subscription has stopped receiving publish responses.
+    ///
+    /// This is yielded by the watchdog of the subscription when its timeout has passed without
+    /// publish responses, e.g. when the network connection has silently died. See
+    /// [`SubscriptionBuilder::watchdog_timeout()`].
+    ///
+    /// This is yielded only once until the subscription has resumed.
+    Stalled,
+    /// Subscription has resumed receiving publish responses after being stalled.
+    ///
+    /// This is yielded by the watchdog of the subscription with the first publish response after
+    /// [`Stalled`](Self::Stalled). See [`SubscriptionBuilder::watchdog_timeout()`].
+    Resumed,
+    /// Subscription has received keep-alive message.
+    ///
+    /// The server sends keep-alive messages when there have been no notifications to send within
+    /// the keep-alive interval of the subscription. This holds the sequence number of the next
+    /// notification message.
+    ///
+    /// This is only yielded for adopted subscriptions while their notifications are received, see
+    /// [`AsyncSubscription::notifications()`]. `open62541` processes keep-alive messages of its own
+    /// subscriptions internally and does not report them.
+    KeepAlive { sequence_number: u32 },
+}
+
+/// Batch of values of monitored items.
+///
+/// See [`AsyncSubscription::publish_batches()`].
+#[derive(Debug, Clone)]
+pub struct PublishBatch {
+    iteration: u64,
+    values: Vec<(ua::MonitoredItemId, ua::DataValue)>,
+}
+
+impl PublishBatch {
+    pub(crate) const fn new(
+        iteration: u64,
+        values: Vec<(ua::MonitoredItemId, ua::DataValue)>,
+    ) -> Self {
+        Self { iteration, values }
+    }
+
+    /// Gets iteration of the client's event loop that the values have been received in.
+    ///
+    /// This increases from batch to batch of a subscription. Batches of different subscriptions
+    /// with the same iteration have been received together.
+    #[must_use]
+    pub const fn iteration(&self) -> u64 {
+        self.iteration
+    }
+
+    /// Gets values, with the ID of their monitored item.
+    #[must_use]
+    pub fn values(&self) -> &[(ua::MonitoredItemId, ua::DataValue)] {
+        &self.values
+    }
+
+    /// Converts batch into values, with the ID of their monitored item.
+    #[must_use]
+    pub fn into_values(self) -> Vec<(ua::MonitoredItemId, ua::DataValue)> {
+        self.values
+    }
+}
+
+impl SubscriptionEvent {
+    /// Creates event from status in `StatusChangeNotification`.
+    pub(crate) fn from_status_change(status_code: ua::StatusCode) -> Self {
+        if status_code == ua::StatusCode::GOODSUBSCRIPTIONTRANSFERRED {
+            Self::TransferInitiated
+        } else {
+            Self::StatusChange(status_code)
+        }
+    }
+}
+
+/// Gets number of intervals needed to cover the given duration.
+fn covering_count(duration: Duration, interval: Duration) -> u32 {
+    if interval.is_zero() {
+        return u32::MAX;
+    }
+    u32::try_from(duration.as_nanos().div_ceil(interval.as_nanos())).unwrap_or(u32::MAX)
+}
+
+/// Checks that revised parameters of durable subscription cover buffer duration.
+fn check_durable_buffer(
+    subscription_id: ua::SubscriptionId,
+    durable_buffer: Duration,
+    require_durable_buffer: bool,
+    revised_publishing_interval: Duration,
+    revised_lifetime_count: u32,
+) -> Result<()> {
+    let revised_buffer = revised_publishing_interval.saturating_mul(revised_lifetime_count);
+
+    if revised_buffer < durable_buffer {
+        if require_durable_buffer {
+            return Err(Error::internal(
+                "server revised subscription lifetime below durable buffer",
+            ));
+        }
+        log::warn!(
+            "Server revised lifetime of subscription {subscription_id} to {revised_buffer:?}, \
+            below requested durable buffer of {durable_buffer:?}",
+        );
+    }
+
+    Ok(())
+}
+
+/// Sets monitoring mode of monitored items in subscription.
+pub(crate) async fn set_monitoring_mode(
+    client: &ua::Client,
+    subscription_id: &Arc<AtomicU32>,
+    monitored_item_ids: &[ua::MonitoredItemId],
+    monitoring_mode: &ua::MonitoringMode,
+) -> Result<Vec<Result<()>>> {
+    let request = ua::SetMonitoringModeRequest::init()
+        .with_subscription_id(ua::SubscriptionId::new(
+            subscription_id.load(Ordering::Relaxed),
+        ))
+        .with_monitoring_mode(monitoring_mode)
+        .with_monitored_item_ids(monitored_item_ids);
+
+    let response = service_request(client, request).await?;
+
+    let results = to_status_results(
+        response.results(),
+        monitored_item_ids.len(),
+        "unexpected number of set monitoring mode results",
+    )?;
+
+    // Recreate monitored items with their current monitoring mode after reconnecting.
+    if let Some(reconnect) = ClientContext::get(client).reconnect() {
+        for (monitored_item_id, result) in monitored_item_ids.iter().zip(&results) {
+            if result.is_ok() {
+                reconnect.set_monitoring_mode(subscription_id, *monitored_item_id, monitoring_mode);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Modifies parameters of monitored items in subscription.
+pub(crate) async fn modify_monitored_items(
+    client: &ua::Client,
+    subscription_id: &Arc<AtomicU32>,
+    items_to_modify: &[ua::MonitoredItemModifyRequest],
+    timestamps_to_return: &ua::TimestampsToReturn,
+) -> Result<Vec<Result<ua::MonitoredItemModifyResult>>> {
+    type Cb = CallbackOnce<std::result::Result<ua::ModifyMonitoredItemsResponse, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("MonitoredItems_modify() completed");
+
+        let response = response.cast::<UA_ModifyMonitoredItemsResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            Ok(ua::ModifyMonitoredItemsResponse::clone_raw(response))
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<ua::ModifyMonitoredItemsResponse>>();
+
+    let callback = |result: std::result::Result<ua::ModifyMonitoredItemsResponse, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been cancelled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    let request = ua::ModifyMonitoredItemsRequest::init()
+        .with_subscription_id(ua::SubscriptionId::new(
+            subscription_id.load(Ordering::Relaxed),
+        ))
+        .with_timestamps_to_return(timestamps_to_return)
+        .with_items_to_modify(items_to_modify);
+
+    let status_code = ua::StatusCode::new({
+        log::debug!("Calling MonitoredItems_modify()");
+
+        // SAFETY: `UA_Client_MonitoredItems_modify_async()` expects the request passed by value but
+        // does not take ownership.
+        let request = unsafe { ua::ModifyMonitoredItemsRequest::to_raw_copy(&request) };
+
+        // This sets the client handles of the monitored items, so that `open62541` still dispatches
+        // notifications to them after the modification.
+        unsafe {
+            UA_Client_MonitoredItems_modify_async(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                client.as_ptr().cast_mut(),
+                request,
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    let response = rx
+        .await
+        .unwrap_or(Err(Error::internal("callback should send result")))?;
+
+    let Some(results) = response.results() else {
+        return Err(Error::internal(
+            "modify monitored items should return results",
+        ));
+    };
+
+    verify_result_count(
+        results.len(),
+        items_to_modify.len(),
+        "unexpected number of modify monitored items results",
+    )?;
+
+    // Recreate monitored items with their modified parameters after reconnecting.
+    let reconnect = ClientContext::get(client).reconnect();
+
+    let results = items_to_modify
+        .iter()
+        .zip(results)
+        .map(|(item, result)| {
+            Error::verify_good(&result.status_code())?;
+            if let Some(reconnect) = reconnect {
+                reconnect.modify_monitored_item(
+                    subscription_id,
+                    item.monitored_item_id(),
+                    item.requested_parameters(),
+                    timestamps_to_return,
+                );
+            }
+            Ok(result.clone())
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Sets triggering links of monitored item in subscription.
+pub(crate) async fn set_triggering(
+    client: &ua::Client,
+    subscription_id: &Arc<AtomicU32>,
+    triggering_item_id: ua::MonitoredItemId,
+    links_to_add: &[ua::MonitoredItemId],
+    links_to_remove: &[ua::MonitoredItemId],
+) -> Result<(Vec<Result<()>>, Vec<Result<()>>)> {
+    let request = ua::SetTriggeringRequest::init()
+        .with_subscription_id(ua::SubscriptionId::new(
+            subscription_id.load(Ordering::Relaxed),
+        ))
+        .with_triggering_item_id(triggering_item_id)
+        .with_links_to_add(links_to_add)
+        .with_links_to_remove(links_to_remove);
+
+    let response = service_request(client, request).await?;
+
+    Ok((
+        to_status_results(
+            response.add_results(),
+            links_to_add.len(),
+            "unexpected number of set triggering results",
+        )?,
+        to_status_results(
+            response.remove_results(),
+            links_to_remove.len(),
+            "unexpected number of set triggering results",
+        )?,
+    ))
+}
+
+pub(crate) async fn create_subscription(
     client: &ua::Client,
     request: &ua::CreateSubscriptionRequest,
 ) -> Result<ua::CreateSubscriptionResponse> {
     type Cb = CallbackOnce<std::result::Result<ua::CreateSubscriptionResponse, ua::StatusCode>>;
 
     unsafe extern "C" fn callback_c(
-        _client: *mut UA_Client,
+        client: *mut UA_Client,
         userdata: *mut c_void,
         _request_id: UA_UInt32,
         response: *mut c_void,
@@ -239,6 +1233,16 @@ async fn create_subscription(
         let response = unsafe { response.as_ref() }.expect("response should be set");
         let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
 
+        if status_code.is_good() {
+            // `open62541` has registered the subscription and publishes for it from now on.
+            // SAFETY: The client is valid for the duration of the callback.
+            if let Some(context) = unsafe { ClientContext::from_client(client) } {
+                context
+                    .adopted_subscriptions()
+                    .managed_subscription_created();
+            }
+        }
+
         let result = if status_code.is_good() {
             Ok(ua::CreateSubscriptionResponse::clone_raw(response))
         } else {
@@ -251,6 +1255,14 @@ async fn create_subscription(
         }
     }
 
+    // Publish responses would go astray when both `open62541` and we publish in the same session.
+    if ClientContext::get(client)
+        .adopted_subscriptions()
+        .is_publishing()
+    {
+        return Err(Error::new(ua::StatusCode::BADINVALIDSTATE));
+    }
+
     let (tx, rx) = oneshot::channel::<Result<ua::CreateSubscriptionResponse>>();
 
     let callback = |result: std::result::Result<ua::CreateSubscriptionResponse, _>| {
@@ -273,8 +1285,8 @@ async fn create_subscription(
                 client.as_ptr().cast_mut(),
                 request,
                 ptr::null_mut(),
-                None,
-                None,
+                Some(subscription_status_change_callback_c),
+                Some(subscription_delete_callback_c),
                 Some(callback_c),
                 Cb::prepare(callback),
                 ptr::null_mut(),
@@ -290,10 +1302,132 @@ async fn create_subscription(
         .unwrap_or(Err(Error::internal("callback should send result")))
 }
 
-fn delete_subscriptions(client: &ua::Client, request: &ua::DeleteSubscriptionsRequest) {
+async fn modify_subscription(
+    client: &ua::Client,
+    request: &ua::ModifySubscriptionRequest,
+) -> Result<ua::ModifySubscriptionResponse> {
+    type Cb = CallbackOnce<std::result::Result<ua::ModifySubscriptionResponse, ua::StatusCode>>;
+
     unsafe extern "C" fn callback_c(
         _client: *mut UA_Client,
-        _userdata: *mut c_void,
+        userdata: *mut c_void,
+        _request_id: UA_UInt32,
+        response: *mut c_void,
+    ) {
+        log::debug!("Subscriptions_modify() completed");
+
+        let response = response.cast::<UA_ModifySubscriptionResponse>();
+        // SAFETY: Incoming pointer is valid for access.
+        // PANIC: We expect pointer to be valid when good.
+        let response = unsafe { response.as_ref() }.expect("response should be set");
+        let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
+
+        let result = if status_code.is_good() {
+            Ok(ua::ModifySubscriptionResponse::clone_raw(response))
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
+        }
+    }
+
+    let (tx, rx) = oneshot::channel::<Result<ua::ModifySubscriptionResponse>>();
+
+    let callback = |result: std::result::Result<ua::ModifySubscriptionResponse, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been cancelled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    let status_code = ua::StatusCode::new({
+        log::debug!("Calling Subscriptions_modify()");
+
+        // SAFETY: `UA_Client_Subscriptions_modify_async()` expects the request passed by value but
+        // does not take ownership.
+        let request = unsafe { ua::ModifySubscriptionRequest::to_raw_copy(request) };
+
+        // This also updates the publishing interval and keep-alive count that the client uses
+        // internally, e.g. to detect inactive subscriptions.
+        unsafe {
+            UA_Client_Subscriptions_modify_async(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                client.as_ptr().cast_mut(),
+                request,
+                Some(callback_c),
+                Cb::prepare(callback),
+                ptr::null_mut(),
+            )
+        }
+    });
+    Error::verify_good(&status_code)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    rx.await
+        .unwrap_or(Err(Error::internal("callback should send result")))
+}
+
+/// Deletes subscriptions, without waiting for the response.
+///
+/// Errors are logged.
+pub(crate) fn delete_subscriptions(client: &ua::Client, request: &ua::DeleteSubscriptionsRequest) {
+    let callback =
+        |result: std::result::Result<ua::DeleteSubscriptionsResponse, ua::StatusCode>| {
+            if let Err(status_code) = result {
+                log::warn!(
+                    "Error in response when deleting subscriptions: {}",
+                    Error::new(status_code)
+                );
+            }
+        };
+
+    if let Err(error) = dispatch_delete_subscriptions(client, request, callback) {
+        log::warn!("Error in request when deleting subscriptions: {error}");
+    }
+}
+
+/// Deletes subscriptions, waiting for the response.
+async fn delete_subscriptions_async(
+    client: &ua::Client,
+    request: &ua::DeleteSubscriptionsRequest,
+) -> Result<ua::DeleteSubscriptionsResponse> {
+    let (tx, rx) = oneshot::channel::<Result<ua::DeleteSubscriptionsResponse>>();
+
+    let callback = |result: std::result::Result<ua::DeleteSubscriptionsResponse, _>| {
+        // We always send a result back via `tx` (in fact, `rx.await` below expects this). We do not
+        // care if that succeeds though: the receiver might already have gone out of scope (when its
+        // future has been cancelled) and we must not panic in FFI callbacks.
+        let _unused = tx.send(result.map_err(Error::new));
+    };
+
+    dispatch_delete_subscriptions(client, request, callback)?;
+
+    // PANIC: When `callback` is called (which owns `tx`), we always call `tx.send()`. So the sender
+    // is only dropped after placing a value into the channel and `rx.await` always finds this value
+    // there.
+    rx.await
+        .unwrap_or(Err(Error::internal("callback should send result")))
+}
+
+/// Sends request to delete subscriptions.
+///
+/// The callback is called with the response. It is not called when this returns an error.
+fn dispatch_delete_subscriptions(
+    client: &ua::Client,
+    request: &ua::DeleteSubscriptionsRequest,
+    callback: impl FnOnce(std::result::Result<ua::DeleteSubscriptionsResponse, ua::StatusCode>)
+        + 'static,
+) -> Result<()> {
+    type Cb = CallbackOnce<std::result::Result<ua::DeleteSubscriptionsResponse, ua::StatusCode>>;
+
+    unsafe extern "C" fn callback_c(
+        _client: *mut UA_Client,
+        userdata: *mut c_void,
         _request_id: UA_UInt32,
         response: *mut c_void,
     ) {
@@ -305,8 +1439,15 @@ fn delete_subscriptions(client: &ua::Client, request: &ua::DeleteSubscriptionsRe
         let response = unsafe { response.as_ref() }.expect("response should be set");
         let status_code = ua::StatusCode::new(response.responseHeader.serviceResult);
 
-        if let Err(error) = Error::verify_good(&status_code) {
-            log::warn!("Error in response when deleting subscriptions: {error}");
+        let result = if status_code.is_good() {
+            Ok(ua::DeleteSubscriptionsResponse::clone_raw(response))
+        } else {
+            Err(status_code)
+        };
+
+        // SAFETY: `userdata` is the result of `Cb::prepare()` and is used only once.
+        unsafe {
+            Cb::execute(userdata, result);
         }
     }
 
@@ -323,12 +1464,421 @@ fn delete_subscriptions(client: &ua::Client, request: &ua::DeleteSubscriptionsRe
                 client.as_ptr().cast_mut(),
                 request,
                 Some(callback_c),
-                ptr::null_mut(),
+                Cb::prepare(callback),
                 ptr::null_mut(),
             )
         }
     });
-    if let Err(error) = Error::verify_good(&status_code) {
-        log::warn!("Error in request when deleting subscriptions: {error}");
+    Error::verify_good(&status_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU32, time::Duration};
+
+    use crate::{ua, TestHarness};
+
+    use super::{SubscriptionBuilder, SubscriptionEvent};
+
+    #[test]
+    fn durable_lifetime_count() {
+        // Buffer is covered at the requested publishing interval.
+        let request = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_secs(1)))
+            .durable(Duration::from_secs(600))
+            .into_request();
+        assert_eq!(request.requested_lifetime_count(), 600);
+        // Keep-alive messages are sent three times within the buffer.
+        assert_eq!(
+            request.requested_max_keep_alive_count(),
+            NonZeroU32::new(200)
+        );
+
+        // Lifetime count is at least three times the keep-alive count.
+        let request = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_secs(1)))
+            .requested_max_keep_alive_count(NonZeroU32::new(50))
+            .durable(Duration::from_secs(10))
+            .into_request();
+        assert_eq!(request.requested_lifetime_count(), 150);
+        assert_eq!(
+            request.requested_max_keep_alive_count(),
+            NonZeroU32::new(50)
+        );
+
+        // Default lifetime count is never lowered.
+        let request = SubscriptionBuilder::default()
+            .durable(Duration::from_secs(60))
+            .into_request();
+        assert_eq!(request.requested_lifetime_count(), 10000);
+        assert_eq!(
+            request.requested_max_keep_alive_count(),
+            NonZeroU32::new(40)
+        );
+
+        // Default publishing interval is used when none is requested.
+        let request = SubscriptionBuilder::default()
+            .durable(Duration::from_secs(3600))
+            .into_request();
+        assert_eq!(request.requested_lifetime_count(), 7200);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn modify() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let (response, mut subscription) = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_millis(500)))
+            .create(client)
+            .await
+            .unwrap();
+        assert_eq!(
+            subscription.revised_publishing_interval(),
+            Some(response.revised_publishing_interval().unwrap())
+        );
+
+        let response = subscription
+            .modify(
+                SubscriptionBuilder::default()
+                    .requested_publishing_interval(Some(Duration::from_secs(2)))
+                    .requested_max_keep_alive_count(NonZeroU32::new(5)),
+            )
+            .await
+            .expect("should modify subscription");
+        assert_eq!(
+            response.revised_publishing_interval().unwrap(),
+            Duration::from_secs(2)
+        );
+        assert_eq!(response.revised_max_keep_alive_count(), 5);
+        assert_eq!(
+            subscription.revised_publishing_interval(),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(subscription.revised_max_keep_alive_count(), Some(5));
+        assert_eq!(
+            subscription.revised_lifetime_count(),
+            Some(response.revised_lifetime_count())
+        );
+
+        // Adopted subscriptions do not know their parameters.
+        let adopted = client.adopt_subscription(ua::SubscriptionId::new(u32::MAX));
+        assert_eq!(adopted.revised_publishing_interval(), None);
+        drop(adopted);
+
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delete() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let response = subscription
+            .delete()
+            .await
+            .expect("should delete subscription");
+        assert_eq!(response.results(), Some(vec![ua::StatusCode::GOOD]));
+
+        // Deleting subscription that does not exist fails for the individual subscription.
+        let subscription = client.adopt_subscription(ua::SubscriptionId::new(u32::MAX));
+        let response = subscription.delete().await.expect("should send request");
+        assert_eq!(
+            response.results(),
+            Some(vec![ua::StatusCode::BADSUBSCRIPTIONIDINVALID])
+        );
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn events_end_when_deleted() {
+        use futures::StreamExt as _;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut events = Box::pin(subscription.events());
+        subscription
+            .delete()
+            .await
+            .expect("should delete subscription");
+        let event = tokio::time::timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect("stream should end");
+        assert_eq!(event, None);
+
+        // Dropping the subscription ends the stream too.
+        let subscription = client.create_subscription().await.unwrap();
+        let mut events = Box::pin(subscription.events());
+        drop(subscription);
+        let event = tokio::time::timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect("stream should end");
+        assert_eq!(event, None);
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[test]
+    fn events_from_status_change() {
+        assert_eq!(
+            SubscriptionEvent::from_status_change(ua::StatusCode::GOODSUBSCRIPTIONTRANSFERRED),
+            SubscriptionEvent::TransferInitiated
+        );
+        assert_eq!(
+            SubscriptionEvent::from_status_change(ua::StatusCode::BADTIMEOUT),
+            SubscriptionEvent::StatusChange(ua::StatusCode::BADTIMEOUT)
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn events_of_transfer() {
+        use futures::StreamExt as _;
+
+        use crate::AsyncClient;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let (_, subscription) = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_millis(100)))
+            .requested_max_keep_alive_count(NonZeroU32::new(1))
+            .create(client)
+            .await
+            .unwrap();
+        let mut events = Box::pin(subscription.events());
+
+        let other_client = AsyncClient::connect(&harness.endpoint_url())
+            .await
+            .expect("should connect");
+        let results = other_client
+            .transfer_subscriptions(&[subscription.subscription_id()], false)
+            .await
+            .unwrap();
+        assert_eq!(results[0].status_code(), ua::StatusCode::GOOD);
+
+        // The previous session is told that the subscription has moved away.
+        let event = tokio::time::timeout(Duration::from_secs(10), events.next())
+            .await
+            .expect("should receive event");
+        assert_eq!(event, Some(SubscriptionEvent::TransferInitiated));
+
+        // Keep-alive messages of adopted subscriptions are reported while publishing for them.
+        let adopted = other_client.adopt_subscription(subscription.subscription_id());
+        let mut adopted_events = Box::pin(adopted.events());
+        let _notifications = adopted.notifications().unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(10), adopted_events.next())
+            .await
+            .expect("should receive event");
+        assert!(matches!(event, Some(SubscriptionEvent::KeepAlive { .. })));
+
+        // Transferring back is reported by the publishing for adopted subscriptions too.
+        let results = client
+            .transfer_subscriptions(&[subscription.subscription_id()], false)
+            .await
+            .unwrap();
+        assert_eq!(results[0].status_code(), ua::StatusCode::GOOD);
+        let event = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let event = adopted_events.next().await.expect("should not end");
+                if !matches!(event, SubscriptionEvent::KeepAlive { .. }) {
+                    break event;
+                }
+            }
+        })
+        .await
+        .expect("should receive event");
+        assert_eq!(event, SubscriptionEvent::TransferInitiated);
+
+        drop(adopted);
+        drop(other_client);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn watchdog() {
+        use std::sync::mpsc;
+
+        use futures::StreamExt as _;
+
+        use crate::{DataSource, DataSourceReadContext, DataSourceResult, VariableNode};
+
+        // Data source that blocks the server when read, so that it stops answering publish
+        // requests until released.
+        struct BlockingDataSource(mpsc::Receiver<()>);
+
+        impl DataSource for BlockingDataSource {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                let _unused = self.0.recv_timeout(Duration::from_secs(10));
+                context.set_variant(ua::Variant::scalar(ua::Int32::new(0)));
+                Ok(())
+            }
+        }
+
+        let (release, released) = mpsc::channel();
+        let mut node_id = None;
+        let harness = TestHarness::start(|server| {
+            let id = server
+                .add_data_source_variable_node(
+                    VariableNode {
+                        requested_new_node_id: None,
+                        parent_node_id: ua::NodeId::ns0_objects_folder(),
+                        reference_type_id: ua::NodeId::ns0_organizes(),
+                        browse_name: ua::QualifiedName::new(1, "Blocking"),
+                        type_definition: ua::NodeId::ns0_base_data_variable_type(),
+                        attributes: ua::VariableAttributes::init()
+                            .with_data_type(&ua::NodeId::ns0_int32())
+                            .with_value_rank(-1),
+                    },
+                    BlockingDataSource(released),
+                )
+                .expect("should add node");
+            node_id = Some(id);
+        })
+        .expect("should start harness");
+        let client = harness.client();
+        let node_id = node_id.unwrap();
+
+        let (_, subscription) = SubscriptionBuilder::default()
+            .requested_publishing_interval(Some(Duration::from_millis(100)))
+            .watchdog_timeout(Duration::from_millis(500))
+            .create(client)
+            .await
+            .expect("should create subscription");
+        let mut events = Box::pin(subscription.events());
+        let mut batches = Box::pin(subscription.publish_batches().unwrap());
+
+        // Heartbeat monitored item of watchdog is internal.
+        if let Ok((server_handles, _)) = subscription.get_monitored_items().await {
+            assert!(server_handles.is_empty());
+        }
+
+        // Subscription without monitored items of its own is not reported as stalled.
+        tokio::time::timeout(Duration::from_secs(1), events.next())
+            .await
+            .expect_err("should not yield event");
+        tokio::time::timeout(Duration::from_millis(100), batches.next())
+            .await
+            .expect_err("should not yield batch");
+
+        // Block server while waiting for the watchdog.
+        let (_, event) = tokio::join!(client.read_value(&node_id), async {
+            let event = tokio::time::timeout(Duration::from_secs(5), events.next()).await;
+            release.send(()).unwrap();
+            event
+        });
+        assert_eq!(event, Ok(Some(SubscriptionEvent::Stalled)));
+
+        // Server answers publish requests again after it has been released.
+        let event = tokio::time::timeout(Duration::from_secs(5), events.next())
+            .await
+            .expect("should yield event");
+        assert_eq!(event, Some(SubscriptionEvent::Resumed));
+
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn get_monitored_items() {
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let (server_handles, client_handles) = subscription
+            .get_monitored_items()
+            .await
+            .expect("should get monitored items");
+        assert!(server_handles.is_empty());
+        assert!(client_handles.is_empty());
+
+        let monitored_item = subscription
+            .create_monitored_item(&ua::NodeId::ns0_server_status_current_time())
+            .await
+            .unwrap();
+        let (server_handles, client_handles) = subscription
+            .get_monitored_items()
+            .await
+            .expect("should get monitored items");
+        assert_eq!(
+            server_handles,
+            [monitored_item.monitored_item_id().as_u32()]
+        );
+        assert_eq!(client_handles.len(), 1);
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn publish_batches() {
+        use futures::StreamExt as _;
+
+        use crate::MonitoredItemBuilder;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut batches = Box::pin(subscription.publish_batches().unwrap());
+
+        // Initial values of monitored items that are created together are sent in the same publish
+        // response.
+        let results = MonitoredItemBuilder::new([
+            ua::NodeId::ns0_server_status_start_time(),
+            ua::NodeId::ns0_server_status_state(),
+        ])
+        .create(&subscription)
+        .await
+        .unwrap();
+        let monitored_items = results
+            .into_iter()
+            .map(|result| result.unwrap().1)
+            .collect::<Vec<_>>();
+
+        let batch = tokio::time::timeout(Duration::from_secs(5), batches.next())
+            .await
+            .expect("should receive batch")
+            .expect("stream should not end");
+        let mut monitored_item_ids = batch.values().iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        monitored_item_ids.sort_by_key(|id| id.as_u32());
+        let mut expected_ids = monitored_items
+            .iter()
+            .map(crate::AsyncMonitoredItem::monitored_item_id)
+            .collect::<Vec<_>>();
+        expected_ids.sort_by_key(|id| id.as_u32());
+        assert_eq!(monitored_item_ids, expected_ids);
+        assert!(batch
+            .values()
+            .iter()
+            .all(|(_, value)| value.value().is_some()));
+
+        // Monitored items still receive their values.
+        for mut monitored_item in monitored_items {
+            let value = monitored_item.next().await.unwrap();
+            assert!(value.value().is_some());
+        }
+
+        // Adopted subscriptions have no batches.
+        let adopted = client.adopt_subscription(ua::SubscriptionId::new(u32::MAX));
+        assert!(adopted.publish_batches().is_err());
+        drop(adopted);
+
+        subscription
+            .delete()
+            .await
+            .expect("should delete subscription");
+        let batch = tokio::time::timeout(Duration::from_secs(1), batches.next())
+            .await
+            .expect("stream should end");
+        assert!(batch.is_none());
+
+        harness.shutdown().await.unwrap();
     }
 }
@@ -1,4 +1,16 @@
 use crate::{ua, Result};
 
 /// Result type for browsing.
+///
+/// When not all references could be returned at once, this includes a continuation point to get
+/// more references, e.g. with [`AsyncClient::browse_next()`] or [`Server::browse_next()`]. The
+/// continuation point keeps resources allocated on the server until all references have been
+/// browsed, or until it is released with [`AsyncClient::release_continuation_points()`] or
+/// [`Server::browse_next_release()`]. Unreleased continuation points of a client are released only
+/// when its session is closed, and the number of continuation points per session is limited.
+///
+/// [`AsyncClient::browse_next()`]: crate::AsyncClient::browse_next
+/// [`AsyncClient::release_continuation_points()`]: crate::AsyncClient::release_continuation_points
+/// [`Server::browse_next()`]: crate::Server::browse_next
+/// [`Server::browse_next_release()`]: crate::Server::browse_next_release
 pub type BrowseResult = Result<(Vec<ua::ReferenceDescription>, Option<ua::ContinuationPoint>)>;
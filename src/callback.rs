@@ -1,6 +1,15 @@
-use std::{ffi::c_void, marker::PhantomData};
+use std::{
+    collections::VecDeque,
+    ffi::c_void,
+    future,
+    marker::PhantomData,
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{self, Poll, Waker},
+};
 
-use tokio::sync::mpsc;
+use futures_core::Stream;
 
 use crate::Userdata;
 
@@ -74,27 +83,35 @@ impl<T> CallbackOnce<T> {
 
 /// Type-erased stream sender.
 ///
-/// Use this to wrap a [`Sender`] into a data structure that may be passed via a [`c_void`] pointer
-/// as user data to an external library. Later, when this `extern` callback is run with that data,
-/// we may unwrap it and can thus send messages into the stream or close the stream.
+/// Use this to bridge callbacks of an external library into a [`Stream`]. The stream sender is
+/// wrapped into a data structure that may be passed via a [`c_void`] pointer as user data to the
+/// external library. Later, when the `extern` callback is run with that data, we may unwrap it and
+/// can thus send messages into the stream or close the stream.
 ///
 /// The implementation uses associated methods:
 ///
+/// - [`CallbackStream::channel()`] to create the stream with given capacity and overflow policy
 /// - [`CallbackStream::prepare()`] to wrap the stream sender and get the [`c_void`] pointer
 /// - [`CallbackStream::notify()`] to use the [`c_void`] pointer and send messages to the stream
+/// - [`CallbackStream::close()`] to use the [`c_void`] pointer and close the stream
 /// - [`CallbackStream::delete()`] to unwrap the [`c_void`] pointer and close the underlying stream
 ///
-/// [`Sender`]: mpsc::Sender
+/// Sending never blocks: when the buffer is full, messages are discarded according to the given
+/// [`StreamOverflow`] policy. Blocking would stall the event loop that runs the callbacks, possibly
+/// preventing the receiver from clearing the buffer.
+///
+/// Both [`CallbackStreamSender`] and [`CallbackStreamReceiver`] are `Send` and `Sync` when the
+/// message type is `Send`.
 ///
 /// # Examples
 ///
 /// ```
 /// use futures::executor::block_on;
-/// use open62541::CallbackStream;
-/// # use std::ffi::c_void;
-/// use tokio::sync::mpsc;
+/// use open62541::{CallbackStream, StreamOverflow};
+/// # use std::{ffi::c_void, num::NonZeroUsize};
 ///
-/// let (tx, mut rx) = mpsc::channel::<u32>(10);
+/// let capacity = NonZeroUsize::new(10).unwrap();
+/// let (tx, mut rx) = CallbackStream::<u32>::channel(capacity, StreamOverflow::DiscardOldest);
 ///
 /// // Turn `tx` into type-erased void pointer for FFI.
 /// let raw_data: *mut c_void = CallbackStream::<u32>::prepare(tx);
@@ -119,15 +136,41 @@ pub struct CallbackStream<T: 'static>(PhantomData<T>);
 // TODO: Use inherent associated type to define this directly on `CallbackOnce`. At the moment, this
 // is not possible yet.
 // https://github.com/rust-lang/rust/issues/8995
-type CallbackStreamUserdata<T> = Userdata<mpsc::Sender<T>>;
+type CallbackStreamUserdata<T> = Userdata<CallbackStreamSender<T>>;
 
 impl<T> CallbackStream<T> {
+    /// Creates stream with sender and receiver.
+    ///
+    /// The stream buffers up to `capacity` messages. When the buffer is full, messages are
+    /// discarded according to `overflow`.
+    #[must_use]
+    pub fn channel(
+        capacity: NonZeroUsize,
+        overflow: StreamOverflow,
+    ) -> (CallbackStreamSender<T>, CallbackStreamReceiver<T>) {
+        let shared = Arc::new(Mutex::new(StreamState {
+            buffer: VecDeque::new(),
+            capacity,
+            overflow,
+            discarded: 0,
+            closed: false,
+            waker: None,
+        }));
+
+        (
+            CallbackStreamSender {
+                shared: Arc::clone(&shared),
+            },
+            CallbackStreamReceiver { shared },
+        )
+    }
+
     /// Prepares sender for later use.
     ///
     /// This allocates memory. To prevent memory leaks, call [`delete()`](CallbackStream::delete) on
     /// the returned pointer exactly once.
     #[must_use]
-    pub fn prepare(tx: mpsc::Sender<T>) -> *mut c_void {
+    pub fn prepare(tx: CallbackStreamSender<T>) -> *mut c_void {
         CallbackStreamUserdata::<T>::prepare(tx)
     }
 
@@ -141,10 +184,23 @@ impl<T> CallbackStream<T> {
     /// The value type `T` must be the same as in [`prepare()`](CallbackStream::prepare).
     pub unsafe fn notify(data: *mut c_void, payload: T) {
         let tx = unsafe { CallbackStreamUserdata::<T>::peek_at(data) };
-        // Send message. Ignore disconnects and full buffers. (There is not much we can do here when
-        // the buffer is full. We could blockingly wait but that blocks `UA_Client_run_iterate()` in
-        // our event loop, potentially preventing the receiver from clearing the stream.)
-        let _unused = tx.try_send(payload);
+        tx.send(payload);
+    }
+
+    /// Uses [`c_void`] pointer and closes stream.
+    ///
+    /// The receiver still gets all messages that have been sent before. Messages sent afterwards
+    /// are discarded. Unlike [`delete()`](CallbackStream::delete), this does not free memory.
+    ///
+    /// # Safety
+    ///
+    /// The given pointer must have been returned from [`prepare()`](CallbackStream::prepare) and
+    /// must not have been passed into [`delete()`](CallbackStream::delete) yet.
+    ///
+    /// The value type `T` must be the same as in [`prepare()`](CallbackStream::prepare).
+    pub unsafe fn close(data: *mut c_void) {
+        let tx = unsafe { CallbackStreamUserdata::<T>::peek_at(data) };
+        tx.close();
     }
 
     /// Unwraps [`c_void`] pointer and closes channel.
@@ -157,3 +213,253 @@ impl<T> CallbackStream<T> {
         let _unused = unsafe { CallbackStreamUserdata::<T>::consume(data) };
     }
 }
+
+/// Policy for full buffer of [`CallbackStream`].
+///
+/// This matches the discard policy of monitored items, see [`MonitoredItemBuilder::discard_oldest()`].
+///
+/// [`MonitoredItemBuilder::discard_oldest()`]: crate::MonitoredItemBuilder::discard_oldest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamOverflow {
+    /// Discard oldest buffered message to make room for new message.
+    #[default]
+    DiscardOldest,
+    /// Discard new message.
+    DiscardNewest,
+}
+
+impl StreamOverflow {
+    /// Gets policy from discard flag of monitored items.
+    #[must_use]
+    pub const fn from_discard_oldest(discard_oldest: bool) -> Self {
+        if discard_oldest {
+            Self::DiscardOldest
+        } else {
+            Self::DiscardNewest
+        }
+    }
+}
+
+/// Sending side of [`CallbackStream`].
+///
+/// The stream is closed when the sender is dropped.
+#[derive(Debug)]
+pub struct CallbackStreamSender<T> {
+    shared: Arc<Mutex<StreamState<T>>>,
+}
+
+impl<T> CallbackStreamSender<T> {
+    /// Sends message.
+    ///
+    /// This never blocks. When the buffer is full, the overflow policy determines which message is
+    /// discarded. Messages are discarded as well when the stream has been closed.
+    pub fn send(&self, payload: T) {
+        // PANIC: We never panic while holding the lock.
+        let mut state = self.shared.lock().unwrap();
+
+        if state.closed {
+            return;
+        }
+
+        if state.buffer.len() >= state.capacity.get() {
+            state.discarded = state.discarded.saturating_add(1);
+            match state.overflow {
+                StreamOverflow::DiscardOldest => {
+                    log::debug!("Stream buffer is full, discarding oldest message");
+                    state.buffer.pop_front();
+                }
+                StreamOverflow::DiscardNewest => {
+                    log::debug!("Stream buffer is full, discarding new message");
+                    return;
+                }
+            }
+        }
+
+        state.buffer.push_back(payload);
+        state.wake();
+    }
+
+    /// Closes stream.
+    ///
+    /// The receiver still gets all messages that have been sent before.
+    pub fn close(&self) {
+        // PANIC: We never panic while holding the lock.
+        let mut state = self.shared.lock().unwrap();
+        state.closed = true;
+        state.wake();
+    }
+
+    /// Checks if stream has been closed.
+    ///
+    /// This is also the case when the receiver has been dropped.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        // PANIC: We never panic while holding the lock.
+        self.shared.lock().unwrap().closed
+    }
+}
+
+impl<T> Drop for CallbackStreamSender<T> {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Receiving side of [`CallbackStream`].
+///
+/// The stream ends when the sender has been closed or dropped and all buffered messages have been
+/// received.
+#[derive(Debug)]
+pub struct CallbackStreamReceiver<T> {
+    shared: Arc<Mutex<StreamState<T>>>,
+}
+
+impl<T> CallbackStreamReceiver<T> {
+    /// Waits for next message.
+    ///
+    /// Returns `None` when the stream has been closed.
+    pub async fn recv(&mut self) -> Option<T> {
+        future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Polls for next message.
+    ///
+    /// Returns `Poll::Ready(None)` when the stream has been closed.
+    pub fn poll_recv(&mut self, cx: &mut task::Context<'_>) -> Poll<Option<T>> {
+        // PANIC: We never panic while holding the lock.
+        let mut state = self.shared.lock().unwrap();
+
+        if let Some(payload) = state.buffer.pop_front() {
+            return Poll::Ready(Some(payload));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Takes next message without waiting.
+    ///
+    /// This is like [`poll_recv()`](Self::poll_recv) but does not register to be woken up. Returns
+    /// `Poll::Pending` when no message is buffered.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn try_recv(&mut self) -> Poll<Option<T>> {
+        // PANIC: We never panic while holding the lock.
+        let mut state = self.shared.lock().unwrap();
+
+        if let Some(payload) = state.buffer.pop_front() {
+            return Poll::Ready(Some(payload));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+
+    /// Gets number of discarded messages.
+    ///
+    /// This counts messages that have been discarded because the buffer was full, according to the
+    /// [`StreamOverflow`] policy.
+    #[must_use]
+    pub fn discarded(&self) -> u64 {
+        // PANIC: We never panic while holding the lock.
+        self.shared.lock().unwrap().discarded
+    }
+}
+
+impl<T> Drop for CallbackStreamReceiver<T> {
+    fn drop(&mut self) {
+        // PANIC: We never panic while holding the lock.
+        let mut state = self.shared.lock().unwrap();
+        // Discard messages that are still buffered or sent later.
+        state.closed = true;
+        state.buffer.clear();
+    }
+}
+
+impl<T> Stream for CallbackStreamReceiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_recv(cx)
+    }
+}
+
+/// State shared between [`CallbackStreamSender`] and [`CallbackStreamReceiver`].
+#[derive(Debug)]
+struct StreamState<T> {
+    buffer: VecDeque<T>,
+    capacity: NonZeroUsize,
+    overflow: StreamOverflow,
+    discarded: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> StreamState<T> {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use futures::executor::block_on;
+
+    use super::{CallbackStream, StreamOverflow};
+
+    fn receive_all(overflow: StreamOverflow) -> Vec<u32> {
+        let capacity = NonZeroUsize::new(2).unwrap();
+        let (tx, mut rx) = CallbackStream::<u32>::channel(capacity, overflow);
+        for value in 1..=4 {
+            tx.send(value);
+        }
+        drop(tx);
+        assert_eq!(rx.discarded(), 2);
+
+        let mut values = Vec::new();
+        while let Some(value) = block_on(rx.recv()) {
+            values.push(value);
+        }
+        values
+    }
+
+    #[test]
+    fn stream_overflow() {
+        assert_eq!(receive_all(StreamOverflow::DiscardOldest), [3, 4]);
+        assert_eq!(receive_all(StreamOverflow::DiscardNewest), [1, 2]);
+    }
+
+    #[test]
+    fn stream_close() {
+        let capacity = NonZeroUsize::new(10).unwrap();
+        let (tx, mut rx) = CallbackStream::<u32>::channel(capacity, StreamOverflow::default());
+        let raw_data = CallbackStream::<u32>::prepare(tx);
+
+        unsafe { CallbackStream::<u32>::notify(raw_data, 1) };
+        unsafe { CallbackStream::<u32>::close(raw_data) };
+        // Messages after closing are discarded.
+        unsafe { CallbackStream::<u32>::notify(raw_data, 2) };
+
+        assert_eq!(block_on(rx.recv()), Some(1));
+        assert_eq!(block_on(rx.recv()), None);
+
+        unsafe { CallbackStream::<u32>::delete(raw_data) };
+    }
+
+    #[test]
+    fn stream_receiver_dropped() {
+        let capacity = NonZeroUsize::new(10).unwrap();
+        let (tx, rx) = CallbackStream::<u32>::channel(capacity, StreamOverflow::default());
+        assert!(!tx.is_closed());
+        drop(rx);
+        assert!(tx.is_closed());
+    }
+}
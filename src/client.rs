@@ -1,4 +1,16 @@
-use std::{ffi::CString, ptr, time::Duration};
+#[cfg(feature = "tokio")]
+mod adopted_subscriptions;
+mod client_context;
+#[cfg(feature = "tokio")]
+mod publish_batches;
+#[cfg(feature = "tokio")]
+mod reconnect;
+#[cfg(feature = "tokio")]
+mod session_keep_alive;
+#[cfg(feature = "tokio")]
+mod subscription_watchdogs;
+
+use std::{ptr, time::Duration};
 
 use open62541_sys::{
     UA_CertificateVerification_AcceptAll, UA_ClientConfig, UA_Client_connect,
@@ -7,10 +19,38 @@ use open62541_sys::{
 
 use crate::{ua, DataType as _, Error, Result};
 
+#[cfg(feature = "tokio")]
+pub(crate) use self::adopted_subscriptions::{start_publishing, AdoptedSubscriptions};
+pub(crate) use self::client_context::ClientContext;
+#[cfg(feature = "tokio")]
+pub(crate) use self::client_context::{
+    subscription_delete_callback_c, subscription_status_change_callback_c,
+};
+#[cfg(feature = "tokio")]
+pub(crate) use self::publish_batches::PublishBatches;
+#[cfg(feature = "tokio")]
+pub(crate) use self::reconnect::Reconnect;
+#[cfg(feature = "tokio")]
+pub use self::reconnect::ReconnectPolicy;
+#[cfg(feature = "tokio")]
+pub(crate) use self::session_keep_alive::SessionKeepAlive;
+#[cfg(feature = "tokio")]
+pub(crate) use self::subscription_watchdogs::SubscriptionWatchdogs;
+
+/// Default fraction of session timeout after which idle sessions are kept alive.
+#[cfg(feature = "tokio")]
+const DEFAULT_SESSION_KEEP_ALIVE_FRACTION: f64 = 0.5;
+
+/// Minimum size of message chunks that OPC UA requires to be permissible (in bytes).
+const MIN_BUFFER_SIZE: u32 = 8192;
+
 /// Builder for [`Client`].
 ///
 /// Use this to specify additional options when connecting to an OPC UA endpoint.
 ///
+/// Methods that take URLs do not panic when these contain NUL bytes. They return [`Error::NulByte`]
+/// instead, so that they may be used with user-supplied input.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -28,14 +68,39 @@ use crate::{ua, DataType as _, Error, Result};
 /// # }
 /// ```
 #[derive(Debug)]
-pub struct ClientBuilder(ua::ClientConfig);
+pub struct ClientBuilder {
+    config: ua::ClientConfig,
+    #[cfg(feature = "tokio")]
+    session_keep_alive: bool,
+    #[cfg(feature = "tokio")]
+    session_keep_alive_fraction: f64,
+    /// Application URI from local certificate, if encryption is used.
+    certificate_application_uri: Option<String>,
+    application_uri_from_certificate: bool,
+    client_description_set: bool,
+    context: ClientContext,
+}
 
 impl ClientBuilder {
+    const fn new(config: ua::ClientConfig) -> Self {
+        Self {
+            config,
+            #[cfg(feature = "tokio")]
+            session_keep_alive: true,
+            #[cfg(feature = "tokio")]
+            session_keep_alive_fraction: DEFAULT_SESSION_KEEP_ALIVE_FRACTION,
+            certificate_application_uri: None,
+            application_uri_from_certificate: true,
+            client_description_set: false,
+            context: ClientContext::new(),
+        }
+    }
+
     /// Creates builder from default client config.
     // Method name refers to call of `UA_ClientConfig_setDefault()`.
     #[must_use]
     fn default() -> Self {
-        Self(ua::ClientConfig::default())
+        Self::new(ua::ClientConfig::default())
     }
 
     /// Creates builder from default client config with encryption.
@@ -43,6 +108,10 @@ impl ClientBuilder {
     /// This requires certificate and associated private key data in [DER] or [PEM] format. Data may
     /// be read from local files or created with [`crate::create_certificate()`].
     ///
+    /// The application URI of the client description is taken from the URI in the subject
+    /// alternative names of the certificate, as required by servers. See
+    /// [`application_uri_from_certificate()`](Self::application_uri_from_certificate).
+    ///
     /// ```
     /// use open62541::{Certificate, ClientBuilder, PrivateKey};
     ///
@@ -73,10 +142,81 @@ impl ClientBuilder {
         local_certificate: &crate::Certificate,
         private_key: &crate::PrivateKey,
     ) -> Result<Self> {
-        Ok(Self(ua::ClientConfig::default_encryption(
-            local_certificate,
-            private_key,
-        )?))
+        Ok(Self::new_with_certificate(
+            ua::ClientConfig::default_encryption(local_certificate, private_key)?,
+        ))
+    }
+
+    /// Creates builder from default client config with encryption and certificate chain.
+    ///
+    /// This is similar to [`default_encryption()`](Self::default_encryption) but also sends the
+    /// given intermediate certificates along with the local certificate. Use this when the local
+    /// certificate has been issued by an intermediate CA that the server may not know about.
+    ///
+    /// The intermediate certificates must be in [DER] format, ordered from the issuer of the local
+    /// certificate towards the root CA.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the certificate is invalid, the private key cannot be decrypted (e.g. when it
+    /// has been protected by a password), or the intermediate certificates are not in DER format.
+    ///
+    /// [DER]: https://en.wikipedia.org/wiki/X.690#DER_encoding
+    #[cfg(feature = "mbedtls")]
+    pub fn default_encryption_with_chain(
+        local_certificate: &crate::Certificate,
+        intermediate_certificates: &[crate::Certificate],
+        private_key: &crate::PrivateKey,
+    ) -> Result<Self> {
+        Ok(Self::new_with_certificate(
+            ua::ClientConfig::default_encryption_with_chain(
+                local_certificate,
+                intermediate_certificates,
+                private_key,
+            )?,
+        ))
+    }
+
+    /// Creates builder from client config with local certificate.
+    #[cfg(feature = "mbedtls")]
+    fn new_with_certificate(mut config: ua::ClientConfig) -> Self {
+        // SAFETY: Ownership is not given away.
+        let config_ref = unsafe { config.as_mut() };
+        // SAFETY: The security policies have been set up by the client config with encryption.
+        let certificate_application_uri = unsafe {
+            crate::ssl::local_application_uri(
+                config_ref.securityPolicies,
+                config_ref.securityPoliciesSize,
+            )
+        };
+        if certificate_application_uri.is_none() {
+            log::warn!("Local certificate has no application URI in subject alternative names");
+        }
+
+        Self {
+            certificate_application_uri,
+            ..Self::new(config)
+        }
+    }
+
+    /// Sets whether to take application URI from local certificate.
+    ///
+    /// When encryption is used (see [`default_encryption()`](Self::default_encryption)), servers
+    /// reject connections unless the application URI in the client description matches the URI in
+    /// the subject alternative names of the local certificate. By default, the application URI is
+    /// taken from the certificate unless [`client_description()`](Self::client_description) is
+    /// set. Pass `false` to keep the application URI of the default client config.
+    ///
+    /// When the client description is set explicitly and its application URI does not match the
+    /// certificate, a warning is logged.
+    #[cfg(feature = "mbedtls")]
+    #[must_use]
+    pub const fn application_uri_from_certificate(
+        mut self,
+        application_uri_from_certificate: bool,
+    ) -> Self {
+        self.application_uri_from_certificate = application_uri_from_certificate;
+        self
     }
 
     /// Sets (response) timeout.
@@ -96,13 +236,73 @@ impl ClientBuilder {
     ///
     /// The description must be internally consistent. The application URI set in the application
     /// description must match the URI set in the certificate.
+    ///
+    /// When encryption is used, this takes precedence over the application URI that is otherwise
+    /// taken from the local certificate.
     #[must_use]
     pub fn client_description(mut self, client_description: ua::ApplicationDescription) -> Self {
         client_description.move_into_raw(&mut self.config_mut().clientDescription);
+        self.client_description_set = true;
+        self
+    }
+
+    /// Sets endpoint to connect to.
+    ///
+    /// Use this with one of the endpoints returned by [`get_endpoints()`](Self::get_endpoints).
+    /// When connecting, the client then uses exactly this endpoint (its security mode and security
+    /// policy) instead of requesting endpoints from the server and selecting one of them by itself.
+    ///
+    /// When the endpoint URL of the endpoint cannot be reached (e.g. when the server is behind NAT
+    /// and advertises its internal address), the client falls back to the URL that is passed to
+    /// [`connect()`](Self::connect). Use [`ua::EndpointDescription::with_endpoint_url()`] to set the
+    /// endpoint URL explicitly.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: ua::EndpointDescription) -> Self {
+        endpoint.move_into_raw(&mut self.config_mut().endpoint);
+        self
+    }
+
+    /// Sets session name.
+    ///
+    /// The name is sent to the server when creating the session. Servers may show it in diagnostics
+    /// to identify the client's session.
+    ///
+    /// # Panics
+    ///
+    /// The session name must not contain any NUL bytes.
+    #[must_use]
+    pub fn session_name(mut self, session_name: &str) -> Self {
+        let session_name = ua::String::new(session_name).expect("session name should be valid");
+        session_name.move_into_raw(&mut self.config_mut().sessionName);
+        self
+    }
+
+    /// Sets preferred locale IDs of session.
+    ///
+    /// The locale IDs are sent to the server when activating the session, in order of preference
+    /// (e.g. `["de-DE", "en-US"]`). Servers use them when returning localized texts.
+    ///
+    /// # Panics
+    ///
+    /// The locale IDs must not contain any NUL bytes.
+    #[must_use]
+    pub fn session_locale_ids(mut self, locale_ids: &[&str]) -> Self {
+        let locale_ids = locale_ids
+            .iter()
+            .map(|locale_id| ua::String::new(locale_id).expect("locale ID should be valid"))
+            .collect::<Vec<_>>();
+        let config = self.config_mut();
+        ua::Array::from_slice(&locale_ids).move_into_raw(
+            &mut config.sessionLocaleIdsSize,
+            &mut config.sessionLocaleIds,
+        );
         self
     }
 
     /// Sets user identity token.
+    ///
+    /// The policy ID of the token is filled in when connecting, from the endpoint's user token policy
+    /// that matches the token type (see [`ua::EndpointDescription::select_user_token_policy()`]).
     #[must_use]
     pub fn user_identity_token(mut self, user_identity_token: &ua::UserIdentityToken) -> Self {
         user_identity_token
@@ -111,6 +311,51 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets user name and password for authentication.
+    ///
+    /// This is a shorthand for [`user_identity_token()`](Self::user_identity_token) with
+    /// [`ua::UserNameIdentityToken`]. When connecting, the password is encrypted with the security
+    /// policy of the endpoint's user token policy (or of the secure channel when the user token
+    /// policy does not specify one). Use [`security_policy_uri()`](Self::security_policy_uri) to
+    /// select a secure channel with encryption.
+    ///
+    /// The password is kept in plaintext in the client config for as long as the client exists: it
+    /// is needed again whenever a session is (re-)activated, e.g. after reconnecting. It is
+    /// zeroized when the client (or this builder) is dropped. Temporary copies are zeroized right
+    /// away.
+    ///
+    /// # Panics
+    ///
+    /// The user name must not contain any NUL bytes.
+    #[must_use]
+    pub fn username_password(mut self, user_name: &str, password: &str) -> Self {
+        let mut user_name_identity_token = ua::UserNameIdentityToken::init()
+            .with_user_name(user_name)
+            .with_password_bytes(password.as_bytes());
+        ua::ExtensionObject::new(&user_name_identity_token)
+            .move_into_raw(&mut self.config_mut().userIdentityToken);
+        // The extension object holds its own copy of the token.
+        user_name_identity_token.zeroize_password();
+        self
+    }
+
+    /// Sets security policy URI of secure channel.
+    ///
+    /// When connecting, the client selects an endpoint with this security policy, e.g.
+    /// `http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256`. By default, an endpoint with
+    /// any security policy that is supported by the client may be selected.
+    ///
+    /// # Panics
+    ///
+    /// The security policy URI must not contain any NUL bytes.
+    #[must_use]
+    pub fn security_policy_uri(mut self, security_policy_uri: &str) -> Self {
+        ua::String::new(security_policy_uri)
+            .expect("security policy URI should be valid")
+            .move_into_raw(&mut self.config_mut().securityPolicyUri);
+        self
+    }
+
     /// Sets secure channel life time.
     ///
     /// After this life time, the channel needs to be renewed.
@@ -141,6 +386,102 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets maximum number of outstanding publish requests.
+    ///
+    /// This is the number of publish requests that the client keeps queued up in the server to
+    /// receive notifications of subscriptions. When the server responds with
+    /// `BadTooManyPublishRequests`, the client reduces this number by one (and logs a warning)
+    /// instead of sending more requests. Set this to the limit of the server to avoid these
+    /// responses in the first place.
+    ///
+    /// # Panics
+    ///
+    /// The given number must not be zero.
+    #[must_use]
+    pub fn max_outstanding_publish_requests(
+        mut self,
+        max_outstanding_publish_requests: u16,
+    ) -> Self {
+        assert!(
+            max_outstanding_publish_requests > 0,
+            "maximum number of outstanding publish requests should be non-zero"
+        );
+        self.config_mut().outStandingPublishRequests = max_outstanding_publish_requests;
+        self
+    }
+
+    /// Sets maximum size of messages (in bytes) that the client accepts.
+    ///
+    /// Larger responses are rejected with `BadResponseTooLarge`. Use `0` for no limit.
+    ///
+    /// # Panics
+    ///
+    /// The given size must be zero or at least 8,192 bytes, and less than 4,294,967,295 bytes.
+    #[must_use]
+    pub fn max_message_size(mut self, max_message_size: usize) -> Self {
+        let max_message_size = u32::try_from(max_message_size)
+            .expect("maximum message size should be in range of u32");
+        assert!(
+            max_message_size == 0 || max_message_size >= MIN_BUFFER_SIZE,
+            "maximum message size should be zero or at least {MIN_BUFFER_SIZE} bytes"
+        );
+        self.config_mut().localConnectionConfig.localMaxMessageSize = max_message_size;
+        self
+    }
+
+    /// Sets maximum number of chunks per message that the client accepts.
+    ///
+    /// Messages are split into chunks of at most the [receive buffer size](Self::recv_buffer_size).
+    /// Use `0` for no limit.
+    ///
+    /// # Panics
+    ///
+    /// The given number must be less than 4,294,967,295.
+    #[must_use]
+    pub fn max_chunk_count(mut self, max_chunk_count: usize) -> Self {
+        self.config_mut().localConnectionConfig.localMaxChunkCount =
+            u32::try_from(max_chunk_count).expect("maximum chunk count should be in range of u32");
+        self
+    }
+
+    /// Sets size of receive buffer (in bytes).
+    ///
+    /// This is the maximum size of message chunks that the client receives.
+    ///
+    /// # Panics
+    ///
+    /// The given size must be at least 8,192 bytes and less than 4,294,967,295 bytes.
+    #[must_use]
+    pub fn recv_buffer_size(mut self, recv_buffer_size: usize) -> Self {
+        let recv_buffer_size =
+            u32::try_from(recv_buffer_size).expect("receive buffer size should be in range of u32");
+        assert!(
+            recv_buffer_size >= MIN_BUFFER_SIZE,
+            "receive buffer size should be at least {MIN_BUFFER_SIZE} bytes"
+        );
+        self.config_mut().localConnectionConfig.recvBufferSize = recv_buffer_size;
+        self
+    }
+
+    /// Sets size of send buffer (in bytes).
+    ///
+    /// This is the maximum size of message chunks that the client sends.
+    ///
+    /// # Panics
+    ///
+    /// The given size must be at least 8,192 bytes and less than 4,294,967,295 bytes.
+    #[must_use]
+    pub fn send_buffer_size(mut self, send_buffer_size: usize) -> Self {
+        let send_buffer_size =
+            u32::try_from(send_buffer_size).expect("send buffer size should be in range of u32");
+        assert!(
+            send_buffer_size >= MIN_BUFFER_SIZE,
+            "send buffer size should be at least {MIN_BUFFER_SIZE} bytes"
+        );
+        self.config_mut().localConnectionConfig.sendBufferSize = send_buffer_size;
+        self
+    }
+
     /// Sets connectivity check interval.
     ///
     /// Use `None` to disable background task.
@@ -160,6 +501,136 @@ impl ClientBuilder {
         self
     }
 
+    /// Enables or disables session keep-alive of [`AsyncClient`].
+    ///
+    /// Sessions without any traffic expire after the session timeout. With keep-alive, the client
+    /// reads the server state (`Server_ServerStatus_State`) in the background when no service
+    /// request has been answered for a fraction of the
+    /// [requested session timeout](Self::requested_session_timeout), see
+    /// [`session_keep_alive_fraction()`](Self::session_keep_alive_fraction). This happens only
+    /// while the session is activated and resumes after reconnecting.
+    ///
+    /// Default value is `true`.
+    ///
+    /// [`AsyncClient`]: crate::AsyncClient
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub const fn session_keep_alive(mut self, session_keep_alive: bool) -> Self {
+        self.session_keep_alive = session_keep_alive;
+        self
+    }
+
+    /// Sets fraction of session timeout after which idle sessions are kept alive.
+    ///
+    /// See [`session_keep_alive()`](Self::session_keep_alive).
+    ///
+    /// Default value is `0.5`, i.e. half of the requested session timeout.
+    ///
+    /// # Panics
+    ///
+    /// The fraction must be greater than 0 and at most 1.
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn session_keep_alive_fraction(mut self, session_keep_alive_fraction: f64) -> Self {
+        assert!(
+            session_keep_alive_fraction > 0.0 && session_keep_alive_fraction <= 1.0,
+            "session keep-alive fraction should be greater than 0 and at most 1"
+        );
+        self.session_keep_alive_fraction = session_keep_alive_fraction;
+        self
+    }
+
+    /// Sets whether to forward log messages of `open62541` to the `log` crate.
+    ///
+    /// When enabled, log messages are emitted with target `open62541::client`, extended by their
+    /// log category (e.g. `open62541::client::network`). Otherwise, they are written to stdout by
+    /// the default logger of `open62541`.
+    ///
+    /// Default value is `true`.
+    #[must_use]
+    pub fn log_to_log_crate(mut self, log_to_log_crate: bool) -> Self {
+        let config = self.config_mut();
+        // SAFETY: The logger has been created by `ua::ClientConfig` and is only cleared along with
+        // the config.
+        unsafe { ua::Logger::set_log_to_log_crate(config.logging, log_to_log_crate) };
+        self
+    }
+
+    /// Sets callback for session inactivity.
+    ///
+    /// The callback is called when the connectivity check does not get a response from the server
+    /// in time, see [`connectivity_check_interval()`](Self::connectivity_check_interval). The client
+    /// then tries to recover the connection by itself.
+    ///
+    /// The callback is run on the client's event loop and should return quickly.
+    #[must_use]
+    pub fn on_session_inactivity(mut self, callback: impl Fn() + Send + 'static) -> Self {
+        self.context.set_session_inactivity(callback);
+        self
+    }
+
+    /// Sets callback for subscription inactivity.
+    ///
+    /// The callback is called with the subscription ID when no publish response (not even a
+    /// keep-alive message) has been received for the subscription in time. This happens after the
+    /// publishing interval times the maximum keep-alive count, plus the [timeout](Self::timeout).
+    ///
+    /// The callback is run on the client's event loop and should return quickly.
+    #[must_use]
+    pub fn on_subscription_inactivity(
+        mut self,
+        callback: impl Fn(ua::SubscriptionId) + Send + 'static,
+    ) -> Self {
+        self.context.set_subscription_inactivity(callback);
+        self
+    }
+
+    /// Enables or disables cancellation of dropped requests.
+    ///
+    /// When the future of an [`AsyncClient`] method is dropped before the response has been
+    /// received, e.g. because of a timeout, the server keeps processing the request. With this
+    /// enabled, the client then sends a `Cancel` request to the server. Servers may abort the
+    /// operation in turn, but they are not required to do so.
+    ///
+    /// Default value is `false`.
+    ///
+    /// [`AsyncClient`]: crate::AsyncClient
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn cancel_dropped_requests(mut self, cancel_dropped_requests: bool) -> Self {
+        self.context
+            .set_cancel_dropped_requests(cancel_dropped_requests);
+        self
+    }
+
+    /// Enables automatic reconnecting of [`AsyncClient`].
+    ///
+    /// When the connection has been lost and cannot be restored right away, the client keeps
+    /// trying to reconnect, with delays between attempts according to `policy`. When the session
+    /// has been lost too, subscriptions and monitored items that have been created through
+    /// [`AsyncSubscription`] and [`MonitoredItemBuilder`] are recreated in the new session.
+    ///
+    /// Streams of [`AsyncMonitoredItem`] stay open while reconnecting. When the session has been
+    /// lost, each stream receives a value with status [`ua::StatusCode::BADNOCOMMUNICATION`] to
+    /// mark the gap, followed by the values of the recreated monitored item. Streams of monitored
+    /// items that cannot be recreated are closed. Recreated subscriptions and monitored items get
+    /// new server-assigned IDs. Subscriptions adopted with [`AsyncClient::adopt_subscription()`]
+    /// are not recreated.
+    ///
+    /// By default, the client stops when the connection cannot be restored right away.
+    ///
+    /// [`AsyncClient`]: crate::AsyncClient
+    /// [`AsyncClient::adopt_subscription()`]: crate::AsyncClient::adopt_subscription
+    /// [`AsyncMonitoredItem`]: crate::AsyncMonitoredItem
+    /// [`AsyncSubscription`]: crate::AsyncSubscription
+    /// [`MonitoredItemBuilder`]: crate::MonitoredItemBuilder
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn auto_reconnect(mut self, policy: ReconnectPolicy) -> Self {
+        self.context.set_reconnect(policy);
+        self
+    }
+
     /// Disables server certificate checks.
     ///
     /// Note that this disables all certificate verification of server communications. Use only when
@@ -191,30 +662,44 @@ impl ClientBuilder {
     ///
     /// # Errors
     ///
-    /// This fails when the target server is not reachable.
-    ///
-    /// # Panics
-    ///
-    /// The endpoint URL must not contain any NUL bytes.
+    /// This fails when the target server is not reachable, or when the endpoint URL contains NUL
+    /// bytes.
     pub fn connect(self, endpoint_url: &str) -> Result<Client> {
         let mut client = self.build();
         client.connect(endpoint_url)?;
         Ok(client)
     }
 
-    /// Connects to OPC UA server and returns endpoints.
+    /// Connects to OPC UA endpoint without blocking and returns [`AsyncClient`].
+    ///
+    /// Unlike [`connect()`](Self::connect), this does not block the current thread while the
+    /// connection is established. The returned future fails when the connection cannot be
+    /// established within the [timeout](Self::timeout). When the future is dropped before it has
+    /// completed, the connection attempt is aborted.
     ///
     /// # Errors
     ///
-    /// This fails when the target server is not reachable.
+    /// This fails when the target server is not reachable, or when the endpoint URL contains NUL
+    /// bytes.
     ///
-    /// # Panics
+    /// [`AsyncClient`]: crate::AsyncClient
+    #[cfg(feature = "tokio")]
+    pub async fn connect_async(mut self, endpoint_url: &str) -> Result<crate::AsyncClient> {
+        let timeout = Duration::from_millis(u64::from(self.config_mut().timeout));
+        let client = self.build();
+        crate::AsyncClient::connect_with(client.0, endpoint_url, timeout).await
+    }
+
+    /// Connects to OPC UA server and returns endpoints.
     ///
-    /// The server URL must not contain any NUL bytes.
+    /// # Errors
+    ///
+    /// This fails when the target server is not reachable, or when the server URL contains NUL
+    /// bytes.
     pub fn get_endpoints(self, server_url: &str) -> Result<ua::Array<ua::EndpointDescription>> {
         log::info!("Getting endpoints of server {server_url}");
 
-        let server_url = CString::new(server_url).expect("server URL does not contain NUL bytes");
+        let server_url = Error::c_string(server_url, "server URL")?;
 
         let mut client = self.build();
         let endpoint_descriptions: Option<ua::Array<ua::EndpointDescription>>;
@@ -249,14 +734,64 @@ impl ClientBuilder {
 
     /// Builds OPC UA client.
     #[must_use]
-    fn build(self) -> Client {
-        Client(ua::Client::new_with_config(self.0))
+    fn build(mut self) -> Client {
+        #[cfg(feature = "tokio")]
+        if self.session_keep_alive {
+            let session_timeout =
+                Duration::from_millis(self.config_mut().requestedSessionTimeout.into());
+            let interval = session_timeout.mul_f64(self.session_keep_alive_fraction);
+            self.context.set_session_keep_alive(interval);
+        }
+
+        if let Some(certificate_application_uri) = self.certificate_application_uri.take() {
+            self.apply_certificate_application_uri(&certificate_application_uri);
+        }
+
+        let Self {
+            mut config,
+            #[cfg(feature = "tokio")]
+                session_keep_alive: _,
+            #[cfg(feature = "tokio")]
+                session_keep_alive_fraction: _,
+            certificate_application_uri: _,
+            application_uri_from_certificate: _,
+            client_description_set: _,
+            context,
+        } = self;
+
+        // SAFETY: Ownership is not given away.
+        context.install(unsafe { config.as_mut() });
+
+        Client(ua::Client::new_with_config(config))
+    }
+
+    /// Applies application URI from local certificate to client description.
+    fn apply_certificate_application_uri(&mut self, certificate_application_uri: &str) {
+        let client_description_set = self.client_description_set;
+        let application_uri_from_certificate = self.application_uri_from_certificate;
+        let application_uri = &mut self.config_mut().clientDescription.applicationUri;
+
+        if client_description_set {
+            let application_uri = ua::String::raw_ref(application_uri);
+            if application_uri.as_str() != Some(certificate_application_uri) {
+                log::warn!(
+                    "Application URI {application_uri} does not match URI \
+                     {certificate_application_uri} of local certificate"
+                );
+            }
+        } else if application_uri_from_certificate {
+            log::debug!("Using application URI {certificate_application_uri} of local certificate");
+            // PANIC: URIs with NUL bytes are rejected when reading the certificate.
+            ua::String::new(certificate_application_uri)
+                .expect("application URI should be valid")
+                .move_into_raw(application_uri);
+        }
     }
 
     /// Access client configuration.
     fn config_mut(&mut self) -> &mut UA_ClientConfig {
         // SAFETY: Ownership is not given away.
-        unsafe { self.0.as_mut() }
+        unsafe { self.config.as_mut() }
     }
 }
 
@@ -294,10 +829,6 @@ impl Client {
     /// # Errors
     ///
     /// See [`ClientBuilder::connect()`].
-    ///
-    /// # Panics
-    ///
-    /// See [`ClientBuilder::connect()`].
     pub fn new(endpoint_url: &str) -> Result<Self> {
         ClientBuilder::default().connect(endpoint_url)
     }
@@ -326,8 +857,7 @@ impl Client {
     fn connect(&mut self, endpoint_url: &str) -> Result<()> {
         log::info!("Connecting to endpoint {endpoint_url}");
 
-        let endpoint_url =
-            CString::new(endpoint_url).expect("endpoint URL does not contain NUL bytes");
+        let endpoint_url = Error::c_string(endpoint_url, "endpoint URL")?;
 
         let status_code = ua::StatusCode::new(unsafe {
             // SAFETY: The method does not take ownership of `client`.
@@ -348,3 +878,89 @@ impl Client {
         self.0.disconnect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, ClientBuilder, DataType as _, Error};
+
+    #[test]
+    fn connect_with_nul_byte() {
+        let result = ClientBuilder::default().connect("opc.tcp://localhost\0:4840");
+        assert!(matches!(result, Err(Error::NulByte("endpoint URL"))));
+    }
+
+    #[test]
+    fn get_endpoints_with_nul_byte() {
+        let result = ClientBuilder::default().get_endpoints("opc.tcp://localhost\0:4840");
+        assert!(matches!(result, Err(Error::NulByte("server URL"))));
+    }
+
+    #[test]
+    fn session_name_and_locale_ids() {
+        // Setting values twice releases the previous values instead of leaking them.
+        let mut builder = ClientBuilder::default()
+            .session_name("first")
+            .session_name("second")
+            .session_locale_ids(&["de-DE"])
+            .session_locale_ids(&["en-US", "de-DE"]);
+        let config = builder.config_mut();
+
+        let session_name = ua::String::raw_ref(&config.sessionName);
+        assert_eq!(session_name.as_str(), Some("second"));
+
+        // SAFETY: The array is owned by the config and not modified while the slice is in use.
+        let locale_ids = unsafe {
+            ua::Array::<ua::String>::slice_from_raw_parts(
+                config.sessionLocaleIdsSize,
+                config.sessionLocaleIds,
+            )
+        }
+        .expect("locale IDs should be set");
+        let locale_ids = locale_ids
+            .iter()
+            .map(ua::String::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(locale_ids, [Some("en-US"), Some("de-DE")]);
+    }
+
+    #[test]
+    fn message_size_limits() {
+        let mut builder = ClientBuilder::default()
+            .max_message_size(0)
+            .max_message_size(64 * 1024 * 1024)
+            .max_chunk_count(0)
+            .recv_buffer_size(1024 * 1024)
+            .send_buffer_size(8192);
+        let config = &builder.config_mut().localConnectionConfig;
+        assert_eq!(config.localMaxMessageSize, 64 * 1024 * 1024);
+        assert_eq!(config.localMaxChunkCount, 0);
+        assert_eq!(config.recvBufferSize, 1024 * 1024);
+        assert_eq!(config.sendBufferSize, 8192);
+    }
+
+    #[test]
+    #[should_panic(expected = "receive buffer size should be at least 8192 bytes")]
+    fn recv_buffer_size_too_small() {
+        drop(ClientBuilder::default().recv_buffer_size(4096));
+    }
+
+    #[test]
+    fn username_password() {
+        let mut builder = ClientBuilder::default()
+            .username_password("user", "secret")
+            .security_policy_uri("http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256");
+        let config = builder.config_mut();
+
+        let user_identity_token = ua::ExtensionObject::raw_ref(&config.userIdentityToken);
+        let user_name_identity_token = user_identity_token
+            .decoded_content::<ua::UserNameIdentityToken>()
+            .expect("should have user name identity token");
+        assert_eq!(user_name_identity_token.user_name().as_str(), Some("user"));
+
+        let security_policy_uri = ua::String::raw_ref(&config.securityPolicyUri);
+        assert_eq!(
+            security_policy_uri.as_str(),
+            Some("http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256")
+        );
+    }
+}
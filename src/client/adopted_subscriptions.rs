@@ -0,0 +1,266 @@
+use std::{
+    ffi::c_void,
+    mem, ptr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use open62541_sys::{UA_Client, UA_PublishResponse, UA_UInt32, __UA_Client_AsyncService};
+
+use crate::{
+    ua, CallbackStreamSender, DataType as _, Error, ServiceRequest as _, ServiceResponse as _,
+    SubscriptionEvent,
+};
+
+use super::ClientContext;
+
+/// Time that publish requests are valid for.
+///
+/// The server holds publish requests until a notification message or keep-alive message is due,
+/// which may take much longer than regular requests.
+const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Publishing for adopted subscriptions.
+///
+/// `open62541` only processes publish responses of subscriptions that it has created itself. It
+/// drops notifications of subscriptions that have been transferred from another session. We send
+/// our own publish requests for adopted subscriptions instead, see
+/// [`AsyncSubscription::notifications()`].
+///
+/// The server does not answer publish requests for particular subscriptions: each response holds
+/// the next notification message of any subscription in the session. So this may only be used as
+/// long as `open62541` does not publish for subscriptions of its own in the same session.
+///
+/// [`AsyncSubscription::notifications()`]: crate::AsyncSubscription::notifications
+pub(crate) struct AdoptedSubscriptions {
+    /// Number of subscriptions that `open62541` publishes for itself.
+    managed_count: usize,
+    /// Senders of notification streams, with the shared ID of their subscription.
+    senders: Vec<(
+        Arc<AtomicU32>,
+        CallbackStreamSender<ua::NotificationMessage>,
+    )>,
+    /// Acknowledgements to send with the next publish request.
+    acknowledgements: Vec<ua::SubscriptionAcknowledgement>,
+    /// Whether a publish request is outstanding.
+    publishing: bool,
+}
+
+impl AdoptedSubscriptions {
+    pub(crate) const fn new() -> Self {
+        Self {
+            managed_count: 0,
+            senders: Vec::new(),
+            acknowledgements: Vec::new(),
+            publishing: false,
+        }
+    }
+
+    /// Counts subscription that has been created by `open62541`.
+    pub(crate) fn managed_subscription_created(&mut self) {
+        self.managed_count += 1;
+    }
+
+    /// Counts subscription that has been removed by `open62541`.
+    pub(crate) fn managed_subscription_deleted(&mut self) {
+        self.managed_count = self.managed_count.saturating_sub(1);
+    }
+
+    /// Checks if a publish request for adopted subscriptions is outstanding.
+    ///
+    /// `open62541` must not create subscriptions of its own then, see above. This also holds for a
+    /// while after the last stream has been dropped, until the outstanding request is answered.
+    pub(crate) const fn is_publishing(&self) -> bool {
+        self.publishing
+    }
+
+    /// Adds sender to notify of notification messages of adopted subscription.
+    ///
+    /// This returns `true` when publishing must be started with [`start_publishing()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADINVALIDSTATE`] while `open62541` publishes for
+    /// subscriptions of its own, see above.
+    pub(crate) fn add_sender(
+        &mut self,
+        subscription_id: &Arc<AtomicU32>,
+        sender: CallbackStreamSender<ua::NotificationMessage>,
+    ) -> Result<bool, Error> {
+        if self.managed_count > 0 {
+            return Err(Error::new(ua::StatusCode::BADINVALIDSTATE));
+        }
+        self.senders.push((Arc::clone(subscription_id), sender));
+        Ok(!mem::replace(&mut self.publishing, true))
+    }
+
+    /// Removes senders of adopted subscription, closing their streams.
+    pub(crate) fn remove_senders(&mut self, subscription_id: &Arc<AtomicU32>) {
+        self.senders
+            .retain(|(id, _)| !Arc::ptr_eq(id, subscription_id));
+    }
+
+    /// Processes publish response.
+    ///
+    /// This returns the next publish request to send, or `None` when publishing stops.
+    fn process_publish_response(
+        &mut self,
+        response: &ua::PublishResponse,
+    ) -> Option<ua::PublishRequest> {
+        let status_code = response.service_result();
+        if status_code.is_good() {
+            let subscription_id = response.subscription_id();
+            let message = response.notification_message();
+            if !message.is_keep_alive() {
+                // We are the only ones to publish in this session. Messages that nobody listens to
+                // would otherwise be retransmitted.
+                self.acknowledgements
+                    .push(ua::SubscriptionAcknowledgement::new(
+                        subscription_id,
+                        message.sequence_number(),
+                    ));
+            }
+            for (id, sender) in &self.senders {
+                if id.load(Ordering::Relaxed) == subscription_id.as_u32() {
+                    sender.send(message.clone());
+                }
+            }
+        } else if status_code != ua::StatusCode::BADTIMEOUT
+            && status_code != ua::StatusCode::BADTOOMANYPUBLISHREQUESTS
+        {
+            // The session has no subscriptions anymore or has been lost, so there is nothing left
+            // to receive. End the streams to let consumers know.
+            log::warn!("Publishing for adopted subscriptions stopped: {status_code}");
+            self.senders.clear();
+        }
+
+        self.retain_open_senders();
+        if self.senders.is_empty() {
+            self.publishing = false;
+            return None;
+        }
+        Some(self.next_publish_request())
+    }
+
+    /// Prepares next publish request, with pending acknowledgements.
+    fn next_publish_request(&mut self) -> ua::PublishRequest {
+        let acknowledgements = mem::take(&mut self.acknowledgements);
+        let mut request =
+            ua::PublishRequest::init().with_subscription_acknowledgements(&acknowledgements);
+        request
+            .request_header_mut()
+            .set_timeout_hint(PUBLISH_TIMEOUT);
+        request
+    }
+
+    /// Stops publishing, ending all streams.
+    fn stop_publishing(&mut self) {
+        self.senders.clear();
+        self.publishing = false;
+    }
+
+    fn retain_open_senders(&mut self) {
+        // Forget senders whose receivers have been dropped.
+        self.senders.retain(|(_, sender)| !sender.is_closed());
+    }
+}
+
+/// Starts publishing for adopted subscriptions.
+///
+/// This must be called when [`AdoptedSubscriptions::add_sender()`] asks for it. Publishing then
+/// continues from the callback of each publish response, as long as there are streams.
+pub(crate) fn start_publishing(client: &ua::Client) {
+    let context = ClientContext::get(client);
+    let request = context.adopted_subscriptions().next_publish_request();
+    // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+    unsafe { send_publish_request(context, client.as_ptr().cast_mut(), &request) };
+}
+
+/// Sends publish request.
+///
+/// # Safety
+///
+/// The client must be valid.
+unsafe fn send_publish_request(
+    context: &ClientContext,
+    client: *mut UA_Client,
+    request: &ua::PublishRequest,
+) {
+    log::trace!("Sending publish request for adopted subscriptions");
+
+    let status_code = ua::StatusCode::new(unsafe {
+        __UA_Client_AsyncService(
+            client,
+            request.as_ptr().cast::<c_void>(),
+            ua::PublishRequest::data_type(),
+            Some(publish_callback_c),
+            ua::PublishResponse::data_type(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
+    });
+    if let Err(error) = Error::verify_good(&status_code) {
+        log::warn!("Publishing for adopted subscriptions failed: {error}");
+        context.adopted_subscriptions().stop_publishing();
+    }
+}
+
+/// Processes publish response for adopted subscriptions.
+///
+/// This is called by `open62541` when the response has been received or when the request has
+/// failed, e.g. when the connection has been lost.
+unsafe extern "C" fn publish_callback_c(
+    client: *mut UA_Client,
+    _userdata: *mut c_void,
+    _request_id: UA_UInt32,
+    response: *mut c_void,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        // The context has already been taken, i.e. the client is being deleted.
+        return;
+    };
+
+    // SAFETY: Incoming pointer is valid for access.
+    let Some(response) = (unsafe { response.cast::<UA_PublishResponse>().as_ref() }) else {
+        context.adopted_subscriptions().stop_publishing();
+        return;
+    };
+    let response = ua::PublishResponse::raw_ref(response);
+
+    // Release lock before sending the next request.
+    let request = context
+        .adopted_subscriptions()
+        .process_publish_response(response);
+
+    if response.service_result().is_good() {
+        for event in subscription_events(response.notification_message()) {
+            context.notify_subscription_event(response.subscription_id(), &event);
+        }
+    }
+
+    if let Some(request) = request {
+        // SAFETY: The client is valid for the duration of the callback.
+        unsafe { send_publish_request(context, client, &request) };
+    }
+}
+
+/// Gets subscription events from notification message.
+///
+/// `open62541` reports status changes of its own subscriptions with callbacks. It does not report
+/// keep-alive messages.
+fn subscription_events(message: &ua::NotificationMessage) -> Vec<SubscriptionEvent> {
+    if message.is_keep_alive() {
+        return vec![SubscriptionEvent::KeepAlive {
+            sequence_number: message.sequence_number(),
+        }];
+    }
+    message
+        .status_change_notifications()
+        .into_iter()
+        .map(|notification| SubscriptionEvent::from_status_change(notification.status()))
+        .collect()
+}
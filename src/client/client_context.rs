@@ -0,0 +1,563 @@
+use std::{
+    ffi::c_void,
+    fmt, mem, ptr,
+    sync::{Mutex, PoisonError},
+};
+
+use open62541_sys::{
+    UA_Client, UA_ClientConfig, UA_Client_getConfig, UA_SecureChannelState, UA_SessionState,
+    UA_StatusCode, UA_UInt32,
+};
+
+use crate::ua;
+
+/// Context attached to client.
+///
+/// This is prepared by [`ClientBuilder`], stored in the client config and owned by [`ua::Client`].
+/// It is released after the client has been deleted.
+///
+/// [`ClientBuilder`]: crate::ClientBuilder
+pub(crate) struct ClientContext {
+    /// Senders of streams returned by [`AsyncClient::state_stream()`].
+    ///
+    /// [`AsyncClient::state_stream()`]: crate::AsyncClient::state_stream
+    #[cfg(feature = "tokio")]
+    state_senders: Mutex<Vec<crate::CallbackStreamSender<ua::ClientState>>>,
+    /// Senders of streams returned by [`AsyncSubscription::events()`], with the shared ID of their
+    /// subscription.
+    ///
+    /// [`AsyncSubscription::events()`]: crate::AsyncSubscription::events
+    #[cfg(feature = "tokio")]
+    subscription_event_senders: Mutex<Vec<SubscriptionEventSender>>,
+    /// Publishing for subscriptions adopted with [`AsyncClient::adopt_subscription()`].
+    ///
+    /// [`AsyncClient::adopt_subscription()`]: crate::AsyncClient::adopt_subscription
+    #[cfg(feature = "tokio")]
+    adopted_subscriptions: Mutex<super::AdoptedSubscriptions>,
+    /// Watchdogs of subscriptions, see [`SubscriptionBuilder::watchdog_timeout()`].
+    ///
+    /// [`SubscriptionBuilder::watchdog_timeout()`]: crate::SubscriptionBuilder::watchdog_timeout
+    #[cfg(feature = "tokio")]
+    subscription_watchdogs: super::SubscriptionWatchdogs,
+    /// Batches of values for streams returned by [`AsyncSubscription::publish_batches()`].
+    ///
+    /// [`AsyncSubscription::publish_batches()`]: crate::AsyncSubscription::publish_batches
+    #[cfg(feature = "tokio")]
+    publish_batches: super::PublishBatches,
+    /// See [`ClientBuilder::cancel_dropped_requests()`].
+    ///
+    /// [`ClientBuilder::cancel_dropped_requests()`]: crate::ClientBuilder::cancel_dropped_requests
+    #[cfg(feature = "tokio")]
+    cancel_dropped_requests: bool,
+    /// See [`AsyncClient::set_default_request_header_options()`].
+    ///
+    /// [`AsyncClient::set_default_request_header_options()`]: crate::AsyncClient::set_default_request_header_options
+    #[cfg(feature = "tokio")]
+    request_header_options: Mutex<Option<ua::RequestHeader>>,
+    /// See [`ClientBuilder::on_session_inactivity()`](crate::ClientBuilder::on_session_inactivity).
+    session_inactivity: Option<Mutex<SessionInactivityCallback>>,
+    /// See [`ClientBuilder::on_subscription_inactivity()`].
+    ///
+    /// [`ClientBuilder::on_subscription_inactivity()`]: crate::ClientBuilder::on_subscription_inactivity
+    subscription_inactivity: Option<Mutex<SubscriptionInactivityCallback>>,
+    /// See [`ClientBuilder::auto_reconnect()`](crate::ClientBuilder::auto_reconnect).
+    #[cfg(feature = "tokio")]
+    reconnect: Option<super::Reconnect>,
+    /// See [`ClientBuilder::session_keep_alive()`](crate::ClientBuilder::session_keep_alive).
+    #[cfg(feature = "tokio")]
+    session_keep_alive: Option<super::SessionKeepAlive>,
+    /// Previous user identity token while the session is being activated with a new one, see
+    /// [`ua::Client::activate_current_session_with()`].
+    previous_user_identity_token: Mutex<Option<ua::ExtensionObject>>,
+}
+
+#[cfg(feature = "tokio")]
+type SubscriptionEventSender = (
+    std::sync::Arc<std::sync::atomic::AtomicU32>,
+    crate::CallbackStreamSender<crate::SubscriptionEvent>,
+);
+
+type SessionInactivityCallback = Box<dyn Fn() + Send>;
+type SubscriptionInactivityCallback = Box<dyn Fn(ua::SubscriptionId) + Send>;
+
+impl ClientContext {
+    pub(crate) const fn new() -> Self {
+        Self {
+            #[cfg(feature = "tokio")]
+            state_senders: Mutex::new(Vec::new()),
+            #[cfg(feature = "tokio")]
+            subscription_event_senders: Mutex::new(Vec::new()),
+            #[cfg(feature = "tokio")]
+            adopted_subscriptions: Mutex::new(super::AdoptedSubscriptions::new()),
+            #[cfg(feature = "tokio")]
+            subscription_watchdogs: super::SubscriptionWatchdogs::new(),
+            #[cfg(feature = "tokio")]
+            publish_batches: super::PublishBatches::new(),
+            #[cfg(feature = "tokio")]
+            cancel_dropped_requests: false,
+            #[cfg(feature = "tokio")]
+            request_header_options: Mutex::new(None),
+            session_inactivity: None,
+            subscription_inactivity: None,
+            #[cfg(feature = "tokio")]
+            reconnect: None,
+            #[cfg(feature = "tokio")]
+            session_keep_alive: None,
+            previous_user_identity_token: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn set_session_inactivity(&mut self, callback: impl Fn() + Send + 'static) {
+        self.session_inactivity = Some(Mutex::new(Box::new(callback)));
+    }
+
+    pub(crate) fn set_subscription_inactivity(
+        &mut self,
+        callback: impl Fn(ua::SubscriptionId) + Send + 'static,
+    ) {
+        self.subscription_inactivity = Some(Mutex::new(Box::new(callback)));
+    }
+
+    #[cfg(feature = "tokio")]
+    pub(crate) fn set_cancel_dropped_requests(&mut self, cancel_dropped_requests: bool) {
+        self.cancel_dropped_requests = cancel_dropped_requests;
+    }
+
+    #[cfg(feature = "tokio")]
+    pub(crate) fn set_reconnect(&mut self, policy: super::ReconnectPolicy) {
+        self.reconnect = Some(super::Reconnect::new(policy));
+    }
+
+    #[cfg(feature = "tokio")]
+    pub(crate) fn set_session_keep_alive(&mut self, interval: std::time::Duration) {
+        self.session_keep_alive = Some(super::SessionKeepAlive::new(interval));
+    }
+
+    /// Installs client context into config.
+    ///
+    /// To prevent memory leaks, the client must be created from the config, which then takes
+    /// ownership of the context.
+    pub(crate) fn install(self, config: &mut UA_ClientConfig) {
+        if self.session_inactivity.is_some() {
+            config.inactivityCallback = Some(inactivity_callback_c);
+        }
+        if self.subscription_inactivity.is_some() {
+            config.subscriptionInactivityCallback = Some(subscription_inactivity_callback_c);
+        }
+
+        // PANIC: We never set context elsewhere in config.
+        debug_assert!(config.clientContext.is_null());
+        // The context is only ever accessed by shared reference, from multiple threads. We cannot
+        // use `Userdata` here because it hands out exclusive references.
+        config.clientContext = Box::into_raw(Box::new(self)).cast::<c_void>();
+        config.stateCallback = Some(state_callback_c);
+    }
+
+    /// Gets client context of client.
+    ///
+    /// # Panics
+    ///
+    /// The client must have been created from a config with [`install()`](Self::install).
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn get(client: &ua::Client) -> &Self {
+        // SAFETY: The context has been installed before the client was created and is only released
+        // when the client is dropped. `client` is borrowed for the lifetime of the reference.
+        unsafe { Self::from_client(client.as_ptr().cast_mut()) }.expect("client context is set")
+    }
+
+    /// Takes client context from client.
+    ///
+    /// # Safety
+    ///
+    /// This must only be called right before the client is deleted. The returned context must only
+    /// be dropped after the client has been deleted.
+    pub(crate) unsafe fn take(client: *mut UA_Client) -> Option<Box<Self>> {
+        // SAFETY: Client config is owned by client. We only access the context field.
+        let config = unsafe { UA_Client_getConfig(client).as_mut() }?;
+        let context = config.clientContext;
+        if context.is_null() {
+            return None;
+        }
+        config.clientContext = ptr::null_mut();
+
+        // SAFETY: The context has been created by `install()` and is taken only once here.
+        Some(unsafe { Box::from_raw(context.cast::<Self>()) })
+    }
+
+    /// Gets client context from raw client pointer.
+    ///
+    /// # Safety
+    ///
+    /// The client must be valid for the lifetime of the returned reference.
+    pub(crate) unsafe fn from_client<'a>(client: *mut UA_Client) -> Option<&'a Self> {
+        // SAFETY: Client config is owned by client. The context field is only written before the
+        // client is created and when it is deleted.
+        let config = unsafe { UA_Client_getConfig(client).as_ref() }?;
+        unsafe { config.clientContext.cast::<Self>().as_ref() }
+    }
+
+    /// Gets publishing state of adopted subscriptions.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn adopted_subscriptions(
+        &self,
+    ) -> std::sync::MutexGuard<'_, super::AdoptedSubscriptions> {
+        // PANIC: We never panic while holding the lock.
+        self.adopted_subscriptions.lock().unwrap()
+    }
+
+    /// Gets batches of values of subscriptions.
+    #[cfg(feature = "tokio")]
+    pub(crate) const fn publish_batches(&self) -> &super::PublishBatches {
+        &self.publish_batches
+    }
+
+    /// Gets watchdogs of subscriptions.
+    #[cfg(feature = "tokio")]
+    pub(crate) const fn subscription_watchdogs(&self) -> &super::SubscriptionWatchdogs {
+        &self.subscription_watchdogs
+    }
+
+    /// Checks watchdogs of subscriptions, notifying streams of stalled and resumed subscriptions.
+    ///
+    /// This must be called regularly from the client's event loop.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn check_subscription_watchdogs(&self) {
+        for (subscription_id, event) in self.subscription_watchdogs.check() {
+            self.notify_subscription_event(subscription_id, &event);
+        }
+    }
+
+    /// Adds sender to notify of state changes.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn add_state_sender(&self, sender: crate::CallbackStreamSender<ua::ClientState>) {
+        // PANIC: We never panic while holding the lock.
+        self.state_senders.lock().unwrap().push(sender);
+    }
+
+    /// Adds sender to notify of events of subscription.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn add_subscription_event_sender(
+        &self,
+        subscription_id: &std::sync::Arc<std::sync::atomic::AtomicU32>,
+        sender: crate::CallbackStreamSender<crate::SubscriptionEvent>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        self.subscription_event_senders
+            .lock()
+            .unwrap()
+            .push((std::sync::Arc::clone(subscription_id), sender));
+    }
+
+    /// Removes senders of events of subscription, closing their streams.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn remove_subscription_event_senders(
+        &self,
+        subscription_id: &std::sync::Arc<std::sync::atomic::AtomicU32>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        self.subscription_event_senders
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| !std::sync::Arc::ptr_eq(id, subscription_id));
+    }
+
+    /// Gets keep-alive of idle session, if enabled.
+    #[cfg(feature = "tokio")]
+    pub(crate) const fn session_keep_alive(&self) -> Option<&super::SessionKeepAlive> {
+        self.session_keep_alive.as_ref()
+    }
+
+    /// Checks whether requests should be cancelled when their futures are dropped.
+    #[cfg(feature = "tokio")]
+    pub(crate) const fn cancel_dropped_requests(&self) -> bool {
+        self.cancel_dropped_requests
+    }
+
+    /// Gets state of automatic reconnecting, if enabled.
+    #[cfg(feature = "tokio")]
+    pub(crate) const fn reconnect(&self) -> Option<&super::Reconnect> {
+        self.reconnect.as_ref()
+    }
+
+    /// Sets options to apply to request headers.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn set_request_header_options(&self, options: ua::RequestHeader) {
+        // PANIC: We never panic while holding the lock.
+        *self.request_header_options.lock().unwrap() = Some(options);
+    }
+
+    /// Applies options to request header, if set.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn apply_request_header_options(&self, request_header: &mut ua::RequestHeader) {
+        // PANIC: We never panic while holding the lock.
+        if let Some(options) = self.request_header_options.lock().unwrap().as_ref() {
+            request_header.set_options(options);
+        }
+    }
+
+    /// Notifies streams of subscription with the given server-assigned ID.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn notify_subscription_event(
+        &self,
+        subscription_id: ua::SubscriptionId,
+        event: &crate::SubscriptionEvent,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut senders = self.subscription_event_senders.lock().unwrap();
+        // Forget senders whose receivers have been dropped.
+        senders.retain(|(_, sender)| !sender.is_closed());
+        for (id, sender) in senders.iter() {
+            if id.load(std::sync::atomic::Ordering::Relaxed) == subscription_id.as_u32() {
+                sender.send(event.clone());
+            }
+        }
+    }
+
+    /// Closes streams of subscription with the given server-assigned ID.
+    #[cfg(feature = "tokio")]
+    fn close_subscription_events(&self, subscription_id: ua::SubscriptionId) {
+        // PANIC: We never panic while holding the lock.
+        self.subscription_event_senders
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| {
+                id.load(std::sync::atomic::Ordering::Relaxed) != subscription_id.as_u32()
+            });
+    }
+
+    /// Replaces user identity token in client config.
+    ///
+    /// The previous token is kept until the session has been activated with the new token, so that
+    /// it can be restored when the server rejects the new token.
+    ///
+    /// # Safety
+    ///
+    /// The client must be valid. This must be called from the client's event loop: `open62541` only
+    /// reads the token there, when it activates sessions. The context lock is held while writing the
+    /// token to not race with [`restore_user_identity_token()`](Self::restore_user_identity_token).
+    pub(crate) unsafe fn replace_user_identity_token(
+        &self,
+        client: *mut UA_Client,
+        user_identity_token: ua::ExtensionObject,
+    ) -> Result<(), crate::Error> {
+        // SAFETY: Client config is owned by client, see above for concurrent access.
+        let config = unsafe { UA_Client_getConfig(client).as_mut() }
+            .ok_or(crate::Error::internal("client config should be set"))?;
+
+        // PANIC: We never panic while holding the lock.
+        let mut previous_user_identity_token = self.previous_user_identity_token.lock().unwrap();
+        let previous = mem::replace(
+            &mut config.userIdentityToken,
+            user_identity_token.into_raw(),
+        );
+        // SAFETY: The previous token has been owned by the config, ownership passes to us.
+        let mut previous = unsafe { ua::ExtensionObject::from_raw(previous) };
+        if previous_user_identity_token.is_none() {
+            *previous_user_identity_token = Some(previous);
+        } else {
+            // Another change is still pending. Keep the token from before that change instead.
+            ua::UserNameIdentityToken::zeroize_password_in(&mut previous);
+        }
+        Ok(())
+    }
+
+    /// Restores previous user identity token in client config, if any.
+    ///
+    /// # Safety
+    ///
+    /// The client must be valid. This must be called from the client's event loop or while holding
+    /// the client lock, i.e. from a state callback.
+    pub(crate) unsafe fn restore_user_identity_token(&self, client: *mut UA_Client) {
+        // PANIC: We never panic while holding the lock.
+        let mut previous_user_identity_token = self.previous_user_identity_token.lock().unwrap();
+        let Some(previous) = previous_user_identity_token.take() else {
+            return;
+        };
+        // SAFETY: Client config is owned by client, see above for concurrent access.
+        let Some(config) = (unsafe { UA_Client_getConfig(client).as_mut() }) else {
+            return;
+        };
+
+        log::warn!("Restoring previous user identity token");
+        let rejected = mem::replace(&mut config.userIdentityToken, previous.into_raw());
+        // SAFETY: The rejected token has been owned by the config, ownership passes to us.
+        let mut rejected = unsafe { ua::ExtensionObject::from_raw(rejected) };
+        ua::UserNameIdentityToken::zeroize_password_in(&mut rejected);
+    }
+
+    /// Forgets previous user identity token, if any.
+    ///
+    /// This is called when the session has been activated with the new token.
+    pub(crate) fn forget_previous_user_identity_token(&self) {
+        // PANIC: We never panic while holding the lock.
+        if let Some(mut previous) = self.previous_user_identity_token.lock().unwrap().take() {
+            ua::UserNameIdentityToken::zeroize_password_in(&mut previous);
+        }
+    }
+
+    #[cfg_attr(not(feature = "tokio"), allow(clippy::unused_self, unused_variables))]
+    fn notify_state(&self, state: &ua::ClientState) {
+        #[cfg(feature = "tokio")]
+        {
+            // PANIC: We never panic while holding the lock.
+            let mut state_senders = self.state_senders.lock().unwrap();
+            // Forget senders whose receivers have been dropped.
+            state_senders.retain(|sender| !sender.is_closed());
+            for sender in state_senders.iter() {
+                sender.send(state.clone());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ClientContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientContext").finish_non_exhaustive()
+    }
+}
+
+/// Notifies client context of state changes.
+///
+/// This is called by `open62541` for every change of the secure channel state, the session state or
+/// the connect status.
+unsafe extern "C" fn state_callback_c(
+    client: *mut UA_Client,
+    channel_state: UA_SecureChannelState,
+    session_state: UA_SessionState,
+    connect_status: UA_StatusCode,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        // The context has already been taken, i.e. the client is being deleted.
+        return;
+    };
+
+    let state = ua::ClientState {
+        channel_state: ua::SecureChannelState::new(channel_state),
+        session_state: ua::SessionState::new(session_state),
+        connect_status: ua::StatusCode::new(connect_status),
+    };
+    log::debug!("Client state changed to {state:?}");
+
+    // Settle pending change of user identity before anyone else gets to know about the new state.
+    // The client lock is held here, so we may access the config.
+    if state.session_state.is_activated() {
+        context.forget_previous_user_identity_token();
+    } else if !state.connect_status.is_good() {
+        // SAFETY: The client is valid for the duration of the callback.
+        unsafe { context.restore_user_identity_token(client) };
+    }
+
+    context.notify_state(&state);
+}
+
+/// Calls session inactivity callback.
+///
+/// This is called by `open62541` when the connectivity check does not get a response in time.
+unsafe extern "C" fn inactivity_callback_c(client: *mut UA_Client) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        return;
+    };
+    let Some(callback) = &context.session_inactivity else {
+        return;
+    };
+
+    log::warn!("Session is inactive");
+
+    // The callback is only ever called from the client's event loop. Poisoning is irrelevant here
+    // because the mutex guards no state besides the callback itself.
+    let callback = callback.lock().unwrap_or_else(PoisonError::into_inner);
+    callback();
+}
+
+/// Calls subscription inactivity callback.
+///
+/// This is called by `open62541` when no publish response has been received for the subscription
+/// in time.
+unsafe extern "C" fn subscription_inactivity_callback_c(
+    client: *mut UA_Client,
+    subscription_id: UA_UInt32,
+    _sub_context: *mut c_void,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        return;
+    };
+    let Some(callback) = &context.subscription_inactivity else {
+        return;
+    };
+
+    let subscription_id = ua::SubscriptionId::new(subscription_id);
+    log::warn!("Subscription {subscription_id} is inactive");
+
+    // See `inactivity_callback_c()`.
+    let callback = callback.lock().unwrap_or_else(PoisonError::into_inner);
+    callback(subscription_id);
+}
+
+/// Notifies client context of deleted subscription.
+///
+/// This is called by `open62541` when a subscription is removed from the client, either because it
+/// has been deleted or because it has been lost along with the session.
+#[cfg(feature = "tokio")]
+pub(crate) unsafe extern "C" fn subscription_delete_callback_c(
+    client: *mut UA_Client,
+    subscription_id: UA_UInt32,
+    _sub_context: *mut c_void,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        return;
+    };
+    let subscription_id = ua::SubscriptionId::new(subscription_id);
+
+    context
+        .adopted_subscriptions()
+        .managed_subscription_deleted();
+
+    // Lost subscriptions that are recreated later keep their streams open.
+    if let Some(reconnect) = context.reconnect() {
+        if reconnect.subscription_deleted(subscription_id) {
+            context.notify_subscription_event(
+                subscription_id,
+                &crate::SubscriptionEvent::StatusChange(ua::StatusCode::BADNOCOMMUNICATION),
+            );
+            return;
+        }
+    }
+
+    context.close_subscription_events(subscription_id);
+    context.publish_batches.close(subscription_id);
+    context.subscription_watchdogs.close(subscription_id);
+}
+
+/// Notifies client context of status change of subscription.
+///
+/// This is called by `open62541` when a publish response carries a `StatusChangeNotification`, e.g.
+/// when the subscription has timed out on the server or has been transferred to another session.
+#[cfg(feature = "tokio")]
+pub(crate) unsafe extern "C" fn subscription_status_change_callback_c(
+    client: *mut UA_Client,
+    subscription_id: UA_UInt32,
+    _sub_context: *mut c_void,
+    notification: *mut open62541_sys::UA_StatusChangeNotification,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        return;
+    };
+    // SAFETY: Incoming pointer is valid for access.
+    let Some(notification) = (unsafe { notification.as_ref() }) else {
+        return;
+    };
+
+    let subscription_id = ua::SubscriptionId::new(subscription_id);
+    let status_code = ua::StatusCode::new(notification.status);
+    log::info!("Subscription {subscription_id} changed status to {status_code}");
+
+    context.notify_subscription_event(
+        subscription_id,
+        &crate::SubscriptionEvent::from_status_change(status_code),
+    );
+}
@@ -0,0 +1,129 @@
+use std::{
+    mem,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::{ua, CallbackStreamSender, PublishBatch};
+
+/// Collection of values into batches per subscription.
+///
+/// `open62541` processes publish responses internally and calls the notification callbacks of the
+/// monitored items one by one. Each response is processed within a single iteration of the event
+/// loop though. We collect values in the callbacks and flush them as one batch after the iteration,
+/// see [`flush()`](Self::flush).
+pub(crate) struct PublishBatches {
+    /// Whether there are any streams. Notification callbacks skip the lock when there are none.
+    active: AtomicBool,
+    /// Number of flushes, i.e. iterations of the event loop.
+    iteration: AtomicU64,
+    collectors: Mutex<Vec<Collector>>,
+}
+
+struct Collector {
+    /// Shared ID of subscription. This changes when the subscription is recreated.
+    subscription_id: Arc<AtomicU32>,
+    /// Shared ID of heartbeat monitored item of watchdog, whose values are left out.
+    heartbeat_id: Option<Arc<AtomicU32>>,
+    sender: CallbackStreamSender<PublishBatch>,
+    /// Values collected since the last flush.
+    pending: Vec<(ua::MonitoredItemId, ua::DataValue)>,
+}
+
+impl PublishBatches {
+    pub(crate) const fn new() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            iteration: AtomicU64::new(0),
+            collectors: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds sender to notify of batches of subscription.
+    ///
+    /// Values of the heartbeat monitored item of the subscription's watchdog are not included, see
+    /// [`SubscriptionWatchdogs::heartbeat_id()`](super::SubscriptionWatchdogs::heartbeat_id).
+    pub(crate) fn add_sender(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        heartbeat_id: Option<Arc<AtomicU32>>,
+        sender: CallbackStreamSender<PublishBatch>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut collectors = self.collectors.lock().unwrap();
+        collectors.push(Collector {
+            subscription_id: Arc::clone(subscription_id),
+            heartbeat_id,
+            sender,
+            pending: Vec::new(),
+        });
+        self.active.store(true, Ordering::Relaxed);
+    }
+
+    /// Removes senders of subscription, closing their streams.
+    pub(crate) fn remove_senders(&self, subscription_id: &Arc<AtomicU32>) {
+        self.retain(|collector| !Arc::ptr_eq(&collector.subscription_id, subscription_id));
+    }
+
+    /// Closes streams of subscription with the given server-assigned ID.
+    pub(crate) fn close(&self, subscription_id: ua::SubscriptionId) {
+        self.retain(|collector| {
+            collector.subscription_id.load(Ordering::Relaxed) != subscription_id.as_u32()
+        });
+    }
+
+    /// Collects value of monitored item for the next batch of its subscription.
+    ///
+    /// This is called for every notification that is received. It returns right away when there
+    /// are no streams.
+    pub(crate) fn collect(
+        &self,
+        subscription_id: ua::SubscriptionId,
+        monitored_item_id: ua::MonitoredItemId,
+        value: &ua::DataValue,
+    ) {
+        if !self.active.load(Ordering::Relaxed) {
+            return;
+        }
+        // PANIC: We never panic while holding the lock.
+        let mut collectors = self.collectors.lock().unwrap();
+        for collector in collectors.iter_mut() {
+            if collector.subscription_id.load(Ordering::Relaxed) != subscription_id.as_u32() {
+                continue;
+            }
+            if collector.heartbeat_id.as_ref().is_some_and(|heartbeat_id| {
+                heartbeat_id.load(Ordering::Relaxed) == monitored_item_id.as_u32()
+            }) {
+                continue;
+            }
+            collector.pending.push((monitored_item_id, value.clone()));
+        }
+    }
+
+    /// Sends collected values as one batch per subscription.
+    ///
+    /// This must be called after each iteration of the event loop.
+    pub(crate) fn flush(&self) {
+        let iteration = self.iteration.fetch_add(1, Ordering::Relaxed);
+        if !self.active.load(Ordering::Relaxed) {
+            return;
+        }
+        self.retain(|collector| {
+            if !collector.pending.is_empty() {
+                let values = mem::take(&mut collector.pending);
+                collector.sender.send(PublishBatch::new(iteration, values));
+            }
+            // Forget senders whose receivers have been dropped.
+            !collector.sender.is_closed()
+        });
+    }
+
+    fn retain(&self, f: impl FnMut(&mut Collector) -> bool) {
+        // PANIC: We never panic while holding the lock.
+        let mut collectors = self.collectors.lock().unwrap();
+        collectors.retain_mut(f);
+        self.active.store(!collectors.is_empty(), Ordering::Relaxed);
+    }
+}
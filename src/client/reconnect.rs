@@ -0,0 +1,530 @@
+use std::{
+    num::NonZeroU32,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use crate::{
+    async_monitored_item::create_monitored_items,
+    async_subscription::{create_subscription, delete_subscriptions},
+    ua, CallbackStreamSender, DataType as _,
+};
+
+/// Policy for reconnecting automatically.
+///
+/// The delay between attempts starts at [`initial_delay()`](Self::initial_delay) and is doubled
+/// after each failed attempt, up to [`max_delay()`](Self::max_delay).
+///
+/// See [`ClientBuilder::auto_reconnect()`](crate::ClientBuilder::auto_reconnect).
+///
+/// # Examples
+///
+/// ```
+/// use open62541::ReconnectPolicy;
+/// use std::{num::NonZeroU32, time::Duration};
+///
+/// let policy = ReconnectPolicy::default()
+///     .initial_delay(Duration::from_millis(500))
+///     .max_delay(Duration::from_secs(30))
+///     .max_attempts(NonZeroU32::new(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_attempts: Option<NonZeroU32>,
+}
+
+impl ReconnectPolicy {
+    /// Sets delay before first attempt to reconnect.
+    ///
+    /// Default value is 1 s.
+    #[must_use]
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets maximum delay between attempts to reconnect.
+    ///
+    /// Default value is 60 s.
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets maximum number of attempts to reconnect.
+    ///
+    /// Attempts are counted from the loss of the connection until the session has been activated
+    /// again. When all attempts have failed, the client gives up and stays disconnected.
+    ///
+    /// Default value is `None` (unlimited).
+    #[must_use]
+    pub const fn max_attempts(mut self, max_attempts: Option<NonZeroU32>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Gets delay before given attempt (starting at 0).
+    ///
+    /// This returns `None` when no more attempts should be made.
+    pub(crate) fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self
+            .max_attempts
+            .is_some_and(|max_attempts| attempt >= max_attempts.get())
+        {
+            return None;
+        }
+
+        let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        Some(
+            self.initial_delay
+                .saturating_mul(factor)
+                .min(self.max_delay),
+        )
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            max_attempts: None,
+        }
+    }
+}
+
+/// State of automatic reconnecting.
+///
+/// This is held by [`ClientContext`](super::ClientContext). It tracks subscriptions and monitored
+/// items that have been created through [`AsyncSubscription`] and [`MonitoredItemBuilder`], so that
+/// they can be recreated when the session has been lost.
+///
+/// The tracked monitored items hold a reference to the sender of their stream. This keeps streams
+/// open while `open62541` has discarded the monitored items of a lost session.
+///
+/// [`AsyncSubscription`]: crate::AsyncSubscription
+/// [`MonitoredItemBuilder`]: crate::MonitoredItemBuilder
+#[derive(Debug)]
+pub(crate) struct Reconnect {
+    policy: ReconnectPolicy,
+    /// Set when the client is being disconnected on purpose.
+    disconnecting: AtomicBool,
+    /// Set when lost subscriptions should be recreated.
+    restore_pending: AtomicBool,
+    subscriptions: Mutex<Vec<TrackedSubscription>>,
+}
+
+#[derive(Debug)]
+struct TrackedSubscription {
+    /// Server-assigned ID, shared with [`AsyncSubscription`](crate::AsyncSubscription).
+    subscription_id: Arc<AtomicU32>,
+    request: ua::CreateSubscriptionRequest,
+    monitored_items: Vec<TrackedMonitoredItem>,
+    /// Set when `open62541` has discarded the subscription without it being deleted by us.
+    lost: bool,
+}
+
+#[derive(Debug)]
+struct TrackedMonitoredItem {
+    /// Server-assigned ID, shared with [`AsyncMonitoredItem`](crate::AsyncMonitoredItem).
+    monitored_item_id: Arc<AtomicU32>,
+    request: ua::MonitoredItemCreateRequest,
+    timestamps_to_return: ua::TimestampsToReturn,
+    tx: Arc<CallbackStreamSender<ua::DataValue>>,
+}
+
+impl Reconnect {
+    pub(crate) fn new(policy: ReconnectPolicy) -> Self {
+        Self {
+            policy,
+            disconnecting: AtomicBool::new(false),
+            restore_pending: AtomicBool::new(false),
+            subscriptions: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub(crate) const fn policy(&self) -> &ReconnectPolicy {
+        &self.policy
+    }
+
+    /// Prevents reconnecting, when the client is being disconnected on purpose.
+    pub(crate) fn set_disconnecting(&self) {
+        self.disconnecting.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_disconnecting(&self) -> bool {
+        self.disconnecting.load(Ordering::Relaxed)
+    }
+
+    /// Starts tracking subscription that has been created with the given request.
+    pub(crate) fn track_subscription(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        request: ua::CreateSubscriptionRequest,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .push(TrackedSubscription {
+                subscription_id: Arc::clone(subscription_id),
+                request,
+                monitored_items: Vec::new(),
+                lost: false,
+            });
+    }
+
+    /// Stops tracking subscription, along with its monitored items.
+    pub(crate) fn untrack_subscription(&self, subscription_id: &Arc<AtomicU32>) {
+        // PANIC: We never panic while holding the lock.
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .retain(|subscription| !Arc::ptr_eq(&subscription.subscription_id, subscription_id));
+    }
+
+    /// Updates parameters that subscription is recreated with.
+    ///
+    /// Publishing is not enabled or disabled by modifying subscriptions, so this keeps the previous
+    /// setting.
+    pub(crate) fn update_subscription(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        request: ua::CreateSubscriptionRequest,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(subscription) = subscriptions
+            .iter_mut()
+            .find(|subscription| Arc::ptr_eq(&subscription.subscription_id, subscription_id))
+        else {
+            return;
+        };
+
+        let publishing_enabled = subscription.request.publishing_enabled();
+        subscription.request = request.with_publishing_enabled(publishing_enabled);
+    }
+
+    /// Starts tracking monitored item that has been created with the given request.
+    ///
+    /// This does nothing when the subscription is not tracked.
+    pub(crate) fn track_monitored_item(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        monitored_item_id: &Arc<AtomicU32>,
+        request: ua::MonitoredItemCreateRequest,
+        timestamps_to_return: ua::TimestampsToReturn,
+        tx: Arc<CallbackStreamSender<ua::DataValue>>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(subscription) = subscriptions
+            .iter_mut()
+            .find(|subscription| Arc::ptr_eq(&subscription.subscription_id, subscription_id))
+        else {
+            return;
+        };
+
+        subscription.monitored_items.push(TrackedMonitoredItem {
+            monitored_item_id: Arc::clone(monitored_item_id),
+            request,
+            timestamps_to_return,
+            tx,
+        });
+    }
+
+    /// Stops tracking monitored item.
+    pub(crate) fn untrack_monitored_item(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        monitored_item_id: &Arc<AtomicU32>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(subscription) = subscriptions
+            .iter_mut()
+            .find(|subscription| Arc::ptr_eq(&subscription.subscription_id, subscription_id))
+        else {
+            return;
+        };
+
+        subscription.monitored_items.retain(|monitored_item| {
+            !Arc::ptr_eq(&monitored_item.monitored_item_id, monitored_item_id)
+        });
+    }
+
+    /// Updates monitoring mode that monitored item is recreated with.
+    pub(crate) fn set_monitoring_mode(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        monitored_item_id: ua::MonitoredItemId,
+        monitoring_mode: &ua::MonitoringMode,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(monitored_item) = subscriptions
+            .iter_mut()
+            .filter(|subscription| Arc::ptr_eq(&subscription.subscription_id, subscription_id))
+            .flat_map(|subscription| subscription.monitored_items.iter_mut())
+            .find(|monitored_item| {
+                monitored_item.monitored_item_id.load(Ordering::Relaxed)
+                    == monitored_item_id.as_u32()
+            })
+        else {
+            return;
+        };
+
+        monitored_item.request = monitored_item
+            .request
+            .clone()
+            .with_monitoring_mode(monitoring_mode);
+    }
+
+    /// Updates parameters that monitored item is recreated with.
+    pub(crate) fn modify_monitored_item(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        monitored_item_id: ua::MonitoredItemId,
+        requested_parameters: &ua::MonitoringParameters,
+        timestamps_to_return: &ua::TimestampsToReturn,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(monitored_item) = subscriptions
+            .iter_mut()
+            .filter(|subscription| Arc::ptr_eq(&subscription.subscription_id, subscription_id))
+            .flat_map(|subscription| subscription.monitored_items.iter_mut())
+            .find(|monitored_item| {
+                monitored_item.monitored_item_id.load(Ordering::Relaxed)
+                    == monitored_item_id.as_u32()
+            })
+        else {
+            return;
+        };
+
+        monitored_item.request = monitored_item
+            .request
+            .clone()
+            .with_requested_parameters(requested_parameters);
+        monitored_item.timestamps_to_return = timestamps_to_return.clone();
+    }
+
+    /// Notes that `open62541` has discarded subscription.
+    ///
+    /// Subscriptions that we delete ourselves are not tracked anymore at this point. Tracked
+    /// subscriptions have been lost, usually along with their session, and are recreated later.
+    ///
+    /// This returns `true` when the subscription is recreated later.
+    pub(crate) fn subscription_deleted(&self, subscription_id: ua::SubscriptionId) -> bool {
+        if self.is_disconnecting() {
+            return false;
+        }
+
+        // PANIC: We never panic while holding the lock.
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        let Some(subscription) = subscriptions.iter_mut().find(|subscription| {
+            !subscription.lost
+                && subscription.subscription_id.load(Ordering::Relaxed) == subscription_id.as_u32()
+        }) else {
+            return false;
+        };
+
+        log::warn!("Subscription {subscription_id} has been lost");
+        subscription.lost = true;
+
+        // Mark gap in streams. Values of recreated monitored items follow later.
+        let gap = ua::DataValue::init().with_status(&ua::StatusCode::BADNOCOMMUNICATION);
+        for monitored_item in &subscription.monitored_items {
+            monitored_item.tx.send(gap.clone());
+        }
+
+        self.restore_pending.store(true, Ordering::Relaxed);
+
+        true
+    }
+
+    /// Requests recreation of lost subscriptions, e.g. after the session has been activated again.
+    pub(crate) fn schedule_restore(&self) {
+        self.restore_pending.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks and resets whether lost subscriptions should be recreated.
+    pub(crate) fn take_restore_pending(&self) -> bool {
+        self.restore_pending.swap(false, Ordering::Relaxed)
+    }
+
+    /// Recreates lost subscriptions and their monitored items.
+    ///
+    /// The recreated subscriptions and monitored items get new server-assigned IDs. Monitored items
+    /// that cannot be recreated are not tracked anymore and their streams are closed. Subscriptions
+    /// that cannot be recreated are tried again the next time.
+    pub(crate) async fn restore_subscriptions(&self, client: &ua::Client) {
+        // We must not hold the lock across requests. Callbacks that are run by `open62541` while it
+        // holds its own lock may need our lock too.
+        let lost_subscriptions = self.with_subscriptions(|subscriptions| {
+            subscriptions
+                .iter()
+                .filter(|subscription| subscription.lost)
+                .map(|subscription| {
+                    (
+                        Arc::clone(&subscription.subscription_id),
+                        subscription.request.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+
+        for (subscription_id, request) in lost_subscriptions {
+            let response = match create_subscription(client, &request).await {
+                Ok(response) => response,
+                Err(error) => {
+                    log::error!("Cannot recreate lost subscription: {error}");
+                    continue;
+                }
+            };
+            let new_subscription_id = response.subscription_id();
+            log::info!("Recreated lost subscription as {new_subscription_id}");
+
+            let monitored_items = self.with_subscriptions(|subscriptions| {
+                let subscription = subscriptions.iter_mut().find(|subscription| {
+                    Arc::ptr_eq(&subscription.subscription_id, &subscription_id)
+                })?;
+                subscription
+                    .subscription_id
+                    .store(new_subscription_id.as_u32(), Ordering::Relaxed);
+                subscription.lost = false;
+
+                Some(
+                    subscription
+                        .monitored_items
+                        .iter()
+                        .map(|monitored_item| {
+                            (
+                                Arc::clone(&monitored_item.monitored_item_id),
+                                monitored_item.request.clone(),
+                                monitored_item.timestamps_to_return.clone(),
+                                Arc::clone(&monitored_item.tx),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            });
+
+            let Some(monitored_items) = monitored_items else {
+                // The subscription has been dropped in the meantime. Do not leave the recreated
+                // subscription dangling on the server.
+                log::info!("Deleting recreated subscription {new_subscription_id} again");
+                delete_subscriptions(
+                    client,
+                    &ua::DeleteSubscriptionsRequest::init()
+                        .with_subscription_ids(&[new_subscription_id]),
+                );
+                continue;
+            };
+
+            // Monitored items are created in batches that share their timestamps to return.
+            for batch in monitored_items.chunk_by(|a, b| a.2 == b.2) {
+                self.restore_monitored_items(client, &subscription_id, new_subscription_id, batch)
+                    .await;
+            }
+        }
+    }
+
+    async fn restore_monitored_items(
+        &self,
+        client: &ua::Client,
+        subscription_id: &Arc<AtomicU32>,
+        new_subscription_id: ua::SubscriptionId,
+        batch: &[(
+            Arc<AtomicU32>,
+            ua::MonitoredItemCreateRequest,
+            ua::TimestampsToReturn,
+            Arc<CallbackStreamSender<ua::DataValue>>,
+        )],
+    ) {
+        let Some((_, _, timestamps_to_return, _)) = batch.first() else {
+            return;
+        };
+
+        let items_to_create = batch
+            .iter()
+            .map(|(_, request, _, _)| request.clone())
+            .collect::<Vec<_>>();
+        let request = ua::CreateMonitoredItemsRequest::init()
+            .with_subscription_id(new_subscription_id)
+            .with_timestamps_to_return(timestamps_to_return)
+            .with_items_to_create(&items_to_create);
+        let txs = batch
+            .iter()
+            .map(|(_, _, _, tx)| Arc::clone(tx))
+            .collect::<Vec<_>>();
+
+        let results = match create_monitored_items(client, &request, &txs).await {
+            Ok(response) => response.results().map(<[_]>::to_vec),
+            Err(error) => {
+                log::error!("Cannot recreate monitored items of lost subscription: {error}");
+                None
+            }
+        };
+        let results = results.filter(|results| results.len() == batch.len());
+
+        for (index, (monitored_item_id, _, _, tx)) in batch.iter().enumerate() {
+            let result = results.as_ref().and_then(|results| results.get(index));
+            if let Some(result) = result.filter(|result| result.status_code().is_good()) {
+                monitored_item_id.store(result.monitored_item_id().as_u32(), Ordering::Relaxed);
+                continue;
+            }
+
+            // Without the monitored item, there will be no more values. Close its stream.
+            log::warn!("Cannot recreate monitored item of lost subscription, closing stream");
+            self.untrack_monitored_item(subscription_id, monitored_item_id);
+            tx.close();
+        }
+    }
+
+    fn with_subscriptions<T>(&self, f: impl FnOnce(&mut Vec<TrackedSubscription>) -> T) -> T {
+        // PANIC: We never panic while holding the lock.
+        f(&mut self.subscriptions.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU32, time::Duration};
+
+    use super::ReconnectPolicy;
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = ReconnectPolicy::default()
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .max_attempts(NonZeroU32::new(6));
+
+        let delays = (0..7)
+            .map(|attempt| policy.delay(attempt))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [
+                Some(Duration::from_millis(100)),
+                Some(Duration::from_millis(200)),
+                Some(Duration::from_millis(400)),
+                Some(Duration::from_millis(800)),
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(1)),
+                None,
+            ]
+        );
+
+        // Delays saturate instead of overflowing.
+        let policy = ReconnectPolicy::default();
+        assert_eq!(policy.delay(100), Some(Duration::from_secs(60)));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    ffi::c_void,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use open62541_sys::{UA_Client, UA_UInt32, __UA_Client_AsyncService};
+
+use crate::{ua, DataType as _, Error};
+
+use super::ClientContext;
+
+/// Keep-alive of idle sessions, see [`ClientBuilder::session_keep_alive()`].
+///
+/// The server state is read only when no service response has been received for the interval.
+/// Responses are recorded in the callbacks of service requests, see [`record_activity()`].
+///
+/// [`ClientBuilder::session_keep_alive()`]: crate::ClientBuilder::session_keep_alive
+/// [`record_activity()`]: Self::record_activity
+pub(crate) struct SessionKeepAlive {
+    interval: Duration,
+    /// Time of last service response.
+    last_activity: Mutex<Instant>,
+    /// Whether a keep-alive request is outstanding.
+    pending: AtomicBool,
+}
+
+impl SessionKeepAlive {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_activity: Mutex::new(Instant::now()),
+            pending: AtomicBool::new(false),
+        }
+    }
+
+    /// Records that the server has responded to a service request.
+    pub(crate) fn record_activity(&self) {
+        // PANIC: We never panic while holding the lock.
+        *self.last_activity.lock().unwrap() = Instant::now();
+    }
+
+    /// Sends keep-alive request when the session has been idle for the interval.
+    ///
+    /// This must be called regularly from the client's event loop, only while the session is
+    /// activated.
+    pub(crate) fn check(&self, client: &ua::Client) {
+        if self.pending.load(Ordering::Relaxed) {
+            return;
+        }
+        // PANIC: We never panic while holding the lock.
+        if self.last_activity.lock().unwrap().elapsed() < self.interval {
+            return;
+        }
+
+        log::debug!("Sending keep-alive request for idle session");
+
+        let request = ua::ReadRequest::init().with_nodes_to_read(&[ua::ReadValueId::init()
+            .with_node_id(&ua::NodeId::ns0_server_status_state())
+            .with_attribute_id(&ua::AttributeId::VALUE)]);
+
+        let status_code = ua::StatusCode::new(unsafe {
+            __UA_Client_AsyncService(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                client.as_ptr().cast_mut(),
+                request.as_ptr().cast::<c_void>(),
+                ua::ReadRequest::data_type(),
+                Some(keep_alive_callback_c),
+                ua::ReadResponse::data_type(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        });
+        match Error::verify_good(&status_code) {
+            Ok(()) => self.pending.store(true, Ordering::Relaxed),
+            Err(error) => log::warn!("Keep-alive request failed: {error}"),
+        }
+    }
+}
+
+/// Processes response of keep-alive request.
+///
+/// This is called by `open62541` when the response has been received or when the request has
+/// failed, e.g. when the connection has been lost.
+unsafe extern "C" fn keep_alive_callback_c(
+    client: *mut UA_Client,
+    _userdata: *mut c_void,
+    _request_id: UA_UInt32,
+    _response: *mut c_void,
+) {
+    // SAFETY: The client is valid for the duration of the callback.
+    let Some(context) = (unsafe { ClientContext::from_client(client) }) else {
+        // The context has already been taken, i.e. the client is being deleted.
+        return;
+    };
+    if let Some(keep_alive) = context.session_keep_alive() {
+        keep_alive.pending.store(false, Ordering::Relaxed);
+        keep_alive.record_activity();
+    }
+}
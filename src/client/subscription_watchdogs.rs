@@ -0,0 +1,133 @@
+use std::{
+    mem,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+use crate::{ua, CallbackStreamReceiver, SubscriptionEvent};
+
+/// Watchdogs of subscriptions, see [`SubscriptionBuilder::watchdog_timeout()`].
+///
+/// `open62541` handles keep-alive messages internally, so we cannot observe them. Instead, each
+/// watched subscription has a heartbeat monitored item whose values arrive regularly as long as the
+/// server answers publish requests. Only the event loop checks the heartbeats, so notification
+/// callbacks of other monitored items are not involved. The heartbeat monitored item is internal and
+/// hidden from users, see [`heartbeat_id()`](Self::heartbeat_id).
+///
+/// [`SubscriptionBuilder::watchdog_timeout()`]: crate::SubscriptionBuilder::watchdog_timeout
+pub(crate) struct SubscriptionWatchdogs {
+    watchdogs: Mutex<Vec<Watchdog>>,
+}
+
+struct Watchdog {
+    /// Shared ID of subscription. This changes when the subscription is recreated.
+    subscription_id: Arc<AtomicU32>,
+    timeout: Duration,
+    /// Shared ID of heartbeat monitored item. This changes when the subscription is recreated.
+    heartbeat_id: Arc<AtomicU32>,
+    /// Values of heartbeat monitored item.
+    heartbeat: CallbackStreamReceiver<ua::DataValue>,
+    last_activity: Instant,
+    stalled: bool,
+}
+
+impl SubscriptionWatchdogs {
+    pub(crate) const fn new() -> Self {
+        Self {
+            watchdogs: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds watchdog for subscription.
+    pub(crate) fn add(
+        &self,
+        subscription_id: &Arc<AtomicU32>,
+        timeout: Duration,
+        heartbeat_id: &Arc<AtomicU32>,
+        heartbeat: CallbackStreamReceiver<ua::DataValue>,
+    ) {
+        // PANIC: We never panic while holding the lock.
+        self.watchdogs.lock().unwrap().push(Watchdog {
+            subscription_id: Arc::clone(subscription_id),
+            timeout,
+            heartbeat_id: Arc::clone(heartbeat_id),
+            heartbeat,
+            last_activity: Instant::now(),
+            stalled: false,
+        });
+    }
+
+    /// Gets shared ID of heartbeat monitored item of subscription, if it has a watchdog.
+    pub(crate) fn heartbeat_id(&self, subscription_id: &Arc<AtomicU32>) -> Option<Arc<AtomicU32>> {
+        // PANIC: We never panic while holding the lock.
+        self.watchdogs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|watchdog| Arc::ptr_eq(&watchdog.subscription_id, subscription_id))
+            .map(|watchdog| Arc::clone(&watchdog.heartbeat_id))
+    }
+
+    /// Removes watchdog of subscription.
+    pub(crate) fn remove(&self, subscription_id: &Arc<AtomicU32>) {
+        // PANIC: We never panic while holding the lock.
+        self.watchdogs
+            .lock()
+            .unwrap()
+            .retain(|watchdog| !Arc::ptr_eq(&watchdog.subscription_id, subscription_id));
+    }
+
+    /// Removes watchdog of subscription with the given server-assigned ID.
+    pub(crate) fn close(&self, subscription_id: ua::SubscriptionId) {
+        // PANIC: We never panic while holding the lock.
+        self.watchdogs.lock().unwrap().retain(|watchdog| {
+            watchdog.subscription_id.load(Ordering::Relaxed) != subscription_id.as_u32()
+        });
+    }
+
+    /// Checks heartbeats of subscriptions.
+    ///
+    /// This returns [`SubscriptionEvent::Stalled`] for subscriptions that have not received their
+    /// heartbeat within the timeout, and [`SubscriptionEvent::Resumed`] when it arrives again, with
+    /// the server-assigned ID of the subscription.
+    pub(crate) fn check(&self) -> Vec<(ua::SubscriptionId, SubscriptionEvent)> {
+        let now = Instant::now();
+        let mut events = Vec::new();
+
+        // PANIC: We never panic while holding the lock.
+        self.watchdogs.lock().unwrap().retain_mut(|watchdog| {
+            let mut active = false;
+            loop {
+                match watchdog.heartbeat.try_recv() {
+                    Poll::Ready(Some(_)) => active = true,
+                    // The heartbeat monitored item has been removed along with its subscription.
+                    Poll::Ready(None) => return false,
+                    Poll::Pending => break,
+                }
+            }
+
+            let subscription_id =
+                ua::SubscriptionId::new(watchdog.subscription_id.load(Ordering::Relaxed));
+            if active {
+                watchdog.last_activity = now;
+                if mem::take(&mut watchdog.stalled) {
+                    log::info!("Subscription {subscription_id} has resumed");
+                    events.push((subscription_id, SubscriptionEvent::Resumed));
+                }
+            } else if !watchdog.stalled
+                && now.duration_since(watchdog.last_activity) > watchdog.timeout
+            {
+                log::warn!("Subscription {subscription_id} has stalled");
+                watchdog.stalled = true;
+                events.push((subscription_id, SubscriptionEvent::Stalled));
+            }
+            true
+        });
+
+        events
+    }
+}
@@ -64,6 +64,36 @@ impl<T: DataType> DataValue<T> {
 }
 
 impl DataValue<ua::Variant> {
+    /// Converts into raw data value.
+    ///
+    /// This moves the value without cloning it. The status is omitted, i.e. good, since only good
+    /// values are represented by this type.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn into_raw_data_value(self) -> ua::DataValue {
+        let Self {
+            value,
+            source_timestamp,
+            server_timestamp,
+            source_picoseconds,
+            server_picoseconds,
+        } = self;
+
+        let mut data_value = ua::DataValue::new(value);
+        if let Some(source_timestamp) = source_timestamp {
+            data_value = data_value.with_source_timestamp(&source_timestamp);
+        }
+        if let Some(server_timestamp) = server_timestamp {
+            data_value = data_value.with_server_timestamp(&server_timestamp);
+        }
+        if let Some(source_picoseconds) = source_picoseconds {
+            data_value = data_value.with_source_picoseconds(source_picoseconds);
+        }
+        if let Some(server_picoseconds) = server_picoseconds {
+            data_value = data_value.with_server_picoseconds(server_picoseconds);
+        }
+        data_value
+    }
+
     /// Cast to specific value type.
     ///
     /// This consumes `self` and casts the inner value to the specified data type. This should be
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::ua;
+
+/// Diagnostic info returned by server.
+///
+/// Servers may return this along with failed operations to describe the failure in more detail.
+/// Unlike [`ua::DiagnosticInfo`], this holds the strings themselves instead of indices into the
+/// string table of the response. See [`Error::diagnostic_info()`](crate::Error::diagnostic_info).
+#[derive(Debug, Clone)]
+pub struct DiagnosticInfo {
+    symbolic_id: Option<String>,
+    namespace_uri: Option<String>,
+    localized_text: Option<String>,
+    locale: Option<String>,
+    additional_info: Option<String>,
+    inner_status_code: Option<ua::StatusCode>,
+    inner_diagnostic_info: Option<Box<DiagnosticInfo>>,
+}
+
+impl DiagnosticInfo {
+    /// Resolves diagnostic info with string table of response.
+    ///
+    /// This returns `None` when the diagnostic info is empty.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn new(
+        diagnostic_info: &ua::DiagnosticInfo,
+        string_table: &[ua::String],
+    ) -> Option<Self> {
+        if diagnostic_info.is_empty() {
+            return None;
+        }
+
+        // Indices that are out of range are ignored. There is nothing better to do with them.
+        let resolve = |index: Option<i32>| {
+            let index = usize::try_from(index?).ok()?;
+            string_table.get(index)?.as_str().map(str::to_owned)
+        };
+
+        Some(Self {
+            symbolic_id: resolve(diagnostic_info.symbolic_id()),
+            namespace_uri: resolve(diagnostic_info.namespace_uri()),
+            localized_text: resolve(diagnostic_info.localized_text()),
+            locale: resolve(diagnostic_info.locale()),
+            additional_info: diagnostic_info
+                .additional_info()
+                .and_then(ua::String::as_str)
+                .map(str::to_owned),
+            inner_status_code: diagnostic_info.inner_status_code(),
+            inner_diagnostic_info: diagnostic_info
+                .inner_diagnostic_info()
+                .and_then(|inner| Self::new(inner, string_table))
+                .map(Box::new),
+        })
+    }
+
+    /// Gets symbolic ID.
+    ///
+    /// This is a vendor-specific identifier of the error condition.
+    #[must_use]
+    pub fn symbolic_id(&self) -> Option<&str> {
+        self.symbolic_id.as_deref()
+    }
+
+    /// Gets namespace URI that the symbolic ID is defined in.
+    #[must_use]
+    pub fn namespace_uri(&self) -> Option<&str> {
+        self.namespace_uri.as_deref()
+    }
+
+    /// Gets human-readable description of the error condition.
+    #[must_use]
+    pub fn localized_text(&self) -> Option<&str> {
+        self.localized_text.as_deref()
+    }
+
+    /// Gets locale of localized text.
+    #[must_use]
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// Gets additional (vendor-specific) information.
+    #[must_use]
+    pub fn additional_info(&self) -> Option<&str> {
+        self.additional_info.as_deref()
+    }
+
+    /// Gets status code from underlying system.
+    #[must_use]
+    pub const fn inner_status_code(&self) -> Option<&ua::StatusCode> {
+        self.inner_status_code.as_ref()
+    }
+
+    /// Gets diagnostic info associated with inner status code.
+    #[must_use]
+    pub fn inner_diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        self.inner_diagnostic_info.as_deref()
+    }
+}
+
+impl fmt::Display for DiagnosticInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(localized_text) = &self.localized_text {
+            parts.push(localized_text.clone());
+        }
+        if let Some(symbolic_id) = &self.symbolic_id {
+            parts.push(format!("symbolic ID {symbolic_id}"));
+        }
+        if let Some(additional_info) = &self.additional_info {
+            parts.push(additional_info.clone());
+        }
+        if let Some(inner_status_code) = &self.inner_status_code {
+            parts.push(format!("inner status {inner_status_code}"));
+        }
+        if let Some(inner_diagnostic_info) = &self.inner_diagnostic_info {
+            parts.push(format!("({inner_diagnostic_info})"));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    use super::DiagnosticInfo;
+
+    #[test]
+    fn resolve_strings() {
+        let string_table = [
+            ua::String::new("BadThing").unwrap(),
+            ua::String::new("Something went wrong").unwrap(),
+        ];
+
+        let mut raw = ua::DiagnosticInfo::init().into_raw();
+        raw.set_hasSymbolicId(true);
+        raw.symbolicId = 0;
+        raw.set_hasLocalizedText(true);
+        raw.localizedText = 1;
+        // Out-of-range indices are ignored.
+        raw.set_hasNamespaceUri(true);
+        raw.namespaceUri = 2;
+        raw.set_hasInnerStatusCode(true);
+        raw.innerStatusCode = ua::StatusCode::BADINTERNALERROR.code();
+        // SAFETY: We only set fields without dynamic allocations.
+        let diagnostic_info = unsafe { ua::DiagnosticInfo::from_raw(raw) };
+
+        assert_eq!(diagnostic_info.symbolic_id(), Some(0));
+        assert_eq!(diagnostic_info.namespace_uri(), Some(2));
+        assert_eq!(diagnostic_info.locale(), None);
+
+        let resolved =
+            DiagnosticInfo::new(&diagnostic_info, &string_table).expect("should not be empty");
+        assert_eq!(resolved.symbolic_id(), Some("BadThing"));
+        assert_eq!(resolved.localized_text(), Some("Something went wrong"));
+        assert_eq!(resolved.namespace_uri(), None);
+        assert_eq!(
+            resolved.inner_status_code(),
+            Some(&ua::StatusCode::BADINTERNALERROR)
+        );
+        assert!(resolved.inner_diagnostic_info().is_none());
+        assert_eq!(
+            resolved.to_string(),
+            "Something went wrong, symbolic ID BadThing, inner status BadInternalError"
+        );
+    }
+
+    #[test]
+    fn attach_to_server_error() {
+        use crate::Error;
+
+        let mut raw = ua::DiagnosticInfo::init().into_raw();
+        raw.set_hasInnerStatusCode(true);
+        raw.innerStatusCode = ua::StatusCode::BADINTERNALERROR.code();
+        // SAFETY: We only set fields without dynamic allocations.
+        let diagnostic_info = unsafe { ua::DiagnosticInfo::from_raw(raw) };
+        let diagnostic_info = DiagnosticInfo::new(&diagnostic_info, &[]);
+
+        let error =
+            Error::new(ua::StatusCode::BADNODEIDUNKNOWN).with_diagnostic_info(diagnostic_info);
+        let Error::Diagnostic(status_code, _) = &error else {
+            panic!("should be server error with diagnostic info");
+        };
+        assert_eq!(status_code, &ua::StatusCode::BADNODEIDUNKNOWN);
+        assert_eq!(
+            error
+                .diagnostic_info()
+                .and_then(DiagnosticInfo::inner_status_code),
+            Some(&ua::StatusCode::BADINTERNALERROR)
+        );
+        assert_eq!(
+            error.to_string(),
+            "BadNodeIdUnknown: inner status BadInternalError"
+        );
+    }
+
+    #[test]
+    fn skip_empty() {
+        let diagnostic_info = ua::DiagnosticInfo::init();
+        assert!(diagnostic_info.is_empty());
+        assert!(DiagnosticInfo::new(&diagnostic_info, &[]).is_none());
+    }
+}
@@ -1,6 +1,8 @@
+use std::{convert::Infallible, ffi::CString, fmt};
+
 use thiserror::Error;
 
-use crate::ua;
+use crate::{ua, DataType, DiagnosticInfo, ValueType};
 
 /// Result type used in this crate.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -13,14 +15,70 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// [`is_good()`]: crate::ua::StatusCode::is_good
 #[derive(Debug, Error)]
 #[allow(clippy::error_impl_error)] // The main error type of our crate may be named `Error`.
+#[non_exhaustive]
 pub enum Error {
     /// Error from server.
     #[error("{0}")]
     Server(ua::StatusCode),
 
+    /// Error from server, with diagnostic info.
+    ///
+    /// This is returned instead of [`Error::Server`] when the server provided diagnostic info for
+    /// the failed operation, see [`Error::diagnostic_info()`]. Use [`Error::status_code()`] to
+    /// handle both variants alike.
+    #[error("{0}: {1}")]
+    Diagnostic(ua::StatusCode, Box<DiagnosticInfo>),
+
     /// Internal error.
     #[error("{0}")]
     Internal(&'static str),
+
+    /// String argument contains NUL byte.
+    ///
+    /// Strings are passed to `open62541` as C strings which cannot represent interior NUL bytes.
+    /// The value names the offending argument.
+    #[error("{0} should not contain NUL bytes")]
+    NulByte(&'static str),
+
+    /// Malformed index range.
+    ///
+    /// This is returned when parsing [`ua::NumericRange`], before any request is sent. The value
+    /// describes the problem.
+    #[error("invalid index range: {0}")]
+    IndexRange(&'static str),
+
+    /// Error when writing node value.
+    ///
+    /// This carries the node, the type of the value that was attempted to be written, and the
+    /// reason of the failure.
+    #[error("{0}")]
+    Write(Box<WriteError>),
+
+    /// Browse path that was resolved only partially.
+    ///
+    /// This carries the last node that was reached and the remaining path.
+    #[error("{0}")]
+    BrowsePath(Box<BrowsePathError>),
+
+    /// Error when calling method.
+    ///
+    /// This carries the method, the reason of the failure, and the results of the individual input
+    /// arguments when the server rejected some of them.
+    #[error("{0}")]
+    Call(Box<CallError>),
+
+    /// Value of unexpected type.
+    ///
+    /// This carries the node, the expected data type, and the type of the value that was read.
+    #[error("{0}")]
+    TypeMismatch(Box<TypeMismatchError>),
+
+    /// Optional feature not supported by server.
+    ///
+    /// This is returned when the server does not implement an optional service or method. The
+    /// value names the missing feature.
+    #[error("{0} is not supported by server")]
+    Unsupported(&'static str),
 }
 
 impl Error {
@@ -45,13 +103,65 @@ impl Error {
     /// Gets associated OPC UA status code.
     ///
     /// This returns the original status code except for internal errors where the generic status
-    /// code [`ua::StatusCode::BAD`] is returned instead.
+    /// code [`ua::StatusCode::BAD`] is returned instead, for invalid strings where
+    /// [`ua::StatusCode::BADINVALIDARGUMENT`] is returned, and for invalid index ranges where
+    /// [`ua::StatusCode::BADINDEXRANGEINVALID`] is returned. For write errors, browse path errors
+    /// and call errors, this is the status code of the [`WriteError`], [`BrowsePathError`] or
+    /// [`CallError`]. For values of unexpected type, [`ua::StatusCode::BADTYPEMISMATCH`] is
+    /// returned, and for unsupported features, [`ua::StatusCode::BADNOTSUPPORTED`].
     #[must_use]
     pub fn status_code(&self) -> ua::StatusCode {
         match self {
             // TODO: Avoid clone and make `ua::StatusCode` derive `Copy`.
-            Error::Server(status_code) => status_code.clone(),
+            Error::Server(status_code) | Error::Diagnostic(status_code, _) => status_code.clone(),
             Error::Internal(_) => ua::StatusCode::BAD,
+            Error::NulByte(_) => ua::StatusCode::BADINVALIDARGUMENT,
+            Error::IndexRange(_) => ua::StatusCode::BADINDEXRANGEINVALID,
+            Error::Write(error) => error.status_code().clone(),
+            Error::BrowsePath(error) => error.status_code().clone(),
+            Error::Call(error) => error.status_code().clone(),
+            Error::TypeMismatch(_) => ua::StatusCode::BADTYPEMISMATCH,
+            Error::Unsupported(_) => ua::StatusCode::BADNOTSUPPORTED,
+        }
+    }
+
+    /// Gets diagnostic info.
+    ///
+    /// Servers return diagnostic info only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`]. This is available for errors of individual
+    /// operations, such as [`Error::Diagnostic`], [`Error::Write`] and [`Error::Call`].
+    #[must_use]
+    pub fn diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        match self {
+            Error::Diagnostic(_, diagnostic_info) => Some(diagnostic_info),
+            Error::Write(error) => error.diagnostic_info(),
+            Error::Call(error) => error.diagnostic_info(),
+            _ => None,
+        }
+    }
+
+    /// Attaches diagnostic info, if any.
+    ///
+    /// Errors without status code from the server are returned unchanged.
+    #[allow(dead_code)] // --no-default-features
+    #[must_use]
+    pub(crate) fn with_diagnostic_info(self, diagnostic_info: Option<DiagnosticInfo>) -> Self {
+        let Some(diagnostic_info) = diagnostic_info else {
+            return self;
+        };
+        match self {
+            Error::Server(status_code) | Error::Diagnostic(status_code, _) => {
+                Error::Diagnostic(status_code, Box::new(diagnostic_info))
+            }
+            Error::Write(mut error) => {
+                error.diagnostic_info = Some(diagnostic_info);
+                Error::Write(error)
+            }
+            Error::Call(mut error) => {
+                error.diagnostic_info = Some(diagnostic_info);
+                Error::Call(error)
+            }
+            error => error,
         }
     }
 
@@ -60,4 +170,380 @@ impl Error {
     pub(crate) const fn internal(message: &'static str) -> Self {
         Self::Internal(message)
     }
+
+    /// Converts string slice into C string.
+    ///
+    /// All strings that are passed to `open62541` as C strings go through this helper. Interior NUL
+    /// bytes cannot be represented and are rejected with [`Error::NulByte`] naming `argument`.
+    pub(crate) fn c_string(s: &str, argument: &'static str) -> Result<CString> {
+        CString::new(s).map_err(|_| Self::NulByte(argument))
+    }
 }
+
+impl From<Infallible> for Error {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+/// Details of failed write of node value.
+///
+/// See [`Error::Write`].
+#[derive(Debug, Clone)]
+pub struct WriteError {
+    node_id: ua::NodeId,
+    value_type: Option<ValueType>,
+    status_code: ua::StatusCode,
+    reason: Option<String>,
+    diagnostic_info: Option<DiagnosticInfo>,
+}
+
+impl WriteError {
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) const fn new(
+        node_id: ua::NodeId,
+        value_type: Option<ValueType>,
+        status_code: ua::StatusCode,
+    ) -> Self {
+        Self {
+            node_id,
+            value_type,
+            status_code,
+            reason: None,
+            diagnostic_info: None,
+        }
+    }
+
+    #[allow(dead_code)] // --no-default-features
+    #[must_use]
+    pub(crate) fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Gets node that was written to.
+    #[must_use]
+    pub const fn node_id(&self) -> &ua::NodeId {
+        &self.node_id
+    }
+
+    /// Gets type of value that was attempted to be written.
+    ///
+    /// Returns `None` when the value was empty.
+    #[must_use]
+    pub const fn value_type(&self) -> Option<&ValueType> {
+        self.value_type.as_ref()
+    }
+
+    /// Gets status code.
+    ///
+    /// This is the status code returned by the server, e.g. [`ua::StatusCode::BADTYPEMISMATCH`] or
+    /// [`ua::StatusCode::BADNOTWRITABLE`], or the status code of a failed client-side check.
+    #[must_use]
+    pub const fn status_code(&self) -> &ua::StatusCode {
+        &self.status_code
+    }
+
+    /// Gets description of failed client-side check.
+    ///
+    /// Returns `None` when the write was rejected by the server.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Gets diagnostic info returned by the server.
+    #[must_use]
+    pub const fn diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        self.diagnostic_info.as_ref()
+    }
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            node_id,
+            value_type,
+            status_code,
+            reason,
+            diagnostic_info,
+        } = self;
+
+        match value_type {
+            Some(value_type) => write!(f, "writing {value_type:?} value to {node_id} failed")?,
+            None => write!(f, "writing empty value to {node_id} failed")?,
+        }
+        write!(f, " with {status_code}")?;
+        if let Some(reason) = reason {
+            write!(f, ": {reason}")?;
+        }
+        if let Some(diagnostic_info) = diagnostic_info {
+            write!(f, ": {diagnostic_info}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WriteError {}
+
+/// Details of partially resolved browse path.
+///
+/// See [`Error::BrowsePath`].
+#[derive(Debug, Clone)]
+pub struct BrowsePathError {
+    status_code: ua::StatusCode,
+    target_id: ua::ExpandedNodeId,
+    remaining_path: Vec<ua::QualifiedName>,
+}
+
+impl BrowsePathError {
+    pub(crate) const fn new(
+        status_code: ua::StatusCode,
+        target_id: ua::ExpandedNodeId,
+        remaining_path: Vec<ua::QualifiedName>,
+    ) -> Self {
+        Self {
+            status_code,
+            target_id,
+            remaining_path,
+        }
+    }
+
+    /// Gets status code of the translation.
+    ///
+    /// This is usually [`ua::StatusCode::UNCERTAINREFERENCEOUTOFSERVER`] when the path continues
+    /// on another server.
+    #[must_use]
+    pub const fn status_code(&self) -> &ua::StatusCode {
+        &self.status_code
+    }
+
+    /// Gets last node that the path was resolved to.
+    ///
+    /// This may refer to a node on another server, see [`ua::ExpandedNodeId::server_index()`].
+    #[must_use]
+    pub const fn target_id(&self) -> &ua::ExpandedNodeId {
+        &self.target_id
+    }
+
+    /// Gets target names of path elements that remain to be resolved from [`target_id()`].
+    ///
+    /// [`target_id()`]: Self::target_id
+    #[must_use]
+    pub fn remaining_path(&self) -> &[ua::QualifiedName] {
+        &self.remaining_path
+    }
+}
+
+impl fmt::Display for BrowsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            status_code,
+            target_id,
+            remaining_path,
+        } = self;
+
+        write!(f, "browse path resolved only up to {}", target_id.node_id())?;
+        if target_id.server_index() != 0 {
+            write!(f, " on server {}", target_id.server_index())?;
+        }
+        write!(f, " with {status_code}, remaining path:")?;
+        for target_name in remaining_path {
+            write!(f, " /{target_name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BrowsePathError {}
+
+/// Details of failed method call.
+///
+/// See [`Error::Call`].
+#[derive(Debug, Clone)]
+pub struct CallError {
+    method_id: ua::NodeId,
+    status_code: ua::StatusCode,
+    input_argument_results: Vec<ua::StatusCode>,
+    diagnostic_info: Option<DiagnosticInfo>,
+}
+
+impl CallError {
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) const fn new(
+        method_id: ua::NodeId,
+        status_code: ua::StatusCode,
+        input_argument_results: Vec<ua::StatusCode>,
+    ) -> Self {
+        Self {
+            method_id,
+            status_code,
+            input_argument_results,
+            diagnostic_info: None,
+        }
+    }
+
+    /// Gets method that was called.
+    #[must_use]
+    pub const fn method_id(&self) -> &ua::NodeId {
+        &self.method_id
+    }
+
+    /// Gets status code.
+    ///
+    /// This is the status code returned by the server, e.g. [`ua::StatusCode::BADINVALIDARGUMENT`]
+    /// when some of the input arguments were rejected.
+    #[must_use]
+    pub const fn status_code(&self) -> &ua::StatusCode {
+        &self.status_code
+    }
+
+    /// Gets results of input arguments.
+    ///
+    /// The size and order of this list matches the size and order of the input arguments. This is
+    /// empty when the server did not return results of individual input arguments, which it does
+    /// only when rejecting some of them.
+    #[must_use]
+    pub fn input_argument_results(&self) -> &[ua::StatusCode] {
+        &self.input_argument_results
+    }
+
+    /// Gets index of first rejected input argument.
+    ///
+    /// Returns `None` when the server did not return results of individual input arguments.
+    #[must_use]
+    pub fn argument_index(&self) -> Option<usize> {
+        self.rejected_arguments().next().map(|(index, _)| index)
+    }
+
+    /// Gets indices of rejected input arguments, along with their status codes.
+    pub fn rejected_arguments(&self) -> impl Iterator<Item = (usize, &ua::StatusCode)> {
+        self.input_argument_results
+            .iter()
+            .enumerate()
+            .filter(|(_, status_code)| !status_code.is_good())
+    }
+
+    /// Gets diagnostic info returned by the server.
+    #[must_use]
+    pub const fn diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        self.diagnostic_info.as_ref()
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            method_id,
+            status_code,
+            input_argument_results: _,
+            diagnostic_info,
+        } = self;
+
+        write!(f, "calling method {method_id} failed with {status_code}")?;
+        for (index, status_code) in self.rejected_arguments() {
+            write!(f, ", argument {index} rejected with {status_code}")?;
+        }
+        if let Some(diagnostic_info) = diagnostic_info {
+            write!(f, ": {diagnostic_info}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CallError {}
+
+/// Details of value of unexpected type.
+///
+/// See [`Error::TypeMismatch`].
+#[derive(Debug, Clone)]
+pub struct TypeMismatchError {
+    node_id: ua::NodeId,
+    expected_type_id: ua::NodeId,
+    expected_array: bool,
+    value_type: Option<ValueType>,
+    is_array: bool,
+}
+
+impl TypeMismatchError {
+    /// Creates error for value that is not a scalar (or array) of type `T`.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn new<T: DataType>(
+        node_id: ua::NodeId,
+        value: &ua::Variant,
+        expected_array: bool,
+    ) -> Self {
+        // SAFETY: Data types of `DataType` implementations are statically allocated.
+        // PANIC: The pointer to the data type is never null.
+        let data_type = unsafe { T::data_type().as_ref() }.expect("data type should be set");
+        Self {
+            node_id,
+            expected_type_id: ua::NodeId::raw_ref(&data_type.typeId).clone(),
+            expected_array,
+            value_type: value.value_type(),
+            is_array: !value.is_empty() && !value.is_scalar(),
+        }
+    }
+
+    /// Gets node that was read from.
+    #[must_use]
+    pub const fn node_id(&self) -> &ua::NodeId {
+        &self.node_id
+    }
+
+    /// Gets node ID of expected data type.
+    #[must_use]
+    pub const fn expected_type_id(&self) -> &ua::NodeId {
+        &self.expected_type_id
+    }
+
+    /// Gets whether an array value was expected.
+    ///
+    /// Returns `false` when a scalar value was expected.
+    #[must_use]
+    pub const fn expected_array(&self) -> bool {
+        self.expected_array
+    }
+
+    /// Gets type of value that was read.
+    ///
+    /// Returns `None` when the value was empty.
+    #[must_use]
+    pub const fn value_type(&self) -> Option<&ValueType> {
+        self.value_type.as_ref()
+    }
+
+    /// Gets whether the value that was read is an array.
+    ///
+    /// Returns `false` when the value was a scalar or empty.
+    #[must_use]
+    pub const fn is_array(&self) -> bool {
+        self.is_array
+    }
+}
+
+impl fmt::Display for TypeMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            node_id,
+            expected_type_id,
+            expected_array,
+            value_type,
+            is_array,
+        } = self;
+
+        write!(f, "reading {node_id} returned ")?;
+        match (value_type, is_array) {
+            (Some(value_type), false) => write!(f, "scalar {value_type:?} value")?,
+            (Some(value_type), true) => write!(f, "array of {value_type:?} values")?,
+            (None, _) => write!(f, "empty value")?,
+        }
+        if *expected_array {
+            write!(f, ", expected array of data type {expected_type_id}")
+        } else {
+            write!(f, ", expected scalar of data type {expected_type_id}")
+        }
+    }
+}
+
+impl std::error::Error for TypeMismatchError {}
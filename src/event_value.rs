@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::{ua, DataType};
+
+/// Fields of event, by name of their select clause.
+///
+/// This is received from monitored items of events, see [`AsyncMonitoredItem::next_event()`]. The
+/// fields are named after the browse paths of the select clauses in the [`ua::EventFilter`], with
+/// path elements joined by `/`, e.g. `Message` or `EnabledState/Id`.
+///
+/// [`AsyncMonitoredItem::next_event()`]: crate::AsyncMonitoredItem::next_event
+#[derive(Debug, Clone)]
+pub struct EventValue {
+    names: Arc<[String]>,
+    fields: Vec<ua::Variant>,
+}
+
+impl EventValue {
+    pub(crate) const fn new(names: Arc<[String]>, fields: Vec<ua::Variant>) -> Self {
+        Self { names, fields }
+    }
+
+    /// Gets names of select clauses of event filter.
+    ///
+    /// The names are in the order of the select clauses.
+    pub(crate) fn field_names(filter: &ua::EventFilter) -> Arc<[String]> {
+        filter
+            .select_clauses()
+            .unwrap_or_default()
+            .iter()
+            .map(|select_clause| {
+                select_clause
+                    .browse_path()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|name| name.name().as_str().unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .collect()
+    }
+
+    /// Gets field by name.
+    ///
+    /// This returns `None` when the name does not match any select clause, or when the server did
+    /// not return a value for it.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&ua::Variant> {
+        let index = self
+            .names
+            .iter()
+            .position(|field_name| field_name == name)?;
+        self.fields.get(index).filter(|field| !field.is_empty())
+    }
+
+    /// Gets scalar field by name, with the given data type.
+    ///
+    /// This returns `None` when the field does not exist or is not a scalar of the given type.
+    #[must_use]
+    pub fn field_as<T: DataType>(&self, name: &str) -> Option<&T> {
+        self.field(name)?.as_scalar::<T>()
+    }
+
+    /// Gets all fields, in the order of the select clauses.
+    #[must_use]
+    pub fn fields(&self) -> &[ua::Variant] {
+        &self.fields
+    }
+
+    /// Iterates over names and fields, in the order of the select clauses.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ua::Variant)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.fields.iter())
+    }
+
+    /// Turns event into its fields, in the order of the select clauses.
+    #[must_use]
+    pub fn into_fields(self) -> Vec<ua::Variant> {
+        self.fields
+    }
+}
@@ -0,0 +1,157 @@
+use tokio::task;
+
+use crate::{ua, AsyncMonitoredItem, Error, Result, Server};
+
+/// How [`forward()`] handles failed writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForwardErrorPolicy {
+    /// Log error and continue with next value.
+    Skip,
+    /// Stop forwarding and return error.
+    Stop,
+}
+
+/// Forwards values of monitored item into server node.
+///
+/// This writes each value received for `monitored_item` into the value attribute of `node_id` on
+/// `server`. Source timestamp and status are passed on as received, the server timestamp is set by
+/// `server`. Values are written one at a time: while a write is in progress, new values are queued
+/// in the monitored item.
+///
+/// Writing into the server blocks while the server is busy, so writes are done on the blocking
+/// thread pool of the runtime (see [`tokio::task::spawn_blocking()`]).
+///
+/// This completes when the monitored item has been closed, e.g. when its subscription has been
+/// deleted or the client has been disconnected.
+///
+/// # Examples
+///
+/// ```no_run
+/// use open62541::{forward, ua, AsyncClient, ForwardErrorPolicy, Server};
+///
+/// # async fn example(client: &AsyncClient, server: &Server) -> anyhow::Result<()> {
+/// let subscription = client.create_subscription().await?;
+/// let monitored_item = subscription
+///     .create_monitored_item(&ua::NodeId::numeric(1, 1234))
+///     .await?;
+///
+/// forward(
+///     monitored_item,
+///     server,
+///     &ua::NodeId::numeric(1, 5678),
+///     ForwardErrorPolicy::Skip,
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// With [`ForwardErrorPolicy::Stop`], this fails when a value cannot be written.
+pub async fn forward(
+    mut monitored_item: AsyncMonitoredItem,
+    server: &Server,
+    node_id: &ua::NodeId,
+    error_policy: ForwardErrorPolicy,
+) -> Result<()> {
+    while let Some(value) = monitored_item.next().await {
+        let write = task::spawn_blocking({
+            let server = server.clone();
+            let node_id = node_id.clone();
+            move || server.write_data_value(&node_id, &value.without_server_timestamp())
+        });
+        let Err(error) = write
+            .await
+            .unwrap_or_else(|_| Err(Error::internal("forwarding write should not panic")))
+        else {
+            continue;
+        };
+
+        match error_policy {
+            ForwardErrorPolicy::Skip => {
+                log::warn!("Skipping value that cannot be forwarded to {node_id}: {error}");
+            }
+            ForwardErrorPolicy::Stop => return Err(error),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{forward, ua, DataType as _, ForwardErrorPolicy, Node, Server, TestHarness};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn forward_values() {
+        let add_node = |server: &Server, name: &str| {
+            let node_id = server
+                .add_node(Node::new(
+                    ua::NodeId::ns0_objects_folder(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, name),
+                    ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(-1)
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+                ))
+                .expect("should add node");
+            server
+                .write_value(&node_id, &ua::Variant::scalar(ua::Int32::new(0)))
+                .expect("should write initial value");
+            node_id
+        };
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let server = harness.server();
+        let source_node_id = add_node(server, "Source");
+        let target_node_id = add_node(server, "Target");
+
+        let subscription = harness.client().create_subscription().await.unwrap();
+        let monitored_item = subscription
+            .create_monitored_item(&source_node_id)
+            .await
+            .unwrap();
+
+        let forwarding = tokio::spawn({
+            let server = server.clone();
+            let target_node_id = target_node_id.clone();
+            async move {
+                forward(
+                    monitored_item,
+                    &server,
+                    &target_node_id,
+                    ForwardErrorPolicy::Stop,
+                )
+                .await
+            }
+        });
+
+        server
+            .write_value(&source_node_id, &ua::Variant::scalar(ua::Int32::new(42)))
+            .unwrap();
+
+        let mut forwarded = None;
+        for _ in 0..50 {
+            let value = server
+                .read_attribute(&target_node_id, ua::AttributeId::VALUE_T)
+                .unwrap();
+            forwarded = value.value().as_scalar::<ua::Int32>().map(ua::Int32::value);
+            if forwarded == Some(42) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(forwarded, Some(42));
+
+        // Deleting the subscription closes the monitored item and ends forwarding.
+        drop(subscription);
+        let result = forwarding.await.expect("should not panic");
+        assert!(result.is_ok());
+
+        harness.shutdown().await.unwrap();
+    }
+}
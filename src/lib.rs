@@ -105,10 +105,6 @@
 //! ```
 //! # use open62541::Server;
 //! use open62541::{ObjectNode, ua, VariableNode};
-//! use open62541_sys::{
-//!     UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_FOLDERTYPE, UA_NS0ID_OBJECTSFOLDER,
-//!     UA_NS0ID_ORGANIZES, UA_NS0ID_STRING,
-//! };
 //!
 //! # #[tokio::main]
 //! # async fn main() -> anyhow::Result<()> {
@@ -116,21 +112,21 @@
 //! #
 //! let object_node_id = server.add_object_node(ObjectNode {
 //!     requested_new_node_id: None,
-//!     parent_node_id: ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-//!     reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+//!     parent_node_id: ua::NodeId::ns0_objects_folder(),
+//!     reference_type_id: ua::NodeId::ns0_organizes(),
 //!     browse_name: ua::QualifiedName::new(1, "SomeFolder"),
-//!     type_definition: ua::NodeId::ns0(UA_NS0ID_FOLDERTYPE),
+//!     type_definition: ua::NodeId::ns0_folder_type(),
 //!     attributes: ua::ObjectAttributes::default(),
 //! })?;
 //!
 //! let variable_node_id = server.add_variable_node(VariableNode {
 //!     requested_new_node_id: None,
 //!     parent_node_id: object_node_id,
-//!     reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+//!     reference_type_id: ua::NodeId::ns0_organizes(),
 //!     browse_name: ua::QualifiedName::new(1, "SomeVariable"),
-//!     type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
+//!     type_definition: ua::NodeId::ns0_base_data_variable_type(),
 //!     attributes: ua::VariableAttributes::default()
-//!         .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING)),
+//!         .with_data_type(&ua::NodeId::ns0_string()),
 //! })?;
 //!
 //! server.write_value(
@@ -155,10 +151,6 @@
 //! ```
 //! # use open62541::{ObjectNode, Server, ua, VariableNode};
 //! use open62541::{DataSource, DataSourceReadContext, DataSourceResult, DataSourceWriteContext};
-//! # use open62541_sys::{
-//! #     UA_NS0ID_BASEDATAVARIABLETYPE, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_ORGANIZES,
-//! #     UA_NS0ID_STRING,
-//! # };
 //!
 //! struct SomeDataSource {
 //!     some_value: u32,
@@ -182,16 +174,16 @@
 //! # async fn main() -> anyhow::Result<()> {
 //! # let (server, runner) = Server::new();
 //! #
-//! # let object_node_id = ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER);
+//! # let object_node_id = ua::NodeId::ns0_objects_folder();
 //! #
 //! let variable_node = VariableNode {
 //!     requested_new_node_id: None,
 //!     parent_node_id: object_node_id,
-//!     reference_type_id: ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+//!     reference_type_id: ua::NodeId::ns0_organizes(),
 //!     browse_name: ua::QualifiedName::new(1, "SomeVariable"),
-//!     type_definition: ua::NodeId::ns0(UA_NS0ID_BASEDATAVARIABLETYPE),
+//!     type_definition: ua::NodeId::ns0_base_data_variable_type(),
 //!     attributes: ua::VariableAttributes::default()
-//!         .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING))
+//!         .with_data_type(&ua::NodeId::ns0_string())
 //!         .with_access_level(
 //!             &ua::AccessLevel::NONE
 //!                 .with_current_read(true)
@@ -221,36 +213,58 @@ mod callback;
 mod client;
 mod data_type;
 mod data_value;
+mod diagnostic_info;
 mod error;
+#[cfg(feature = "tokio")]
+mod event_value;
+#[cfg(feature = "tokio")]
+mod forward;
 mod server;
 mod service;
 #[cfg(feature = "mbedtls")]
 mod ssl;
+#[cfg(all(any(test, feature = "test-util"), feature = "tokio"))]
+mod testing;
 mod traits;
 pub mod ua;
 mod userdata;
 mod value;
 
+#[cfg(feature = "test-util")]
+pub use self::server::{AddressSpaceSnapshot, Difference, NodeSnapshot};
 #[cfg(feature = "mbedtls")]
 pub use self::ssl::{create_certificate, Certificate, PrivateKey};
+#[cfg(all(any(test, feature = "test-util"), feature = "tokio"))]
+pub use self::testing::TestHarness;
 #[cfg(feature = "tokio")]
 pub use self::{
-    async_client::AsyncClient,
-    async_monitored_item::{AsyncMonitoredItem, MonitoredItemBuilder},
-    async_subscription::{AsyncSubscription, SubscriptionBuilder},
-    callback::{CallbackOnce, CallbackStream},
+    async_client::{AsyncClient, ReadOptions, ServiceCall},
+    async_monitored_item::{
+        AsyncMonitoredItem, MonitoredItemBuilder, MonitoredItemCreateFailure, MonitoredItemsSummary,
+    },
+    async_subscription::{AsyncSubscription, PublishBatch, SubscriptionBuilder, SubscriptionEvent},
+    callback::{
+        CallbackOnce, CallbackStream, CallbackStreamReceiver, CallbackStreamSender, StreamOverflow,
+    },
+    client::ReconnectPolicy,
+    event_value::EventValue,
+    forward::{forward, ForwardErrorPolicy},
+    server::AsyncDataSource,
 };
 pub use self::{
     browse_result::BrowseResult,
     client::{Client, ClientBuilder},
     data_type::DataType,
     data_value::DataValue,
-    error::{Error, Result},
+    diagnostic_info::DiagnosticInfo,
+    error::{BrowsePathError, CallError, Error, Result, TypeMismatchError, WriteError},
     server::{
-        AccessControl, DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+        AccessControl, AsyncMethodCallback, AsyncMethodCallbackResult, AsyncOperationHandle,
+        DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
         DataSourceWriteContext, DefaultAccessControl, DefaultAccessControlWithLoginCallback,
-        MethodCallback, MethodCallbackContext, MethodCallbackError, MethodCallbackResult,
-        MethodNode, Node, ObjectNode, Server, ServerBuilder, ServerRunner, VariableNode,
+        EventBuilder, Login, MethodCallback, MethodCallbackContext, MethodCallbackError,
+        MethodCallbackResult, MethodNode, Node, ObjectNode, ObjectTypeNode, Server, ServerBuilder,
+        ServerMetrics, ServerRunner, ValueCallback, VariableNode, VariableTypeNode, WeakServer,
     },
     traits::{
         Attribute, Attributes, CustomCertificateVerification, FilterOperand, MonitoringFilter,
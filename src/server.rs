@@ -1,51 +1,81 @@
 mod access_control;
+#[cfg(feature = "tokio")]
+mod async_data_source;
+mod async_operation;
 mod data_source;
+mod event_builder;
 mod method_callback;
+mod metrics;
 mod node_context;
 mod node_types;
+mod server_context;
+#[cfg(feature = "test-util")]
+mod snapshot;
+mod value_callback;
 
 use std::{
     any::Any,
-    ffi::{c_void, CString},
+    collections::HashSet,
+    ffi::c_void,
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
     ptr,
-    sync::Arc,
-    time::Instant,
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
 };
 
+use futures_channel::oneshot;
 use open62541_sys::{
-    UA_CertificateVerification_AcceptAll, UA_NodeId, UA_Server, UA_ServerConfig,
+    UA_CertificateVerification_AcceptAll, UA_MethodCallback, UA_NodeId, UA_Server, UA_ServerConfig,
     UA_Server_addDataSourceVariableNode, UA_Server_addMethodNodeEx, UA_Server_addNamespace,
-    UA_Server_addReference, UA_Server_browse, UA_Server_browseNext, UA_Server_browseRecursive,
-    UA_Server_browseSimplifiedBrowsePath, UA_Server_createEvent, UA_Server_deleteNode,
-    UA_Server_deleteReference, UA_Server_getNamespaceByIndex, UA_Server_getNamespaceByName,
-    UA_Server_read, UA_Server_readObjectProperty, UA_Server_runUntilInterrupt,
+    UA_Server_addReference, UA_Server_addTimedCallback, UA_Server_browse, UA_Server_browseNext,
+    UA_Server_browseRecursive, UA_Server_browseSimplifiedBrowsePath, UA_Server_createEvent,
+    UA_Server_deleteNode, UA_Server_deleteReference, UA_Server_getNamespaceByIndex,
+    UA_Server_getNamespaceByName, UA_Server_getNodeContext, UA_Server_read,
+    UA_Server_readObjectProperty, UA_Server_runUntilInterrupt, UA_Server_setMethodNodeAsync,
+    UA_Server_setNodeContext, UA_Server_setVariableNode_valueCallback,
     UA_Server_translateBrowsePathToNodeIds, UA_Server_triggerEvent, UA_Server_writeDataValue,
-    UA_Server_writeObjectProperty, UA_Server_writeValue, __UA_Server_addNode,
+    UA_Server_writeObjectProperty, UA_Server_writeValue, __UA_Server_addNode, __UA_Server_write,
     UA_STATUSCODE_BADNOTFOUND,
 };
 
 use crate::{
-    ua, Attribute, Attributes, BrowseResult, DataType, DataValue, Error, Result,
+    ua, Attribute, Attributes, BrowseResult, DataType, DataValue, Error, Result, Userdata,
     DEFAULT_PORT_NUMBER,
 };
 
-pub(crate) use self::node_context::NodeContext;
+#[cfg(feature = "tokio")]
+pub use self::async_data_source::AsyncDataSource;
+#[cfg(feature = "test-util")]
+pub use self::snapshot::{AddressSpaceSnapshot, Difference, NodeSnapshot};
 pub use self::{
-    access_control::{AccessControl, DefaultAccessControl, DefaultAccessControlWithLoginCallback},
+    access_control::{
+        AccessControl, DefaultAccessControl, DefaultAccessControlWithLoginCallback, Login,
+    },
+    async_operation::AsyncOperationHandle,
     data_source::{
         DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
         DataSourceWriteContext,
     },
+    event_builder::EventBuilder,
     method_callback::{
-        MethodCallback, MethodCallbackContext, MethodCallbackError, MethodCallbackResult,
+        AsyncMethodCallback, AsyncMethodCallbackResult, MethodCallback, MethodCallbackContext,
+        MethodCallbackError, MethodCallbackResult,
     },
-    node_types::{MethodNode, Node, ObjectNode, VariableNode},
+    metrics::ServerMetrics,
+    node_types::{MethodNode, Node, ObjectNode, ObjectTypeNode, VariableNode, VariableTypeNode},
+    value_callback::ValueCallback,
 };
+pub(crate) use self::{node_context::NodeContext, server_context::ServerContext};
 
 /// Builder for [`Server`].
 ///
 /// Use this to specify additional options when building an OPC UA server.
 ///
+/// Methods that take strings panic when these contain NUL bytes. Each of them has a fallible
+/// variant prefixed with `try_` that returns [`Error::NulByte`] instead, e.g.
+/// [`try_server_urls()`](Self::try_server_urls). Use these for user-supplied input.
+///
 /// # Examples
 ///
 /// ```
@@ -68,6 +98,9 @@ pub struct ServerBuilder {
     /// [`AccessControl`] instances may hold additional data that must be kept alive until server is
     /// shut down. The sentinel value cleans this up when it is dropped.
     access_control_sentinel: Option<Box<dyn Any + Send>>,
+
+    /// See [`model_change_events()`](Self::model_change_events).
+    model_change_events: bool,
 }
 
 impl ServerBuilder {
@@ -75,6 +108,7 @@ impl ServerBuilder {
         Self {
             config,
             access_control_sentinel: None,
+            model_change_events: false,
         }
     }
 
@@ -133,6 +167,38 @@ impl ServerBuilder {
         )?))
     }
 
+    /// Creates builder from default server config with security policies and certificate chain.
+    ///
+    /// This is similar to [`Self::default_with_security_policies()`] but also sends the given
+    /// intermediate certificates along with the server certificate. Use this when the certificate
+    /// has been issued by an intermediate CA that clients may not know about.
+    ///
+    /// The intermediate certificates must be in [DER] format, ordered from the issuer of the server
+    /// certificate towards the root CA.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the certificate is invalid, the private key cannot be decrypted (e.g. when it
+    /// has been protected by a password), or the intermediate certificates are not in DER format.
+    ///
+    /// [DER]: https://en.wikipedia.org/wiki/X.690#DER_encoding
+    #[cfg(feature = "mbedtls")]
+    pub fn default_with_security_policies_with_chain(
+        port_number: u16,
+        certificate: &crate::Certificate,
+        intermediate_certificates: &[crate::Certificate],
+        private_key: &crate::PrivateKey,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            ua::ServerConfig::default_with_security_policies_with_chain(
+                port_number,
+                certificate,
+                intermediate_certificates,
+                private_key,
+            )?,
+        ))
+    }
+
     /// Creates builder from default server config with secure security policies.
     ///
     /// This enables only secure (i.e. encrypted) security policies.
@@ -172,16 +238,28 @@ impl ServerBuilder {
     ///
     /// # Panics
     ///
-    /// The strings must not contain any NUL bytes.
+    /// The strings must not contain any NUL bytes. See [`try_server_urls()`](Self::try_server_urls)
+    /// for a fallible variant.
     #[must_use]
-    pub fn server_urls(mut self, server_urls: &[&str]) -> Self {
-        let config = self.config_mut();
+    pub fn server_urls(self, server_urls: &[&str]) -> Self {
+        self.try_server_urls(server_urls)
+            .expect("server URLs should not contain NUL bytes")
+    }
+
+    /// Sets server URLs.
+    ///
+    /// # Errors
+    ///
+    /// This fails when any of the strings contains NUL bytes.
+    pub fn try_server_urls(mut self, server_urls: &[&str]) -> Result<Self> {
         let server_urls = server_urls
             .iter()
-            .map(|server_url| ua::String::new(server_url).unwrap());
-        ua::Array::from_iter(server_urls)
+            .map(|server_url| ua::String::new(server_url).map_err(|_| Error::NulByte("server URL")))
+            .collect::<Result<Vec<_>>>()?;
+        let config = self.config_mut();
+        ua::Array::from_slice(&server_urls)
             .move_into_raw(&mut config.serverUrlsSize, &mut config.serverUrls);
-        self
+        Ok(self)
     }
 
     /// Disables client certificate checks.
@@ -198,6 +276,52 @@ impl ServerBuilder {
         self
     }
 
+    /// Sets timeout for async operations.
+    ///
+    /// Operations of methods added with [`Server::add_async_method_node()`] that are not completed
+    /// within this time are failed with `BadTimeout`. By default, there is no timeout.
+    #[must_use]
+    pub fn async_operation_timeout(mut self, async_operation_timeout: Duration) -> Self {
+        self.config_mut().asyncOperationTimeout = async_operation_timeout.as_secs_f64() * 1e3;
+        self
+    }
+
+    /// Sets maximum number of queued async operations.
+    ///
+    /// Calls of methods added with [`Server::add_async_method_node()`] are rejected when there are
+    /// already this many operations waiting to be completed. By default, there is no limit.
+    #[must_use]
+    pub fn max_async_operation_queue_size(mut self, max_async_operation_queue_size: usize) -> Self {
+        self.config_mut().maxAsyncOperationQueueSize = max_async_operation_queue_size;
+        self
+    }
+
+    /// Sets maximum number of publish requests per session.
+    ///
+    /// When a session queues up more publish requests, the oldest ones are answered with
+    /// `BadTooManyPublishRequests`. By default, there is no limit.
+    #[must_use]
+    pub fn max_publish_requests_per_session(
+        mut self,
+        max_publish_requests_per_session: u32,
+    ) -> Self {
+        self.config_mut().maxPublishReqPerSession = max_publish_requests_per_session;
+        self
+    }
+
+    /// Enables or disables model change events.
+    ///
+    /// When enabled, adding and deleting nodes with the methods of [`Server`] emits a model change
+    /// event, see [`Server::emit_model_change_event()`]. Clients may subscribe to these events to
+    /// re-browse the address space after structural changes.
+    ///
+    /// Default value is `false`.
+    #[must_use]
+    pub const fn model_change_events(mut self, model_change_events: bool) -> Self {
+        self.model_change_events = model_change_events;
+        self
+    }
+
     /// Applies access control.
     ///
     /// See [`AccessControl`] for available implementations.
@@ -225,37 +349,35 @@ impl ServerBuilder {
     #[must_use]
     pub fn build(mut self) -> (Server, ServerRunner) {
         unsafe extern "C" fn destructor_c(
-            _server: *mut UA_Server,
+            server: *mut UA_Server,
             _session_id: *const UA_NodeId,
             _session_context: *mut c_void,
             node_id: *const UA_NodeId,
             node_context: *mut c_void,
         ) {
             // When associating dynamically allocated data with nodes created by this server, we
-            // always use `NodeContext`. Therefore, if `node_context` is set at all, we can/must
-            // call `NodeContext::consume()` to release that data. No other data must have been
-            // stored inside `node_context`.
-            //
-            // Note: The above assumption is not correct. See issue for more details:
-            // <https://github.com/HMIProject/open62541/issues/125>
-            //
-            // FIXME: Find solution to prevent memory leak.
-            if !node_context.is_null() {
-                if let Some(node_id) = unsafe { node_id.as_ref() }.map(ua::NodeId::raw_ref) {
-                    log::debug!("Destroying node {node_id}, freeing associated data");
-                } else {
-                    log::debug!("Destroying node, freeing associated data");
-                }
-                // SAFETY: The node destructor is run only once and we never consume the context
-                // outside of it.
-                //
-                // Note: We must not consume the node context because we cannot be sure that it
-                // points to valid memory (see above). We leak memory here. Fix this soon.
-                //
-                // unsafe {
-                //     let _unused = NodeContext::consume(node_context);
-                // }
+            // always use `NodeContext`. Other node contexts may have been set by open62541 itself
+            // (see <https://github.com/HMIProject/open62541/issues/125>), so we only release those
+            // that have been registered with the server context.
+            if node_context.is_null() {
+                return;
+            }
+            // SAFETY: The server is valid for the duration of the callback. The server context is
+            // missing only while the server is being deleted; it then releases all node contexts.
+            let Some(context) = (unsafe { ServerContext::from_server(server) }) else {
+                return;
+            };
+            // SAFETY: The node destructor is run only once, after all callbacks of the node.
+            let Some(node_context) = (unsafe { NodeContext::release(context, node_context) })
+            else {
+                return;
+            };
+            if let Some(node_id) = unsafe { node_id.as_ref() }.map(ua::NodeId::raw_ref) {
+                log::debug!("Destroying node {node_id}, freeing associated data");
+            } else {
+                log::debug!("Destroying node, freeing associated data");
             }
+            drop(node_context);
         }
 
         let config = self.config_mut();
@@ -264,9 +386,13 @@ impl ServerBuilder {
         debug_assert!(config.nodeLifecycle.destructor.is_none());
         config.nodeLifecycle.destructor = Some(destructor_c);
 
+        // This wraps the access control, so it must come after all other access control settings.
+        ServerContext::install(config, self.model_change_events);
+
         let Self {
             config,
             access_control_sentinel,
+            model_change_events: _,
         } = self;
 
         let server = Arc::new(ua::Server::new_with_config(config));
@@ -316,13 +442,23 @@ impl Server {
         ServerBuilder::default().build()
     }
 
+    /// Creates weak handle to server.
+    ///
+    /// The returned handle does not keep the server alive. Use this for long-lived references, e.g.
+    /// in callbacks or background tasks, that must not prevent the server from being deleted.
+    #[must_use]
+    pub fn downgrade(&self) -> WeakServer {
+        WeakServer(Arc::downgrade(&self.0))
+    }
+
     /// Adds a new namespace to the server. Returns the index of the new namespace.
     ///
     /// If the namespace already exists, it is not re-created but its index is returned.
     ///
     /// # Panics
     ///
-    /// The namespace URI must not contain any NUL bytes.
+    /// The namespace URI must not contain any NUL bytes. See
+    /// [`try_add_namespace()`](Self::try_add_namespace) for a fallible variant.
     ///
     /// # Examples
     ///
@@ -343,7 +479,42 @@ impl Server {
     /// ```
     #[must_use]
     pub fn add_namespace(&self, namespace_uri: &str) -> u16 {
-        let name = CString::new(namespace_uri).expect("namespace URI does not contain NUL bytes");
+        self.try_add_namespace(namespace_uri)
+            .expect("namespace URI should not contain NUL bytes")
+    }
+
+    /// Adds a new namespace to the server. Returns the index of the new namespace.
+    ///
+    /// If the namespace already exists, it is not re-created but its index is returned.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the namespace URI contains NUL bytes.
+    pub fn try_add_namespace(&self, namespace_uri: &str) -> Result<u16> {
+        self.insert_namespace(namespace_uri)
+            .map(|(namespace_index, _)| namespace_index)
+    }
+
+    /// Adds a new namespace to the server, reporting whether it has been created.
+    ///
+    /// This returns the index of the namespace and `true` when the namespace has been added by this
+    /// call, or `false` when it existed before. Use this to decide whether to emit a model change
+    /// event, see [`emit_model_change_event()`](Self::emit_model_change_event).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the namespace URI contains NUL bytes.
+    pub fn insert_namespace(&self, namespace_uri: &str) -> Result<(u16, bool)> {
+        let name = Error::c_string(namespace_uri, "namespace URI")?;
+        let uri = ua::String::new(namespace_uri).map_err(|_| Error::NulByte("namespace URI"))?;
+
+        // Hold the lock between lookup and insertion to not miss namespaces added in between.
+        let _guard = ServerContext::get(&self.0).lock_namespaces();
+
+        if let Some(namespace_index) = self.get_namespace_by_name(&uri) {
+            return Ok((namespace_index, false));
+        }
+
         let result = unsafe {
             UA_Server_addNamespace(
                 // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
@@ -353,7 +524,7 @@ impl Server {
         };
         // PANIC: The only possible errors here are out-of-memory.
         assert!(result != 0, "namespace should have been added");
-        result
+        Ok((result, true))
     }
 
     /// Looks up namespace by its URI.
@@ -455,6 +626,53 @@ impl Server {
         Some(found_uri)
     }
 
+    /// Reads server array.
+    ///
+    /// The server array (`Server_ServerArray`) lists the URIs of the servers that are represented
+    /// in the address space, e.g. by a gateway that aggregates downstream servers. The first entry
+    /// is always the application URI of this server.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the server array cannot be read.
+    pub fn server_array(&self) -> Result<ua::Array<ua::String>> {
+        let value = self.read_attribute(
+            &ua::NodeId::ns0_server_server_array(),
+            ua::AttributeId::VALUE_T,
+        )?;
+        value
+            .value()
+            .to_array::<ua::String>()
+            .ok_or(Error::internal("server array should be array of strings"))
+    }
+
+    /// Writes server array.
+    ///
+    /// The first entry must be the application URI of this server, i.e. the URI of namespace 1.
+    /// See [`server_array()`](Self::server_array).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the first entry is not the application URI of this server (with
+    /// `BadInvalidArgument`), when any of the strings contains NUL bytes, or when the server array
+    /// cannot be written.
+    pub fn set_server_array(&self, server_uris: &[&str]) -> Result<()> {
+        let server_uris = server_uris
+            .iter()
+            .map(|server_uri| ua::String::new(server_uri).map_err(|_| Error::NulByte("server URI")))
+            .collect::<Result<Vec<_>>>()?;
+
+        let application_uri = self.get_namespace_by_index(1);
+        if server_uris.first() != application_uri.as_ref() {
+            return Err(Error::new(ua::StatusCode::BADINVALIDARGUMENT));
+        }
+
+        self.write_value(
+            &ua::NodeId::ns0_server_server_array(),
+            &ua::Variant::array(ua::Array::from_slice(&server_uris)),
+        )
+    }
+
     /// Adds node to address space.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
@@ -489,16 +707,18 @@ impl Server {
                 requested_new_node_id.as_ptr(),
                 parent_node_id.as_ptr(),
                 reference_type_id.as_ptr(),
-                // TODO: Verify that `__UA_Server_addNode()` takes ownership.
-                browse_name.clone().into_raw(),
+                // SAFETY: `__UA_Server_addNode()` copies the browse name and does not take
+                // ownership, so we keep it and drop it afterwards.
+                DataType::to_raw_copy(&browse_name),
                 type_definition.as_ptr(),
                 attributes.as_node_attributes().as_ptr(),
                 attributes.attribute_type(),
-                context.map_or(ptr::null_mut(), NodeContext::leak),
+                context.map_or(ptr::null_mut(), |context| context.leak(&self.0)),
                 out_new_node_id.as_mut_ptr(),
             )
         });
         Error::verify_good(&status_code)?;
+        self.model_changed();
 
         Ok(out_new_node_id)
     }
@@ -546,10 +766,211 @@ impl Server {
             )
         });
         Error::verify_good(&status_code)?;
+        self.model_changed();
 
         Ok(out_new_node_id)
     }
 
+    /// Adds object type node to address space.
+    ///
+    /// Object types are usually added below [`ua::NodeId::ns0_base_object_type()`] with a
+    /// [`ua::NodeId::ns0_has_subtype()`] reference. Objects of this type are then added by passing
+    /// the returned node ID as type definition, see [`ObjectNode`]. Children of the object type
+    /// (added with [`ua::NodeId::ns0_has_component()`] references and a modelling rule) are
+    /// instantiated along with each object.
+    ///
+    /// This returns the node ID that was actually inserted (when no explicit requested new node ID
+    /// was given in `node`).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_object_type_node(&self, object_type_node: ObjectTypeNode) -> Result<ua::NodeId> {
+        let ObjectTypeNode {
+            requested_new_node_id,
+            parent_node_id,
+            reference_type_id,
+            browse_name,
+            attributes,
+        } = object_type_node;
+
+        let node = Node::new(parent_node_id, reference_type_id, browse_name, attributes);
+        self.add_node(match requested_new_node_id {
+            Some(requested_new_node_id) => node.with_requested_new_node_id(requested_new_node_id),
+            None => node,
+        })
+    }
+
+    /// Adds reference type node to address space.
+    ///
+    /// The reference type is added as subtype of `parent_node_id`, e.g.
+    /// [`ua::NodeId::ns0_non_hierarchical_references()`] or [`ua::NodeId::ns0_references()`].
+    ///
+    /// This returns the node ID that was actually inserted.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_reference_type_node(
+        &self,
+        parent_node_id: &ua::NodeId,
+        browse_name: ua::QualifiedName,
+        attributes: ua::ReferenceTypeAttributes,
+    ) -> Result<ua::NodeId> {
+        self.add_node(Node::new(
+            parent_node_id.clone(),
+            ua::NodeId::ns0_has_subtype(),
+            browse_name,
+            attributes,
+        ))
+    }
+
+    /// Adds data type node to address space.
+    ///
+    /// The data type is added as subtype of `parent_node_id`, e.g.
+    /// [`ua::NodeId::ns0_base_data_type()`] or [`ua::NodeId::ns0_structure()`].
+    ///
+    /// This returns the node ID that was actually inserted.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_data_type_node(
+        &self,
+        parent_node_id: &ua::NodeId,
+        browse_name: ua::QualifiedName,
+        attributes: ua::DataTypeAttributes,
+    ) -> Result<ua::NodeId> {
+        self.add_node(Node::new(
+            parent_node_id.clone(),
+            ua::NodeId::ns0_has_subtype(),
+            browse_name,
+            attributes,
+        ))
+    }
+
+    /// Adds view node to address space.
+    ///
+    /// Views are usually added below [`ua::NodeId::ns0_views_folder()`]. Add references from the
+    /// view node to its members with [`add_reference()`](Self::add_reference). Clients can then
+    /// browse the view node to find its members.
+    ///
+    /// Note that `open62541` does not support browsing restricted to a view: the `Browse` service
+    /// rejects requests with view ID with [`ua::StatusCode::BADVIEWIDUNKNOWN`].
+    ///
+    /// This returns the node ID that was actually inserted.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_view_node(
+        &self,
+        parent_node_id: &ua::NodeId,
+        browse_name: ua::QualifiedName,
+        attributes: ua::ViewAttributes,
+    ) -> Result<ua::NodeId> {
+        self.add_node(Node::new(
+            parent_node_id.clone(),
+            ua::NodeId::ns0_organizes(),
+            browse_name,
+            attributes,
+        ))
+    }
+
+    /// Adds folders along path.
+    ///
+    /// This creates object nodes of type `FolderType` below `parent_node_id`, one for each name in
+    /// `path`, with browse names in namespace `namespace_index`. Existing nodes with matching browse
+    /// name are reused, so this may be called repeatedly with overlapping paths.
+    ///
+    /// This returns the node IDs of all levels, in the order of `path`.
+    ///
+    /// # Errors
+    ///
+    /// This fails when a node cannot be looked up or added, or when a name contains NUL bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, ua};
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let objects_folder = ua::NodeId::ns0_objects_folder();
+    /// let line = server.add_folders(&objects_folder, 1, &["Plant", "Area", "Line"])?;
+    /// let machine = server.add_folders(&objects_folder, 1, &["Plant", "Area", "Line", "Machine"])?;
+    ///
+    /// // Existing folders are reused.
+    /// assert_eq!(line[..], machine[..3]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_folders(
+        &self,
+        parent_node_id: &ua::NodeId,
+        namespace_index: u16,
+        path: &[&str],
+    ) -> Result<Vec<ua::NodeId>> {
+        let mut node_ids = Vec::with_capacity(path.len());
+        let mut parent_node_id = parent_node_id.clone();
+
+        for &name in path {
+            // This fails for NUL bytes, so creating the qualified name below does not panic.
+            let display_name = ua::LocalizedText::new("", name)?;
+            let browse_name = ua::QualifiedName::new(namespace_index, name);
+            let node_id = match self.find_child(&parent_node_id, &browse_name)? {
+                Some(node_id) => node_id,
+                None => {
+                    let result = self.add_object_node(ObjectNode {
+                        requested_new_node_id: None,
+                        parent_node_id: parent_node_id.clone(),
+                        reference_type_id: ua::NodeId::ns0_organizes(),
+                        browse_name: browse_name.clone(),
+                        type_definition: ua::NodeId::ns0_folder_type(),
+                        attributes: ua::ObjectAttributes::init().with_display_name(&display_name),
+                    });
+                    match result {
+                        Ok(node_id) => node_id,
+                        // Folder has been added concurrently, use that one instead.
+                        Err(error)
+                            if error.status_code() == ua::StatusCode::BADBROWSENAMEDUPLICATED =>
+                        {
+                            self.find_child(&parent_node_id, &browse_name)?
+                                .ok_or(error)?
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+            };
+            node_ids.push(node_id.clone());
+            parent_node_id = node_id;
+        }
+
+        Ok(node_ids)
+    }
+
+    /// Finds child node by browse name.
+    ///
+    /// This follows hierarchical references. Returns `None` when there is no such child.
+    fn find_child(
+        &self,
+        parent_node_id: &ua::NodeId,
+        browse_name: &ua::QualifiedName,
+    ) -> Result<Option<ua::NodeId>> {
+        let browse_path = ua::BrowsePath::from_segments(
+            parent_node_id,
+            [ua::RelativePathElement::hierarchical(browse_name)],
+        );
+        match self.translate_browse_path_to_node_id(&browse_path) {
+            Ok(node_id) => Ok(Some(node_id)),
+            Err(error) if error.status_code() == ua::StatusCode::BADNOMATCH => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
     /// Adds variable node to address space.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
@@ -593,10 +1014,83 @@ impl Server {
             )
         });
         Error::verify_good(&status_code)?;
+        self.model_changed();
 
         Ok(out_new_node_id)
     }
 
+    /// Adds variable type node to address space.
+    ///
+    /// Variable types are usually added below [`ua::NodeId::ns0_base_data_variable_type()`] with a
+    /// [`ua::NodeId::ns0_has_subtype()`] reference. Variables of this type are then added by
+    /// passing the returned node ID as type definition. They take default value, data type and
+    /// array dimensions from the type unless they set their own.
+    ///
+    /// This returns the node ID that was actually inserted (when no explicit requested new node ID
+    /// was given in `node`).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ServerBuilder, ua, VariableNode, VariableTypeNode};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let motor_speed_type = server.add_variable_type_node(VariableTypeNode {
+    ///     requested_new_node_id: None,
+    ///     parent_node_id: ua::NodeId::ns0_base_data_variable_type(),
+    ///     reference_type_id: ua::NodeId::ns0_has_subtype(),
+    ///     browse_name: ua::QualifiedName::new(1, "MotorSpeedType"),
+    ///     attributes: ua::VariableTypeAttributes::default()
+    ///         .with_data_type(&ua::NodeId::ns0_double())
+    ///         .with_value_rank(-1)
+    ///         .with_value(&ua::Variant::scalar(ua::Double::new(1500.0))),
+    /// })?;
+    ///
+    /// let motor_speed = server.add_variable_node(VariableNode {
+    ///     requested_new_node_id: None,
+    ///     parent_node_id: ua::NodeId::ns0_objects_folder(),
+    ///     reference_type_id: ua::NodeId::ns0_organizes(),
+    ///     browse_name: ua::QualifiedName::new(1, "MotorSpeed"),
+    ///     type_definition: motor_speed_type,
+    ///     attributes: ua::VariableAttributes::default()
+    ///         .with_data_type(&ua::NodeId::ns0_double())
+    ///         .with_value_rank(-1),
+    /// })?;
+    ///
+    /// // The variable starts with the default value of its type.
+    /// let value = server
+    ///     .read_attribute(&motor_speed, ua::AttributeId::VALUE_T)?
+    ///     .into_value();
+    /// assert_eq!(value.to_scalar::<ua::Double>().map(|value| value.value()), Some(1500.0));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_variable_type_node(
+        &self,
+        variable_type_node: VariableTypeNode,
+    ) -> Result<ua::NodeId> {
+        let VariableTypeNode {
+            requested_new_node_id,
+            parent_node_id,
+            reference_type_id,
+            browse_name,
+            attributes,
+        } = variable_type_node;
+
+        let node = Node::new(parent_node_id, reference_type_id, browse_name, attributes);
+        self.add_node(match requested_new_node_id {
+            Some(requested_new_node_id) => node.with_requested_new_node_id(requested_new_node_id),
+            None => node,
+        })
+    }
+
     /// Adds variable node with data source to address space.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
@@ -644,59 +1138,266 @@ impl Server {
                 // TODO: Verify that `UA_Server_addDataSourceVariableNode()` takes ownership.
                 attributes.into_raw(),
                 data_source,
-                node_context.leak(),
+                node_context.leak(&self.0),
                 out_new_node_id.as_mut_ptr(),
             )
         });
-        // In case of an error, the node context has already been freed by the destructor. We must
-        // not consume it ourselves (to avoid double-freeing). In case of success, the node context
-        // will be consumed when the node is eventually deleted (`UA_ServerConfig::nodeLifecycle`).
+        // In case of an error, the node context may already have been released by the destructor.
+        // We must not release it ourselves (the pointer may have been reused). Otherwise, the node
+        // context is released when the node is eventually deleted (`UA_ServerConfig::nodeLifecycle`)
+        // or when the server is dropped.
         Error::verify_good(&status_code)?;
+        self.model_changed();
 
         Ok(out_new_node_id)
     }
 
-    /// Adds method node to address space.
+    /// Adds variable node with asynchronous data source to address space.
+    ///
+    /// This is like [`add_data_source_variable_node()`](Self::add_data_source_variable_node) but
+    /// runs the callbacks of `data_source` on the runtime of `runtime_handle`. The server thread is
+    /// blocked until they return or time out, see [`AsyncDataSource`] for details.
     ///
     /// This returns the node ID that was actually inserted (when no explicit requested new node ID
-    /// was given in `node`), along with the node IDs for the input and output argument nodes.
+    /// was given in `node`).
     ///
     /// # Errors
     ///
     /// This fails when the node cannot be added.
-    pub fn add_method_node(
+    #[cfg(feature = "tokio")]
+    pub fn add_async_data_source_variable_node(
         &self,
-        method_node: MethodNode,
-        callback: impl MethodCallback + 'static,
-    ) -> Result<(ua::NodeId, (ua::NodeId, ua::NodeId))> {
-        let MethodNode {
-            requested_new_node_id,
-            parent_node_id,
-            reference_type_id,
-            browse_name,
-            attributes,
-            input_arguments,
-            input_arguments_requested_new_node_id,
-            output_arguments,
-            output_arguments_requested_new_node_id,
-        } = method_node;
-
-        let requested_new_node_id = requested_new_node_id.unwrap_or(ua::NodeId::null());
-
-        // SAFETY: We store `node_context` inside the node to keep `data_source` alive.
-        let (method_callback, node_context) =
-            unsafe { method_callback::wrap_method_callback(callback) };
-
-        let (input_arguments_size, input_arguments) = unsafe { input_arguments.as_raw_parts() };
-        let (output_arguments_size, output_arguments) = unsafe { output_arguments.as_raw_parts() };
-
-        // These out variables must be initialized without memory allocation because the call below
-        // overwrites them in place, without releasing any held data first.
-        let mut input_arguments_out_new_node_id = ua::NodeId::null();
-        let mut output_arguments_out_new_node_id = ua::NodeId::null();
-        let mut out_new_node_id = ua::NodeId::null();
+        variable_node: VariableNode,
+        data_source: impl AsyncDataSource + 'static,
+        runtime_handle: tokio::runtime::Handle,
+    ) -> Result<ua::NodeId> {
+        self.add_data_source_variable_node(
+            variable_node,
+            async_data_source::BlockingDataSource::new(data_source, runtime_handle),
+        )
+    }
 
-        let status_code = ua::StatusCode::new(unsafe {
+    /// Sets value callback of variable node.
+    ///
+    /// This notifies `callback` when the value of the variable node is read or written. Unlike
+    /// with [`add_data_source_variable_node()`](Self::add_data_source_variable_node), the value is
+    /// still stored in the server.
+    ///
+    /// The callback is kept alive with the node. When only an index range of the value is written,
+    /// the callback is not told which range was affected.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or is not a variable node. It also fails with
+    /// [`ua::StatusCode::BADINVALIDSTATE`] when the node already has associated data, e.g. when it
+    /// has been added with a data source or when the value callback has already been set.
+    pub fn set_variable_node_value_callback(
+        &self,
+        node_id: &ua::NodeId,
+        callback: impl ValueCallback + 'static,
+    ) -> Result<()> {
+        let mut current_node_context = ptr::null_mut();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_getNodeContext(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: The function expects copies but does not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                ptr::addr_of_mut!(current_node_context),
+            )
+        });
+        Error::verify_good(&status_code)?;
+        if !current_node_context.is_null() {
+            // We cannot tell what kind of data is stored in the node context (see issue #125), so
+            // we must not replace it.
+            return Err(Error::new(ua::StatusCode::BADINVALIDSTATE));
+        }
+
+        // SAFETY: We store `node_context` inside the node to keep `callback` alive.
+        let (value_callback, node_context) =
+            unsafe { value_callback::wrap_value_callback(callback) };
+        let node_context = node_context.leak(&self.0);
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_setNodeContext(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: The function expects copies but does not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                node_context,
+            )
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            // SAFETY: The node context has not been stored in the node.
+            let _unused =
+                unsafe { NodeContext::release(ServerContext::get(&self.0), node_context) };
+            return Err(error);
+        }
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_setVariableNode_valueCallback(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: The function expects copies but does not take ownership.
+                ua::NodeId::to_raw_copy(node_id),
+                value_callback,
+            )
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            // Remove node context again. Without value callback, it is never accessed.
+            let status_code = ua::StatusCode::new(unsafe {
+                UA_Server_setNodeContext(
+                    // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                    self.0.as_ptr().cast_mut(),
+                    // SAFETY: The function expects copies but does not take ownership.
+                    ua::NodeId::to_raw_copy(node_id),
+                    ptr::null_mut(),
+                )
+            });
+            if status_code.is_good() {
+                // SAFETY: The node context has been removed from the node.
+                let _unused =
+                    unsafe { NodeContext::release(ServerContext::get(&self.0), node_context) };
+            } else {
+                // The node context is released by the node destructor or when the server is dropped.
+                log::warn!("Failed to remove node context from node {node_id}: {status_code}");
+            }
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Adds method node to address space.
+    ///
+    /// This returns the node ID that was actually inserted (when no explicit requested new node ID
+    /// was given in `node`), along with the node IDs for the input and output argument nodes.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_method_node(
+        &self,
+        method_node: MethodNode,
+        callback: impl MethodCallback + 'static,
+    ) -> Result<(ua::NodeId, (ua::NodeId, ua::NodeId))> {
+        // SAFETY: We store `node_context` inside the node to keep `callback` alive.
+        let (method_callback, node_context) =
+            unsafe { method_callback::wrap_method_callback(callback) };
+
+        self.add_method_node_with_context(method_node, method_callback, node_context)
+    }
+
+    /// Adds async method node to address space.
+    ///
+    /// This is similar to [`add_method_node()`](Self::add_method_node) but calls to the method are
+    /// queued and do not block the server. The `callback` may finish a call later, from any thread,
+    /// through [`complete_async_operation()`](Self::complete_async_operation).
+    ///
+    /// Note: Queued calls are only dispatched when the server is run with
+    /// [`ServerRunner::run_until_cancelled()`].
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_async_method_node(
+        &self,
+        method_node: MethodNode,
+        callback: impl AsyncMethodCallback + 'static,
+    ) -> Result<(ua::NodeId, (ua::NodeId, ua::NodeId))> {
+        // SAFETY: We store `node_context` inside the node to keep `callback` alive.
+        let (method_callback, node_context) =
+            unsafe { method_callback::wrap_async_method_callback(callback) };
+
+        let (node_id, argument_node_ids) =
+            self.add_method_node_with_context(method_node, method_callback, node_context)?;
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_setMethodNodeAsync(
+                // SAFETY: Cast to `mut` pointer. Function is not marked `UA_THREADSAFE` but locks the
+                // server internally.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: `UA_Server_setMethodNodeAsync()` expects the node ID passed by value but
+                // does not take ownership.
+                ua::NodeId::to_raw_copy(&node_id),
+                true,
+            )
+        });
+        if let Err(error) = Error::verify_good(&status_code) {
+            // Do not leave behind method node that would be called synchronously.
+            if let Err(error) = self.delete_node(&node_id) {
+                log::error!("Failed to delete method node {node_id}: {error}");
+            }
+            return Err(error);
+        }
+
+        Ok((node_id, argument_node_ids))
+    }
+
+    /// Completes async operation.
+    ///
+    /// This returns the result of a pending call to a method added with
+    /// [`add_async_method_node()`](Self::add_async_method_node) to the client. On success, the
+    /// output arguments must match the output arguments defined for the method.
+    ///
+    /// This may be called from any thread. When the operation has already timed out (see
+    /// [`ServerBuilder::async_operation_timeout()`]), the result is discarded.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the handle belongs to a different server.
+    pub fn complete_async_operation(
+        &self,
+        handle: AsyncOperationHandle,
+        result: Result<&[ua::Variant], MethodCallbackError>,
+    ) -> Result<()> {
+        if !handle.belongs_to(&self.0) {
+            return Err(Error::internal("async operation should belong to server"));
+        }
+
+        let result = match result {
+            Ok(output_arguments) => {
+                ua::CallMethodResult::init().with_output_arguments(output_arguments)
+            }
+            Err(err) => ua::CallMethodResult::init().with_status_code(err.into_status_code()),
+        };
+
+        handle.complete(&self.0, &result);
+
+        Ok(())
+    }
+
+    /// Adds method node with method callback and associated node context.
+    fn add_method_node_with_context(
+        &self,
+        method_node: MethodNode,
+        method_callback: UA_MethodCallback,
+        node_context: NodeContext,
+    ) -> Result<(ua::NodeId, (ua::NodeId, ua::NodeId))> {
+        let MethodNode {
+            requested_new_node_id,
+            parent_node_id,
+            reference_type_id,
+            browse_name,
+            attributes,
+            input_arguments,
+            input_arguments_requested_new_node_id,
+            output_arguments,
+            output_arguments_requested_new_node_id,
+        } = method_node;
+
+        let requested_new_node_id = requested_new_node_id.unwrap_or(ua::NodeId::null());
+
+        let (input_arguments_size, input_arguments) = unsafe { input_arguments.as_raw_parts() };
+        let (output_arguments_size, output_arguments) = unsafe { output_arguments.as_raw_parts() };
+
+        // These out variables must be initialized without memory allocation because the call below
+        // overwrites them in place, without releasing any held data first.
+        let mut input_arguments_out_new_node_id = ua::NodeId::null();
+        let mut output_arguments_out_new_node_id = ua::NodeId::null();
+        let mut out_new_node_id = ua::NodeId::null();
+
+        let status_code = ua::StatusCode::new(unsafe {
             UA_Server_addMethodNodeEx(
                 // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
                 self.0.as_ptr().cast_mut(),
@@ -725,11 +1426,12 @@ impl Server {
                     // TODO: Verify that `UA_Server_addMethodNodeEx()` takes ownership.
                     .into_raw(),
                 output_arguments_out_new_node_id.as_mut_ptr(),
-                node_context.leak(),
+                node_context.leak(&self.0),
                 out_new_node_id.as_mut_ptr(),
             )
         });
         Error::verify_good(&status_code)?;
+        self.model_changed();
 
         Ok((
             out_new_node_id,
@@ -759,7 +1461,108 @@ impl Server {
                 true,
             )
         });
-        Error::verify_good(&status_code)
+        Error::verify_good(&status_code)?;
+        self.model_changed();
+        Ok(())
+    }
+
+    /// Deletes node and its children from address space.
+    ///
+    /// This follows hierarchical forward references (such as `Organizes`, `HasComponent`) from the
+    /// given node and deletes child nodes depth-first, before their parents. Child nodes that are
+    /// also referenced hierarchically from nodes outside of the deleted subtree are not deleted, only
+    /// the references from deleted nodes to them are removed. The given node itself is always
+    /// deleted.
+    ///
+    /// Returns the number of deleted nodes, including the given node.
+    ///
+    /// # Errors
+    ///
+    /// This fails when browsing or deleting any of the nodes fails. Nodes that have been deleted up
+    /// to that point are not restored.
+    pub fn delete_node_recursive(&self, node_id: &ua::NodeId) -> Result<usize> {
+        self.delete_subtree(node_id, &mut HashSet::new())
+    }
+
+    fn delete_subtree(
+        &self,
+        node_id: &ua::NodeId,
+        visited: &mut HashSet<ua::NodeId>,
+    ) -> Result<usize> {
+        visited.insert(node_id.clone());
+
+        let children = self.browse_all(&ua::BrowseDescription::default().with_node_id(node_id))?;
+
+        let mut deleted = 0;
+        for reference in children {
+            let target_id = reference.node_id();
+            // Skip nodes on remote servers and nodes that we have already seen (there should be no
+            // loops in hierarchical references, but we do not rely on this).
+            if target_id.server_index() != 0 || visited.contains(target_id.node_id()) {
+                continue;
+            }
+
+            // Remove reference first, to find out whether the child has other parents.
+            self.delete_reference(
+                node_id,
+                reference.reference_type_id(),
+                target_id,
+                true,
+                true,
+            )?;
+            if self.has_hierarchical_parent(target_id.node_id())? {
+                continue;
+            }
+
+            deleted += self.delete_subtree(target_id.node_id(), visited)?;
+        }
+
+        self.delete_node(node_id)?;
+
+        Ok(deleted + 1)
+    }
+
+    /// Checks if node is target of any hierarchical reference.
+    fn has_hierarchical_parent(&self, node_id: &ua::NodeId) -> Result<bool> {
+        let browse_description = ua::BrowseDescription::default()
+            .with_node_id(node_id)
+            .with_browse_direction(&ua::BrowseDirection::INVERSE);
+        let (references, continuation_point) = self.browse(1, &browse_description)?;
+        if let Some(continuation_point) = continuation_point {
+            self.browse_next_release(&continuation_point)?;
+        }
+        Ok(!references.is_empty())
+    }
+
+    /// Takes snapshot of address space.
+    ///
+    /// This captures all nodes in the given namespaces that are reachable from the root folder
+    /// through hierarchical references: their node class, browse name, type definition, data type
+    /// (for variables), and references. Compare snapshots with [`AddressSpaceSnapshot::diff()`].
+    ///
+    /// This is meant for tests that verify that the information model of the server does not
+    /// change unexpectedly.
+    ///
+    /// # Errors
+    ///
+    /// This fails when browsing the address space or reading node attributes fails.
+    #[cfg(feature = "test-util")]
+    pub fn snapshot(&self, namespace_indices: &[u16]) -> Result<AddressSpaceSnapshot> {
+        AddressSpaceSnapshot::capture(self, namespace_indices)
+    }
+
+    /// Browses node and follows continuation points until all references have been returned.
+    fn browse_all(
+        &self,
+        browse_description: &ua::BrowseDescription,
+    ) -> Result<Vec<ua::ReferenceDescription>> {
+        let (mut references, mut continuation_point) = self.browse(0, browse_description)?;
+        while let Some(current) = continuation_point {
+            let (more_references, next) = self.browse_next(&current)?;
+            references.extend(more_references);
+            continuation_point = next;
+        }
+        Ok(references)
     }
 
     /// Adds a reference from one node to another.
@@ -772,8 +1575,6 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, Node, ServerBuilder, ua};
-    /// # use open62541_sys::{UA_NS0ID_HASCOMPONENT, UA_NS0ID_OBJECTSFOLDER};
-    /// use open62541_sys::{UA_NS0ID_ORGANIZES};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
@@ -781,22 +1582,22 @@ impl Server {
     /// #
     /// // let parent_one_node_id = server.add_node(/* snip */)?;
     /// # let parent_one_node_id = server.add_node(Node::new(
-    /// #     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-    /// #     ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    /// #     ua::NodeId::ns0_objects_folder(),
+    /// #     ua::NodeId::ns0_organizes(),
     /// #     ua::QualifiedName::new(1, "ParentOne"),
     /// #     ua::ObjectAttributes::init(),
     /// # ))?;
     /// // let parent_two_node_id = server.add_node(/* snip */)?;
     /// # let parent_two_node_id = server.add_node(Node::new(
-    /// #     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-    /// #     ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    /// #     ua::NodeId::ns0_objects_folder(),
+    /// #     ua::NodeId::ns0_organizes(),
     /// #     ua::QualifiedName::new(1, "ParentTwo"),
     /// #     ua::ObjectAttributes::init(),
     /// # ))?;
     ///
     /// let variable_node_id = server.add_node(Node::new(
     ///     parent_one_node_id.clone(),
-    ///     ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    ///     ua::NodeId::ns0_organizes(),
     ///     ua::QualifiedName::new(1, "Variable"),
     ///     ua::VariableAttributes::init(),
     /// ))?;
@@ -804,7 +1605,7 @@ impl Server {
     /// // This makes the variable available in two parents.
     /// server.add_reference(
     ///     &parent_two_node_id,
-    ///     &ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    ///     &ua::NodeId::ns0_organizes(),
     ///     &variable_node_id.clone().into_expanded_node_id(),
     ///     true,
     /// )?;
@@ -812,7 +1613,7 @@ impl Server {
     /// // Duplicating an existing reference is not allowed.
     /// let error = server.add_reference(
     ///     &parent_one_node_id,
-    ///     &ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    ///     &ua::NodeId::ns0_organizes(),
     ///     &variable_node_id.clone().into_expanded_node_id(),
     ///     true,
     /// ).unwrap_err();
@@ -934,6 +1735,47 @@ impl Server {
         Ok(event_id)
     }
 
+    /// Prepares event.
+    ///
+    /// This returns an [`EventBuilder`] to set the fields of the event, e.g. its severity and
+    /// message, before triggering it. See [`EventBuilder`] for an example.
+    pub fn event_builder(&self, event_type: &ua::NodeId) -> EventBuilder<'_> {
+        EventBuilder::new(self, event_type)
+    }
+
+    /// Emits model change event.
+    ///
+    /// This triggers an event of type `BaseModelChangeEventType` from the server object. It tells
+    /// clients that the structure of the address space has changed and that they should browse it
+    /// again.
+    ///
+    /// Use [`ServerBuilder::model_change_events()`] to emit this event automatically when adding or
+    /// deleting nodes.
+    ///
+    /// Note: open62541 does not support `ModelChangeStructureDataType`, so the event does not tell
+    /// which nodes have changed (`GeneralModelChangeEventType` cannot be used).
+    ///
+    /// # Errors
+    ///
+    /// This fails when the event could not be triggered.
+    pub fn emit_model_change_event(&self) -> Result<()> {
+        let event_node_id = self.create_event(&ua::NodeId::ns0_base_model_change_event_type())?;
+        self.trigger_event(&event_node_id, &ua::NodeId::ns0_server(), true)?;
+        Ok(())
+    }
+
+    /// Emits model change event if enabled.
+    ///
+    /// See [`ServerBuilder::model_change_events()`].
+    fn model_changed(&self) {
+        if !ServerContext::get(&self.0).model_change_events() {
+            return;
+        }
+        if let Err(error) = self.emit_model_change_event() {
+            log::error!("Failed to emit model change event: {error}");
+        }
+    }
+
     /// Browses specific node.
     ///
     /// Use [`ua::BrowseDescription::default()`](ua::BrowseDescription) to set sensible defaults to
@@ -942,10 +1784,9 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{Result, Server, ua};
-    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS;
     ///
     /// # async fn example(server: &Server) -> Result<()> {
-    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS);
+    /// let node_id = ua::NodeId::ns0_server_status();
     /// let browse_description = ua::BrowseDescription::default().with_node_id(&node_id);
     /// let (references, continuation_point) = server.browse(1000, &browse_description)?;
     /// # Ok(())
@@ -978,7 +1819,8 @@ impl Server {
     /// Browses continuation point for more references.
     ///
     /// This uses a continuation point returned from [`browse()`] whenever not all references were
-    /// returned (due to `max_references`).
+    /// returned (due to `max_references`). To discard the remaining references instead, use
+    /// [`browse_next_release()`](Self::browse_next_release).
     ///
     /// # Errors
     ///
@@ -999,6 +1841,33 @@ impl Server {
         to_browse_result(&result)
     }
 
+    /// Releases continuation point without browsing it.
+    ///
+    /// Continuation points returned from [`browse()`] and [`browse_next()`] keep resources allocated
+    /// on the server until they are browsed to the end. Release them when the remaining references
+    /// are not needed.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the continuation point is invalid, e.g. because it has already been
+    /// released.
+    ///
+    /// [`browse()`]: Self::browse
+    /// [`browse_next()`]: Self::browse_next
+    pub fn browse_next_release(&self, continuation_point: &ua::ContinuationPoint) -> Result<()> {
+        // The result contains no references, only the status code.
+        let result = unsafe {
+            ua::BrowseResult::from_raw(UA_Server_browseNext(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // Release continuation point without browsing it.
+                true,
+                continuation_point.as_byte_string().as_ptr(),
+            ))
+        };
+        Error::verify_good(&result.status_code())
+    }
+
     /// Browses nodes recursively.
     ///
     /// This is a non-standard version of the `Browse` service that recurses into child nodes. This
@@ -1019,7 +1888,6 @@ impl Server {
     /// ```
     /// # use std::collections::HashSet;
     /// # use open62541::{DataType as _, ServerBuilder, ua};
-    /// use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
@@ -1027,7 +1895,7 @@ impl Server {
     /// #
     /// let targets = server.browse_recursive(
     ///     &ua::BrowseDescription::default().with_node_id(
-    ///         &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS),
+    ///         &ua::NodeId::ns0_server_status(),
     ///     ),
     /// )?;
     ///
@@ -1037,21 +1905,21 @@ impl Server {
     /// #     targets
     /// #         .as_slice()
     /// #         .iter()
-    /// #         .map(|node| node.node_id().as_ns0().unwrap())
+    /// #         .map(|node| node.node_id().clone())
     /// #         .collect::<HashSet<_>>(),
     /// #     HashSet::from([
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_BUILDDATE,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_BUILDNUMBER,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_MANUFACTURERNAME,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTURI,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_SOFTWAREVERSION,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_CURRENTTIME,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_SECONDSTILLSHUTDOWN,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_SHUTDOWNREASON,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_STARTTIME,
-    /// #         open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS_STATE,
+    /// #         ua::NodeId::ns0_server_status_build_info(),
+    /// #         ua::NodeId::ns0_server_status_build_info_build_date(),
+    /// #         ua::NodeId::ns0_server_status_build_info_build_number(),
+    /// #         ua::NodeId::ns0_server_status_build_info_manufacturer_name(),
+    /// #         ua::NodeId::ns0_server_status_build_info_product_name(),
+    /// #         ua::NodeId::ns0_server_status_build_info_product_uri(),
+    /// #         ua::NodeId::ns0_server_status_build_info_software_version(),
+    /// #         ua::NodeId::ns0_server_status_current_time(),
+    /// #         ua::NodeId::ns0_server_status_seconds_till_shutdown(),
+    /// #         ua::NodeId::ns0_server_status_shutdown_reason(),
+    /// #         ua::NodeId::ns0_server_status_start_time(),
+    /// #         ua::NodeId::ns0_server_status_state(),
     /// #     ])
     /// # );
     /// #
@@ -1098,9 +1966,6 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, ServerBuilder, ua};
-    /// use open62541_sys::{
-    ///     UA_NS0ID_SERVER_SERVERSTATUS, UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
-    /// };
     ///
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
@@ -1110,7 +1975,7 @@ impl Server {
     /// let target_name_2 = ua::QualifiedName::new(0, "ProductName");
     ///
     /// let targets = server.browse_simplified_browse_path(
-    ///     &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS),
+    ///     &ua::NodeId::ns0_server_status(),
     ///     &[target_name_1, target_name_2],
     /// )?;
     ///
@@ -1121,7 +1986,7 @@ impl Server {
     /// // The given path leads to the right node ID.
     /// assert_eq!(
     ///     target.target_id(),
-    ///     &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME)
+    ///     &ua::NodeId::ns0_server_status_build_info_product_name()
     ///         .into_expanded_node_id()
     /// );
     ///
@@ -1168,26 +2033,18 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, ServerBuilder, ua};
-    /// use open62541_sys::{
-    ///     UA_NS0ID_SERVER_SERVERSTATUS, UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
-    /// };
     ///
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
     /// # let (server, _) = ServerBuilder::default().build();
     /// #
-    /// let target_name_1 = ua::QualifiedName::new(0, "BuildInfo");
-    /// let target_name_2 = ua::QualifiedName::new(0, "ProductName");
-    ///
-    /// let targets = server.translate_browse_path_to_node_ids(&ua::BrowsePath::init()
-    ///     .with_starting_node(&ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS))
-    ///     .with_relative_path(&ua::RelativePath::init()
-    ///         .with_elements(&[
-    ///             ua::RelativePathElement::init().with_target_name(&target_name_1),
-    ///             ua::RelativePathElement::init().with_target_name(&target_name_2),
-    ///         ])
-    ///     )
-    /// )?;
+    /// let targets = server.translate_browse_path_to_node_ids(&ua::BrowsePath::from_segments(
+    ///     &ua::NodeId::ns0_server_status(),
+    ///     [
+    ///         ua::RelativePathElement::aggregates(&ua::QualifiedName::new(0, "BuildInfo")),
+    ///         ua::RelativePathElement::aggregates(&ua::QualifiedName::new(0, "ProductName")),
+    ///     ],
+    /// ))?;
     ///
     /// // Translation above returns a single target.
     /// assert_eq!(targets.len(), 1);
@@ -1196,7 +2053,7 @@ impl Server {
     /// // The given path leads to the right node ID.
     /// assert_eq!(
     ///     target.target_id(),
-    ///     &ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME)
+    ///     &ua::NodeId::ns0_server_status_build_info_product_name()
     ///         .into_expanded_node_id()
     /// );
     ///
@@ -1224,6 +2081,32 @@ impl Server {
         Ok(targets)
     }
 
+    /// Translates browse path to single node ID.
+    ///
+    /// This is like [`translate_browse_path_to_node_ids()`] but requires the browse path to be
+    /// fully resolved to a node on this server.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the browse path cannot be translated. When the path is resolved only
+    /// partially, this fails with [`Error::BrowsePath`] carrying the remaining path (see
+    /// [`ua::BrowsePathResult::to_resolved_node_id()`]).
+    ///
+    /// [`translate_browse_path_to_node_ids()`]: Self::translate_browse_path_to_node_ids
+    pub fn translate_browse_path_to_node_id(
+        &self,
+        browse_path: &ua::BrowsePath,
+    ) -> Result<ua::NodeId> {
+        let result = unsafe {
+            ua::BrowsePathResult::from_raw(UA_Server_translateBrowsePathToNodeIds(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                browse_path.as_ptr(),
+            ))
+        };
+        result.to_resolved_node_id(browse_path)
+    }
+
     /// Reads node attribute.
     ///
     /// This method supports static dispatch to the correct value type at compile time and can be
@@ -1241,13 +2124,12 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, ServerBuilder, ua};
-    /// # use open62541_sys::UA_NS0ID_SERVER_SERVERSTATUS;
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
     /// # let (server, _) = ServerBuilder::default().build();
     /// #
-    /// let node_id = ua::NodeId::ns0(UA_NS0ID_SERVER_SERVERSTATUS);
+    /// let node_id = ua::NodeId::ns0_server_status();
     ///
     /// // Use static dispatch to get expected value type directly:
     /// let browse_name = server
@@ -1289,6 +2171,43 @@ impl Server {
         result.to_generic::<T::Value>()
     }
 
+    /// Reads node value as `DataValue`.
+    ///
+    /// Unlike [`read_attribute()`](Self::read_attribute), this returns the value as stored, with
+    /// its status and timestamps. The status is not checked, so values with bad or uncertain
+    /// status are returned as well. When the value cannot be read, e.g. when the node does not
+    /// exist, the returned `DataValue` holds the corresponding bad status and no value.
+    #[must_use]
+    pub fn read_data_value(&self, node_id: &ua::NodeId) -> ua::DataValue {
+        let item = ua::ReadValueId::init()
+            .with_node_id(node_id)
+            .with_attribute_id(&ua::AttributeId::VALUE);
+        unsafe {
+            ua::DataValue::from_raw(UA_Server_read(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                item.as_ptr(),
+                ua::TimestampsToReturn::BOTH.into_raw(),
+            ))
+        }
+    }
+
+    /// Reads several node values.
+    ///
+    /// The size and order of the result list matches the size and order of the given node ID list.
+    /// Values are read one after the other. To read them without client requests being processed
+    /// in between, call this from [`execute()`](Self::execute).
+    ///
+    /// When a node does not exist or its value attribute cannot be read, an `Err` is returned in
+    /// its place.
+    #[must_use]
+    pub fn read_values(&self, node_ids: &[ua::NodeId]) -> Vec<Result<DataValue<ua::Variant>>> {
+        node_ids
+            .iter()
+            .map(|node_id| self.read_attribute(node_id, ua::AttributeId::VALUE_T))
+            .collect()
+    }
+
     /// Writes node value.
     ///
     /// # Errors
@@ -1308,25 +2227,373 @@ impl Server {
         Error::verify_good(&status_code)
     }
 
+    /// Writes several node values.
+    ///
+    /// The size and order of the result list matches the size and order of the given list. Values
+    /// are written one after the other. To write them without client requests being processed in
+    /// between, call this from [`execute()`](Self::execute).
+    ///
+    /// When a node does not exist or its value attribute cannot be written, an `Err` is returned in
+    /// its place, as described in [`write_value()`](Self::write_value).
+    #[must_use]
+    pub fn write_values(&self, node_values: &[(ua::NodeId, ua::Variant)]) -> Vec<Result<()>> {
+        node_values
+            .iter()
+            .map(|(node_id, value)| self.write_value(node_id, value))
+            .collect()
+    }
+
     /// Writes a `DataValue` to a node.
     ///
+    /// Unlike [`write_value()`](Self::write_value), this keeps the status and the source timestamp
+    /// of the given value, when set. Use this to propagate bad or uncertain values from an external
+    /// source. The server timestamp is not stored; this server reports the time of reading.
+    ///
     /// # Errors
     ///
     /// This fails when the node does not exist or its value attribute cannot be written.
-    pub fn write_data_value(&self, node_id: &ua::NodeId, value: &ua::DataValue) -> Result<()> {
-        let status_code = ua::StatusCode::new(unsafe {
-            UA_Server_writeDataValue(
-                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
-                self.0.as_ptr().cast_mut(),
-                // SAFETY: The function expects copies but does not take ownership. It is a wrapper
-                // that internally delegates to `__UA_Server_write()` by pointer.
-                DataType::to_raw_copy(node_id),
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ua, Node, ServerBuilder};
+    /// # use time::macros::datetime;
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// # let node_id = server.add_node(Node::new(
+    /// #     ua::NodeId::ns0_objects_folder(),
+    /// #     ua::NodeId::ns0_organizes(),
+    /// #     ua::QualifiedName::new(1, "Temperature"),
+    /// #     ua::VariableAttributes::init()
+    /// #         .with_data_type(&ua::NodeId::ns0_double())
+    /// #         .with_value_rank(-1),
+    /// # ))?;
+    /// #
+    /// let source_timestamp = ua::DateTime::try_from(datetime!(2024-06-01 12:00 UTC))?;
+    /// let value = ua::DataValue::new(ua::Variant::scalar(ua::Double::new(21.5)))
+    ///     .with_status(&ua::StatusCode::UNCERTAINLASTUSABLEVALUE)
+    ///     .with_source_timestamp(&source_timestamp);
+    /// server.write_data_value(&node_id, &value)?;
+    ///
+    /// let value = server.read_data_value(&node_id);
+    /// assert_eq!(value.status(), Some(ua::StatusCode::UNCERTAINLASTUSABLEVALUE));
+    /// assert_eq!(value.source_timestamp(), Some(&source_timestamp));
+    /// assert!(value.server_timestamp().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_data_value(&self, node_id: &ua::NodeId, value: &ua::DataValue) -> Result<()> {
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_writeDataValue(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                // SAFETY: The function expects copies but does not take ownership. It is a wrapper
+                // that internally delegates to `__UA_Server_write()` by pointer.
+                DataType::to_raw_copy(node_id),
                 DataType::to_raw_copy(value),
             )
         });
         Error::verify_good(&status_code)
     }
 
+    /// Writes typed `DataValue` to a node.
+    ///
+    /// This is like [`write_data_value()`](Self::write_data_value) but takes the value as returned
+    /// by [`AsyncClient`](crate::AsyncClient), e.g. from [`read_value()`]. The value is moved into
+    /// the request without being cloned. The source timestamp is kept, the server timestamp is set
+    /// by this server.
+    ///
+    /// [`read_value()`]: crate::AsyncClient::read_value
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist or its value attribute cannot be written.
+    pub fn write_data_value_from(
+        &self,
+        node_id: &ua::NodeId,
+        value: DataValue<ua::Variant>,
+    ) -> Result<()> {
+        self.write_data_value(
+            node_id,
+            &value.into_raw_data_value().without_server_timestamp(),
+        )
+    }
+
+    /// Writes node attribute.
+    ///
+    /// This is the counterpart to [`read_attribute()`](Self::read_attribute). Use the `_T` variants
+    /// of attribute IDs to have the value type match the attribute, or use a dynamic attribute and
+    /// pass the value as `ua::Variant`.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node does not exist, when the attribute cannot be written, or when the
+    /// value does not match the attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use open62541::{ua, ServerBuilder};
+    /// #
+    /// # fn main() -> anyhow::Result<()> {
+    /// # let (server, _) = ServerBuilder::default().build();
+    /// #
+    /// let node_id = ua::NodeId::ns0_server_status();
+    ///
+    /// // Use static dispatch to pass expected value type directly:
+    /// let display_name = ua::LocalizedText::new("en-US", "Status")?;
+    /// server.write_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T, &display_name)?;
+    ///
+    /// // Use dynamic attribute and wrap value in `ua::Variant` manually:
+    /// let attribute_id: ua::AttributeId = ua::AttributeId::DESCRIPTION;
+    /// let description = ua::LocalizedText::new("en-US", "Status of server")?;
+    /// server.write_attribute(&node_id, &attribute_id, &ua::Variant::scalar(description))?;
+    /// #
+    /// # let read_display_name = server
+    /// #     .read_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T)?
+    /// #     .into_value();
+    /// # assert_eq!(read_display_name, display_name);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_attribute<T: Attribute>(
+        &self,
+        node_id: &ua::NodeId,
+        attribute: T,
+        value: &T::Value,
+    ) -> Result<()> {
+        let status_code = ua::StatusCode::new(unsafe {
+            __UA_Server_write(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                node_id.as_ptr(),
+                attribute.id().into_raw(),
+                // Untyped values (e.g. from `VALUE_T`) are passed on as variants. This is handled
+                // explicitly by `__UA_Server_write()`.
+                T::Value::data_type(),
+                value.as_ptr().cast::<c_void>(),
+            )
+        });
+        Error::verify_good(&status_code)
+    }
+
+    /// Executes closure on server's event loop.
+    ///
+    /// The closure is queued as timed callback without delay and run by [`ServerRunner`] in the next
+    /// iteration of the event loop, i.e. not concurrently with the processing of client requests.
+    /// Use this to batch changes to the address space from async code.
+    ///
+    /// The returned future completes when the closure has been run. The closure is queued right
+    /// away, it is not necessary to poll the future for this.
+    ///
+    /// Note: When the server is not running or is shut down before the closure is executed, the
+    /// closure is never run and the future never completes.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the closure cannot be queued, or when it panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{sync::{atomic::{AtomicBool, Ordering}, Arc}, thread};
+    /// # use open62541::ServerBuilder;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// # let (server, runner) = ServerBuilder::minimal(0, None).build();
+    /// # let cancelled = Arc::new(AtomicBool::new(false));
+    /// # let runner_thread = thread::spawn({
+    /// #     let cancelled = Arc::clone(&cancelled);
+    /// #     move || runner.run_until_cancelled(&mut || cancelled.load(Ordering::Relaxed))
+    /// # });
+    /// #
+    /// server
+    ///     .execute(|server| {
+    ///         // Changes are applied in one go, without processing client requests in between.
+    ///         let ns_index = server.add_namespace("http://hmi-project.com/UA/");
+    ///         assert!(ns_index >= 2);
+    ///     })
+    ///     .await?;
+    /// #
+    /// # cancelled.store(true, Ordering::Relaxed);
+    /// # runner_thread.join().unwrap()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute<F>(&self, f: F) -> impl Future<Output = Result<()>> + Send + 'static
+    where
+        F: FnOnce(&Server) + Send + 'static,
+    {
+        type ExecuteTask = Box<dyn FnOnce() + Send>;
+
+        unsafe extern "C" fn callback_c(_server: *mut UA_Server, data: *mut c_void) {
+            // SAFETY: The timed callback is run exactly once, so we take back ownership here.
+            let task = unsafe { Userdata::<ExecuteTask>::consume(data) };
+            task();
+        }
+
+        let (tx, rx) = oneshot::channel();
+
+        // Hold only weak reference to not keep the server alive when the callback is never run.
+        let server = self.downgrade();
+        let task: ExecuteTask = Box::new(move || {
+            let Some(server) = server.upgrade() else {
+                // Server is being dropped, there is nothing that the closure could do.
+                return;
+            };
+
+            let result = catch_unwind(AssertUnwindSafe(|| f(&server))).map_err(|err| {
+                log::error!("Closure in execute() panicked: {err:?}");
+                Error::internal("closure panicked")
+            });
+
+            // Ignore result. The receiver may have been dropped by now.
+            let _unused = tx.send(result);
+        });
+        let data = Userdata::<ExecuteTask>::prepare(task);
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Server_addTimedCallback(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                Some(callback_c),
+                data,
+                // Run as soon as possible, i.e. in the next iteration of the event loop.
+                0,
+                ptr::null_mut(),
+            )
+        });
+        let result = Error::verify_good(&status_code).inspect_err(|_| {
+            // SAFETY: The callback has not been added, so we still own the data.
+            drop(unsafe { Userdata::<ExecuteTask>::consume(data) });
+        });
+
+        async move {
+            result?;
+            rx.await
+                .unwrap_or_else(|_| Err(Error::internal("closure should have been run")))
+        }
+    }
+
+    /// Enables or disables write protection.
+    ///
+    /// While write protection is enabled, value writes from client sessions are rejected with status
+    /// `BadUserAccessDenied`. Reads are still served. Writes from the server application itself, e.g.
+    /// through [`write_value()`](Self::write_value), are not affected. This does not require changing
+    /// the access level of nodes.
+    ///
+    /// When this method returns, writes that were being processed concurrently have completed and no
+    /// further writes from client sessions are accepted.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node added with [`add_write_protection_node()`] cannot be updated. Write
+    /// protection has been changed nonetheless.
+    ///
+    /// [`add_write_protection_node()`]: Self::add_write_protection_node
+    pub fn set_write_protection(&self, enabled: bool) -> Result<()> {
+        let context = ServerContext::get(&self.0);
+        context.set_write_protected(enabled);
+
+        // Writes from client sessions check the access level while holding the service lock, so
+        // this waits for writes that may have checked the access level before we changed it.
+        self.wait_for_service_lock();
+
+        if let Some(node_id) = context.write_protection_node_id() {
+            self.write_value(&node_id, &ua::Variant::scalar(ua::Boolean::new(enabled)))?;
+        }
+        Ok(())
+    }
+
+    /// Acquires and releases the service lock of the server.
+    ///
+    /// When this returns, services that were being processed while calling this have completed.
+    fn wait_for_service_lock(&self) {
+        // `UA_Server_getNamespaceByIndex()` is the cheapest public function that holds the service
+        // lock. Namespace 0 always exists, the result itself is irrelevant.
+        let mut found_uri = ua::String::init();
+        let _unused = unsafe {
+            UA_Server_getNamespaceByIndex(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.0.as_ptr().cast_mut(),
+                0,
+                found_uri.as_mut_ptr(),
+            )
+        };
+    }
+
+    /// Checks if write protection is enabled.
+    ///
+    /// See [`set_write_protection()`](Self::set_write_protection).
+    #[must_use]
+    pub fn is_write_protected(&self) -> bool {
+        ServerContext::get(&self.0).is_write_protected()
+    }
+
+    /// Adds variable node that reflects write protection.
+    ///
+    /// This adds a read-only `Boolean` variable node below `parent_node_id` and updates it whenever
+    /// [`set_write_protection()`](Self::set_write_protection) is called. Clients may read or monitor
+    /// the node to observe when writes are rejected.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the node cannot be added.
+    pub fn add_write_protection_node(
+        &self,
+        parent_node_id: &ua::NodeId,
+        browse_name: ua::QualifiedName,
+    ) -> Result<ua::NodeId> {
+        let node_id = self.add_node(Node::new(
+            parent_node_id.clone(),
+            ua::NodeId::ns0_has_component(),
+            browse_name,
+            ua::VariableAttributes::init()
+                .with_data_type(&ua::NodeId::ns0_boolean())
+                .with_value_rank(-1)
+                .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+        ))?;
+
+        let context = ServerContext::get(&self.0);
+        context.set_write_protection_node_id(node_id.clone());
+        self.write_value(
+            &node_id,
+            &ua::Variant::scalar(ua::Boolean::new(context.is_write_protected())),
+        )?;
+
+        Ok(node_id)
+    }
+
+    /// Collects health metrics of server.
+    ///
+    /// This returns counters of secure channels, sessions, subscriptions and rejected requests.
+    /// The counters are collected on the server's event loop (see [`execute()`](Self::execute)),
+    /// so they are consistent with each other and do not race with the running server.
+    ///
+    /// Note: When the server is not running, the future never completes.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the server diagnostics summary cannot be read.
+    pub fn metrics(&self) -> impl Future<Output = Result<ServerMetrics>> + Send + 'static {
+        let (tx, rx) = oneshot::channel();
+
+        let executed = self.execute(move |server| {
+            // Ignore result. The receiver may have been dropped by now.
+            let _unused = tx.send(ServerMetrics::collect(server));
+        });
+
+        async move {
+            executed.await?;
+            rx.await
+                .unwrap_or_else(|_| Err(Error::internal("metrics should have been collected")))
+        }
+    }
+
     /// Reads object property.
     ///
     /// # Errors
@@ -1337,26 +2604,23 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, Node, ServerBuilder, ua};
-    /// # use open62541_sys::{
-    /// #     UA_NS0ID_HASPROPERTY, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_ORGANIZES, UA_NS0ID_STRING,
-    /// # };
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
     /// # let (server, _) = ServerBuilder::default().build();
     /// #
     /// # let object_node_id = server.add_node(Node::new(
-    /// #     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-    /// #     ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    /// #     ua::NodeId::ns0_objects_folder(),
+    /// #     ua::NodeId::ns0_organizes(),
     /// #     ua::QualifiedName::new(1, "SomeObject"),
     /// #     ua::ObjectAttributes::init(),
     /// # ))?;
     /// # let variable_node_id = server.add_node(Node::new(
     /// #     object_node_id.clone(),
-    /// #     ua::NodeId::ns0(UA_NS0ID_HASPROPERTY),
+    /// #     ua::NodeId::ns0_has_property(),
     /// #     ua::QualifiedName::new(1, "SomeVariable"),
     /// #     ua::VariableAttributes::init()
-    /// #         .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING))
+    /// #         .with_data_type(&ua::NodeId::ns0_string())
     /// #         .with_value_rank(-1),
     /// # ))?;
     /// #
@@ -1413,26 +2677,23 @@ impl Server {
     ///
     /// ```
     /// # use open62541::{DataType as _, Node, ServerBuilder, ua};
-    /// # use open62541_sys::{
-    /// #     UA_NS0ID_HASPROPERTY, UA_NS0ID_OBJECTSFOLDER, UA_NS0ID_ORGANIZES, UA_NS0ID_STRING,
-    /// # };
     /// #
     /// # #[tokio::main]
     /// # async fn main() -> anyhow::Result<()> {
     /// # let (server, _) = ServerBuilder::default().build();
     /// #
     /// # let object_node_id = server.add_node(Node::new(
-    /// #     ua::NodeId::ns0(UA_NS0ID_OBJECTSFOLDER),
-    /// #     ua::NodeId::ns0(UA_NS0ID_ORGANIZES),
+    /// #     ua::NodeId::ns0_objects_folder(),
+    /// #     ua::NodeId::ns0_organizes(),
     /// #     ua::QualifiedName::new(1, "SomeObject"),
     /// #     ua::ObjectAttributes::init(),
     /// # ))?;
     /// # let variable_node_id = server.add_node(Node::new(
     /// #     object_node_id.clone(),
-    /// #     ua::NodeId::ns0(UA_NS0ID_HASPROPERTY),
+    /// #     ua::NodeId::ns0_has_property(),
     /// #     ua::QualifiedName::new(1, "SomeVariable"),
     /// #     ua::VariableAttributes::init()
-    /// #         .with_data_type(&ua::NodeId::ns0(UA_NS0ID_STRING))
+    /// #         .with_data_type(&ua::NodeId::ns0_string())
     /// #         .with_value_rank(-1),
     /// # ))?;
     /// #
@@ -1466,6 +2727,38 @@ impl Server {
     }
 }
 
+/// Weak handle to OPC UA server.
+///
+/// This does not keep the server alive. Use [`upgrade()`](Self::upgrade) to get back a [`Server`]
+/// while there are other handles left. See [`Server::downgrade()`].
+#[derive(Debug, Clone, Default)]
+pub struct WeakServer(Weak<ua::Server>);
+
+impl WeakServer {
+    /// Creates weak handle that does not refer to any server.
+    ///
+    /// [`upgrade()`](Self::upgrade) always returns `None`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Weak::new())
+    }
+
+    /// Gets server handle.
+    ///
+    /// This returns `None` when the server has been deleted already, i.e. when all [`Server`] and
+    /// [`ServerRunner`] instances have been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Server> {
+        self.0.upgrade().map(Server)
+    }
+}
+
+/// Runner for OPC UA server.
+///
+/// The runner shares ownership of the server with all [`Server`] handles. Its run methods return
+/// only after the server has been shut down completely. When no other [`Server`] handles are left
+/// at that point, the server is deleted before the method returns. Otherwise, it is deleted when
+/// the last [`Server`] handle is dropped, which is always safe after shutdown.
 #[derive(Debug)]
 pub struct ServerRunner {
     server: Arc<ua::Server>,
@@ -1487,7 +2780,10 @@ impl ServerRunner {
     /// Runs the server until interrupted.
     ///
     /// The server is shut down cleanly upon receiving the `SIGINT` signal at which point the method
-    /// returns.
+    /// returns. Shutdown has been completed when the method returns.
+    ///
+    /// Note: Calls of methods added with [`Server::add_async_method_node()`] are not dispatched. Use
+    /// [`run_until_cancelled()`](Self::run_until_cancelled) instead.
     ///
     /// # Errors
     ///
@@ -1520,7 +2816,7 @@ impl ServerRunner {
     /// Runs the server until it is cancelled.
     ///
     /// The server is shut down cleanly when `is_cancelled` returns true at which point the method
-    /// returns.
+    /// returns. Shutdown has been completed when the method returns.
     ///
     /// # Errors
     ///
@@ -1565,6 +2861,11 @@ impl ServerRunner {
                 );
             }
 
+            // Hand over queued calls of async methods to their callbacks. This must happen outside
+            // of `UA_Server_run_iterate()` because the callbacks are invoked through the regular
+            // (locking) service interface.
+            async_operation::dispatch(&server);
+
             let time_taken = start_of_iteration.elapsed();
             log::trace!("Iterate run took {time_taken:?}");
         }
@@ -1607,3 +2908,1443 @@ fn to_browse_result(result: &ua::BrowseResult) -> BrowseResult {
 
     Ok((references.into_vec(), result.continuation_point()))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _, Error, Node, ObjectNode, ObjectTypeNode, ServerBuilder};
+
+    #[test]
+    fn server_urls_with_nul_byte() {
+        let result = ServerBuilder::default().try_server_urls(&["opc.tcp://localhost\0:4840"]);
+        assert!(matches!(result, Err(Error::NulByte("server URL"))));
+    }
+
+    #[test]
+    fn add_namespace_with_nul_byte() {
+        let (server, _) = ServerBuilder::default().build();
+        let result = server.try_add_namespace("http://hmi-project.com/\0/UA/");
+        assert!(matches!(result, Err(Error::NulByte("namespace URI"))));
+    }
+
+    #[test]
+    fn insert_namespace() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let (ns_index, created) = server
+            .insert_namespace("http://hmi-project.com/UA/")
+            .expect("should insert namespace");
+        assert!(created);
+
+        let result = server.insert_namespace("http://hmi-project.com/UA/");
+        assert_eq!(result.ok(), Some((ns_index, false)));
+        assert_eq!(server.add_namespace("http://hmi-project.com/UA/"), ns_index);
+    }
+
+    #[test]
+    fn server_array() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let application_uri = server.get_namespace_by_index(1).unwrap();
+        let application_uri = application_uri.as_str().unwrap();
+
+        let server_array = server.server_array().expect("should read server array");
+        assert_eq!(
+            server_array
+                .iter()
+                .map(ua::String::as_str)
+                .collect::<Vec<_>>(),
+            [Some(application_uri)]
+        );
+
+        server
+            .set_server_array(&[application_uri, "urn:downstream"])
+            .expect("should write server array");
+        let server_array = server.server_array().expect("should read server array");
+        assert_eq!(
+            server_array
+                .iter()
+                .map(ua::String::as_str)
+                .collect::<Vec<_>>(),
+            [Some(application_uri), Some("urn:downstream")]
+        );
+
+        // First entry must be local application URI.
+        let result = server.set_server_array(&["urn:downstream"]);
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADINVALIDARGUMENT)
+        );
+    }
+
+    #[test]
+    fn browse_next_release() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let browse_description =
+            ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_server());
+        let (references, continuation_point) = server
+            .browse(1, &browse_description)
+            .expect("should browse node");
+        assert_eq!(references.len(), 1);
+        let continuation_point = continuation_point.expect("should return continuation point");
+
+        server
+            .browse_next_release(&continuation_point)
+            .expect("should release continuation point");
+
+        // Released continuation points can neither be browsed nor released again.
+        let result = server.browse_next(&continuation_point);
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADCONTINUATIONPOINTINVALID)
+        );
+        let result = server.browse_next_release(&continuation_point);
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADCONTINUATIONPOINTINVALID)
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn model_change_events() {
+        use std::time::Duration;
+
+        use crate::{AsyncMonitoredItem, ClientBuilder, MonitoredItemBuilder, TestHarness};
+
+        async fn next_event_type(monitored_item: &mut AsyncMonitoredItem) -> Option<ua::NodeId> {
+            let event = tokio::time::timeout(Duration::from_secs(5), monitored_item.next_event())
+                .await
+                .expect("should receive model change event")
+                .expect("should not end");
+            event.field_as::<ua::NodeId>("EventType").cloned()
+        }
+
+        let harness = TestHarness::start_with(
+            ServerBuilder::minimal(0, None).model_change_events(true),
+            ClientBuilder::default(),
+            |_| {},
+        )
+        .expect("should start harness");
+        let server = harness.server();
+
+        let subscription = harness.client().create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server()])
+            .attribute_id(ua::AttributeId::EVENTNOTIFIER)
+            .filter(
+                ua::EventFilter::init()
+                    .with_select_simple("EventType")
+                    .with_where_clause(ua::ContentFilter::of_type(
+                        &ua::NodeId::ns0_base_model_change_event_type(),
+                    )),
+            )
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        server
+            .emit_model_change_event()
+            .expect("should emit model change event");
+        assert_eq!(
+            next_event_type(&mut monitored_item).await,
+            Some(ua::NodeId::ns0_base_model_change_event_type())
+        );
+
+        // Adding and deleting nodes emits events automatically.
+        let node_id = server
+            .add_object_node(ObjectNode {
+                requested_new_node_id: None,
+                parent_node_id: ua::NodeId::ns0_objects_folder(),
+                reference_type_id: ua::NodeId::ns0_organizes(),
+                browse_name: ua::QualifiedName::new(1, "Folder"),
+                type_definition: ua::NodeId::ns0_folder_type(),
+                attributes: ua::ObjectAttributes::init(),
+            })
+            .expect("should add node");
+        assert_eq!(
+            next_event_type(&mut monitored_item).await,
+            Some(ua::NodeId::ns0_base_model_change_event_type())
+        );
+        server.delete_node(&node_id).expect("should delete node");
+        assert_eq!(
+            next_event_type(&mut monitored_item).await,
+            Some(ua::NodeId::ns0_base_model_change_event_type())
+        );
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn no_model_change_events_by_default() {
+        use std::time::Duration;
+
+        use crate::{MonitoredItemBuilder, TestHarness};
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let subscription = harness.client().create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server()])
+            .attribute_id(ua::AttributeId::EVENTNOTIFIER)
+            .filter(
+                ua::EventFilter::init()
+                    .with_select_simple("EventType")
+                    .with_where_clause(ua::ContentFilter::of_type(
+                        &ua::NodeId::ns0_base_model_change_event_type(),
+                    )),
+            )
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        harness
+            .server()
+            .add_object_node(ObjectNode {
+                requested_new_node_id: None,
+                parent_node_id: ua::NodeId::ns0_objects_folder(),
+                reference_type_id: ua::NodeId::ns0_organizes(),
+                browse_name: ua::QualifiedName::new(1, "Folder"),
+                type_definition: ua::NodeId::ns0_folder_type(),
+                attributes: ua::ObjectAttributes::init(),
+            })
+            .expect("should add node");
+
+        let result =
+            tokio::time::timeout(Duration::from_secs(1), monitored_item.next_event()).await;
+        assert!(result.is_err(), "should not receive model change event");
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[test]
+    fn add_object_type_node() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let motor_type = server
+            .add_object_type_node(ObjectTypeNode {
+                requested_new_node_id: None,
+                parent_node_id: ua::NodeId::ns0_base_object_type(),
+                reference_type_id: ua::NodeId::ns0_has_subtype(),
+                browse_name: ua::QualifiedName::new(1, "MotorType"),
+                attributes: ua::ObjectTypeAttributes::default(),
+            })
+            .expect("should add object type node");
+
+        let motor = server
+            .add_object_node(ObjectNode {
+                requested_new_node_id: None,
+                parent_node_id: ua::NodeId::ns0_objects_folder(),
+                reference_type_id: ua::NodeId::ns0_organizes(),
+                browse_name: ua::QualifiedName::new(1, "Motor"),
+                type_definition: motor_type.clone(),
+                attributes: ua::ObjectAttributes::default(),
+            })
+            .expect("should add object node");
+
+        let (references, _) = server
+            .browse(
+                0,
+                &ua::BrowseDescription::default()
+                    .with_node_id(&motor)
+                    .with_reference_type_id(&ua::NodeId::ns0_has_type_definition()),
+            )
+            .expect("should browse object node");
+        let type_definitions: Vec<_> = references
+            .iter()
+            .map(|reference| reference.node_id().node_id())
+            .collect();
+        assert_eq!(type_definitions, [&motor_type]);
+    }
+
+    #[test]
+    fn add_type_nodes() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let reference_type = server
+            .add_reference_type_node(
+                &ua::NodeId::ns0_non_hierarchical_references(),
+                ua::QualifiedName::new(1, "Feeds"),
+                ua::ReferenceTypeAttributes::default()
+                    .with_inverse_name(&ua::LocalizedText::new("", "FedBy").unwrap()),
+            )
+            .expect("should add reference type node");
+        let data_type = server
+            .add_data_type_node(
+                &ua::NodeId::ns0_base_data_type(),
+                ua::QualifiedName::new(1, "Measurement"),
+                ua::DataTypeAttributes::default().with_is_abstract(true),
+            )
+            .expect("should add data type node");
+
+        let subtypes = |node_id: &ua::NodeId| {
+            server
+                .browse_recursive(&ua::BrowseDescription::default().with_node_id(node_id))
+                .expect("should browse type hierarchy")
+                .iter()
+                .map(|target| target.node_id().clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(subtypes(&ua::NodeId::ns0_references()).contains(&reference_type));
+        assert!(subtypes(&ua::NodeId::ns0_base_data_type()).contains(&data_type));
+
+        let is_abstract = server
+            .read_attribute(&data_type, ua::AttributeId::ISABSTRACT_T)
+            .expect("should read attribute")
+            .into_value();
+        assert!(is_abstract.value());
+    }
+
+    #[test]
+    fn delete_node_recursive() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let add_node = |parent_node_id: &ua::NodeId, name: &str| {
+            server
+                .add_node(Node::new(
+                    parent_node_id.clone(),
+                    ua::NodeId::ns0_organizes(),
+                    ua::QualifiedName::new(1, name),
+                    ua::ObjectAttributes::init(),
+                ))
+                .expect("should add node")
+        };
+        let exists = |node_id: &ua::NodeId| {
+            server
+                .read_attribute(node_id, ua::AttributeId::NODEID_T)
+                .is_ok()
+        };
+
+        // Build tree with shared node that is also referenced from outside of the tree:
+        //
+        //   Device ─┬─ Child ─── Grandchild
+        //           └─ Shared ─┐
+        //   Other ─────────────┘
+        //
+        let device = add_node(&ua::NodeId::ns0_objects_folder(), "Device");
+        let child = add_node(&device, "Child");
+        let grandchild = add_node(&child, "Grandchild");
+        let shared = add_node(&device, "Shared");
+        let other = add_node(&ua::NodeId::ns0_objects_folder(), "Other");
+        server
+            .add_reference(
+                &other,
+                &ua::NodeId::ns0_organizes(),
+                &shared.clone().into_expanded_node_id(),
+                true,
+            )
+            .expect("should add reference");
+
+        let deleted = server
+            .delete_node_recursive(&device)
+            .expect("should delete nodes");
+        assert_eq!(deleted, 3);
+
+        assert!(!exists(&device));
+        assert!(!exists(&child));
+        assert!(!exists(&grandchild));
+        assert!(exists(&shared));
+        assert!(exists(&other));
+    }
+
+    #[test]
+    fn translate_browse_path_to_node_id() {
+        let (server, _) = ServerBuilder::default().build();
+
+        let browse_path = |names: &[&str]| {
+            ua::BrowsePath::from_segments(
+                &ua::NodeId::ns0_server(),
+                names.iter().map(|name| {
+                    ua::RelativePathElement::hierarchical(&ua::QualifiedName::new(0, name))
+                }),
+            )
+        };
+
+        let node_id = server
+            .translate_browse_path_to_node_id(&browse_path(&["ServerStatus", "BuildInfo"]))
+            .expect("should resolve browse path");
+        assert_eq!(node_id, ua::NodeId::ns0_server_status_build_info());
+
+        let result = server.translate_browse_path_to_node_id(&browse_path(&["Unknown"]));
+        assert_eq!(
+            result.map_err(|error| error.status_code()).err(),
+            Some(ua::StatusCode::BADNOMATCH)
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_on_event_loop() {
+        use std::{
+            sync::{
+                atomic::{AtomicBool, Ordering},
+                Arc, Mutex,
+            },
+            thread,
+        };
+
+        // Use dynamic port, the test does not connect to the server.
+        let (server, runner) = ServerBuilder::minimal(0, None).build();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let runner_thread = thread::spawn({
+            let cancelled = Arc::clone(&cancelled);
+            move || runner.run_until_cancelled(&mut || cancelled.load(Ordering::Relaxed))
+        });
+
+        let runner_thread_id = runner_thread.thread().id();
+        let executed_on = Arc::new(Mutex::new(None));
+        server
+            .execute({
+                let executed_on = Arc::clone(&executed_on);
+                move |_| *executed_on.lock().unwrap() = Some(thread::current().id())
+            })
+            .await
+            .expect("should execute closure");
+        assert_eq!(*executed_on.lock().unwrap(), Some(runner_thread_id));
+
+        // Panics are caught and reported.
+        let result = server.execute(|_| panic!("closure panics")).await;
+        assert!(result.is_err());
+
+        cancelled.store(true, Ordering::Relaxed);
+        runner_thread.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn shutdown_with_server_handles_on_other_threads() {
+        use std::{
+            sync::{
+                atomic::{AtomicBool, Ordering},
+                Arc,
+            },
+            thread,
+            time::Duration,
+        };
+
+        // Repeat to catch races between shutdown and handles being dropped on other threads. Use
+        // dynamic ports to not depend on sockets from previous rounds being released.
+        for _ in 0..4 {
+            let (server, runner) = ServerBuilder::minimal(0, None).build();
+            let weak_server = server.downgrade();
+
+            let cancelled = Arc::new(AtomicBool::new(false));
+            let runner_thread = thread::spawn({
+                let cancelled = Arc::clone(&cancelled);
+                move || runner.run_until_cancelled(&mut || cancelled.load(Ordering::Relaxed))
+            });
+
+            let worker_threads: Vec<_> = (0..4)
+                .map(|worker| {
+                    let server = server.clone();
+                    let cancelled = Arc::clone(&cancelled);
+                    thread::spawn(move || {
+                        let node_id = server
+                            .add_node(Node::new(
+                                ua::NodeId::ns0_objects_folder(),
+                                ua::NodeId::ns0_organizes(),
+                                ua::QualifiedName::new(1, &format!("Worker{worker}")),
+                                ua::VariableAttributes::init()
+                                    .with_data_type(&ua::NodeId::ns0_string())
+                                    .with_value_rank(-1),
+                            ))
+                            .expect("should add node");
+                        let mut count = 0;
+                        while !cancelled.load(Ordering::Relaxed) || count < 10 {
+                            let value =
+                                ua::Variant::scalar(ua::String::new(&count.to_string()).unwrap());
+                            // Writing may fail while the server is shutting down, but it must not
+                            // crash.
+                            let _unused = server.write_value(&node_id, &value);
+                            count += 1;
+                        }
+                        // Drop server handle here, possibly while the runner is shutting down.
+                        drop(server);
+                    })
+                })
+                .collect();
+
+            thread::sleep(Duration::from_millis(20));
+            cancelled.store(true, Ordering::Relaxed);
+            drop(server);
+
+            runner_thread.join().unwrap().unwrap();
+            for worker_thread in worker_threads {
+                worker_thread.join().unwrap();
+            }
+
+            // All handles have been dropped, the server must have been deleted.
+            assert!(weak_server.upgrade().is_none());
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn write_protection() {
+        use crate::TestHarness;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let server = harness.server();
+
+        let node_id = server
+            .add_node(Node::new(
+                ua::NodeId::ns0_objects_folder(),
+                ua::NodeId::ns0_organizes(),
+                ua::QualifiedName::new(1, "Value"),
+                ua::VariableAttributes::init()
+                    .with_data_type(&ua::NodeId::ns0_int32())
+                    .with_value_rank(-1)
+                    .with_access_level(
+                        &ua::AccessLevel::NONE
+                            .with_current_read(true)
+                            .with_current_write(true),
+                    ),
+            ))
+            .expect("should add node");
+        let status_node_id = server
+            .add_write_protection_node(
+                &ua::NodeId::ns0_objects_folder(),
+                ua::QualifiedName::new(1, "WriteProtection"),
+            )
+            .expect("should add status node");
+
+        let value = |value| ua::DataValue::new(ua::Variant::scalar(ua::Int32::new(value)));
+        let is_protected = || async {
+            harness
+                .client()
+                .read_value(&status_node_id)
+                .await
+                .expect("should read status node")
+                .value()
+                .as_scalar::<ua::Boolean>()
+                .map(ua::Boolean::value)
+        };
+
+        let client = harness.client();
+        client
+            .write_value(&node_id, &value(1))
+            .await
+            .expect("should write value");
+        assert_eq!(is_protected().await, Some(false));
+
+        server
+            .set_write_protection(true)
+            .expect("should enable write protection");
+        assert!(server.is_write_protected());
+        assert_eq!(is_protected().await, Some(true));
+        let result = client.write_value(&node_id, &value(2)).await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()).err(),
+            Some(ua::StatusCode::BADUSERACCESSDENIED)
+        );
+        // Reads and writes from the server application still work.
+        server
+            .write_value(&node_id, &ua::Variant::scalar(ua::Int32::new(3)))
+            .expect("should write value from server");
+        let read = client
+            .read_value(&node_id)
+            .await
+            .expect("should read value");
+        assert_eq!(read.value().as_scalar(), Some(&ua::Int32::new(3)));
+
+        server
+            .set_write_protection(false)
+            .expect("should disable write protection");
+        assert_eq!(is_protected().await, Some(false));
+        client
+            .write_value(&node_id, &value(4))
+            .await
+            .expect("should write value");
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[test]
+    fn write_attribute() {
+        let (server, _) = ServerBuilder::default().build();
+        let node_id = ua::NodeId::ns0_server_status();
+
+        let display_name = ua::LocalizedText::new("en-US", "Status").unwrap();
+        server
+            .write_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T, &display_name)
+            .expect("should write display name");
+        let read_display_name = server
+            .read_attribute(&node_id, ua::AttributeId::DISPLAYNAME_T)
+            .expect("should read display name")
+            .into_value();
+        assert_eq!(read_display_name, display_name);
+
+        let result = server.write_attribute(
+            &node_id,
+            ua::AttributeId::NODEID_T,
+            &ua::NodeId::ns0(123_456_789),
+        );
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADWRITENOTSUPPORTED)
+        );
+    }
+
+    #[test]
+    fn read_write_values() {
+        let (server, _) = ServerBuilder::default().build();
+        let node_id = server
+            .add_node(Node::new(
+                ua::NodeId::ns0_objects_folder(),
+                ua::NodeId::ns0_organizes(),
+                ua::QualifiedName::new(1, "Value"),
+                ua::VariableAttributes::init()
+                    .with_data_type(&ua::NodeId::ns0_int32())
+                    .with_value_rank(-1)
+                    .with_access_level(
+                        &ua::AccessLevel::NONE
+                            .with_current_read(true)
+                            .with_current_write(true),
+                    ),
+            ))
+            .expect("should add node");
+        let unknown_node_id = ua::NodeId::numeric(1, 123_456_789);
+
+        let results = server.write_values(&[
+            (node_id.clone(), ua::Variant::scalar(ua::Int32::new(42))),
+            (
+                unknown_node_id.clone(),
+                ua::Variant::scalar(ua::Int32::new(43)),
+            ),
+        ]);
+        let status_codes: Vec<_> = results
+            .into_iter()
+            .map(|result| result.map_err(|error| error.status_code()))
+            .collect();
+        assert_eq!(
+            status_codes,
+            [Ok(()), Err(ua::StatusCode::BADNODEIDUNKNOWN)]
+        );
+
+        let mut results = server.read_values(&[node_id, unknown_node_id]).into_iter();
+        let value = results.next().unwrap().expect("should read value");
+        assert_eq!(value.value().as_scalar(), Some(&ua::Int32::new(42)));
+        let result = results.next().unwrap();
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNODEIDUNKNOWN)
+        );
+        assert!(results.next().is_none());
+    }
+
+    #[test]
+    fn set_variable_node_value_callback() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::ValueCallback;
+
+        struct RecordingCallback(Arc<Mutex<Vec<(&'static str, Option<i32>)>>>);
+
+        impl ValueCallback for RecordingCallback {
+            fn on_read(&mut self, _node_id: &ua::NodeId, value: &ua::DataValue) {
+                let value = value
+                    .value()
+                    .and_then(|value| value.as_scalar::<ua::Int32>());
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(("read", value.map(ua::Int32::value)));
+            }
+
+            fn on_write(&mut self, _node_id: &ua::NodeId, value: &ua::DataValue) {
+                let value = value
+                    .value()
+                    .and_then(|value| value.as_scalar::<ua::Int32>());
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(("write", value.map(ua::Int32::value)));
+            }
+        }
+
+        let (server, _) = ServerBuilder::default().build();
+        let node_id = server
+            .add_node(Node::new(
+                ua::NodeId::ns0_objects_folder(),
+                ua::NodeId::ns0_organizes(),
+                ua::QualifiedName::new(1, "Value"),
+                ua::VariableAttributes::init()
+                    .with_data_type(&ua::NodeId::ns0_int32())
+                    .with_value_rank(-1),
+            ))
+            .expect("should add node");
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        server
+            .set_variable_node_value_callback(&node_id, RecordingCallback(Arc::clone(&calls)))
+            .expect("should set value callback");
+
+        server
+            .write_value(&node_id, &ua::Variant::scalar(ua::Int32::new(42)))
+            .expect("should write value");
+        let value = server
+            .read_attribute(&node_id, ua::AttributeId::VALUE_T)
+            .expect("should read value");
+        assert_eq!(value.value().as_scalar(), Some(&ua::Int32::new(42)));
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [("write", Some(42)), ("read", Some(42))]
+        );
+
+        // Setting the callback again would replace the associated node context.
+        let result = server
+            .set_variable_node_value_callback(&node_id, RecordingCallback(Arc::clone(&calls)));
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADINVALIDSTATE)
+        );
+
+        // Value callbacks are only supported for variable nodes.
+        let result = server.set_variable_node_value_callback(
+            &ua::NodeId::ns0_objects_folder(),
+            RecordingCallback(Arc::clone(&calls)),
+        );
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADNODECLASSINVALID)
+        );
+    }
+
+    #[test]
+    fn release_node_contexts() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use crate::{DataSource, DataSourceReadContext, DataSourceResult, VariableNode};
+
+        struct CountingDataSource(Arc<AtomicUsize>);
+
+        impl DataSource for CountingDataSource {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                context.set_variant(ua::Variant::scalar(ua::Int32::new(0)));
+                Ok(())
+            }
+        }
+
+        impl Drop for CountingDataSource {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        const NODE_COUNT: usize = 2_000;
+
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let (server, _) = ServerBuilder::default().build();
+
+        let variable_node = |index| VariableNode {
+            requested_new_node_id: None,
+            parent_node_id: ua::NodeId::ns0_objects_folder(),
+            reference_type_id: ua::NodeId::ns0_organizes(),
+            browse_name: ua::QualifiedName::new(1, &format!("Value{index}")),
+            type_definition: ua::NodeId::ns0_base_data_variable_type(),
+            attributes: ua::VariableAttributes::init()
+                .with_data_type(&ua::NodeId::ns0_int32())
+                .with_value_rank(-1),
+        };
+
+        // Deleting nodes releases their node contexts.
+        for index in 0..NODE_COUNT {
+            let node_id = server
+                .add_data_source_variable_node(
+                    variable_node(index),
+                    CountingDataSource(Arc::clone(&dropped)),
+                )
+                .expect("should add node");
+            server.delete_node(&node_id).expect("should delete node");
+        }
+        assert_eq!(dropped.load(Ordering::SeqCst), NODE_COUNT);
+
+        // Dropping the server releases the node contexts of remaining nodes.
+        for index in 0..NODE_COUNT {
+            server
+                .add_data_source_variable_node(
+                    variable_node(index),
+                    CountingDataSource(Arc::clone(&dropped)),
+                )
+                .expect("should add node");
+        }
+        drop(server);
+        assert_eq!(dropped.load(Ordering::SeqCst), 2 * NODE_COUNT);
+    }
+
+    #[cfg(all(feature = "time", feature = "tokio"))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn data_source_timestamp_and_status() {
+        use std::sync::{Arc, Mutex};
+
+        use time::macros::datetime;
+
+        use crate::{
+            DataSource, DataSourceReadContext, DataSourceResult, ReadOptions, TestHarness,
+            VariableNode,
+        };
+
+        struct TimestampDataSource {
+            source_timestamp: ua::DateTime,
+            status: Arc<Mutex<Option<ua::StatusCode>>>,
+            include_source_timestamp: Arc<Mutex<Vec<bool>>>,
+        }
+
+        impl DataSource for TimestampDataSource {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                self.include_source_timestamp
+                    .lock()
+                    .unwrap()
+                    .push(context.include_source_timestamp());
+                context.set_variant(ua::Variant::scalar(ua::Int32::new(42)));
+                context.set_source_timestamp(self.source_timestamp.clone());
+                if let Some(status) = self.status.lock().unwrap().clone() {
+                    context.set_status(status);
+                }
+                Ok(())
+            }
+        }
+
+        let source_timestamp = ua::DateTime::try_from(datetime!(2024-06-01 12:00 UTC)).unwrap();
+        let status = Arc::new(Mutex::new(None));
+        let include_source_timestamp = Arc::new(Mutex::new(Vec::new()));
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let node_id = harness
+            .server()
+            .add_data_source_variable_node(
+                VariableNode {
+                    requested_new_node_id: None,
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(1, "Value"),
+                    type_definition: ua::NodeId::ns0_base_data_variable_type(),
+                    attributes: ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(-1)
+                        .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+                },
+                TimestampDataSource {
+                    source_timestamp: source_timestamp.clone(),
+                    status: Arc::clone(&status),
+                    include_source_timestamp: Arc::clone(&include_source_timestamp),
+                },
+            )
+            .expect("should add node");
+        let read_value = |timestamps| {
+            let node_id = node_id.clone();
+            let client = harness.client();
+            async move {
+                client
+                    .read_value_with_options(
+                        &node_id,
+                        &ReadOptions {
+                            timestamps,
+                            ..ReadOptions::default()
+                        },
+                    )
+                    .await
+            }
+        };
+
+        let value = read_value(ua::TimestampsToReturn::SOURCE)
+            .await
+            .expect("should read value");
+        assert_eq!(value.value().as_scalar(), Some(&ua::Int32::new(42)));
+        assert_eq!(value.source_timestamp(), Some(&source_timestamp));
+
+        let value = read_value(ua::TimestampsToReturn::SERVER)
+            .await
+            .expect("should read value");
+        assert!(value.source_timestamp().is_none());
+        assert_eq!(*include_source_timestamp.lock().unwrap(), [true, false]);
+
+        *status.lock().unwrap() = Some(ua::StatusCode::UNCERTAINLASTUSABLEVALUE);
+        let result = read_value(ua::TimestampsToReturn::BOTH).await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::UNCERTAINLASTUSABLEVALUE)
+        );
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn data_source_index_range() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{
+            DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+            DataSourceWriteContext, TestHarness, VariableNode,
+        };
+
+        struct ArrayDataSource(Arc<Mutex<Vec<i32>>>);
+
+        impl DataSource for ArrayDataSource {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                let values = self.0.lock().unwrap();
+                let value = ua::Variant::array(ua::Array::from_iter(
+                    values.iter().copied().map(ua::Int32::new),
+                ));
+                let value = match context.index_range() {
+                    Some(index_range) => value.slice_by_range(index_range)?,
+                    None => value,
+                };
+                context.set_variant(value);
+                Ok(())
+            }
+
+            fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+                let written = context
+                    .value()
+                    .value()
+                    .and_then(ua::Variant::to_array::<ua::Int32>)
+                    .ok_or_else(|| {
+                        DataSourceError::from_status_code(ua::StatusCode::BADTYPEMISMATCH)
+                    })?;
+                let mut values = self.0.lock().unwrap();
+                let offset = match context.index_range().map(ua::NumericRange::dimensions) {
+                    Some(&[(min, _)]) => usize::try_from(min).unwrap(),
+                    Some(_) => {
+                        return Err(DataSourceError::from_status_code(
+                            ua::StatusCode::BADINDEXRANGEINVALID,
+                        ))
+                    }
+                    None => {
+                        values.clear();
+                        values.resize(written.len(), 0);
+                        0
+                    }
+                };
+                let Some(target) = values.get_mut(offset..offset + written.len()) else {
+                    return Err(DataSourceError::from_status_code(
+                        ua::StatusCode::BADINDEXRANGENODATA,
+                    ));
+                };
+                for (target, value) in target.iter_mut().zip(written.iter()) {
+                    *target = value.value();
+                }
+                Ok(())
+            }
+        }
+
+        let values = Arc::new(Mutex::new(vec![0, 1, 2, 3, 4]));
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let node_id = harness
+            .server()
+            .add_data_source_variable_node(
+                VariableNode {
+                    requested_new_node_id: None,
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(1, "Values"),
+                    type_definition: ua::NodeId::ns0_base_data_variable_type(),
+                    attributes: ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_int32())
+                        .with_value_rank(1)
+                        .with_access_level(
+                            &ua::AccessLevel::NONE
+                                .with_current_read(true)
+                                .with_current_write(true),
+                        ),
+                },
+                ArrayDataSource(Arc::clone(&values)),
+            )
+            .expect("should add node");
+        let client = harness.client();
+
+        let index_range: ua::NumericRange = "2:3".parse().unwrap();
+        let value = ua::DataValue::new(ua::Variant::array(ua::Array::from_slice(&[
+            ua::Int32::new(20),
+            ua::Int32::new(30),
+        ])));
+        client
+            .write_value_range(&node_id, &index_range, &value)
+            .await
+            .expect("should write value range");
+        assert_eq!(*values.lock().unwrap(), [0, 1, 20, 30, 4]);
+
+        let index_range: ua::NumericRange = "1:2".parse().unwrap();
+        let value = client
+            .read_value_range(&node_id, &index_range)
+            .await
+            .expect("should read value range");
+        let value = value.value().to_array::<ua::Int32>().unwrap();
+        assert_eq!(value.as_slice(), &[ua::Int32::new(1), ua::Int32::new(20)]);
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn data_source_session_data() {
+        use crate::{
+            ClientBuilder, DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+            DataSourceWriteContext, DefaultAccessControlWithLoginCallback, Login, TestHarness,
+            VariableNode,
+        };
+
+        #[derive(Debug, PartialEq)]
+        enum Role {
+            Operator,
+            Guest,
+        }
+
+        struct RoleDataSource;
+
+        impl DataSource for RoleDataSource {
+            fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                assert_ne!(context.session_id(), &ua::NodeId::null());
+                let role = context
+                    .session_data()
+                    .and_then(|session_data| session_data.downcast_ref::<Role>());
+                context.set_variant(ua::Variant::scalar(ua::String::new(&format!("{role:?}"))?));
+                Ok(())
+            }
+
+            fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+                let role = context
+                    .session_data()
+                    .and_then(|session_data| session_data.downcast_ref::<Role>());
+                if role != Some(&Role::Operator) {
+                    return Err(DataSourceError::from_status_code(
+                        ua::StatusCode::BADUSERACCESSDENIED,
+                    ));
+                }
+                Ok(())
+            }
+        }
+
+        let login_callback = |user_name: &ua::String, password: &ua::ByteString| {
+            if password.as_bytes() != Some(b"secret") {
+                return Login::reject(ua::StatusCode::BADUSERACCESSDENIED);
+            }
+            match user_name.as_str() {
+                Some("operator") => Login::accept_with(Role::Operator),
+                Some("guest") => Login::accept_with(Role::Guest),
+                _ => Login::reject(ua::StatusCode::BADUSERACCESSDENIED),
+            }
+        };
+        let mut server_builder = ServerBuilder::minimal(0, None)
+            .access_control(DefaultAccessControlWithLoginCallback::new(
+                false,
+                login_callback,
+            ))
+            .expect("should set access control");
+        // The test client connects without encryption.
+        server_builder.config_mut().allowNonePolicyPassword = true;
+
+        let harness = TestHarness::start_with(
+            server_builder,
+            ClientBuilder::default().username_password("guest", "secret"),
+            |_| {},
+        )
+        .expect("should start harness");
+        let node_id = harness
+            .server()
+            .add_data_source_variable_node(
+                VariableNode {
+                    requested_new_node_id: None,
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(1, "Role"),
+                    type_definition: ua::NodeId::ns0_base_data_variable_type(),
+                    attributes: ua::VariableAttributes::init()
+                        .with_data_type(&ua::NodeId::ns0_string())
+                        .with_access_level(
+                            &ua::AccessLevel::NONE
+                                .with_current_read(true)
+                                .with_current_write(true),
+                        ),
+                },
+                RoleDataSource,
+            )
+            .expect("should add node");
+        let client = harness.client();
+
+        let value = client
+            .read_value(&node_id)
+            .await
+            .expect("should read value");
+        let value = value.value().to_scalar::<ua::String>().unwrap();
+        assert_eq!(value.as_str(), Some("Some(Guest)"));
+
+        let value = ua::DataValue::new(ua::Variant::scalar(ua::String::new("").unwrap()));
+        let result = client.write_value(&node_id, &value).await;
+        assert_eq!(
+            result.map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADUSERACCESSDENIED)
+        );
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn async_data_source() {
+        use std::time::Duration;
+
+        use crate::{
+            AsyncDataSource, DataSourceReadContext, DataSourceResult, TestHarness, VariableNode,
+        };
+
+        struct DelayedDataSource(Duration);
+
+        impl AsyncDataSource for DelayedDataSource {
+            async fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+                tokio::time::sleep(self.0).await;
+                context.set_variant(ua::Variant::scalar(ua::Int32::new(42)));
+                Ok(())
+            }
+
+            fn timeout(&self) -> Duration {
+                Duration::from_millis(200)
+            }
+        }
+
+        let variable_node = |name: &str| VariableNode {
+            requested_new_node_id: None,
+            parent_node_id: ua::NodeId::ns0_objects_folder(),
+            reference_type_id: ua::NodeId::ns0_organizes(),
+            browse_name: ua::QualifiedName::new(1, name),
+            type_definition: ua::NodeId::ns0_base_data_variable_type(),
+            attributes: ua::VariableAttributes::init()
+                .with_data_type(&ua::NodeId::ns0_int32())
+                .with_access_level(&ua::AccessLevel::NONE.with_current_read(true)),
+        };
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let runtime_handle = tokio::runtime::Handle::current();
+        let fast_node_id = harness
+            .server()
+            .add_async_data_source_variable_node(
+                variable_node("Fast"),
+                DelayedDataSource(Duration::from_millis(10)),
+                runtime_handle.clone(),
+            )
+            .expect("should add node");
+        let slow_node_id = harness
+            .server()
+            .add_async_data_source_variable_node(
+                variable_node("Slow"),
+                DelayedDataSource(Duration::from_secs(10)),
+                runtime_handle,
+            )
+            .expect("should add node");
+        let client = harness.client();
+
+        let value = client
+            .read_value(&fast_node_id)
+            .await
+            .expect("should read value");
+        assert_eq!(value.value().as_scalar(), Some(&ua::Int32::new(42)));
+
+        let result = client.read_value(&slow_node_id).await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADTIMEOUT)
+        );
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(all(feature = "time", feature = "tokio"))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn event_builder() {
+        use time::macros::datetime;
+
+        use crate::{MonitoredItemBuilder, TestHarness};
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let client = harness.client();
+
+        let subscription = client.create_subscription().await.unwrap();
+        let mut results = MonitoredItemBuilder::new([ua::NodeId::ns0_server()])
+            .attribute_id(ua::AttributeId::EVENTNOTIFIER)
+            .filter(
+                ua::EventFilter::init()
+                    .with_select_simple("Severity")
+                    .with_select_simple("Message")
+                    .with_select_simple("SourceName")
+                    .with_select_simple("Time")
+                    .with_where_clause(ua::ContentFilter::of_type(
+                        &ua::NodeId::ns0_base_event_type(),
+                    )),
+            )
+            .create(&subscription)
+            .await
+            .unwrap();
+        let (_, mut monitored_item) = results.pop().unwrap().unwrap();
+
+        let message = ua::LocalizedText::new("en-US", "Temperature too high").unwrap();
+        let time = ua::DateTime::try_from(datetime!(2024-01-02 03:04:05 UTC)).unwrap();
+        harness
+            .server()
+            .event_builder(&ua::NodeId::ns0_base_event_type())
+            .severity(500)
+            .message(message.clone())
+            .source_name("Boiler")
+            .time(time.clone())
+            .trigger(&ua::NodeId::ns0_server())
+            .expect("should trigger event");
+
+        let event = monitored_item
+            .next_event()
+            .await
+            .expect("should receive event");
+        assert_eq!(
+            event.field_as::<ua::UInt16>("Severity"),
+            Some(&ua::UInt16::new(500))
+        );
+        assert_eq!(
+            event.field_as::<ua::LocalizedText>("Message"),
+            Some(&message)
+        );
+        assert_eq!(
+            event
+                .field_as::<ua::String>("SourceName")
+                .and_then(ua::String::as_str),
+            Some("Boiler")
+        );
+        assert_eq!(event.field_as::<ua::DateTime>("Time"), Some(&time));
+
+        // Unknown properties fail and do not emit an event.
+        let result = harness
+            .server()
+            .event_builder(&ua::NodeId::ns0_base_event_type())
+            .property(
+                ua::QualifiedName::new(1, "Unknown"),
+                ua::Variant::scalar(ua::Int32::new(1)),
+            )
+            .trigger(&ua::NodeId::ns0_server());
+        assert!(result.is_err());
+
+        // Invalid source names fail when triggered instead of panicking.
+        let result = harness
+            .server()
+            .event_builder(&ua::NodeId::ns0_base_event_type())
+            .source_name("Boi\0ler")
+            .trigger(&ua::NodeId::ns0_server());
+        assert!(matches!(result, Err(Error::NulByte("source name"))));
+
+        drop(monitored_item);
+        drop(subscription);
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn async_method_node() {
+        use std::thread;
+
+        use crate::{
+            AsyncMethodCallback, AsyncMethodCallbackResult, AsyncOperationHandle,
+            MethodCallbackContext, MethodCallbackError, MethodNode, TestHarness, WeakServer,
+        };
+
+        /// Doubles its argument on another thread. Negative arguments are rejected right away.
+        struct DoubleLater(WeakServer);
+
+        impl AsyncMethodCallback for DoubleLater {
+            fn call(
+                &mut self,
+                context: &mut MethodCallbackContext,
+                handle: AsyncOperationHandle,
+            ) -> AsyncMethodCallbackResult {
+                let input = match context.input_argument::<ua::Int32>(0) {
+                    Ok(input) if input.value() >= 0 => input.value(),
+                    Ok(_) => {
+                        return AsyncMethodCallbackResult::Ready(Err(
+                            MethodCallbackError::from_status_code(ua::StatusCode::BADOUTOFRANGE),
+                        ))
+                    }
+                    Err(error) => return AsyncMethodCallbackResult::Ready(Err(error)),
+                };
+                let server = self.0.clone();
+                thread::spawn(move || {
+                    let server = server.upgrade().expect("server should be alive");
+                    let output = [ua::Variant::scalar(ua::Int32::new(input * 2))];
+                    server
+                        .complete_async_operation(handle, Ok(&output))
+                        .expect("should complete operation");
+                });
+                AsyncMethodCallbackResult::Pending
+            }
+        }
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        let argument = ua::Argument::init()
+            .with_data_type(&ua::NodeId::ns0_int32())
+            .with_value_rank(-1);
+        let (method_id, _) = harness
+            .server()
+            .add_async_method_node(
+                MethodNode {
+                    requested_new_node_id: None,
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_has_component(),
+                    browse_name: ua::QualifiedName::new(1, "DoubleLater"),
+                    attributes: ua::MethodAttributes::init()
+                        .with_executable(true)
+                        .with_user_executable(true),
+                    input_arguments: ua::Array::from_slice(&[argument.clone()]),
+                    input_arguments_requested_new_node_id: None,
+                    output_arguments: ua::Array::from_slice(&[argument]),
+                    output_arguments_requested_new_node_id: None,
+                },
+                DoubleLater(harness.server().downgrade()),
+            )
+            .expect("should add method node");
+        let client = harness.client();
+        let object_id = ua::NodeId::ns0_objects_folder();
+
+        let output_arguments = client
+            .call_method(
+                &object_id,
+                &method_id,
+                &[ua::Variant::scalar(ua::Int32::new(21))],
+            )
+            .await
+            .expect("should call method");
+        assert_eq!(output_arguments.len(), 1);
+        assert_eq!(output_arguments[0].as_scalar(), Some(&ua::Int32::new(42)));
+
+        let result = client
+            .call_method(
+                &object_id,
+                &method_id,
+                &[ua::Variant::scalar(ua::Int32::new(-1))],
+            )
+            .await;
+        assert_eq!(
+            result.map(|_| ()).map_err(|error| error.status_code()),
+            Err(ua::StatusCode::BADOUTOFRANGE)
+        );
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[cfg(all(feature = "mbedtls", feature = "tokio"))]
+    #[tokio::test]
+    async fn certificate_chain() {
+        use std::ptr;
+
+        use open62541_sys::UA_CertificateVerification_Trustlist;
+
+        use crate::{Certificate, ClientBuilder, PrivateKey, TestHarness};
+
+        // These files have been created with `tests/certificates/create_chain.sh`.
+        const ROOT_CA: &[u8] = include_bytes!("../tests/certificates/root_ca_certificate.der");
+        const INTERMEDIATE_CA: &[u8] =
+            include_bytes!("../tests/certificates/intermediate_ca_certificate.der");
+        const SERVER_CERTIFICATE: &[u8] =
+            include_bytes!("../tests/certificates/server_certificate.der");
+        const SERVER_PRIVATE_KEY: &[u8] =
+            include_bytes!("../tests/certificates/server_private_key.pem");
+        const CLIENT_CERTIFICATE: &[u8] =
+            include_bytes!("../tests/certificates/client_certificate.der");
+        const CLIENT_PRIVATE_KEY: &[u8] =
+            include_bytes!("../tests/certificates/client_private_key.pem");
+
+        // Trust only the root CA. The intermediate CA must be sent along by the other end.
+        let trust_root_ca = || {
+            let mut certificate_verification = ua::CertificateVerification::init();
+            let status_code = ua::StatusCode::new(unsafe {
+                UA_CertificateVerification_Trustlist(
+                    certificate_verification.as_mut_ptr(),
+                    ua::ByteString::new(ROOT_CA).as_ptr(),
+                    1,
+                    ptr::null(),
+                    0,
+                    ptr::null(),
+                    0,
+                )
+            });
+            Error::verify_good(&status_code).expect("should create trust list");
+            certificate_verification
+        };
+
+        let server_builder = |intermediate_certificates: &[Certificate]| {
+            let mut server_builder = ServerBuilder::default_with_security_policies_with_chain(
+                0,
+                &Certificate::from_bytes(SERVER_CERTIFICATE),
+                intermediate_certificates,
+                &PrivateKey::from_bytes(SERVER_PRIVATE_KEY),
+            )
+            .expect("should create server builder");
+            trust_root_ca().move_into_raw(&mut server_builder.config_mut().secureChannelPKI);
+            server_builder
+        };
+
+        let client_builder = |intermediate_certificates: &[Certificate]| {
+            ClientBuilder::default_encryption_with_chain(
+                &Certificate::from_bytes(CLIENT_CERTIFICATE),
+                intermediate_certificates,
+                &PrivateKey::from_bytes(CLIENT_PRIVATE_KEY),
+            )
+            .expect("should create client builder")
+            .security_policy_uri("http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256")
+            .certificate_verification(trust_root_ca())
+        };
+
+        let intermediate_ca = [Certificate::from_bytes(INTERMEDIATE_CA)];
+
+        // Both ends verify the leaf certificate of the other end against the root CA.
+        let harness = TestHarness::start_with(
+            server_builder(&intermediate_ca),
+            client_builder(&intermediate_ca),
+            |_| {},
+        )
+        .expect("should connect with complete certificate chains");
+        let value = harness
+            .client()
+            .read_value(&ua::NodeId::ns0_server_namespace_array())
+            .await;
+        assert!(value.is_ok());
+        harness.shutdown().await.expect("should shut down harness");
+
+        // Without the intermediate CA, the chain of the server is incomplete.
+        let result = TestHarness::start_with(
+            server_builder(&[]),
+            client_builder(&intermediate_ca),
+            |_| {},
+        );
+        assert!(result.is_err());
+
+        // Without the intermediate CA, the chain of the client is incomplete.
+        let result = TestHarness::start_with(
+            server_builder(&intermediate_ca),
+            client_builder(&[]),
+            |_| {},
+        );
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn metrics() {
+        use crate::TestHarness;
+
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+
+        let metrics = harness
+            .server()
+            .metrics()
+            .await
+            .expect("should collect metrics");
+        assert!(metrics.current_channel_count() >= 1);
+        assert!(metrics.current_session_count() >= 1);
+        assert!(metrics.cumulated_session_count() >= metrics.current_session_count());
+        assert_eq!(metrics.current_subscription_count(), 0);
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ffi::c_void, ptr};
+use std::{any::Any, ffi::c_void, ptr, sync::Arc};
 
 use open62541_sys::{
     UA_AccessControl_default, UA_AccessControl_defaultWithLoginCallback, UA_ByteString,
@@ -6,7 +6,12 @@ use open62541_sys::{
     UA_STATUSCODE_BADINTERNALERROR,
 };
 
-use crate::{ua, userdata::UserdataSentinel, DataType, Error, Result, Userdata};
+use crate::{
+    server::server_context::{set_pending_session_data, SessionData},
+    ua,
+    userdata::UserdataSentinel,
+    DataType, Error, Result, Userdata,
+};
 
 /// Server access control.
 ///
@@ -111,6 +116,61 @@ unsafe impl AccessControl for DefaultAccessControl<'_> {
     }
 }
 
+/// Result of login callback.
+///
+/// This is returned from the login callback of [`DefaultAccessControlWithLoginCallback`]. Accepted
+/// logins may attach data to the session, e.g. the roles of the user. This data is available in
+/// callbacks that are run for this session, see [`DataSourceReadContext::session_data()`].
+///
+/// For backwards compatibility, the login callback may also return [`ua::StatusCode`] directly.
+///
+/// [`DataSourceReadContext::session_data()`]: crate::DataSourceReadContext::session_data
+#[derive(Debug)]
+pub struct Login {
+    status_code: ua::StatusCode,
+    session_data: Option<SessionData>,
+}
+
+impl Login {
+    /// Accepts login.
+    #[must_use]
+    pub const fn accept() -> Self {
+        Self {
+            status_code: ua::StatusCode::GOOD,
+            session_data: None,
+        }
+    }
+
+    /// Accepts login and attaches data to session.
+    #[must_use]
+    pub fn accept_with(session_data: impl Any + Send + Sync) -> Self {
+        Self {
+            status_code: ua::StatusCode::GOOD,
+            session_data: Some(Arc::new(session_data)),
+        }
+    }
+
+    /// Rejects login.
+    ///
+    /// Use a status code such as [`ua::StatusCode::BADUSERACCESSDENIED`].
+    #[must_use]
+    pub const fn reject(status_code: ua::StatusCode) -> Self {
+        Self {
+            status_code,
+            session_data: None,
+        }
+    }
+}
+
+impl From<ua::StatusCode> for Login {
+    fn from(status_code: ua::StatusCode) -> Self {
+        Self {
+            status_code,
+            session_data: None,
+        }
+    }
+}
+
 /// Default server access control with login callback.
 ///
 /// This uses `UA_AccessControl_defaultWithLoginCallback()` which comes with the following warning:
@@ -123,6 +183,9 @@ unsafe impl AccessControl for DefaultAccessControl<'_> {
 /// >
 /// > For `TransferSubscriptions`, we check whether the transfer happens between Sessions for the
 /// > same user.
+///
+/// The login callback returns either [`ua::StatusCode`] or [`Login`]. The latter allows attaching
+/// data to the session.
 #[derive(Debug)]
 pub struct DefaultAccessControlWithLoginCallback<F> {
     allow_anonymous: bool,
@@ -139,17 +202,18 @@ impl<F> DefaultAccessControlWithLoginCallback<F> {
 }
 
 // SAFETY: `UA_AccessControl_defaultWithLoginCallback()` replaces previously set config.
-unsafe impl<F> AccessControl for DefaultAccessControlWithLoginCallback<F>
+unsafe impl<F, R> AccessControl for DefaultAccessControlWithLoginCallback<F>
 where
     // Note the lifetime constraint `'static` here. It is required to prevent accepting closures and
     // moving them into the server config that do not live long enough for the (unknown) lifetime of
     // the `Server` instance that gets eventually built from that config.
-    F: Fn(&ua::String, &ua::ByteString) -> ua::StatusCode + Send + 'static,
+    F: Fn(&ua::String, &ua::ByteString) -> R + Send + 'static,
+    R: Into<Login>,
 {
     type Sentinel = UserdataSentinel<F>;
 
     unsafe fn apply(self, config: &mut UA_ServerConfig) -> Result<Self::Sentinel> {
-        unsafe extern "C" fn login_callback_c<F>(
+        unsafe extern "C" fn login_callback_c<F, R>(
             user_name: *const UA_String,
             password: *const UA_ByteString,
             _username_password_login_size: usize,
@@ -158,7 +222,8 @@ where
             login_context: *mut c_void,
         ) -> UA_StatusCode
         where
-            F: Fn(&ua::String, &ua::ByteString) -> ua::StatusCode + 'static,
+            F: Fn(&ua::String, &ua::ByteString) -> R + 'static,
+            R: Into<Login>,
         {
             let Some(user_name) = (unsafe { user_name.as_ref() }) else {
                 return UA_STATUSCODE_BADINTERNALERROR;
@@ -174,10 +239,18 @@ where
 
             let login_callback = unsafe { Userdata::<F>::peek_at(login_context) };
 
-            let status_code = login_callback(user_name, password);
+            let Login {
+                status_code,
+                session_data,
+            } = login_callback(user_name, password).into();
 
             log::debug!("Login callback for {user_name:?} returned {status_code}");
 
+            if status_code.is_good() {
+                // This is picked up by the server when the session gets activated.
+                set_pending_session_data(session_data);
+            }
+
             // The actual status code is not relevant here: the plugin implementation only looks for
             // `UA_STATUSCODE_GOOD`. Forward other codes directly anyway in case this changes.
             status_code.into_raw()
@@ -217,7 +290,7 @@ where
                 // token policy _at all_.
                 username_password_login.len(),
                 username_password_login.as_ptr(),
-                Some(login_callback_c::<F>),
+                Some(login_callback_c::<F, R>),
                 login_callback_sentinel.as_ptr(),
             )
         });
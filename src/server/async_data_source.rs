@@ -0,0 +1,126 @@
+use std::{future::Future, time::Duration};
+
+use tokio::runtime::Handle;
+
+use crate::{
+    ua, DataSource, DataSourceError, DataSourceReadContext, DataSourceResult,
+    DataSourceWriteContext,
+};
+
+/// Default timeout of [`AsyncDataSource`] operations.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Asynchronous data source with callbacks.
+///
+/// The `read` and `write` callbacks implement the operations on the variable when it is added via
+/// [`Server::add_async_data_source_variable_node()`]. Unlike [`DataSource`], they may await other
+/// asynchronous operations, e.g. database queries.
+///
+/// The server still has to wait for the result before it can answer the client. The callbacks are
+/// run on the given runtime while the server thread is blocked, so operations are cancelled after
+/// [`timeout()`] and [`ua::StatusCode::BADTIMEOUT`] is returned to the client. This limits how long
+/// other sessions may be stalled by slow operations.
+///
+/// The runtime must be multi-threaded: the server thread does not drive its timers and I/O. The
+/// server must not be run from within an asynchronous task (use `spawn_blocking()` instead).
+///
+/// [`Server::add_async_data_source_variable_node()`]: crate::Server::add_async_data_source_variable_node
+/// [`timeout()`]: Self::timeout
+// The returned futures are only awaited on the server thread. They need not be `Send`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncDataSource {
+    /// Reads from variable.
+    ///
+    /// This is called when a client wants to read the value from the variable. See
+    /// [`DataSource::read()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// This should return an appropriate error when the read is not possible. The underlying status
+    /// code is forwarded to the client.
+    async fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult;
+
+    /// Writes to variable.
+    ///
+    /// This is called when a client wants to write the value to the variable. See
+    /// [`DataSource::write()`] for details.
+    ///
+    /// If this method is not implemented, [`ua::StatusCode::BADNOTSUPPORTED`] is returned to the
+    /// client.
+    ///
+    /// # Errors
+    ///
+    /// This should return an appropriate error when the write is not possible. The underlying
+    /// status code is forwarded to the client.
+    #[allow(unused_variables)]
+    async fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+        Err(DataSourceError::from_status_code(
+            ua::StatusCode::BADNOTSUPPORTED,
+        ))
+    }
+
+    /// Gets timeout of operations.
+    ///
+    /// Reads and writes that take longer are cancelled. The default is 5 seconds.
+    fn timeout(&self) -> Duration {
+        DEFAULT_TIMEOUT
+    }
+}
+
+/// Adapter that runs [`AsyncDataSource`] as [`DataSource`].
+///
+/// This blocks the calling thread on the runtime for each operation, see
+/// [`Server::add_async_data_source_variable_node()`].
+///
+/// [`Server::add_async_data_source_variable_node()`]: crate::Server::add_async_data_source_variable_node
+#[derive(Debug)]
+pub(crate) struct BlockingDataSource<T> {
+    data_source: T,
+    handle: Handle,
+}
+
+impl<T: AsyncDataSource> BlockingDataSource<T> {
+    pub(crate) const fn new(data_source: T, handle: Handle) -> Self {
+        Self {
+            data_source,
+            handle,
+        }
+    }
+}
+
+impl<T: AsyncDataSource> DataSource for BlockingDataSource<T> {
+    fn read(&mut self, context: &mut DataSourceReadContext) -> DataSourceResult {
+        let Self {
+            data_source,
+            handle,
+        } = self;
+        let timeout = data_source.timeout();
+        block_on_with_timeout(handle, timeout, data_source.read(context))
+    }
+
+    fn write(&mut self, context: &mut DataSourceWriteContext) -> DataSourceResult {
+        let Self {
+            data_source,
+            handle,
+        } = self;
+        let timeout = data_source.timeout();
+        block_on_with_timeout(handle, timeout, data_source.write(context))
+    }
+}
+
+fn block_on_with_timeout(
+    handle: &Handle,
+    timeout: Duration,
+    future: impl Future<Output = DataSourceResult>,
+) -> DataSourceResult {
+    // PANIC: This panics when called from within an asynchronous task, e.g. when the server is read
+    // from with `Server::read_value()` there. The panic is caught by `wrap_data_source()`.
+    handle
+        .block_on(async { tokio::time::timeout(timeout, future).await })
+        .unwrap_or_else(|_| {
+            log::warn!("Asynchronous data source timed out after {timeout:?}");
+            Err(DataSourceError::from_status_code(
+                ua::StatusCode::BADTIMEOUT,
+            ))
+        })
+}
@@ -0,0 +1,210 @@
+use std::{
+    cell::Cell,
+    ffi::c_void,
+    ptr::{self, NonNull},
+};
+
+use open62541_sys::{
+    UA_AsyncOperationRequest, UA_AsyncOperationResponse, UA_AsyncOperationType,
+    UA_CallMethodRequest, UA_Server, UA_Server_call, UA_Server_getAsyncOperationNonBlocking,
+    UA_Server_setAsyncOperationResult,
+};
+
+use crate::{ua, DataType as _};
+
+/// Handle to pending async operation.
+///
+/// This is handed to [`AsyncMethodCallback::call()`] and must be passed to
+/// [`Server::complete_async_operation()`] to return the result of the operation to the client.
+///
+/// When the handle is dropped without completing the operation, the client receives `BadTimeout`
+/// after the timeout set with [`ServerBuilder::async_operation_timeout()`]. Without timeout, the
+/// client never receives a response.
+///
+/// [`AsyncMethodCallback::call()`]: crate::AsyncMethodCallback::call
+/// [`Server::complete_async_operation()`]: crate::Server::complete_async_operation
+/// [`ServerBuilder::async_operation_timeout()`]: crate::ServerBuilder::async_operation_timeout
+#[derive(Debug)]
+pub struct AsyncOperationHandle {
+    /// Server that the operation belongs to.
+    server: NonNull<UA_Server>,
+    /// Operation context as returned by `UA_Server_getAsyncOperationNonBlocking()`.
+    context: NonNull<c_void>,
+}
+
+// SAFETY: The handle is only an opaque token. It is never dereferenced by us and the functions that
+// it is passed to (`UA_Server_setAsyncOperationResult()`) use internal locking.
+unsafe impl Send for AsyncOperationHandle {}
+
+// SAFETY: There are no methods that take `&self` and access the pointers.
+unsafe impl Sync for AsyncOperationHandle {}
+
+impl AsyncOperationHandle {
+    /// Checks if operation belongs to given server.
+    pub(crate) fn belongs_to(&self, server: &ua::Server) -> bool {
+        ptr::eq(self.server.as_ptr(), unsafe { server.as_ptr() })
+    }
+
+    /// Submits result of operation.
+    ///
+    /// When the operation has timed out in the meantime, the server discards the result.
+    pub(crate) fn complete(self, server: &ua::Server, result: &ua::CallMethodResult) {
+        debug_assert!(self.belongs_to(server));
+
+        unsafe {
+            UA_Server_setAsyncOperationResult(
+                // SAFETY: Cast to `mut` pointer. Function is not marked `UA_THREADSAFE` but uses an
+                // internal lock to allow submitting results from worker threads.
+                server.as_ptr().cast_mut(),
+                // SAFETY: `UA_AsyncOperationResponse` is a union with `UA_CallMethodResult` as its
+                // only member. The result is copied, we keep ownership of our value.
+                result.as_ptr().cast::<UA_AsyncOperationResponse>(),
+                self.context.as_ptr(),
+            );
+        }
+    }
+}
+
+/// State of async operation while the method callback is being called.
+#[derive(Debug, Clone, Copy)]
+enum OperationState {
+    /// Operation is being dispatched, handle has not been handed out yet.
+    Dispatched(AsyncOperationRef),
+    /// Handle has been handed out to method callback.
+    Running,
+    /// Method callback has returned [`AsyncMethodCallbackResult::Pending`].
+    ///
+    /// [`AsyncMethodCallbackResult::Pending`]: crate::AsyncMethodCallbackResult::Pending
+    Pending,
+}
+
+/// Unowned reference to async operation.
+#[derive(Debug, Clone, Copy)]
+struct AsyncOperationRef {
+    server: NonNull<UA_Server>,
+    context: NonNull<c_void>,
+}
+
+impl AsyncOperationRef {
+    const fn into_handle(self) -> AsyncOperationHandle {
+        let Self { server, context } = self;
+        AsyncOperationHandle { server, context }
+    }
+}
+
+thread_local! {
+    /// Operation that is currently being dispatched on this thread.
+    ///
+    /// `UA_Server_call()` does not allow passing additional data to the method callback. We use this
+    /// to hand over the operation's handle to the callback, and to find out whether the callback has
+    /// finished the operation.
+    static CURRENT_OPERATION: Cell<Option<OperationState>> = const { Cell::new(None) };
+}
+
+/// Takes handle of operation currently being dispatched.
+///
+/// This returns `None` when no operation is being dispatched, or when its handle has already been
+/// taken.
+pub(crate) fn take_handle() -> Option<AsyncOperationHandle> {
+    CURRENT_OPERATION.with(|current| {
+        let Some(OperationState::Dispatched(operation)) = current.get() else {
+            return None;
+        };
+        current.set(Some(OperationState::Running));
+        Some(operation.into_handle())
+    })
+}
+
+/// Marks operation currently being dispatched as pending.
+///
+/// The result of the operation is then not submitted by [`dispatch()`].
+pub(crate) fn mark_pending() {
+    CURRENT_OPERATION.with(|current| {
+        debug_assert!(matches!(current.get(), Some(OperationState::Running)));
+        current.set(Some(OperationState::Pending));
+    });
+}
+
+/// Dispatches queued async operations.
+///
+/// This takes all operations from the server's queue and calls the corresponding method callbacks.
+/// Operations that finish right away are completed, the others are completed later through their
+/// [`AsyncOperationHandle`].
+pub(crate) fn dispatch(server: &ua::Server) {
+    // PANIC: Pointer to server is never null.
+    let server_ptr = NonNull::new(unsafe { server.as_ptr() }.cast_mut()).expect("server pointer");
+
+    loop {
+        let mut operation_type = UA_AsyncOperationType::UA_ASYNCOPERATIONTYPE_INVALID;
+        let mut request: *const UA_AsyncOperationRequest = ptr::null();
+        let mut context: *mut c_void = ptr::null_mut();
+
+        let found = unsafe {
+            UA_Server_getAsyncOperationNonBlocking(
+                // SAFETY: Function is not marked `UA_THREADSAFE` but uses an internal lock to allow
+                // fetching operations from worker threads.
+                server_ptr.as_ptr(),
+                &mut operation_type,
+                &mut request,
+                &mut context,
+                // We do not need the timeout, the server fails operations on its own.
+                ptr::null_mut(),
+            )
+        };
+        if !found {
+            break;
+        }
+
+        let Some(context) = NonNull::new(context) else {
+            log::error!("Received async operation without context");
+            continue;
+        };
+        let operation = AsyncOperationRef {
+            server: server_ptr,
+            context,
+        };
+
+        if operation_type != UA_AsyncOperationType::UA_ASYNCOPERATIONTYPE_CALL || request.is_null()
+        {
+            // There are no other operation types yet. We must not leave the operation dangling.
+            log::error!("Received unsupported async operation");
+            let result =
+                ua::CallMethodResult::init().with_status_code(ua::StatusCode::BADNOTSUPPORTED);
+            operation.into_handle().complete(server, &result);
+            continue;
+        }
+
+        // SAFETY: `UA_AsyncOperationRequest` is a union with `UA_CallMethodRequest` as its only
+        // member (and we have checked the operation type above).
+        dispatch_call(server, operation, request.cast::<UA_CallMethodRequest>());
+    }
+}
+
+fn dispatch_call(
+    server: &ua::Server,
+    operation: AsyncOperationRef,
+    request: *const UA_CallMethodRequest,
+) {
+    CURRENT_OPERATION.with(|current| current.set(Some(OperationState::Dispatched(operation))));
+
+    // This invokes the method callback synchronously, regardless of the async flag on the method
+    // node. The callback picks up the operation's handle from `CURRENT_OPERATION`.
+    let result = unsafe {
+        ua::CallMethodResult::from_raw(UA_Server_call(
+            // SAFETY: Function is marked `UA_THREADSAFE`.
+            operation.server.as_ptr(),
+            request,
+        ))
+    };
+
+    match CURRENT_OPERATION.with(Cell::take) {
+        // Callback has taken over the operation. It completes the operation later.
+        Some(OperationState::Pending) => {}
+        // Callback has finished the operation (or was not called at all, e.g. because the input
+        // arguments were invalid). Return the result to the client right away.
+        Some(OperationState::Dispatched(_) | OperationState::Running) => {
+            operation.into_handle().complete(server, &result);
+        }
+        None => unreachable!("operation state should be set"),
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    any::Any,
     ffi::c_void,
     panic::{catch_unwind, AssertUnwindSafe},
     ptr::NonNull,
@@ -9,7 +10,13 @@ use open62541_sys::{
 };
 use thiserror::Error;
 
-use crate::{server::NodeContext, ua, DataType as _, Error};
+use crate::{
+    server::{
+        server_context::{session_from_raw, SessionData},
+        NodeContext,
+    },
+    ua, DataType as _, Error,
+};
 
 /// Result from [`DataSource`] operations.
 ///
@@ -56,7 +63,8 @@ pub trait DataSource {
     ///
     /// This is called when a client wants to read the value from the variable. The value is
     /// expected to be returned through the `context` argument. See
-    /// [`DataSourceReadContext::set_value()`] for details.
+    /// [`DataSourceReadContext::set_value()`] for details. When the client reads only part of an
+    /// array value, see [`DataSourceReadContext::index_range()`].
     ///
     /// # Errors
     ///
@@ -69,7 +77,8 @@ pub trait DataSource {
     ///
     /// This is called when a client wants to write the value to the variable. The value is
     /// transmitted through the `context` argument. See [`DataSourceWriteContext::value()`] for
-    /// details.
+    /// details. When the client writes only part of an array value, see
+    /// [`DataSourceWriteContext::index_range()`].
     ///
     /// If this method is not implemented, [`ua::StatusCode::BADNOTSUPPORTED`] is returned to the
     /// client.
@@ -94,16 +103,73 @@ pub struct DataSourceReadContext {
     ///
     /// This is a mutable cell where the read callback puts the data to be returned to the client.
     value_target: NonNull<UA_DataValue>,
+    /// Whether the client requested the source timestamp.
+    include_source_timestamp: bool,
+    /// Index range requested by the client, if any.
+    index_range: Option<ua::NumericRange>,
+    /// Session of the client.
+    session_id: ua::NodeId,
+    /// Data attached to session, if any.
+    session_data: Option<SessionData>,
 }
 
 impl DataSourceReadContext {
     /// Creates context for `read` callback.
-    fn new(value: *mut UA_DataValue) -> Option<Self> {
+    fn new(
+        value: *mut UA_DataValue,
+        include_source_timestamp: bool,
+        range: *const UA_NumericRange,
+        (session_id, session_data): (ua::NodeId, Option<SessionData>),
+    ) -> Option<Self> {
         Some(Self {
             value_target: NonNull::new(value)?,
+            include_source_timestamp,
+            index_range: unsafe { range.as_ref() }.and_then(ua::NumericRange::from_raw),
+            session_id,
+            session_data,
         })
     }
 
+    /// Gets session ID.
+    ///
+    /// This returns the ID of the session of the client that is reading from this [`DataSource`].
+    #[must_use]
+    pub const fn session_id(&self) -> &ua::NodeId {
+        &self.session_id
+    }
+
+    /// Gets session data.
+    ///
+    /// This returns the data attached to the session when the client logged in, see [`Login`]. Use
+    /// [`downcast_ref()`] to get the concrete type.
+    ///
+    /// [`Login`]: crate::Login
+    /// [`downcast_ref()`]: https://doc.rust-lang.org/std/any/trait.Any.html#method.downcast_ref
+    #[must_use]
+    pub fn session_data(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.session_data.as_deref()
+    }
+
+    /// Gets requested index range.
+    ///
+    /// This returns the index range when the client wants to read only part of an array value.
+    /// Implementations should then return only the selected elements, e.g. with
+    /// [`ua::Variant::slice_by_range()`]. Otherwise, the entire value is returned to the client.
+    #[must_use]
+    pub const fn index_range(&self) -> Option<&ua::NumericRange> {
+        self.index_range.as_ref()
+    }
+
+    /// Checks if source timestamp is requested.
+    ///
+    /// This tells whether the client wants to receive the source timestamp of the value. When this
+    /// returns `false`, there is no need to look up the timestamp for
+    /// [`set_source_timestamp()`](Self::set_source_timestamp).
+    #[must_use]
+    pub const fn include_source_timestamp(&self) -> bool {
+        self.include_source_timestamp
+    }
+
     /// Gets mutable reference to value.
     ///
     /// This allows setting the value to report back to the client that is reading from this
@@ -117,6 +183,7 @@ impl DataSourceReadContext {
     /// Sets value.
     ///
     /// This sets the value to report back to the client that is reading from this [`DataSource`].
+    /// The entire `DataValue` is used, including status and timestamps.
     pub fn set_value(&mut self, value: ua::DataValue) {
         *self.value_mut() = value;
     }
@@ -129,6 +196,36 @@ impl DataSourceReadContext {
     pub fn set_variant(&mut self, variant: ua::Variant) {
         *self.value_mut() = ua::DataValue::new(variant);
     }
+
+    /// Sets source timestamp.
+    ///
+    /// This sets the source timestamp of the value to report back to the client. When it is not
+    /// set, the server uses the current time.
+    ///
+    /// Call this after [`set_value()`] or [`set_variant()`] because these replace the entire value.
+    ///
+    /// [`set_value()`]: Self::set_value
+    /// [`set_variant()`]: Self::set_variant
+    pub fn set_source_timestamp(&mut self, source_timestamp: ua::DateTime) {
+        let value_target = unsafe { self.value_target.as_mut() };
+        value_target.sourceTimestamp = source_timestamp.into_raw();
+        value_target.hasSourceTimestamp = true;
+    }
+
+    /// Sets status.
+    ///
+    /// This sets the status of the value to report back to the client, e.g. to indicate uncertain
+    /// values. To reject the read instead, return an error from [`DataSource::read()`].
+    ///
+    /// Call this after [`set_value()`] or [`set_variant()`] because these replace the entire value.
+    ///
+    /// [`set_value()`]: Self::set_value
+    /// [`set_variant()`]: Self::set_variant
+    pub fn set_status(&mut self, status: ua::StatusCode) {
+        let value_target = unsafe { self.value_target.as_mut() };
+        value_target.status = status.into_raw();
+        value_target.hasStatus = true;
+    }
 }
 
 /// Context when [`DataSource`] is being written to.
@@ -139,18 +236,61 @@ pub struct DataSourceWriteContext {
     /// This is an immutable (const) cell where the write callback receives the data to be written
     /// by the client.
     value_source: NonNull<UA_DataValue>,
+    /// Index range requested by the client, if any.
+    index_range: Option<ua::NumericRange>,
+    /// Session of the client.
+    session_id: ua::NodeId,
+    /// Data attached to session, if any.
+    session_data: Option<SessionData>,
 }
 
 impl DataSourceWriteContext {
     /// Creates context for `write` callback.
-    fn new(value: *const UA_DataValue) -> Option<Self> {
+    fn new(
+        value: *const UA_DataValue,
+        range: *const UA_NumericRange,
+        (session_id, session_data): (ua::NodeId, Option<SessionData>),
+    ) -> Option<Self> {
         Some(Self {
             // SAFETY: `NonNull` implicitly expects a `*mut` but we take care to never mutate the
             // target.
             value_source: NonNull::new(value.cast_mut())?,
+            index_range: unsafe { range.as_ref() }.and_then(ua::NumericRange::from_raw),
+            session_id,
+            session_data,
         })
     }
 
+    /// Gets session ID.
+    ///
+    /// This returns the ID of the session of the client that is writing to this [`DataSource`].
+    #[must_use]
+    pub const fn session_id(&self) -> &ua::NodeId {
+        &self.session_id
+    }
+
+    /// Gets session data.
+    ///
+    /// This returns the data attached to the session when the client logged in, see [`Login`]. Use
+    /// [`downcast_ref()`] to get the concrete type.
+    ///
+    /// [`Login`]: crate::Login
+    /// [`downcast_ref()`]: https://doc.rust-lang.org/std/any/trait.Any.html#method.downcast_ref
+    #[must_use]
+    pub fn session_data(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.session_data.as_deref()
+    }
+
+    /// Gets requested index range.
+    ///
+    /// This returns the index range when the client wants to write only part of an array value.
+    /// The written value then holds only the selected elements, and implementations should replace
+    /// only these elements instead of the entire value.
+    #[must_use]
+    pub const fn index_range(&self) -> Option<&ua::NumericRange> {
+        self.index_range.as_ref()
+    }
+
     /// Gets value.
     ///
     /// This returns the value received from the client that is writing to this [`DataSource`].
@@ -172,13 +312,13 @@ pub(crate) unsafe fn wrap_data_source(
     data_source: impl DataSource + 'static,
 ) -> (UA_DataSource, NodeContext) {
     unsafe extern "C" fn read_c(
-        _server: *mut UA_Server,
-        _session_id: *const UA_NodeId,
+        server: *mut UA_Server,
+        session_id: *const UA_NodeId,
         _session_context: *mut c_void,
         _node_id: *const UA_NodeId,
         node_context: *mut c_void,
-        _include_source_time_stamp: UA_Boolean,
-        _range: *const UA_NumericRange,
+        include_source_time_stamp: UA_Boolean,
+        range: *const UA_NumericRange,
         value: *mut UA_DataValue,
     ) -> UA_StatusCode {
         let node_context = unsafe { NodeContext::peek_at(node_context) };
@@ -189,7 +329,10 @@ pub(crate) unsafe fn wrap_data_source(
             return ua::StatusCode::BADINTERNALERROR.into_raw();
         };
 
-        let Some(mut context) = DataSourceReadContext::new(value) else {
+        let session = unsafe { session_from_raw(server, session_id) };
+        let Some(mut context) =
+            DataSourceReadContext::new(value, include_source_time_stamp, range, session)
+        else {
             // Creating context for callback should always succeed.
             return ua::StatusCode::BADINTERNALERROR.into_raw();
         };
@@ -208,12 +351,12 @@ pub(crate) unsafe fn wrap_data_source(
     }
 
     unsafe extern "C" fn write_c(
-        _server: *mut UA_Server,
-        _session_id: *const UA_NodeId,
+        server: *mut UA_Server,
+        session_id: *const UA_NodeId,
         _session_context: *mut c_void,
         _node_id: *const UA_NodeId,
         node_context: *mut c_void,
-        _range: *const UA_NumericRange,
+        range: *const UA_NumericRange,
         value: *const UA_DataValue,
     ) -> UA_StatusCode {
         let node_context = unsafe { NodeContext::peek_at(node_context) };
@@ -224,7 +367,8 @@ pub(crate) unsafe fn wrap_data_source(
             return ua::StatusCode::BADINTERNALERROR.into_raw();
         };
 
-        let Some(mut context) = DataSourceWriteContext::new(value) else {
+        let session = unsafe { session_from_raw(server, session_id) };
+        let Some(mut context) = DataSourceWriteContext::new(value, range, session) else {
             // Creating context for callback should always succeed.
             return ua::StatusCode::BADINTERNALERROR.into_raw();
         };
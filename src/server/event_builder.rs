@@ -0,0 +1,135 @@
+use crate::{ua, Error, Result, Server};
+
+/// Builder for events.
+///
+/// This collects the fields of an event before it is triggered, see [`Server::event_builder()`].
+/// When triggered, the event node is created with [`Server::create_event()`], the fields are
+/// written to the properties of the event node with [`Server::write_object_property()`], and the
+/// event is emitted with [`Server::trigger_event()`].
+///
+/// # Examples
+///
+/// ```
+/// # use open62541::{ServerBuilder, ua};
+/// #
+/// # fn main() -> anyhow::Result<()> {
+/// # let (server, _) = ServerBuilder::default().build();
+/// #
+/// let event_id = server
+///     .event_builder(&ua::NodeId::ns0_base_event_type())
+///     .severity(500)
+///     .message(ua::LocalizedText::new("en-US", "Temperature too high")?)
+///     .source_name("Boiler")
+///     .trigger(&ua::NodeId::ns0_server())?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct EventBuilder<'a> {
+    server: &'a Server,
+    event_type: ua::NodeId,
+    properties: Vec<(ua::QualifiedName, ua::Variant)>,
+    /// First error from setting any of the fields, returned by [`trigger()`](Self::trigger).
+    error: Option<Error>,
+}
+
+impl<'a> EventBuilder<'a> {
+    pub(crate) fn new(server: &'a Server, event_type: &ua::NodeId) -> Self {
+        Self {
+            server,
+            event_type: event_type.clone(),
+            properties: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Sets severity.
+    ///
+    /// This ranges from 1 (lowest) to 1000 (highest).
+    pub fn severity(self, severity: u16) -> Self {
+        self.property(
+            ua::QualifiedName::ns0("Severity"),
+            ua::Variant::scalar(ua::UInt16::new(severity)),
+        )
+    }
+
+    /// Sets message.
+    pub fn message(self, message: ua::LocalizedText) -> Self {
+        self.property(
+            ua::QualifiedName::ns0("Message"),
+            ua::Variant::scalar(message),
+        )
+    }
+
+    /// Sets source name.
+    ///
+    /// When the source name contains NUL bytes, [`trigger()`](Self::trigger) fails.
+    pub fn source_name(mut self, source_name: &str) -> Self {
+        match ua::String::new(source_name) {
+            Ok(source_name) => self.property(
+                ua::QualifiedName::ns0("SourceName"),
+                ua::Variant::scalar(source_name),
+            ),
+            Err(_) => {
+                if self.error.is_none() {
+                    self.error = Some(Error::NulByte("source name"));
+                }
+                self
+            }
+        }
+    }
+
+    /// Sets time.
+    ///
+    /// This is the time when the event occurred. When it is not set, the time of creation is used.
+    pub fn time(self, time: ua::DateTime) -> Self {
+        self.property(ua::QualifiedName::ns0("Time"), ua::Variant::scalar(time))
+    }
+
+    /// Sets property.
+    ///
+    /// The property must exist on the event type. Properties are written in order, so later values
+    /// replace earlier ones.
+    pub fn property(mut self, property_name: ua::QualifiedName, value: ua::Variant) -> Self {
+        self.properties.push((property_name, value));
+        self
+    }
+
+    /// Triggers event.
+    ///
+    /// This returns the [`ua::EventId`] of the new event. The event node is deleted afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This fails when any of the fields is invalid, when the event cannot be created, when any of
+    /// the properties cannot be written, or when the event cannot be triggered.
+    pub fn trigger(self, origin_id: &ua::NodeId) -> Result<ua::EventId> {
+        let Self {
+            server,
+            event_type,
+            properties,
+            error,
+        } = self;
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        let event_node_id = server.create_event(&event_type)?;
+
+        let result = properties.iter().try_for_each(|(property_name, value)| {
+            server.write_object_property(&event_node_id, property_name, value)
+        });
+        if let Err(error) = result {
+            // The event is not triggered, so the event node would be left behind.
+            if let Err(error) = server.delete_node(&event_node_id) {
+                log::warn!("Failed to delete event node: {error}");
+            }
+            return Err(error);
+        }
+
+        server.trigger_event(&event_node_id, origin_id, true)
+    }
+}
@@ -1,5 +1,6 @@
 use ::core::ffi::c_void;
 use std::{
+    any::Any,
     panic::{catch_unwind, AssertUnwindSafe},
     ptr::NonNull,
 };
@@ -9,7 +10,14 @@ use open62541_sys::{
 };
 use thiserror::Error;
 
-use crate::{server::NodeContext, ua, DataType as _, Error};
+use crate::{
+    server::{
+        async_operation,
+        server_context::{session_from_raw, SessionData},
+        AsyncOperationHandle, NodeContext,
+    },
+    ua, DataType, Error,
+};
 
 /// Result from [`MethodCallback`] operations.
 ///
@@ -67,6 +75,54 @@ pub trait MethodCallback {
     fn call(&mut self, context: &mut MethodCallbackContext) -> MethodCallbackResult;
 }
 
+/// Result from [`AsyncMethodCallback`] operations.
+///
+/// This tells the server whether the method call has already finished or whether the result will
+/// be submitted later through [`Server::complete_async_operation()`].
+///
+/// [`Server::complete_async_operation()`]: crate::Server::complete_async_operation
+#[derive(Debug)]
+pub enum AsyncMethodCallbackResult {
+    /// Call has finished.
+    ///
+    /// The output arguments have been set through the `context` argument (or the call failed). The
+    /// result is returned to the client right away.
+    Ready(MethodCallbackResult),
+
+    /// Call is still running.
+    ///
+    /// The [`AsyncOperationHandle`] given to [`AsyncMethodCallback::call()`] must be passed to
+    /// [`Server::complete_async_operation()`] eventually to return the result to the client.
+    ///
+    /// [`Server::complete_async_operation()`]: crate::Server::complete_async_operation
+    Pending,
+}
+
+/// Asynchronous method callback.
+///
+/// The `call` callback implements the operation on the method when it is added via
+/// [`Server::add_async_method_node()`]. Unlike [`MethodCallback`], the call may finish later (from
+/// any thread) without blocking the server.
+///
+/// [`Server::add_async_method_node()`]: crate::Server::add_async_method_node
+pub trait AsyncMethodCallback {
+    /// Calls method.
+    ///
+    /// This is called when a client wants to call the method. The input arguments are available
+    /// through the `context` argument, see [`MethodCallbackContext::input_arguments()`].
+    ///
+    /// When the result is known immediately, the output arguments may be set through `context` and
+    /// [`AsyncMethodCallbackResult::Ready`] be returned. Otherwise, the `handle` should be moved to
+    /// wherever the operation continues and [`AsyncMethodCallbackResult::Pending`] be returned.
+    ///
+    /// Note that `handle` must not be used when returning [`AsyncMethodCallbackResult::Ready`].
+    fn call(
+        &mut self,
+        context: &mut MethodCallbackContext,
+        handle: AsyncOperationHandle,
+    ) -> AsyncMethodCallbackResult;
+}
+
 /// Context when [`MethodCallback`] is being called.
 #[derive(Debug)]
 pub struct MethodCallbackContext {
@@ -75,6 +131,8 @@ pub struct MethodCallbackContext {
     input_source: NonNull<UA_Variant>,
     output_size: usize,
     output_target: NonNull<UA_Variant>,
+    session_id: ua::NodeId,
+    session_data: Option<SessionData>,
 }
 
 impl MethodCallbackContext {
@@ -85,6 +143,7 @@ impl MethodCallbackContext {
         input: *const UA_Variant,
         output_size: usize,
         output: *mut UA_Variant,
+        (session_id, session_data): (ua::NodeId, Option<SessionData>),
     ) -> Option<Self> {
         let ptr = unsafe { UA_EMPTY_ARRAY_SENTINEL };
 
@@ -106,9 +165,31 @@ impl MethodCallbackContext {
             input_source: NonNull::new(input.cast_mut())?,
             output_size,
             output_target: NonNull::new(output)?,
+            session_id,
+            session_data,
         })
     }
 
+    /// Gets session ID.
+    ///
+    /// This returns the ID of the session of the client that is calling this [`MethodCallback`].
+    #[must_use]
+    pub const fn session_id(&self) -> &ua::NodeId {
+        &self.session_id
+    }
+
+    /// Gets session data.
+    ///
+    /// This returns the data attached to the session when the client logged in, see [`Login`]. Use
+    /// [`downcast_ref()`] to get the concrete type.
+    ///
+    /// [`Login`]: crate::Login
+    /// [`downcast_ref()`]: https://doc.rust-lang.org/std/any/trait.Any.html#method.downcast_ref
+    #[must_use]
+    pub fn session_data(&self) -> Option<&(dyn Any + Send + Sync)> {
+        self.session_data.as_deref()
+    }
+
     /// Gets object node ID.
     ///
     /// This returns the object node ID used by the client that is calling this [`MethodCallback`].
@@ -133,6 +214,28 @@ impl MethodCallbackContext {
         input_arguments
     }
 
+    /// Gets input argument.
+    ///
+    /// This returns the scalar value at `index` received from the client that is calling this
+    /// [`MethodCallback`], converted to `T`.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADARGUMENTSMISSING`] when there is no argument at `index`
+    /// and with [`ua::StatusCode::BADTYPEMISMATCH`] when the argument is not a scalar of type `T`.
+    /// The error may be returned from [`MethodCallback::call()`] to forward it to the client.
+    pub fn input_argument<T: DataType>(&self, index: usize) -> Result<T, MethodCallbackError> {
+        let Some(input_argument) = self.input_arguments().get(index) else {
+            return Err(MethodCallbackError::from_status_code(
+                ua::StatusCode::BADARGUMENTSMISSING,
+            ));
+        };
+
+        input_argument
+            .to_scalar::<T>()
+            .ok_or_else(|| MethodCallbackError::from_status_code(ua::StatusCode::BADTYPEMISMATCH))
+    }
+
     /// Gets mutable reference to output arguments.
     ///
     /// This allows setting the values to report back to the client that is calling this
@@ -147,6 +250,40 @@ impl MethodCallbackContext {
 
         output_arguments
     }
+
+    /// Sets output arguments.
+    ///
+    /// This sets the values to report back to the client that is calling this [`MethodCallback`].
+    /// The number of values must match the output arguments of the method.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`ua::StatusCode::BADINTERNALERROR`] when the number of values does not
+    /// match. The output arguments are left unchanged then.
+    pub fn set_output_arguments(
+        &mut self,
+        output_arguments: impl IntoIterator<Item = ua::Variant>,
+    ) -> Result<(), MethodCallbackError> {
+        let output_arguments = output_arguments.into_iter().collect::<Vec<_>>();
+        if output_arguments.len() != self.output_size {
+            log::error!(
+                "Method callback returned {} output arguments, expected {}",
+                output_arguments.len(),
+                self.output_size
+            );
+            return Err(MethodCallbackError::from_status_code(
+                ua::StatusCode::BADINTERNALERROR,
+            ));
+        }
+
+        for (target, output_argument) in
+            self.output_arguments_mut().iter_mut().zip(output_arguments)
+        {
+            *target = output_argument;
+        }
+
+        Ok(())
+    }
 }
 
 /// Transforms into raw value.
@@ -160,8 +297,8 @@ pub(crate) unsafe fn wrap_method_callback(
     method_callback: impl MethodCallback + 'static,
 ) -> (UA_MethodCallback, NodeContext) {
     unsafe extern "C" fn callback_c(
-        _server: *mut UA_Server,
-        _session_id: *const UA_NodeId,
+        server: *mut UA_Server,
+        session_id: *const UA_NodeId,
         _session_context: *mut c_void,
         _method_id: *const UA_NodeId,
         method_context: *mut c_void,
@@ -180,8 +317,9 @@ pub(crate) unsafe fn wrap_method_callback(
             return ua::StatusCode::BADINTERNALERROR.into_raw();
         };
 
+        let session = unsafe { session_from_raw(server, session_id) };
         let Some(mut context) =
-            MethodCallbackContext::new(object_id, input_size, input, output_size, output)
+            MethodCallbackContext::new(object_id, input_size, input, output_size, output, session)
         else {
             // Creating context for callback should always succeed.
             return ua::StatusCode::BADINTERNALERROR.into_raw();
@@ -204,3 +342,129 @@ pub(crate) unsafe fn wrap_method_callback(
 
     (Some(callback_c), node_context)
 }
+
+/// Transforms into raw value.
+///
+/// The returned [`UA_MethodCallback`] must only be invoked through [`async_operation::dispatch()`],
+/// i.e. the method node must be marked as async.
+///
+/// # Safety
+///
+/// The returned [`UA_MethodCallback`] is only valid for as long as [`NodeContext`] is alive. The
+/// lifetime can be extended by using [`NodeContext::leak()`] to save this value inside the
+/// corresponding server node, to be eventually cleaned up when the node is destroyed.
+pub(crate) unsafe fn wrap_async_method_callback(
+    async_method_callback: impl AsyncMethodCallback + 'static,
+) -> (UA_MethodCallback, NodeContext) {
+    unsafe extern "C" fn callback_c(
+        server: *mut UA_Server,
+        session_id: *const UA_NodeId,
+        _session_context: *mut c_void,
+        _method_id: *const UA_NodeId,
+        method_context: *mut c_void,
+        object_id: *const UA_NodeId,
+        _object_context: *mut c_void,
+        input_size: usize,
+        input: *const UA_Variant,
+        output_size: usize,
+        output: *mut UA_Variant,
+    ) -> UA_StatusCode {
+        let node_context = unsafe { NodeContext::peek_at(method_context) };
+        let NodeContext::AsyncMethodCallback(async_method_callback) = node_context else {
+            // We expect to always find this node context type.
+            return ua::StatusCode::BADINTERNALERROR.into_raw();
+        };
+
+        let Some(handle) = async_operation::take_handle() else {
+            // The method has been called outside of `async_operation::dispatch()`, e.g. directly
+            // through `UA_Server_call()`. There is no operation to complete later.
+            return ua::StatusCode::BADNOTSUPPORTED.into_raw();
+        };
+
+        let session = unsafe { session_from_raw(server, session_id) };
+        let Some(mut context) =
+            MethodCallbackContext::new(object_id, input_size, input, output_size, output, session)
+        else {
+            // Creating context for callback should always succeed.
+            return ua::StatusCode::BADINTERNALERROR.into_raw();
+        };
+        let mut async_method_callback = AssertUnwindSafe(async_method_callback);
+
+        let status_code =
+            match catch_unwind(move || async_method_callback.call(&mut context, handle)) {
+                Ok(AsyncMethodCallbackResult::Ready(Ok(()))) => ua::StatusCode::GOOD,
+                Ok(AsyncMethodCallbackResult::Ready(Err(err))) => err.into_status_code(),
+                Ok(AsyncMethodCallbackResult::Pending) => {
+                    async_operation::mark_pending();
+                    ua::StatusCode::GOOD
+                }
+                Err(err) => {
+                    log::error!("Call callback in async method callback panicked: {err:?}");
+                    ua::StatusCode::BADINTERNALERROR
+                }
+            };
+
+        status_code.into_raw()
+    }
+
+    let node_context = NodeContext::AsyncMethodCallback(Box::new(async_method_callback));
+
+    (Some(callback_c), node_context)
+}
+
+#[cfg(test)]
+mod tests {
+    use open62541_sys::UA_Variant;
+
+    use crate::{ua, DataType as _};
+
+    use super::MethodCallbackContext;
+
+    #[test]
+    fn typed_arguments() {
+        let object_id = ua::NodeId::null();
+        let input = [
+            ua::Variant::scalar(ua::UInt32::new(7)),
+            ua::Variant::scalar(ua::String::new("input").unwrap()),
+        ];
+        let mut output = [ua::Variant::init(), ua::Variant::init()];
+        let mut context = MethodCallbackContext::new(
+            object_id.as_ptr(),
+            input.len(),
+            input.as_ptr().cast::<UA_Variant>(),
+            output.len(),
+            output.as_mut_ptr().cast::<UA_Variant>(),
+            (ua::NodeId::null(), None),
+        )
+        .expect("should create context");
+
+        let value = context.input_argument::<ua::UInt32>(0);
+        assert_eq!(value.ok(), Some(ua::UInt32::new(7)));
+        let value = context.input_argument::<ua::UInt32>(1);
+        assert_eq!(
+            value.map_err(|error| error.into_status_code()),
+            Err(ua::StatusCode::BADTYPEMISMATCH)
+        );
+        let value = context.input_argument::<ua::UInt32>(2);
+        assert_eq!(
+            value.map_err(|error| error.into_status_code()),
+            Err(ua::StatusCode::BADARGUMENTSMISSING)
+        );
+
+        let result = context.set_output_arguments([ua::Variant::scalar(ua::Int32::new(1))]);
+        assert_eq!(
+            result.map_err(|error| error.into_status_code()),
+            Err(ua::StatusCode::BADINTERNALERROR)
+        );
+        context
+            .set_output_arguments([
+                ua::Variant::scalar(ua::Int32::new(1)),
+                ua::Variant::scalar(ua::Int32::new(2)),
+            ])
+            .expect("should set output arguments");
+        drop(context);
+
+        assert_eq!(output[0].as_scalar(), Some(&ua::Int32::new(1)));
+        assert_eq!(output[1].as_scalar(), Some(&ua::Int32::new(2)));
+    }
+}
@@ -0,0 +1,208 @@
+use open62541_sys::UA_Server_getStatistics;
+
+use crate::{ua, Error, Result, Server};
+
+/// Health metrics of server.
+///
+/// This holds counters that can be observed cheaply from the running server, e.g. to export them
+/// to a monitoring system. All counters are plain numbers; cumulated counters only ever increase
+/// during the lifetime of the server.
+///
+/// The number of monitored items is not included: open62541 does not keep a server-wide counter
+/// for it.
+///
+/// See [`Server::metrics()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerMetrics {
+    current_channel_count: u64,
+    cumulated_channel_count: u64,
+    rejected_channel_count: u64,
+    channel_timeout_count: u64,
+    channel_abort_count: u64,
+    current_session_count: u64,
+    cumulated_session_count: u64,
+    rejected_session_count: u64,
+    security_rejected_session_count: u64,
+    session_timeout_count: u64,
+    session_abort_count: u64,
+    current_subscription_count: u64,
+    cumulated_subscription_count: u64,
+    rejected_requests_count: u64,
+    security_rejected_requests_count: u64,
+}
+
+impl ServerMetrics {
+    /// Collects metrics.
+    ///
+    /// This must be called on the server's event loop: the secure channel statistics are updated
+    /// there without taking the service lock.
+    pub(crate) fn collect(server: &Server) -> Result<Self> {
+        // SAFETY: The function only copies counters of the server. We are on the event loop, so the
+        // counters are not modified concurrently.
+        let statistics = unsafe { UA_Server_getStatistics(server.0.as_ptr().cast_mut()) };
+        let channels = statistics.scs;
+
+        let summary = server.read_attribute(
+            &ua::NodeId::ns0_server_diagnostics_summary(),
+            ua::AttributeId::VALUE_T,
+        )?;
+        let summary = summary
+            .value()
+            .as_scalar::<ua::ServerDiagnosticsSummaryDataType>()
+            .ok_or(Error::internal("diagnostics summary should be available"))?;
+
+        Ok(Self {
+            current_channel_count: count(channels.currentChannelCount),
+            cumulated_channel_count: count(channels.cumulatedChannelCount),
+            rejected_channel_count: count(channels.rejectedChannelCount),
+            channel_timeout_count: count(channels.channelTimeoutCount),
+            channel_abort_count: count(channels.channelAbortCount),
+            current_session_count: summary.current_session_count().into(),
+            cumulated_session_count: summary.cumulated_session_count().into(),
+            rejected_session_count: summary.rejected_session_count().into(),
+            security_rejected_session_count: summary.security_rejected_session_count().into(),
+            session_timeout_count: summary.session_timeout_count().into(),
+            session_abort_count: summary.session_abort_count().into(),
+            current_subscription_count: summary.current_subscription_count().into(),
+            cumulated_subscription_count: summary.cumulated_subscription_count().into(),
+            rejected_requests_count: summary.rejected_requests_count().into(),
+            security_rejected_requests_count: summary.security_rejected_requests_count().into(),
+        })
+    }
+
+    /// Gets number of currently open secure channels.
+    #[must_use]
+    pub const fn current_channel_count(&self) -> u64 {
+        self.current_channel_count
+    }
+
+    /// Gets number of secure channels opened since server start.
+    #[must_use]
+    pub const fn cumulated_channel_count(&self) -> u64 {
+        self.cumulated_channel_count
+    }
+
+    /// Gets number of rejected connection attempts, i.e. secure channels that could not be opened.
+    #[must_use]
+    pub const fn rejected_channel_count(&self) -> u64 {
+        self.rejected_channel_count
+    }
+
+    /// Gets number of secure channels closed because of timeout.
+    #[must_use]
+    pub const fn channel_timeout_count(&self) -> u64 {
+        self.channel_timeout_count
+    }
+
+    /// Gets number of secure channels closed because of errors.
+    #[must_use]
+    pub const fn channel_abort_count(&self) -> u64 {
+        self.channel_abort_count
+    }
+
+    /// Gets number of currently active sessions.
+    #[must_use]
+    pub const fn current_session_count(&self) -> u64 {
+        self.current_session_count
+    }
+
+    /// Gets number of sessions created since server start.
+    #[must_use]
+    pub const fn cumulated_session_count(&self) -> u64 {
+        self.cumulated_session_count
+    }
+
+    /// Gets number of rejected session requests.
+    #[must_use]
+    pub const fn rejected_session_count(&self) -> u64 {
+        self.rejected_session_count
+    }
+
+    /// Gets number of session requests rejected for security reasons.
+    #[must_use]
+    pub const fn security_rejected_session_count(&self) -> u64 {
+        self.security_rejected_session_count
+    }
+
+    /// Gets number of sessions closed because of timeout.
+    #[must_use]
+    pub const fn session_timeout_count(&self) -> u64 {
+        self.session_timeout_count
+    }
+
+    /// Gets number of sessions closed because of errors.
+    #[must_use]
+    pub const fn session_abort_count(&self) -> u64 {
+        self.session_abort_count
+    }
+
+    /// Gets number of currently active subscriptions.
+    #[must_use]
+    pub const fn current_subscription_count(&self) -> u64 {
+        self.current_subscription_count
+    }
+
+    /// Gets number of subscriptions created since server start.
+    #[must_use]
+    pub const fn cumulated_subscription_count(&self) -> u64 {
+        self.cumulated_subscription_count
+    }
+
+    /// Gets number of rejected service requests.
+    #[must_use]
+    pub const fn rejected_requests_count(&self) -> u64 {
+        self.rejected_requests_count
+    }
+
+    /// Gets number of service requests rejected for security reasons.
+    #[must_use]
+    pub const fn security_rejected_requests_count(&self) -> u64 {
+        self.security_rejected_requests_count
+    }
+}
+
+fn count(value: usize) -> u64 {
+    // `usize` fits into `u64` on all supported platforms, saturate just in case.
+    u64::try_from(value).unwrap_or(u64::MAX)
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use serde::{ser::SerializeStruct as _, Serialize, Serializer};
+
+    use super::ServerMetrics;
+
+    impl Serialize for ServerMetrics {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ServerMetrics", 15)?;
+            state.serialize_field("current_channel_count", &self.current_channel_count)?;
+            state.serialize_field("cumulated_channel_count", &self.cumulated_channel_count)?;
+            state.serialize_field("rejected_channel_count", &self.rejected_channel_count)?;
+            state.serialize_field("channel_timeout_count", &self.channel_timeout_count)?;
+            state.serialize_field("channel_abort_count", &self.channel_abort_count)?;
+            state.serialize_field("current_session_count", &self.current_session_count)?;
+            state.serialize_field("cumulated_session_count", &self.cumulated_session_count)?;
+            state.serialize_field("rejected_session_count", &self.rejected_session_count)?;
+            state.serialize_field(
+                "security_rejected_session_count",
+                &self.security_rejected_session_count,
+            )?;
+            state.serialize_field("session_timeout_count", &self.session_timeout_count)?;
+            state.serialize_field("session_abort_count", &self.session_abort_count)?;
+            state.serialize_field(
+                "current_subscription_count",
+                &self.current_subscription_count,
+            )?;
+            state.serialize_field(
+                "cumulated_subscription_count",
+                &self.cumulated_subscription_count,
+            )?;
+            state.serialize_field("rejected_requests_count", &self.rejected_requests_count)?;
+            state.serialize_field(
+                "security_rejected_requests_count",
+                &self.security_rejected_requests_count,
+            )?;
+            state.end()
+        }
+    }
+}
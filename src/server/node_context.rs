@@ -1,29 +1,35 @@
 use std::ffi::c_void;
 
 use crate::{
-    server::{DataSource, MethodCallback},
-    Userdata,
+    server::{AsyncMethodCallback, DataSource, MethodCallback, ServerContext, ValueCallback},
+    ua, Userdata,
 };
 
 /// Context attached to server node.
 ///
 /// Nodes created by [`Server`](crate::Server) need to keep track of dynamic data structures. These
 /// are cleaned up when the corresponding node is destroyed by the server.
+///
+/// Node contexts are registered with the [`ServerContext`] when they are leaked. This tells them
+/// apart from contexts set by open62541 itself (see issue #125), which must not be released by us.
 pub(crate) enum NodeContext {
     DataSource(Box<dyn DataSource>),
     MethodCallback(Box<dyn MethodCallback>),
+    AsyncMethodCallback(Box<dyn AsyncMethodCallback>),
+    ValueCallback(Box<dyn ValueCallback>),
 }
 
-#[allow(dead_code)] // We will use the methods soon.
 impl NodeContext {
     /// Leaks node context.
     ///
-    /// This allocates memory. To prevent memory leaks, make sure to call [`consume()`] on the
-    /// returned pointer exactly once.
+    /// This allocates memory and registers the returned pointer with the server. The node context
+    /// is released when the node is destroyed (see [`release()`]), or when the server is dropped.
     ///
-    /// [`consume()`]: Self::consume
-    pub(crate) fn leak(self) -> *mut c_void {
-        Userdata::<Self>::prepare(self)
+    /// [`release()`]: Self::release
+    pub(crate) fn leak(self, server: &ua::Server) -> *mut c_void {
+        let data = Userdata::<Self>::prepare(self);
+        ServerContext::get(server).register_node_context(data);
+        data
     }
 
     /// Unwraps [`c_void`] pointer to access node context.
@@ -31,27 +37,51 @@ impl NodeContext {
     /// # Safety
     ///
     /// The given pointer must have been returned from [`leak()`]. It must not have been given to
-    /// [`consume()`] yet.
+    /// [`release()`] or [`consume()`] yet.
     ///
     /// The lifetime of the returned reference is not allowed to extend past the next call to either
-    /// [`peek_at()`] or [`consume()`].
+    /// [`peek_at()`], [`release()`] or [`consume()`].
     ///
     /// [`leak()`]: Self::leak
     /// [`peek_at()`]: Self::peek_at
+    /// [`release()`]: Self::release
     /// [`consume()`]: Self::consume
     pub(crate) unsafe fn peek_at<'a>(data: *mut c_void) -> &'a mut Self {
         // SAFETY: We require the same safety guarantees from our callers.
         unsafe { Userdata::<Self>::peek_at(data) }
     }
 
+    /// Unwraps [`c_void`] pointer and returns owned node context, if it is ours.
+    ///
+    /// This returns `None` when the pointer has not been returned from [`leak()`] for this server,
+    /// or when it has already been released.
+    ///
+    /// # Safety
+    ///
+    /// No reference returned from [`peek_at()`] may be alive.
+    ///
+    /// [`leak()`]: Self::leak
+    /// [`peek_at()`]: Self::peek_at
+    pub(crate) unsafe fn release(context: &ServerContext, data: *mut c_void) -> Option<Self> {
+        if !context.unregister_node_context(data) {
+            return None;
+        }
+        // SAFETY: The pointer has been registered by `leak()` and is unregistered only once.
+        Some(unsafe { Userdata::<Self>::consume(data) })
+    }
+
     /// Unwraps [`c_void`] pointer and returns owned node context.
     ///
+    /// This does not unregister the pointer from the server. Use [`release()`] instead unless the
+    /// server context is being dropped.
+    ///
     /// # Safety
     ///
     /// The given pointer must have been returned from [`leak()`]. It must not have been given to
-    /// [`consume()`] yet.
+    /// [`release()`] or [`consume()`] yet.
     ///
     /// [`leak()`]: Self::leak
+    /// [`release()`]: Self::release
     /// [`consume()`]: Self::consume
     #[must_use]
     pub(crate) unsafe fn consume(data: *mut c_void) -> Self {
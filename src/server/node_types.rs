@@ -122,6 +122,21 @@ pub struct ObjectNode {
     pub attributes: ua::ObjectAttributes,
 }
 
+/// Object type node, see [`Server::add_object_type_node()`].
+///
+/// Unlike [`ObjectNode`], this has no type definition: object types are related to their supertype
+/// by a [`ua::NodeId::ns0_has_subtype()`] reference from the parent node instead.
+///
+/// [`Server::add_object_type_node()`]: crate::Server::add_object_type_node
+#[derive(Debug, Clone)]
+pub struct ObjectTypeNode {
+    pub requested_new_node_id: Option<ua::NodeId>,
+    pub parent_node_id: ua::NodeId,
+    pub reference_type_id: ua::NodeId,
+    pub browse_name: ua::QualifiedName,
+    pub attributes: ua::ObjectTypeAttributes,
+}
+
 #[derive(Debug, Clone)]
 pub struct VariableNode {
     pub requested_new_node_id: Option<ua::NodeId>,
@@ -132,6 +147,21 @@ pub struct VariableNode {
     pub attributes: ua::VariableAttributes,
 }
 
+/// Variable type node, see [`Server::add_variable_type_node()`].
+///
+/// Unlike [`VariableNode`], this has no type definition: variable types are related to their
+/// supertype by a [`ua::NodeId::ns0_has_subtype()`] reference from the parent node instead.
+///
+/// [`Server::add_variable_type_node()`]: crate::Server::add_variable_type_node
+#[derive(Debug, Clone)]
+pub struct VariableTypeNode {
+    pub requested_new_node_id: Option<ua::NodeId>,
+    pub parent_node_id: ua::NodeId,
+    pub reference_type_id: ua::NodeId,
+    pub browse_name: ua::QualifiedName,
+    pub attributes: ua::VariableTypeAttributes,
+}
+
 #[derive(Debug, Clone)]
 pub struct MethodNode {
     pub requested_new_node_id: Option<ua::NodeId>,
@@ -0,0 +1,397 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ffi::c_void,
+    mem, ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+use open62541_sys::{
+    UA_AccessControl, UA_Byte, UA_ByteString, UA_EndpointDescription, UA_ExtensionObject,
+    UA_NodeId, UA_Server, UA_ServerConfig, UA_Server_getConfig, UA_StatusCode,
+    UA_ACCESSLEVELTYPE_CURRENTWRITE, UA_STATUSCODE_BADINTERNALERROR,
+};
+
+use crate::{server::NodeContext, ua, DataType as _};
+
+type GetUserAccessLevel = unsafe extern "C" fn(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    session_id: *const UA_NodeId,
+    session_context: *mut c_void,
+    node_id: *const UA_NodeId,
+    node_context: *mut c_void,
+) -> UA_Byte;
+
+type ActivateSession = unsafe extern "C" fn(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    endpoint_description: *const UA_EndpointDescription,
+    secure_channel_remote_certificate: *const UA_ByteString,
+    session_id: *const UA_NodeId,
+    user_identity_token: *const UA_ExtensionObject,
+    session_context: *mut *mut c_void,
+) -> UA_StatusCode;
+
+type CloseSession = unsafe extern "C" fn(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    session_id: *const UA_NodeId,
+    session_context: *mut c_void,
+);
+
+/// Data attached to session, see [`set_pending_session_data()`].
+pub(crate) type SessionData = Arc<dyn Any + Send + Sync>;
+
+thread_local! {
+    /// Session data handed over from login callback to [`activate_session_c()`].
+    ///
+    /// The login callback of the access control does not learn the session ID. It is called from
+    /// within `activateSession()`, on the same thread, so we use this to pass on the data.
+    static PENDING_SESSION_DATA: RefCell<Option<SessionData>> = const { RefCell::new(None) };
+}
+
+/// Attaches data to session that is currently being activated.
+///
+/// This must be called from within the login callback of the access control. The data is attached
+/// when the session is activated successfully.
+pub(crate) fn set_pending_session_data(session_data: Option<SessionData>) {
+    PENDING_SESSION_DATA.with(|pending| *pending.borrow_mut() = session_data);
+}
+
+/// Context attached to server.
+///
+/// This is stored in the server config and owned by [`ua::Server`]. It is released after the server
+/// has been deleted.
+#[derive(Debug)]
+pub(crate) struct ServerContext {
+    /// Whether value writes from sessions are currently rejected.
+    write_protected: AtomicBool,
+    /// Variable node that reflects [`Self::write_protected`], if any.
+    write_protection_node_id: Mutex<Option<ua::NodeId>>,
+    /// Original callback from access control, see [`get_user_access_level_c()`].
+    get_user_access_level: Option<GetUserAccessLevel>,
+    /// Original callback from access control, see [`activate_session_c()`].
+    activate_session: Option<ActivateSession>,
+    /// Original callback from access control, see [`close_session_c()`].
+    close_session: Option<CloseSession>,
+    /// Data attached to active sessions by the login callback.
+    session_data: Mutex<HashMap<ua::NodeId, SessionData>>,
+    /// Whether adding and deleting nodes emits model change events.
+    model_change_events: bool,
+    /// Serializes adding namespaces, to find out whether they have been added by us.
+    namespace_lock: Mutex<()>,
+    /// Node contexts that have been leaked into nodes, see [`NodeContext::leak()`].
+    node_contexts: Mutex<HashSet<NodeContextPtr>>,
+}
+
+impl ServerContext {
+    /// Installs server context into config.
+    ///
+    /// This must be called after the access control has been set, i.e. right before creating the
+    /// server from the config. To prevent memory leaks, the server must be created from the config,
+    /// which then takes ownership of the context.
+    pub(crate) fn install(config: &mut UA_ServerConfig, model_change_events: bool) {
+        let context = Self {
+            write_protected: AtomicBool::new(false),
+            write_protection_node_id: Mutex::new(None),
+            get_user_access_level: config.accessControl.getUserAccessLevel,
+            activate_session: config.accessControl.activateSession,
+            close_session: config.accessControl.closeSession,
+            session_data: Mutex::new(HashMap::new()),
+            model_change_events,
+            namespace_lock: Mutex::new(()),
+            node_contexts: Mutex::new(HashSet::new()),
+        };
+
+        // PANIC: We never set context elsewhere in config.
+        debug_assert!(config.context.is_null());
+        // The context is only ever accessed by shared reference, from multiple threads. We cannot
+        // use `Userdata` here because it hands out exclusive references.
+        config.context = Box::into_raw(Box::new(context)).cast::<c_void>();
+        config.accessControl.getUserAccessLevel = Some(get_user_access_level_c);
+        config.accessControl.activateSession = Some(activate_session_c);
+        config.accessControl.closeSession = Some(close_session_c);
+    }
+
+    /// Gets server context of server.
+    ///
+    /// # Panics
+    ///
+    /// The server must have been created from a config with [`install()`](Self::install).
+    pub(crate) fn get(server: &ua::Server) -> &Self {
+        // SAFETY: The context has been installed before the server was created and is only released
+        // when the server is dropped. `server` is borrowed for the lifetime of the reference.
+        unsafe { Self::from_server(server.as_ptr().cast_mut()) }.expect("server context is set")
+    }
+
+    /// Takes server context from server.
+    ///
+    /// # Safety
+    ///
+    /// This must only be called right before the server is deleted. The returned context must only
+    /// be dropped after the server has been deleted.
+    pub(crate) unsafe fn take(server: *mut UA_Server) -> Option<Box<Self>> {
+        // SAFETY: Server config is owned by server. We only access the context field.
+        let config = unsafe { UA_Server_getConfig(server).as_mut() }?;
+        let context = config.context;
+        if context.is_null() {
+            return None;
+        }
+        config.context = ptr::null_mut();
+
+        // SAFETY: The context has been created by `install()` and is taken only once here.
+        let context = unsafe { Box::from_raw(context.cast::<Self>()) };
+
+        // Sessions are closed while the server is deleted. Restore the original callbacks: our
+        // wrappers would not find the server context anymore.
+        config.accessControl.activateSession = context.activate_session;
+        config.accessControl.closeSession = context.close_session;
+
+        Some(context)
+    }
+
+    /// Gets server context from raw server pointer.
+    ///
+    /// # Safety
+    ///
+    /// The server must be valid for the lifetime of the returned reference.
+    pub(crate) unsafe fn from_server<'a>(server: *mut UA_Server) -> Option<&'a Self> {
+        // SAFETY: Server config is owned by server. The context field is only written before the
+        // server is created and when it is deleted.
+        let config = unsafe { UA_Server_getConfig(server).as_ref() }?;
+        unsafe { config.context.cast::<Self>().as_ref() }
+    }
+
+    pub(crate) fn is_write_protected(&self) -> bool {
+        self.write_protected.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn set_write_protected(&self, write_protected: bool) {
+        self.write_protected
+            .store(write_protected, Ordering::SeqCst);
+    }
+
+    pub(crate) fn write_protection_node_id(&self) -> Option<ua::NodeId> {
+        // PANIC: We never panic while holding the lock.
+        self.write_protection_node_id.lock().unwrap().clone()
+    }
+
+    pub(crate) fn set_write_protection_node_id(&self, node_id: ua::NodeId) {
+        // PANIC: We never panic while holding the lock.
+        *self.write_protection_node_id.lock().unwrap() = Some(node_id);
+    }
+
+    pub(crate) const fn model_change_events(&self) -> bool {
+        self.model_change_events
+    }
+
+    pub(crate) fn lock_namespaces(&self) -> MutexGuard<'_, ()> {
+        // The lock guards no data, so poisoning can be ignored.
+        self.namespace_lock
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Gets data attached to session by login callback.
+    pub(crate) fn session_data(&self, session_id: &ua::NodeId) -> Option<SessionData> {
+        // PANIC: We never panic while holding the lock.
+        self.session_data.lock().unwrap().get(session_id).cloned()
+    }
+
+    /// Remembers node context as owned by this server.
+    pub(crate) fn register_node_context(&self, data: *mut c_void) {
+        // PANIC: We never panic while holding the lock.
+        let inserted = self
+            .node_contexts
+            .lock()
+            .unwrap()
+            .insert(NodeContextPtr(data));
+        debug_assert!(inserted, "node context should be registered only once");
+    }
+
+    /// Forgets node context.
+    ///
+    /// This returns `true` when the node context has been registered with this server before, i.e.
+    /// when it is owned by us and may be released.
+    pub(crate) fn unregister_node_context(&self, data: *mut c_void) -> bool {
+        // PANIC: We never panic while holding the lock.
+        self.node_contexts
+            .lock()
+            .unwrap()
+            .remove(&NodeContextPtr(data))
+    }
+}
+
+impl Drop for ServerContext {
+    fn drop(&mut self) {
+        // The server has been deleted at this point (see `take()`), so the remaining node contexts
+        // are not accessed anymore. Not all of them are passed to the node destructor, e.g. when the
+        // server is deleted with nodes still in place.
+        let node_contexts = mem::take(
+            self.node_contexts
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        if !node_contexts.is_empty() {
+            log::debug!("Freeing {} remaining node contexts", node_contexts.len());
+        }
+        for NodeContextPtr(data) in node_contexts {
+            // SAFETY: The pointer has been registered by `NodeContext::leak()` and has not been
+            // released since, otherwise it would have been unregistered.
+            drop(unsafe { NodeContext::consume(data) });
+        }
+    }
+}
+
+/// Pointer to node context.
+///
+/// This is only used to identify node contexts owned by us, see [`NodeContext::leak()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct NodeContextPtr(*mut c_void);
+
+// SAFETY: The pointer is never dereferenced through this type. It is consumed only by the owner of
+// the server context, when the server is gone.
+unsafe impl Send for NodeContextPtr {}
+
+/// Wraps `getUserAccessLevel()` of access control.
+///
+/// This removes write access while the server is write-protected. The server does not ask the access
+/// control for writes from the server application itself (admin session), so these still work.
+unsafe extern "C" fn get_user_access_level_c(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    session_id: *const UA_NodeId,
+    session_context: *mut c_void,
+    node_id: *const UA_NodeId,
+    node_context: *mut c_void,
+) -> UA_Byte {
+    // SAFETY: The server is valid for the duration of the callback.
+    let Some(context) = (unsafe { ServerContext::from_server(server) }) else {
+        return 0;
+    };
+
+    let access_level = context
+        .get_user_access_level
+        .map_or(0xFF, |get_user_access_level| unsafe {
+            get_user_access_level(
+                server,
+                ac,
+                session_id,
+                session_context,
+                node_id,
+                node_context,
+            )
+        });
+
+    if !context.is_write_protected() {
+        return access_level;
+    }
+
+    // PANIC: Mask is always in range of `u8`.
+    let write_mask = u8::try_from(UA_ACCESSLEVELTYPE_CURRENTWRITE).unwrap_or(0);
+    access_level & !write_mask
+}
+
+/// Gets ID and attached data of session from raw callback arguments.
+///
+/// The session ID is null when it is not given, e.g. for calls from the server application itself.
+///
+/// # Safety
+///
+/// The server must be valid for the duration of the call. The session ID must be valid or null.
+pub(crate) unsafe fn session_from_raw(
+    server: *mut UA_Server,
+    session_id: *const UA_NodeId,
+) -> (ua::NodeId, Option<SessionData>) {
+    let Some(session_id) = (unsafe { session_id.as_ref() }) else {
+        return (ua::NodeId::null(), None);
+    };
+    let session_id = ua::NodeId::clone_raw(session_id);
+    let session_data = unsafe { ServerContext::from_server(server) }
+        .and_then(|context| context.session_data(&session_id));
+    (session_id, session_data)
+}
+
+/// Wraps `activateSession()` of access control.
+///
+/// This attaches the data returned from the login callback to the session, see
+/// [`set_pending_session_data()`].
+unsafe extern "C" fn activate_session_c(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    endpoint_description: *const UA_EndpointDescription,
+    secure_channel_remote_certificate: *const UA_ByteString,
+    session_id: *const UA_NodeId,
+    user_identity_token: *const UA_ExtensionObject,
+    session_context: *mut *mut c_void,
+) -> UA_StatusCode {
+    // SAFETY: The server is valid for the duration of the callback.
+    let Some(context) = (unsafe { ServerContext::from_server(server) }) else {
+        return UA_STATUSCODE_BADINTERNALERROR;
+    };
+    let Some(activate_session) = context.activate_session else {
+        return UA_STATUSCODE_BADINTERNALERROR;
+    };
+
+    set_pending_session_data(None);
+    let status_code = unsafe {
+        activate_session(
+            server,
+            ac,
+            endpoint_description,
+            secure_channel_remote_certificate,
+            session_id,
+            user_identity_token,
+            session_context,
+        )
+    };
+    let session_data = PENDING_SESSION_DATA.with(|pending| pending.borrow_mut().take());
+
+    let Some(session_id) = (unsafe { session_id.as_ref() }) else {
+        return status_code;
+    };
+    if ua::StatusCode::new(status_code).is_good() {
+        let session_id = ua::NodeId::clone_raw(session_id);
+        // PANIC: We never panic while holding the lock.
+        let mut sessions = context.session_data.lock().unwrap();
+        // Sessions may be activated again, e.g. with another user. Replace previous data.
+        match session_data {
+            Some(session_data) => sessions.insert(session_id, session_data),
+            None => sessions.remove(&session_id),
+        };
+    }
+
+    status_code
+}
+
+/// Wraps `closeSession()` of access control.
+///
+/// This releases the data attached to the session by [`activate_session_c()`].
+unsafe extern "C" fn close_session_c(
+    server: *mut UA_Server,
+    ac: *mut UA_AccessControl,
+    session_id: *const UA_NodeId,
+    session_context: *mut c_void,
+) {
+    // SAFETY: The server is valid for the duration of the callback.
+    let Some(context) = (unsafe { ServerContext::from_server(server) }) else {
+        return;
+    };
+
+    if let Some(close_session) = context.close_session {
+        unsafe { close_session(server, ac, session_id, session_context) };
+    }
+
+    if let Some(session_id) = unsafe { session_id.as_ref() } {
+        let session_id = ua::NodeId::raw_ref(session_id);
+        // PANIC: We never panic while holding the lock.
+        let session_data = context.session_data.lock().unwrap().remove(session_id);
+        // Drop data outside of lock, in case it has its own drop logic.
+        drop(session_data);
+    }
+}
@@ -0,0 +1,440 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::{ua, DataType as _, Result, Server};
+
+/// Snapshot of server's address space.
+///
+/// This captures the nodes of selected namespaces in a deterministic structure: nodes are ordered
+/// by node ID and references by their string representation. Snapshots can be compared with
+/// [`diff()`](Self::diff), and (with feature `serde`) serialized to be committed as golden files.
+///
+/// See [`Server::snapshot()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddressSpaceSnapshot {
+    nodes: BTreeMap<String, NodeSnapshot>,
+}
+
+impl AddressSpaceSnapshot {
+    pub(crate) fn capture(server: &Server, namespace_indices: &[u16]) -> Result<Self> {
+        let node_ids = server.browse_recursive(
+            &ua::BrowseDescription::default().with_node_id(&ua::NodeId::ns0_root_folder()),
+        )?;
+
+        let mut nodes = BTreeMap::new();
+
+        for node_id in node_ids.iter() {
+            if node_id.server_index() != 0 {
+                continue;
+            }
+            let node_id = node_id.node_id();
+            if !namespace_indices.contains(&node_id.namespace_index()) {
+                continue;
+            }
+
+            nodes.insert(node_id.to_string(), NodeSnapshot::capture(server, node_id)?);
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Gets captured nodes, keyed by node ID.
+    #[must_use]
+    pub const fn nodes(&self) -> &BTreeMap<String, NodeSnapshot> {
+        &self.nodes
+    }
+
+    /// Compares snapshot to other snapshot.
+    ///
+    /// This returns the changes that lead from this snapshot to `other`, ordered by node ID.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<Difference> {
+        let mut differences = Vec::new();
+
+        let node_ids: BTreeSet<&String> = self.nodes.keys().chain(other.nodes.keys()).collect();
+
+        for node_id in node_ids {
+            match (self.nodes.get(node_id), other.nodes.get(node_id)) {
+                (Some(_), None) => differences.push(Difference::NodeRemoved {
+                    node_id: node_id.clone(),
+                }),
+                (None, Some(_)) => differences.push(Difference::NodeAdded {
+                    node_id: node_id.clone(),
+                }),
+                (Some(old), Some(new)) => old.diff(node_id, new, &mut differences),
+                (None, None) => unreachable!("node ID should be in either snapshot"),
+            }
+        }
+
+        differences
+    }
+}
+
+/// Snapshot of single node.
+///
+/// See [`AddressSpaceSnapshot`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeSnapshot {
+    node_class: String,
+    browse_name: String,
+    type_definition: Option<String>,
+    data_type: Option<String>,
+    references: BTreeSet<String>,
+}
+
+impl NodeSnapshot {
+    fn capture(server: &Server, node_id: &ua::NodeId) -> Result<Self> {
+        let node_class = server
+            .read_attribute(node_id, ua::AttributeId::NODECLASS_T)?
+            .into_value();
+        let browse_name = server
+            .read_attribute(node_id, ua::AttributeId::BROWSENAME_T)?
+            .into_value();
+
+        let data_type = if node_class == ua::NodeClass::VARIABLE {
+            let data_type = server
+                .read_attribute(node_id, ua::AttributeId::DATATYPE_T)?
+                .into_value();
+            Some(data_type.to_string())
+        } else {
+            None
+        };
+
+        // Browse all references in both directions. This includes references from nodes in other
+        // namespaces which would otherwise not show up in the snapshot.
+        let references = server.browse_all(
+            &ua::BrowseDescription::init()
+                .with_node_id(node_id)
+                .with_browse_direction(&ua::BrowseDirection::BOTH)
+                .with_include_subtypes(true)
+                .with_result_mask(&ua::BrowseResultMask::ALL),
+        )?;
+
+        let type_definition = references
+            .iter()
+            .find(|reference| {
+                reference.is_forward()
+                    && reference.reference_type_id() == &ua::NodeId::ns0_has_type_definition()
+            })
+            .map(|reference| reference.node_id().node_id().to_string());
+
+        let references = references
+            .iter()
+            .map(|reference| {
+                format!(
+                    "{} {} {}",
+                    reference.reference_type_id(),
+                    if reference.is_forward() { "->" } else { "<-" },
+                    reference.node_id().node_id(),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            node_class: node_class.to_string(),
+            browse_name: browse_name.to_string(),
+            type_definition,
+            data_type,
+            references,
+        })
+    }
+
+    /// Gets node class, e.g. `Variable`.
+    #[must_use]
+    pub fn node_class(&self) -> &str {
+        &self.node_class
+    }
+
+    /// Gets browse name, including namespace index.
+    #[must_use]
+    pub fn browse_name(&self) -> &str {
+        &self.browse_name
+    }
+
+    /// Gets type definition.
+    ///
+    /// This is only set for object and variable nodes.
+    #[must_use]
+    pub fn type_definition(&self) -> Option<&str> {
+        self.type_definition.as_deref()
+    }
+
+    /// Gets data type.
+    ///
+    /// This is only set for variable nodes.
+    #[must_use]
+    pub fn data_type(&self) -> Option<&str> {
+        self.data_type.as_deref()
+    }
+
+    /// Gets references in both directions.
+    ///
+    /// Each reference is given as `<reference type> -> <target>` for forward references and as
+    /// `<reference type> <- <source>` for inverse references.
+    #[must_use]
+    pub const fn references(&self) -> &BTreeSet<String> {
+        &self.references
+    }
+
+    fn diff(&self, node_id: &str, other: &Self, differences: &mut Vec<Difference>) {
+        let attributes = [
+            (
+                "NodeClass",
+                Some(self.node_class.as_str()),
+                Some(other.node_class.as_str()),
+            ),
+            (
+                "BrowseName",
+                Some(self.browse_name.as_str()),
+                Some(other.browse_name.as_str()),
+            ),
+            (
+                "TypeDefinition",
+                self.type_definition(),
+                other.type_definition(),
+            ),
+            ("DataType", self.data_type(), other.data_type()),
+        ];
+
+        for (attribute, old, new) in attributes {
+            if old != new {
+                differences.push(Difference::AttributeChanged {
+                    node_id: node_id.to_owned(),
+                    attribute,
+                    old: old.map(ToOwned::to_owned),
+                    new: new.map(ToOwned::to_owned),
+                });
+            }
+        }
+
+        for reference in self.references.difference(&other.references) {
+            differences.push(Difference::ReferenceRemoved {
+                node_id: node_id.to_owned(),
+                reference: reference.clone(),
+            });
+        }
+        for reference in other.references.difference(&self.references) {
+            differences.push(Difference::ReferenceAdded {
+                node_id: node_id.to_owned(),
+                reference: reference.clone(),
+            });
+        }
+    }
+}
+
+/// Difference between two [snapshots](AddressSpaceSnapshot).
+///
+/// The [`Display`](fmt::Display) implementation gives a human-readable description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Difference {
+    /// Node exists only in the new snapshot.
+    NodeAdded { node_id: String },
+    /// Node exists only in the old snapshot.
+    NodeRemoved { node_id: String },
+    /// Attribute of node has changed. The value is `None` when the attribute is not set.
+    AttributeChanged {
+        node_id: String,
+        attribute: &'static str,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    /// Reference of node exists only in the new snapshot.
+    ReferenceAdded { node_id: String, reference: String },
+    /// Reference of node exists only in the old snapshot.
+    ReferenceRemoved { node_id: String, reference: String },
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difference::NodeAdded { node_id } => write!(f, "added node {node_id}"),
+            Difference::NodeRemoved { node_id } => write!(f, "removed node {node_id}"),
+            Difference::AttributeChanged {
+                node_id,
+                attribute,
+                old,
+                new,
+            } => write!(
+                f,
+                "changed {attribute} of node {node_id} from {} to {}",
+                old.as_deref().unwrap_or("(none)"),
+                new.as_deref().unwrap_or("(none)"),
+            ),
+            Difference::ReferenceAdded { node_id, reference } => {
+                write!(f, "added reference {reference} to node {node_id}")
+            }
+            Difference::ReferenceRemoved { node_id, reference } => {
+                write!(f, "removed reference {reference} from node {node_id}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use std::{collections::BTreeMap, fmt};
+
+    use serde::{
+        de::{self, IgnoredAny, MapAccess, Visitor},
+        ser::SerializeStruct as _,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use super::{AddressSpaceSnapshot, NodeSnapshot};
+
+    impl Serialize for AddressSpaceSnapshot {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.nodes.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AddressSpaceSnapshot {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let nodes = BTreeMap::deserialize(deserializer)?;
+            Ok(Self { nodes })
+        }
+    }
+
+    impl Serialize for NodeSnapshot {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("NodeSnapshot", 5)?;
+            state.serialize_field("node_class", &self.node_class)?;
+            state.serialize_field("browse_name", &self.browse_name)?;
+            state.serialize_field("type_definition", &self.type_definition)?;
+            state.serialize_field("data_type", &self.data_type)?;
+            state.serialize_field("references", &self.references)?;
+            state.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NodeSnapshot {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct NodeSnapshotVisitor;
+
+            impl<'de> Visitor<'de> for NodeSnapshotVisitor {
+                type Value = NodeSnapshot;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("node snapshot")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(
+                    self,
+                    mut map: A,
+                ) -> Result<NodeSnapshot, A::Error> {
+                    let mut node_class = None;
+                    let mut browse_name = None;
+                    let mut node = NodeSnapshot::default();
+
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "node_class" => node_class = Some(map.next_value()?),
+                            "browse_name" => browse_name = Some(map.next_value()?),
+                            "type_definition" => node.type_definition = map.next_value()?,
+                            "data_type" => node.data_type = map.next_value()?,
+                            "references" => node.references = map.next_value()?,
+                            _ => {
+                                map.next_value::<IgnoredAny>()?;
+                            }
+                        }
+                    }
+
+                    node.node_class =
+                        node_class.ok_or_else(|| de::Error::missing_field("node_class"))?;
+                    node.browse_name =
+                        browse_name.ok_or_else(|| de::Error::missing_field("browse_name"))?;
+
+                    Ok(node)
+                }
+            }
+
+            deserializer.deserialize_map(NodeSnapshotVisitor)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::{ua, ServerBuilder};
+
+        #[test]
+        fn snapshot_json_roundtrip() {
+            let (server, _) = ServerBuilder::default().build();
+            let namespace_index = server.add_namespace("http://hmi-project.com/UA/Snapshot/");
+            server
+                .add_object_node(crate::ObjectNode {
+                    requested_new_node_id: Some(ua::NodeId::numeric(namespace_index, 1)),
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(namespace_index, "Object"),
+                    type_definition: ua::NodeId::ns0_base_object_type(),
+                    attributes: ua::ObjectAttributes::default(),
+                })
+                .unwrap();
+
+            let snapshot = server.snapshot(&[namespace_index]).unwrap();
+            let json = serde_json::to_string(&snapshot).unwrap();
+            let parsed: super::AddressSpaceSnapshot = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(parsed, snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, ObjectNode, ServerBuilder};
+
+    use super::Difference;
+
+    #[test]
+    fn snapshot_diff() {
+        let (server, _) = ServerBuilder::default().build();
+        let namespace_index = server.add_namespace("http://hmi-project.com/UA/Snapshot/");
+
+        let add_object = |id: u32, name: &str| {
+            server
+                .add_object_node(ObjectNode {
+                    requested_new_node_id: Some(ua::NodeId::numeric(namespace_index, id)),
+                    parent_node_id: ua::NodeId::ns0_objects_folder(),
+                    reference_type_id: ua::NodeId::ns0_organizes(),
+                    browse_name: ua::QualifiedName::new(namespace_index, name),
+                    type_definition: ua::NodeId::ns0_base_object_type(),
+                    attributes: ua::ObjectAttributes::default(),
+                })
+                .unwrap()
+        };
+
+        let object_1 = add_object(1, "One");
+        let before = server.snapshot(&[namespace_index]).unwrap();
+        assert_eq!(before.nodes().len(), 1);
+        let node = &before.nodes()[&object_1.to_string()];
+        assert_eq!(node.node_class(), "OBJECT");
+        assert_eq!(
+            node.type_definition(),
+            Some(ua::NodeId::ns0_base_object_type().to_string().as_str())
+        );
+
+        // Snapshots are deterministic.
+        assert!(before
+            .diff(&server.snapshot(&[namespace_index]).unwrap())
+            .is_empty());
+
+        let object_2 = add_object(2, "Two");
+        let after = server.snapshot(&[namespace_index]).unwrap();
+
+        assert_eq!(
+            before.diff(&after),
+            vec![Difference::NodeAdded {
+                node_id: object_2.to_string(),
+            }]
+        );
+        assert_eq!(
+            after.diff(&before),
+            vec![Difference::NodeRemoved {
+                node_id: object_2.to_string(),
+            }]
+        );
+    }
+}
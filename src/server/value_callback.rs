@@ -0,0 +1,112 @@
+use std::{
+    ffi::c_void,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use open62541_sys::{UA_DataValue, UA_NodeId, UA_NumericRange, UA_Server, UA_ValueCallback};
+
+use crate::{server::NodeContext, ua, DataType as _};
+
+/// Value callback.
+///
+/// The `on_read` and `on_write` callbacks are notified of access to the value of a regular variable
+/// node when set via [`Server::set_variable_node_value_callback()`]. Unlike [`DataSource`], the
+/// value is still stored in the server.
+///
+/// The callbacks are run while the server is locked. They must not call methods of the server.
+///
+/// [`DataSource`]: crate::DataSource
+/// [`Server::set_variable_node_value_callback()`]: crate::Server::set_variable_node_value_callback
+pub trait ValueCallback {
+    /// Notifies of read from variable.
+    ///
+    /// This is called before the value is read, with the value currently stored in the variable.
+    // TODO: Check if we can guarantee `&mut self`.
+    #[allow(unused_variables)]
+    fn on_read(&mut self, node_id: &ua::NodeId, value: &ua::DataValue) {}
+
+    /// Notifies of write to variable.
+    ///
+    /// This is called after the value has been written, with the value now stored in the variable.
+    // TODO: Check if we can guarantee `&mut self`.
+    #[allow(unused_variables)]
+    fn on_write(&mut self, node_id: &ua::NodeId, value: &ua::DataValue) {}
+}
+
+/// Transforms into raw value.
+///
+/// # Safety
+///
+/// The returned [`UA_ValueCallback`] is only valid for as long as [`NodeContext`] is alive. The
+/// lifetime can be extended by using [`NodeContext::leak()`] to save this value inside the
+/// corresponding server node, to be eventually cleaned up when the node is destroyed.
+pub(crate) unsafe fn wrap_value_callback(
+    value_callback: impl ValueCallback + 'static,
+) -> (UA_ValueCallback, NodeContext) {
+    unsafe extern "C" fn on_read_c(
+        _server: *mut UA_Server,
+        _session_id: *const UA_NodeId,
+        _session_context: *mut c_void,
+        node_id: *const UA_NodeId,
+        node_context: *mut c_void,
+        _range: *const UA_NumericRange,
+        value: *const UA_DataValue,
+    ) {
+        let node_context = unsafe { NodeContext::peek_at(node_context) };
+        let NodeContext::ValueCallback(value_callback) = node_context else {
+            // We expect to always find this node context type.
+            return;
+        };
+
+        let (Some(node_id), Some(value)) = (unsafe { node_id.as_ref() }, unsafe { value.as_ref() })
+        else {
+            // The server always passes both arguments.
+            return;
+        };
+        let node_id = ua::NodeId::raw_ref(node_id);
+        let value = ua::DataValue::raw_ref(value);
+        let mut value_callback = AssertUnwindSafe(value_callback);
+
+        if let Err(err) = catch_unwind(move || value_callback.on_read(node_id, value)) {
+            log::error!("Read callback in value callback panicked: {err:?}");
+        }
+    }
+
+    unsafe extern "C" fn on_write_c(
+        _server: *mut UA_Server,
+        _session_id: *const UA_NodeId,
+        _session_context: *mut c_void,
+        node_id: *const UA_NodeId,
+        node_context: *mut c_void,
+        _range: *const UA_NumericRange,
+        data: *const UA_DataValue,
+    ) {
+        let node_context = unsafe { NodeContext::peek_at(node_context) };
+        let NodeContext::ValueCallback(value_callback) = node_context else {
+            // We expect to always find this node context type.
+            return;
+        };
+
+        let (Some(node_id), Some(value)) = (unsafe { node_id.as_ref() }, unsafe { data.as_ref() })
+        else {
+            // The server always passes both arguments.
+            return;
+        };
+        let node_id = ua::NodeId::raw_ref(node_id);
+        let value = ua::DataValue::raw_ref(value);
+        let mut value_callback = AssertUnwindSafe(value_callback);
+
+        if let Err(err) = catch_unwind(move || value_callback.on_write(node_id, value)) {
+            log::error!("Write callback in value callback panicked: {err:?}");
+        }
+    }
+
+    let raw_value_callback = UA_ValueCallback {
+        onRead: Some(on_read_c),
+        onWrite: Some(on_write_c),
+    };
+
+    let node_context = NodeContext::ValueCallback(Box::new(value_callback));
+
+    (raw_value_callback, node_context)
+}
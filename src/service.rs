@@ -1,8 +1,27 @@
+use open62541_sys::{UA_RequestHeader, UA_ResponseHeader};
+
 use crate::{ua, DataType};
 
 #[allow(dead_code)] // --no-default-features
 pub(crate) trait ServiceRequest: DataType + 'static {
     type Response: ServiceResponse;
+
+    /// Gets request header.
+    fn request_header(&self) -> &ua::RequestHeader {
+        // SAFETY: All service requests start with the request header. `open62541` relies on this in
+        // `__UA_Client_AsyncService()` too.
+        let request_header = unsafe { self.as_ptr().cast::<UA_RequestHeader>().as_ref() };
+        // PANIC: The pointer of a data type is never null.
+        ua::RequestHeader::raw_ref(request_header.unwrap())
+    }
+
+    /// Gets mutable request header.
+    fn request_header_mut(&mut self) -> &mut ua::RequestHeader {
+        // SAFETY: See `request_header()`.
+        let request_header = unsafe { self.as_mut_ptr().cast::<UA_RequestHeader>().as_mut() };
+        // PANIC: The pointer of a data type is never null.
+        ua::RequestHeader::raw_mut(request_header.unwrap())
+    }
 }
 
 #[allow(dead_code)] // --no-default-features
@@ -10,4 +29,12 @@ pub(crate) trait ServiceResponse: DataType + 'static {
     type Request: ServiceRequest;
 
     fn service_result(&self) -> ua::StatusCode;
+
+    /// Gets response header.
+    fn response_header(&self) -> &ua::ResponseHeader {
+        // SAFETY: All service responses start with the response header.
+        let response_header = unsafe { self.as_ptr().cast::<UA_ResponseHeader>().as_ref() };
+        // PANIC: The pointer of a data type is never null.
+        ua::ResponseHeader::raw_ref(response_header.unwrap())
+    }
 }
@@ -1,6 +1,6 @@
-use std::{fmt, ptr};
+use std::{fmt, mem, ptr, slice, str};
 
-use open62541_sys::UA_CreateCertificate;
+use open62541_sys::{UA_CreateCertificate, UA_SecurityPolicy};
 use zeroize::Zeroizing;
 
 use crate::{ua, DataType, Error};
@@ -60,6 +60,197 @@ impl Certificate {
     pub(crate) const fn as_byte_string(&self) -> &ua::ByteString {
         &self.0
     }
+
+    /// Checks if certificate data looks like [DER] format.
+    ///
+    /// This only checks for the leading ASN.1 `SEQUENCE` tag, it does not validate the data.
+    ///
+    /// [DER]: https://en.wikipedia.org/wiki/X.690#DER_encoding
+    fn is_der(&self) -> bool {
+        self.as_bytes().first() == Some(&0x30)
+    }
+}
+
+/// Appends intermediate certificates to local certificate of security policies.
+///
+/// OPC UA transmits certificate chains as the leaf certificate followed by its issuers, concatenated
+/// in [DER] format. The security policies in `open62541` only keep the leaf certificate, so we must
+/// append the intermediate certificates after the security policies have been set up.
+///
+/// Security policies without local certificate (such as `None`) are skipped.
+///
+/// # Errors
+///
+/// This fails when any of the intermediate certificates is not in [DER] format.
+///
+/// # Safety
+///
+/// `security_policies` must point to `security_policies_size` valid security policies (or be null
+/// when `security_policies_size` is 0).
+///
+/// [DER]: https://en.wikipedia.org/wiki/X.690#DER_encoding
+pub(crate) unsafe fn append_intermediate_certificates(
+    security_policies: *mut UA_SecurityPolicy,
+    security_policies_size: usize,
+    intermediate_certificates: &[Certificate],
+) -> crate::Result<()> {
+    if !intermediate_certificates.iter().all(Certificate::is_der) {
+        return Err(Error::internal(
+            "intermediate certificates should be in DER format",
+        ));
+    }
+
+    if security_policies.is_null() || security_policies_size == 0 {
+        return Ok(());
+    }
+    // SAFETY: We require the same safety guarantees from our callers.
+    let security_policies =
+        unsafe { slice::from_raw_parts_mut(security_policies, security_policies_size) };
+
+    for security_policy in security_policies {
+        let local_certificate = ua::ByteString::raw_ref(&security_policy.localCertificate);
+        let Some(leaf_certificate) = local_certificate
+            .as_bytes()
+            .filter(|bytes| !bytes.is_empty())
+        else {
+            continue;
+        };
+
+        // The security policy has already converted the leaf certificate into DER format.
+        let chain: Vec<u8> = leaf_certificate
+            .iter()
+            .chain(
+                intermediate_certificates
+                    .iter()
+                    .flat_map(Certificate::as_bytes),
+            )
+            .copied()
+            .collect();
+
+        ua::ByteString::new(&chain).move_into_raw(&mut security_policy.localCertificate);
+    }
+
+    Ok(())
+}
+
+/// Gets application URI from local certificate of security policies.
+///
+/// This returns the first URI in the subject alternative names of the first local certificate that
+/// is set, if any. Security policies without local certificate (such as `None`) are skipped.
+///
+/// # Safety
+///
+/// `security_policies` must point to `security_policies_size` valid security policies (or be null
+/// when `security_policies_size` is 0).
+pub(crate) unsafe fn local_application_uri(
+    security_policies: *const UA_SecurityPolicy,
+    security_policies_size: usize,
+) -> Option<String> {
+    if security_policies.is_null() || security_policies_size == 0 {
+        return None;
+    }
+    // SAFETY: We require the same safety guarantees from our callers.
+    let security_policies =
+        unsafe { slice::from_raw_parts(security_policies, security_policies_size) };
+
+    let local_certificate = security_policies.iter().find_map(|security_policy| {
+        ua::ByteString::raw_ref(&security_policy.localCertificate)
+            .as_bytes()
+            .filter(|bytes| !bytes.is_empty())
+    })?;
+
+    // The security policy has already converted the certificate into DER format. When intermediate
+    // certificates have been appended, we only look at the first (leaf) certificate.
+    application_uri_from_der(local_certificate)
+}
+
+/// DER-encoded object identifier of subject alternative name extension (2.5.29.17).
+const SUBJECT_ALT_NAME_OID: &[u8] = &[0x55, 0x1d, 0x11];
+
+/// Extracts application URI from certificate in [DER] format.
+///
+/// This returns the first URI in the subject alternative name extension. OPC UA requires this to
+/// match the application URI in the application description.
+///
+/// [DER]: https://en.wikipedia.org/wiki/X.690#DER_encoding
+fn application_uri_from_der(certificate: &[u8]) -> Option<String> {
+    // Certificate ::= SEQUENCE { tbsCertificate SEQUENCE, signatureAlgorithm, signatureValue }
+    let (certificate, _) = der_read(certificate, 0x30)?;
+    let (mut tbs_certificate, _) = der_read(certificate, 0x30)?;
+
+    // Extensions are the last (optional) field of `TBSCertificate`, tagged as `[3] EXPLICIT`.
+    let extensions = loop {
+        let (tag, value, rest) = der_next(tbs_certificate)?;
+        if tag == 0xa3 {
+            break value;
+        }
+        tbs_certificate = rest;
+    };
+
+    let (mut extensions, _) = der_read(extensions, 0x30)?;
+    while !extensions.is_empty() {
+        let (extension, rest) = der_read(extensions, 0x30)?;
+        extensions = rest;
+
+        // Extension ::= SEQUENCE { extnID, critical BOOLEAN DEFAULT FALSE, extnValue OCTET STRING }
+        let (extension_id, extension) = der_read(extension, 0x06)?;
+        if extension_id != SUBJECT_ALT_NAME_OID {
+            continue;
+        }
+        let extension = der_read(extension, 0x01).map_or(extension, |(_, rest)| rest);
+        let (extension_value, _) = der_read(extension, 0x04)?;
+
+        // GeneralNames ::= SEQUENCE OF GeneralName, with URIs tagged as `[6] IMPLICIT IA5String`.
+        let (mut general_names, _) = der_read(extension_value, 0x30)?;
+        while !general_names.is_empty() {
+            let (tag, value, rest) = der_next(general_names)?;
+            if tag == 0x86 {
+                // Reject URIs with NUL bytes: they cannot be used in application descriptions.
+                return str::from_utf8(value)
+                    .ok()
+                    .filter(|uri| !uri.contains('\0'))
+                    .map(str::to_owned);
+            }
+            general_names = rest;
+        }
+        return None;
+    }
+
+    None
+}
+
+/// Reads next DER element with the given tag.
+///
+/// This returns the element's value and the remaining data.
+fn der_read(data: &[u8], expected_tag: u8) -> Option<(&[u8], &[u8])> {
+    let (tag, value, rest) = der_next(data)?;
+    (tag == expected_tag).then_some((value, rest))
+}
+
+/// Reads next DER element.
+///
+/// This returns the element's tag and value, and the remaining data.
+fn der_next(data: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, data) = data.split_first()?;
+    let (&length, mut data) = data.split_first()?;
+
+    let length = if length & 0x80 == 0 {
+        usize::from(length)
+    } else {
+        // Long form: the lower bits give the number of subsequent length bytes (big-endian). DER
+        // does not allow the indefinite form (without length bytes).
+        let (length_bytes, rest) = data.split_at_checked(usize::from(length & 0x7f))?;
+        if length_bytes.is_empty() || length_bytes.len() > mem::size_of::<usize>() {
+            return None;
+        }
+        data = rest;
+        length_bytes
+            .iter()
+            .fold(0, |length, &byte| (length << 8) | usize::from(byte))
+    };
+
+    let (value, rest) = data.split_at_checked(length)?;
+    Some((tag, value, rest))
 }
 
 /// Private key in [DER] or [PEM] format.
@@ -183,3 +374,136 @@ pub fn create_certificate(
 
     Ok((certificate, private_key))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{mem, ptr};
+
+    use open62541_sys::UA_SecurityPolicy;
+
+    use crate::{ua, Certificate, DataType as _};
+
+    #[test]
+    fn append_intermediate_certificates() {
+        const LEAF: &[u8] = &[0x30, 0x01, 0xaa];
+        const INTERMEDIATE: &[u8] = &[0x30, 0x01, 0xbb];
+
+        let mut security_policies = [UA_SecurityPolicy::default(), UA_SecurityPolicy::default()];
+        ua::ByteString::new(LEAF).move_into_raw(&mut security_policies[1].localCertificate);
+
+        unsafe {
+            super::append_intermediate_certificates(
+                security_policies.as_mut_ptr(),
+                security_policies.len(),
+                &[Certificate::from_bytes(INTERMEDIATE)],
+            )
+        }
+        .expect("should append intermediate certificates");
+
+        let [none_policy, leaf_policy] = security_policies.each_mut();
+        let none_certificate =
+            unsafe { ua::ByteString::from_raw(mem::take(&mut none_policy.localCertificate)) };
+        let chain =
+            unsafe { ua::ByteString::from_raw(mem::take(&mut leaf_policy.localCertificate)) };
+        assert!(none_certificate.as_bytes().map_or(true, <[u8]>::is_empty));
+        assert_eq!(
+            chain.as_bytes(),
+            Some([LEAF, INTERMEDIATE].concat().as_slice())
+        );
+    }
+
+    #[test]
+    fn reject_pem_intermediate_certificates() {
+        let result = unsafe {
+            super::append_intermediate_certificates(
+                ptr::null_mut(),
+                0,
+                &[Certificate::from_bytes(b"-----BEGIN CERTIFICATE-----")],
+            )
+        };
+        assert!(result.is_err());
+    }
+
+    /// Encodes DER element with the given tag and value.
+    fn der(tag: u8, value: &[u8]) -> Vec<u8> {
+        let length = value.len();
+        let mut element = vec![tag];
+        if length < 0x80 {
+            element.push(u8::try_from(length).unwrap());
+        } else {
+            let [.., high, low] = length.to_be_bytes();
+            element.extend([0x82, high, low]);
+        }
+        element.extend(value);
+        element
+    }
+
+    /// Creates (incomplete) DER certificate with the given extensions.
+    fn certificate_with_extensions(extensions: &[Vec<u8>]) -> Vec<u8> {
+        let tbs_certificate = der(
+            0x30,
+            &[
+                // Version, serial number, etc.
+                der(0xa0, &der(0x02, &[0x02])),
+                der(0x02, &[0x01]),
+                // Long values to exercise long-form lengths.
+                der(0x30, &[0; 200]),
+                der(0xa3, &der(0x30, &extensions.concat())),
+            ]
+            .concat(),
+        );
+        der(
+            0x30,
+            &[tbs_certificate, der(0x30, &[]), der(0x03, &[0x00])].concat(),
+        )
+    }
+
+    #[test]
+    fn application_uri_from_der() {
+        let subject_alt_name = der(
+            0x30,
+            &[
+                der(0x06, &[0x55, 0x1d, 0x11]),
+                der(
+                    0x04,
+                    &der(
+                        0x30,
+                        &[
+                            der(0x82, b"localhost"),
+                            der(0x86, b"urn:open62541.client.application"),
+                        ]
+                        .concat(),
+                    ),
+                ),
+            ]
+            .concat(),
+        );
+        let basic_constraints = der(
+            0x30,
+            &[
+                der(0x06, &[0x55, 0x1d, 0x13]),
+                der(0x01, &[0xff]),
+                der(0x04, &der(0x30, &[])),
+            ]
+            .concat(),
+        );
+
+        let certificate =
+            certificate_with_extensions(&[basic_constraints.clone(), subject_alt_name]);
+        assert_eq!(
+            super::application_uri_from_der(&certificate).as_deref(),
+            Some("urn:open62541.client.application")
+        );
+
+        let certificate = certificate_with_extensions(&[basic_constraints]);
+        assert_eq!(super::application_uri_from_der(&certificate), None);
+
+        // Truncated data must not panic.
+        for length in 0..certificate.len() {
+            assert_eq!(
+                super::application_uri_from_der(certificate.get(..length).unwrap()),
+                None
+            );
+        }
+    }
+}
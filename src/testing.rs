@@ -0,0 +1,284 @@
+use std::{
+    net::{Ipv4Addr, TcpListener},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread::{self, JoinHandle},
+};
+
+use crate::{AsyncClient, ClientBuilder, Error, Result, Server, ServerBuilder};
+
+/// In-process server with connected client, for tests.
+///
+/// This starts a [`Server`] on an ephemeral port, runs it on a background thread and connects an
+/// [`AsyncClient`] to it. When dropped, the client is disconnected first and the server is shut down
+/// afterwards. This also happens when the test panics.
+///
+/// ```
+/// # use open62541::{ua, TestHarness};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let harness = TestHarness::start(|server| {
+///     server.add_namespace("http://hmi-project.com/UA/");
+/// })?;
+///
+/// let value = harness
+///     .client()
+///     .read_value(&ua::NodeId::ns0_server_namespace_array())
+///     .await?;
+/// let namespaces = value.value().to_array::<ua::String>().unwrap();
+/// assert!(namespaces
+///     .iter()
+///     .any(|namespace| namespace.as_str() == Some("http://hmi-project.com/UA/")));
+///
+/// harness.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TestHarness {
+    server: Server,
+    client: Option<AsyncClient>,
+    port: u16,
+    runner_cancelled: Arc<AtomicBool>,
+    runner_handle: Option<JoinHandle<Result<()>>>,
+}
+
+impl TestHarness {
+    /// Starts server and connects client.
+    ///
+    /// `configure` is called with the server before it starts accepting connections. Use this to
+    /// add nodes that are required by the test.
+    ///
+    /// This blocks until the server is listening and the client is connected.
+    ///
+    /// # Errors
+    ///
+    /// This fails when no free port can be found, when the server cannot be started or when the
+    /// client cannot connect to it.
+    pub fn start(configure: impl FnOnce(&Server)) -> Result<Self> {
+        Self::start_with(
+            ServerBuilder::minimal(0, None),
+            ClientBuilder::default(),
+            configure,
+        )
+    }
+
+    /// Starts server and connects client, with custom options.
+    ///
+    /// This is like [`start()`](Self::start) but uses the given builders. Use this to test behavior
+    /// that depends on server or client configuration. The server port is always overwritten with
+    /// an ephemeral port.
+    ///
+    /// # Errors
+    ///
+    /// This fails when no free port can be found, when the server cannot be started or when the
+    /// client cannot connect to it.
+    pub fn start_with(
+        server_builder: ServerBuilder,
+        client_builder: ClientBuilder,
+        configure: impl FnOnce(&Server),
+    ) -> Result<Self> {
+        let port = ephemeral_port()?;
+        let (server, runner_cancelled, runner_handle) =
+            start_server(server_builder, port, configure)?;
+
+        let mut harness = Self {
+            server,
+            client: None,
+            port,
+            runner_cancelled,
+            runner_handle: Some(runner_handle),
+        };
+
+        harness.client = Some(
+            client_builder
+                .connect(&harness.endpoint_url())?
+                .into_async(),
+        );
+
+        Ok(harness)
+    }
+
+    /// Gets port that server is listening on.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Gets endpoint URL of server.
+    #[must_use]
+    pub fn endpoint_url(&self) -> String {
+        format!("opc.tcp://localhost:{}", self.port)
+    }
+
+    /// Gets server.
+    #[must_use]
+    pub const fn server(&self) -> &Server {
+        &self.server
+    }
+
+    /// Gets connected client.
+    ///
+    /// # Panics
+    ///
+    /// The client is only missing while starting and shutting down.
+    #[must_use]
+    pub fn client(&self) -> &AsyncClient {
+        self.client.as_ref().expect("client should be connected")
+    }
+
+    /// Disconnects client and shuts down server.
+    ///
+    /// This should be preferred over simply dropping the instance to disconnect the client without
+    /// blocking and to get errors from running the server.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the server has failed while running.
+    pub async fn shutdown(mut self) -> Result<()> {
+        if let Some(client) = self.client.take() {
+            client.disconnect().await;
+        }
+        self.stop_runner()
+    }
+
+    /// Restarts server on the same port.
+    ///
+    /// This shuts down the server and starts a new one from `server_builder`, like
+    /// [`start_with()`](Self::start_with). The client is kept: it loses its connection, and
+    /// connects to the new server again when [`ClientBuilder::auto_reconnect()`] is set.
+    ///
+    /// Note: This _blocks_ the current thread while waiting for the previous server to shut down.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the previous server has failed while running or when the new server cannot
+    /// be started.
+    pub fn restart_server(
+        &mut self,
+        server_builder: ServerBuilder,
+        configure: impl FnOnce(&Server),
+    ) -> Result<()> {
+        self.stop_runner()?;
+
+        let (server, runner_cancelled, runner_handle) =
+            start_server(server_builder, self.port, configure)?;
+        self.server = server;
+        self.runner_cancelled = runner_cancelled;
+        self.runner_handle = Some(runner_handle);
+
+        Ok(())
+    }
+
+    /// Shuts down server and waits for runner to finish.
+    ///
+    /// Note: This _blocks_ the current thread while waiting for the runner thread to finish.
+    fn stop_runner(&mut self) -> Result<()> {
+        let Some(runner_handle) = self.runner_handle.take() else {
+            return Ok(());
+        };
+
+        self.runner_cancelled.store(true, Ordering::Relaxed);
+
+        runner_handle
+            .join()
+            .unwrap_or_else(|_| Err(Error::internal("server runner panicked")))
+    }
+}
+
+impl Drop for TestHarness {
+    fn drop(&mut self) {
+        // Disconnect client before shutting down server. Otherwise, the client would try to
+        // reconnect to the server that is going away.
+        drop(self.client.take());
+
+        if let Err(error) = self.stop_runner() {
+            log::error!("Server runner failed: {error}");
+        }
+    }
+}
+
+/// Starts server on given port and runs it on a background thread.
+///
+/// This blocks until the server is listening.
+fn start_server(
+    server_builder: ServerBuilder,
+    port: u16,
+    configure: impl FnOnce(&Server),
+) -> Result<(Server, Arc<AtomicBool>, JoinHandle<Result<()>>)> {
+    let (server, runner) = server_builder.port(port).build();
+    configure(&server);
+
+    let runner_cancelled = Arc::new(AtomicBool::new(false));
+    let (ready_tx, ready_rx) = mpsc::channel();
+    let runner_handle = thread::spawn({
+        let cancelled = Arc::clone(&runner_cancelled);
+        move || {
+            runner.run_until_cancelled(&mut || {
+                // This is first called after startup, i.e. when the server is listening. The
+                // receiver may have been dropped by now, ignore errors.
+                let _unused = ready_tx.send(());
+                cancelled.load(Ordering::Relaxed)
+            })
+        }
+    });
+
+    if ready_rx.recv().is_err() {
+        // The runner has exited without becoming ready. Get the error from the runner itself.
+        return Err(runner_handle
+            .join()
+            .unwrap_or_else(|_| Err(Error::internal("server runner panicked")))
+            .err()
+            .unwrap_or(Error::internal("server should have started")));
+    }
+
+    Ok((server, runner_cancelled, runner_handle))
+}
+
+/// Finds port that is currently not in use.
+///
+/// The port is only reserved while probing it, so there is a small chance that another process
+/// takes it before the server is started.
+fn ephemeral_port() -> Result<u16> {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .and_then(|listener| listener.local_addr())
+        .map(|address| address.port())
+        .map_err(|_| Error::internal("should find free port"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, TestHarness};
+
+    #[tokio::test]
+    async fn start_and_shutdown() {
+        let harness = TestHarness::start(|server| {
+            server.add_namespace("http://hmi-project.com/UA/");
+        })
+        .expect("should start harness");
+
+        let value = harness
+            .client()
+            .read_value(&ua::NodeId::ns0_server_namespace_array())
+            .await
+            .expect("should read namespace array");
+        let namespaces = value.value().to_array::<ua::String>().unwrap();
+        assert!(namespaces
+            .iter()
+            .any(|namespace| namespace.as_str() == Some("http://hmi-project.com/UA/")));
+
+        harness.shutdown().await.expect("should shut down harness");
+    }
+
+    #[test]
+    fn drop_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            let _harness = TestHarness::start(|_| {}).expect("should start harness");
+            panic!("test panics");
+        });
+        assert!(result.is_err());
+    }
+}
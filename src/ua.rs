@@ -15,6 +15,7 @@ mod key_value_map;
 mod logger;
 mod monitored_item_id;
 mod node_class_mask;
+mod numeric_range;
 mod secure_channel_state;
 mod security_level;
 mod server;
@@ -31,13 +32,14 @@ pub use self::{
     array::Array,
     browse_result_mask::BrowseResultMask,
     certificate_verification::CertificateVerification,
-    client::{Client, ClientState},
+    client::{Client, ClientState, SecurityInfo, SessionInfo},
     continuation_point::ContinuationPoint,
     data_types::*,
     event_id::EventId,
     key_value_map::KeyValueMap,
     monitored_item_id::MonitoredItemId,
     node_class_mask::NodeClassMask,
+    numeric_range::NumericRange,
     secure_channel_state::SecureChannelState,
     security_level::SecurityLevel,
     server::Server,
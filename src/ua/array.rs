@@ -402,8 +402,10 @@ impl<T: DataType> Array<T> {
     ///
     /// After this, it is the responsibility of `dst` to eventually clean up the data.
     pub(crate) fn move_into_raw(self, dst_size: &mut usize, dst: &mut *mut T::Inner) {
-        // Make sure to clean up any previous value in target.
-        let _unused = Self::from_raw_parts(*dst_size, *dst);
+        // Make sure to clean up any previous value in target. We must take ownership here: merely
+        // copying the previous value would leak it when `dst` is overwritten below.
+        // SAFETY: The caller hands us `dst` by exclusive reference, we own its previous value.
+        let _unused = unsafe { Self::move_from_raw_parts(dst_size, dst) };
 
         let (size, ptr) = self.into_raw_parts();
         *dst_size = size;
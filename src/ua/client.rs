@@ -1,16 +1,24 @@
-use std::ptr::{self, NonNull};
+use std::{
+    ffi::c_void,
+    future::Future,
+    ptr::{self, NonNull},
+    time::Duration,
+};
 
+use futures_channel::oneshot;
 use open62541_sys::{
-    UA_Client, UA_Client_delete, UA_Client_disconnect, UA_Client_getState, UA_Client_new,
-    UA_Client_newWithConfig,
+    UA_Client, UA_Client_activateCurrentSessionAsync, UA_Client_addTimedCallback, UA_Client_delete,
+    UA_Client_disconnect, UA_Client_getConfig, UA_Client_getConnectionAttributeCopy,
+    UA_Client_getSessionAuthenticationToken, UA_Client_getState, UA_Client_new,
+    UA_Client_newWithConfig, __UA_Client_connect,
 };
 
-use crate::{ua, DataType as _, Error};
+use crate::{client::ClientContext, ua, DataType, Error, Userdata};
 
 /// Combined state for [`Client`] and [`AsyncClient`].
 ///
 /// [`AsyncClient`]: crate::AsyncClient
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ClientState {
     pub channel_state: ua::SecureChannelState,
     pub session_state: ua::SessionState,
@@ -20,6 +28,43 @@ pub struct ClientState {
     pub connect_status: ua::StatusCode,
 }
 
+/// Security settings of connection for [`Client`] and [`AsyncClient`].
+///
+/// This describes the secure channel that has actually been negotiated with the server.
+///
+/// Note: Details about the security token (such as its lifetime and renewals) and the certificate
+/// sent by the server are not exposed by `open62541` and are thus not available here.
+///
+/// [`AsyncClient`]: crate::AsyncClient
+#[derive(Debug)]
+pub struct SecurityInfo {
+    /// URI of security policy, e.g. `http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256`.
+    pub security_policy_uri: ua::String,
+    pub security_mode: ua::MessageSecurityMode,
+    pub server_description: ua::ApplicationDescription,
+}
+
+/// Session settings of connection for [`Client`] and [`AsyncClient`].
+///
+/// This describes the session that is currently established with the server. It is re-created when
+/// the client reconnects, so this should be fetched again after reconnecting.
+///
+/// Note: The session ID and the revised session timeout returned by the server are not stored by
+/// `open62541` and are thus not available here. The authentication token is deliberately omitted,
+/// it must be kept secret.
+///
+/// [`AsyncClient`]: crate::AsyncClient
+#[derive(Debug)]
+pub struct SessionInfo {
+    /// Endpoint URL that the client has been configured to connect to.
+    pub endpoint_url: ua::String,
+    /// Session timeout requested by the client. The server may have revised this value.
+    pub requested_session_timeout: Duration,
+    /// Whether the server sent a nonce when creating the session.
+    pub has_server_nonce: bool,
+    pub server_description: ua::ApplicationDescription,
+}
+
 /// Wrapper for [`UA_Client`] from [`open62541_sys`].
 ///
 /// This owns the wrapped data type. When the wrapper is dropped, its inner value is cleaned up with
@@ -96,6 +141,208 @@ impl Client {
         }
     }
 
+    /// Checks if session is currently activated.
+    ///
+    /// Unlike [`state()`](Self::state), this does not log and may be called repeatedly.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn is_session_activated(&self) -> bool {
+        let mut session_state = ua::SessionState::init();
+
+        unsafe {
+            UA_Client_getState(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                ptr::null_mut(),
+                session_state.as_mut_ptr(),
+                ptr::null_mut(),
+            );
+        }
+
+        session_state.is_activated()
+    }
+
+    /// Gets connect status.
+    ///
+    /// Unlike [`state()`](Self::state), this does not log and may be called repeatedly.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn connect_status(&self) -> ua::StatusCode {
+        let mut connect_status = ua::StatusCode::init();
+
+        unsafe {
+            UA_Client_getState(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                connect_status.as_mut_ptr(),
+            );
+        }
+
+        connect_status
+    }
+
+    /// Gets security settings of current connection.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn security_info(&self) -> Result<SecurityInfo, Error> {
+        log::debug!("Getting security info");
+
+        let security_policy_uri = self.connection_attribute("securityPolicyUri")?;
+        let security_mode = self.connection_attribute("securityMode")?;
+        let server_description = self.connection_attribute("serverDescription")?;
+
+        Ok(SecurityInfo {
+            security_policy_uri,
+            security_mode,
+            server_description,
+        })
+    }
+
+    /// Gets session settings of current connection.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn session_info(&self) -> Result<SessionInfo, Error> {
+        log::debug!("Getting session info");
+
+        // We only need the nonce. The token identifies the session and must not be given away.
+        let (_, server_nonce) = self.session_authentication_token()?;
+
+        let server_description = self.connection_attribute("serverDescription")?;
+
+        // SAFETY: Cast to `mut` pointer. We only read from the config. The fields read here are set
+        // before connecting and not changed by `open62541` afterwards.
+        let config = unsafe { UA_Client_getConfig(self.as_ptr().cast_mut()).as_ref() }
+            .ok_or(Error::internal("client config should be set"))?;
+        let endpoint_url = ua::String::raw_ref(&config.endpointUrl).clone();
+        let requested_session_timeout =
+            Duration::from_millis(u64::from(config.requestedSessionTimeout));
+
+        Ok(SessionInfo {
+            endpoint_url,
+            requested_session_timeout,
+            has_server_nonce: !server_nonce.is_invalid() && !server_nonce.is_empty(),
+            server_description,
+        })
+    }
+
+    /// Gets authentication token and server nonce of current session.
+    ///
+    /// The authentication token identifies the session. It must be kept secret and is only used to
+    /// tell whether the session has been replaced.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn session_authentication_token(
+        &self,
+    ) -> Result<(ua::NodeId, ua::ByteString), Error> {
+        let mut authentication_token = ua::NodeId::init();
+        let mut server_nonce = ua::ByteString::init();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_getSessionAuthenticationToken(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                authentication_token.as_mut_ptr(),
+                server_nonce.as_mut_ptr(),
+            )
+        });
+        Error::verify_good(&status_code)?;
+        Ok((authentication_token, server_nonce))
+    }
+
+    /// Activates current session again, with new user identity token.
+    ///
+    /// This replaces the user identity token in the client config and sends an `ActivateSession`
+    /// request. `open62541` does not expose the client lock, so both happen in a callback on the
+    /// client's event loop instead. This does not race with `open62541` reading the token when it
+    /// (re-)activates sessions, which also happens on the event loop.
+    ///
+    /// The returned future resolves when the request has been sent. It does not wait for the
+    /// response: the session state changes to activated when the server has accepted the request.
+    /// When the server rejects it, the previous token is restored by the client context.
+    ///
+    /// The request is not sent when the future is dropped before the callback has been run.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn activate_current_session_with(
+        &self,
+        user_identity_token: ua::ExtensionObject,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'static {
+        type ActivateTask = Box<dyn FnOnce(*mut UA_Client) + Send>;
+
+        unsafe extern "C" fn callback_c(client: *mut UA_Client, data: *mut c_void) {
+            // SAFETY: The timed callback is run exactly once, so we take back ownership here.
+            let task = unsafe { Userdata::<ActivateTask>::consume(data) };
+            task(client);
+        }
+
+        let (tx, rx) = oneshot::channel();
+
+        let task: ActivateTask = Box::new(move |client| {
+            if tx.is_canceled() {
+                // Caller has given up, do not change the identity behind its back.
+                return;
+            }
+            // SAFETY: We are running on the client's event loop.
+            let result = unsafe { activate_with_token_c(client, user_identity_token) };
+            // Ignore result. The receiver may have been dropped by now.
+            let _unused = tx.send(result);
+        });
+        let data = Userdata::<ActivateTask>::prepare(task);
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_addTimedCallback(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                Some(callback_c),
+                data,
+                // Run as soon as possible, i.e. in the next iteration of the event loop.
+                0,
+                ptr::null_mut(),
+            )
+        });
+        let result = Error::verify_good(&status_code).inspect_err(|_| {
+            // SAFETY: The callback has not been added, so we still own the data.
+            drop(unsafe { Userdata::<ActivateTask>::consume(data) });
+        });
+
+        async move {
+            result?;
+            rx.await
+                .unwrap_or_else(|_| Err(Error::internal("session should have been activated")))
+        }
+    }
+
+    /// Connects again, with the endpoint URL of the previous connection.
+    ///
+    /// This resets a bad connect status. It does not wait for the connection: the session state
+    /// changes to activated when the connection has been established by running the event loop.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn reconnect(&self) -> Result<(), Error> {
+        let status_code = ua::StatusCode::new(unsafe {
+            // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+            __UA_Client_connect(self.as_ptr().cast_mut(), true)
+        });
+        Error::verify_good(&status_code)
+    }
+
+    /// Gets connection attribute.
+    ///
+    /// See `UA_Client_getConnectionAttribute()` for available attributes and their types.
+    fn connection_attribute<T: DataType>(&self, key: &str) -> Result<T, Error> {
+        let key = ua::QualifiedName::ns0(key);
+        let mut value = ua::Variant::init();
+
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_Client_getConnectionAttributeCopy(
+                // SAFETY: Cast to `mut` pointer, function is marked `UA_THREADSAFE`.
+                self.as_ptr().cast_mut(),
+                // SAFETY: Function expects struct instead of pointer, despite not taking ownership.
+                ua::QualifiedName::to_raw_copy(&key),
+                value.as_mut_ptr(),
+            )
+        });
+        Error::verify_good(&status_code)?;
+
+        value
+            .to_scalar()
+            .ok_or(Error::internal("unexpected type of connection attribute"))
+    }
+
     /// Disconnects from endpoint.
     pub(crate) fn disconnect(mut self) {
         log::info!("Disconnecting from endpoint");
@@ -114,10 +361,27 @@ impl Drop for Client {
     fn drop(&mut self) {
         log::debug!("Deleting client");
 
+        // SAFETY: The client is deleted right below. Callbacks that use the context do nothing when
+        // it is missing, but we keep the context alive until the client is gone.
+        let context = unsafe { ClientContext::take(self.as_mut_ptr()) };
+        if let Some(context) = &context {
+            context.forget_previous_user_identity_token();
+        }
+
+        // Do not leave the password behind in memory that is released. The config is not needed to
+        // take down the connection.
+        if let Some(config) = unsafe { UA_Client_getConfig(self.as_mut_ptr()).as_mut() } {
+            ua::UserNameIdentityToken::zeroize_password_in(ua::ExtensionObject::raw_mut(
+                &mut config.userIdentityToken,
+            ));
+        }
+
         // `UA_Client_delete()` matches `UA_Client_new()`. This may block (!) whenever the client is
         // still connected, for as long as it takes to take down the connection. This can be avoided
         // by calling `disconnect()` instead of simply dropping the client.
         unsafe { UA_Client_delete(self.as_mut_ptr()) }
+
+        drop(context);
     }
 }
 
@@ -128,3 +392,30 @@ impl Default for Client {
         Self(inner)
     }
 }
+
+/// Replaces user identity token and activates current session again.
+///
+/// See [`Client::activate_current_session_with()`].
+///
+/// # Safety
+///
+/// The client must be valid. This must be called from the client's event loop.
+unsafe fn activate_with_token_c(
+    client: *mut UA_Client,
+    user_identity_token: ua::ExtensionObject,
+) -> Result<(), Error> {
+    // SAFETY: The client is valid for the duration of the call.
+    let context = unsafe { ClientContext::from_client(client) }
+        .ok_or(Error::internal("client context should be set"))?;
+
+    // SAFETY: We are running on the client's event loop.
+    unsafe { context.replace_user_identity_token(client, user_identity_token) }?;
+
+    let status_code = ua::StatusCode::new(unsafe { UA_Client_activateCurrentSessionAsync(client) });
+    Error::verify_good(&status_code).inspect_err(|_| {
+        // The server does not know about the new token. The state callback may have restored the
+        // previous token already, when the connect status has changed.
+        // SAFETY: We are running on the client's event loop.
+        unsafe { context.restore_user_identity_token(client) };
+    })
+}
@@ -2,7 +2,10 @@ use std::{fmt, mem::MaybeUninit};
 
 use open62541_sys::{UA_ClientConfig, UA_ClientConfig_clear, UA_ClientConfig_setDefault};
 
-use crate::{ua, Error};
+use crate::{ua, DataType as _, Error};
+
+/// Target of log messages from `open62541` client.
+const LOG_TARGET: &str = "open62541::client";
 
 pub(crate) struct ClientConfig(Option<UA_ClientConfig>);
 
@@ -11,7 +14,7 @@ impl ClientConfig {
     fn new() -> Self {
         let mut config = Self::init();
 
-        let logger = ua::Logger::rust_log();
+        let logger = ua::Logger::rust_log_with_target(LOG_TARGET);
 
         // Set custom logger first. This is necessary because the same logger instance is used as-is
         // inside derived attributes such as `eventLoop`, `certificateVerification`, etc.
@@ -77,6 +80,32 @@ impl ClientConfig {
         Ok(config)
     }
 
+    /// Creates default client config with encryption and certificate chain.
+    ///
+    /// See [`default_encryption()`](Self::default_encryption).
+    #[cfg(feature = "mbedtls")]
+    pub(crate) fn default_encryption_with_chain(
+        local_certificate: &crate::Certificate,
+        intermediate_certificates: &[crate::Certificate],
+        private_key: &crate::PrivateKey,
+    ) -> Result<Self, crate::Error> {
+        let mut config = Self::default_encryption(local_certificate, private_key)?;
+
+        {
+            let config = unsafe { config.as_mut() };
+            // SAFETY: The security policies have been set up by `default_encryption()`.
+            unsafe {
+                crate::ssl::append_intermediate_certificates(
+                    config.securityPolicies,
+                    config.securityPoliciesSize,
+                    intermediate_certificates,
+                )?;
+            }
+        }
+
+        Ok(config)
+    }
+
     /// Creates wrapper by taking ownership of value.
     ///
     /// When `Self` is dropped, allocations held by the inner type are cleaned up.
@@ -144,6 +173,10 @@ impl Drop for ClientConfig {
         // Check if we still hold the client config. If not, we need not clean up: the ownership has
         // passed to the client that was created from this config.
         if let Some(mut inner) = self.0.take() {
+            // Do not leave the password behind in memory that is released.
+            ua::UserNameIdentityToken::zeroize_password_in(ua::ExtensionObject::raw_mut(
+                &mut inner.userIdentityToken,
+            ));
             unsafe { UA_ClientConfig_clear(&mut inner) }
         }
     }
@@ -1,5 +1,9 @@
 //! Thin wrappers for OPC UA data types from [`open62541_sys`].
 
+mod add_references_item;
+mod add_references_request;
+mod add_references_response;
+mod aggregate_configuration;
 mod aggregate_filter;
 mod anonymous_identity_token;
 mod application_description;
@@ -22,6 +26,8 @@ mod call_method_request;
 mod call_method_result;
 mod call_request;
 mod call_response;
+mod cancel_request;
+mod cancel_response;
 mod content_filter;
 mod content_filter_element;
 mod create_monitored_items_request;
@@ -29,47 +35,112 @@ mod create_monitored_items_response;
 mod create_subscription_request;
 mod create_subscription_respones;
 mod data_change_filter;
+mod data_change_notification;
+mod data_change_trigger;
 mod data_value;
 mod date_time;
+mod deadband_type;
 mod delete_monitored_items_request;
 mod delete_monitored_items_response;
+mod delete_nodes_item;
+mod delete_nodes_request;
+mod delete_nodes_response;
+mod delete_raw_modified_details;
+mod delete_references_item;
+mod delete_references_request;
+mod delete_references_response;
 mod delete_subscriptions_request;
 mod delete_subscriptions_response;
+mod diagnostic_info;
 mod element_operand;
 mod endpoint_description;
+mod event_field_list;
 mod event_filter;
+mod event_notification_list;
 mod expanded_node_id;
 mod extension_object;
 mod filter_operator;
+mod find_servers_on_network_request;
+mod find_servers_on_network_response;
+mod find_servers_request;
+mod find_servers_response;
+mod history_data;
+mod history_read_request;
+mod history_read_response;
+mod history_read_result;
+mod history_read_value_id;
+mod history_update_request;
+mod history_update_response;
+mod history_update_result;
 mod literal_operand;
 mod localized_text;
 mod message_security_mode;
+mod modify_monitored_items_request;
+mod modify_monitored_items_response;
+mod modify_subscription_request;
+mod modify_subscription_response;
 mod monitored_item_create_request;
 mod monitored_item_create_result;
+mod monitored_item_modify_request;
+mod monitored_item_modify_result;
+mod monitored_item_notification;
 mod monitoring_mode;
 mod monitoring_parameters;
 mod node_attributes;
 mod node_class;
 mod node_id;
 mod node_id_type;
+mod notification_message;
+mod option_set;
+mod perform_update_type;
+mod publish_request;
+mod publish_response;
 mod qualified_name;
+mod read_processed_details;
+mod read_raw_modified_details;
 mod read_request;
 mod read_response;
 mod read_value_id;
 mod reference_description;
+mod register_nodes_request;
+mod register_nodes_response;
 mod relative_path;
 mod relative_path_element;
+mod request_header;
+mod response_header;
+mod server_diagnostics_summary_data_type;
+mod server_on_network;
+mod set_monitoring_mode_request;
+mod set_monitoring_mode_response;
+mod set_triggering_request;
+mod set_triggering_response;
 mod simple_attribute_operand;
+mod status_change_notification;
 mod status_code;
 mod string;
+mod subscription_acknowledgement;
 mod timestamps_to_return;
+mod transfer_result;
+mod transfer_subscriptions_request;
+mod transfer_subscriptions_response;
+mod unregister_nodes_request;
+mod unregister_nodes_response;
+mod update_data_details;
 mod user_name_identity_token;
+mod user_token_policy;
+mod user_token_type;
 mod variant;
+mod view_description;
 mod write_request;
 mod write_response;
 mod write_value;
+mod xml_element;
 
 pub use self::{
+    add_references_item::AddReferencesItem,
+    add_references_request::AddReferencesRequest,
+    add_references_response::AddReferencesResponse,
+    aggregate_configuration::AggregateConfiguration,
     aggregate_filter::AggregateFilter,
     anonymous_identity_token::AnonymousIdentityToken,
     application_description::ApplicationDescription,
@@ -92,6 +163,8 @@ pub use self::{
     call_method_result::CallMethodResult,
     call_request::CallRequest,
     call_response::CallResponse,
+    cancel_request::CancelRequest,
+    cancel_response::CancelResponse,
     content_filter::ContentFilter,
     content_filter_element::ContentFilterElement,
     create_monitored_items_request::CreateMonitoredItemsRequest,
@@ -99,23 +172,55 @@ pub use self::{
     create_subscription_request::CreateSubscriptionRequest,
     create_subscription_respones::CreateSubscriptionResponse,
     data_change_filter::DataChangeFilter,
+    data_change_notification::DataChangeNotification,
+    data_change_trigger::DataChangeTrigger,
     data_value::DataValue,
     date_time::DateTime,
+    deadband_type::DeadbandType,
     delete_monitored_items_request::DeleteMonitoredItemsRequest,
     delete_monitored_items_response::DeleteMonitoredItemsResponse,
+    delete_nodes_item::DeleteNodesItem,
+    delete_nodes_request::DeleteNodesRequest,
+    delete_nodes_response::DeleteNodesResponse,
+    delete_raw_modified_details::DeleteRawModifiedDetails,
+    delete_references_item::DeleteReferencesItem,
+    delete_references_request::DeleteReferencesRequest,
+    delete_references_response::DeleteReferencesResponse,
     delete_subscriptions_request::DeleteSubscriptionsRequest,
     delete_subscriptions_response::DeleteSubscriptionsResponse,
+    diagnostic_info::DiagnosticInfo,
     element_operand::ElementOperand,
     endpoint_description::EndpointDescription,
+    event_field_list::EventFieldList,
     event_filter::EventFilter,
+    event_notification_list::EventNotificationList,
     expanded_node_id::ExpandedNodeId,
     extension_object::ExtensionObject,
     filter_operator::FilterOperator,
+    find_servers_on_network_request::FindServersOnNetworkRequest,
+    find_servers_on_network_response::FindServersOnNetworkResponse,
+    find_servers_request::FindServersRequest,
+    find_servers_response::FindServersResponse,
+    history_data::HistoryData,
+    history_read_request::HistoryReadRequest,
+    history_read_response::HistoryReadResponse,
+    history_read_result::HistoryReadResult,
+    history_read_value_id::HistoryReadValueId,
+    history_update_request::HistoryUpdateRequest,
+    history_update_response::HistoryUpdateResponse,
+    history_update_result::HistoryUpdateResult,
     literal_operand::LiteralOperand,
     localized_text::LocalizedText,
     message_security_mode::MessageSecurityMode,
+    modify_monitored_items_request::ModifyMonitoredItemsRequest,
+    modify_monitored_items_response::ModifyMonitoredItemsResponse,
+    modify_subscription_request::ModifySubscriptionRequest,
+    modify_subscription_response::ModifySubscriptionResponse,
     monitored_item_create_request::MonitoredItemCreateRequest,
     monitored_item_create_result::MonitoredItemCreateResult,
+    monitored_item_modify_request::MonitoredItemModifyRequest,
+    monitored_item_modify_result::MonitoredItemModifyResult,
+    monitored_item_notification::MonitoredItemNotification,
     monitoring_mode::MonitoringMode,
     monitoring_parameters::MonitoringParameters,
     node_attributes::{
@@ -126,22 +231,51 @@ pub use self::{
     node_class::NodeClass,
     node_id::NodeId,
     node_id_type::NodeIdType,
+    notification_message::NotificationMessage,
+    option_set::OptionSet,
+    perform_update_type::PerformUpdateType,
+    publish_request::PublishRequest,
+    publish_response::PublishResponse,
     qualified_name::QualifiedName,
+    read_processed_details::ReadProcessedDetails,
+    read_raw_modified_details::ReadRawModifiedDetails,
     read_request::ReadRequest,
     read_response::ReadResponse,
     read_value_id::ReadValueId,
     reference_description::ReferenceDescription,
+    register_nodes_request::RegisterNodesRequest,
+    register_nodes_response::RegisterNodesResponse,
     relative_path::RelativePath,
     relative_path_element::RelativePathElement,
+    request_header::RequestHeader,
+    response_header::ResponseHeader,
+    server_diagnostics_summary_data_type::ServerDiagnosticsSummaryDataType,
+    server_on_network::ServerOnNetwork,
+    set_monitoring_mode_request::SetMonitoringModeRequest,
+    set_monitoring_mode_response::SetMonitoringModeResponse,
+    set_triggering_request::SetTriggeringRequest,
+    set_triggering_response::SetTriggeringResponse,
     simple_attribute_operand::SimpleAttributeOperand,
+    status_change_notification::StatusChangeNotification,
     status_code::StatusCode,
     string::String,
+    subscription_acknowledgement::SubscriptionAcknowledgement,
     timestamps_to_return::TimestampsToReturn,
+    transfer_result::TransferResult,
+    transfer_subscriptions_request::TransferSubscriptionsRequest,
+    transfer_subscriptions_response::TransferSubscriptionsResponse,
+    unregister_nodes_request::UnregisterNodesRequest,
+    unregister_nodes_response::UnregisterNodesResponse,
+    update_data_details::UpdateDataDetails,
     user_name_identity_token::UserNameIdentityToken,
+    user_token_policy::UserTokenPolicy,
+    user_token_type::UserTokenType,
     variant::Variant,
+    view_description::ViewDescription,
     write_request::WriteRequest,
     write_response::WriteResponse,
     write_value::WriteValue,
+    xml_element::XmlElement,
 };
 
 macro_rules! primitive {
@@ -161,6 +295,20 @@ macro_rules! primitive {
                 }
             }
 
+            impl From<$type> for $crate::ua::Variant {
+                fn from(value: $type) -> Self {
+                    Self::scalar($name::new(value))
+                }
+            }
+
+            impl From<Vec<$type>> for $crate::ua::Variant {
+                fn from(values: Vec<$type>) -> Self {
+                    Self::array($crate::ua::Array::from_iter(
+                        values.into_iter().map($name::new),
+                    ))
+                }
+            }
+
             #[cfg(feature = "serde")]
             impl serde::Serialize for $name {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
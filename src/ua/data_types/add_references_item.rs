@@ -0,0 +1,77 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(AddReferencesItem);
+
+impl AddReferencesItem {
+    #[must_use]
+    pub fn with_source_node_id(mut self, source_node_id: &ua::NodeId) -> Self {
+        source_node_id.clone_into_raw(&mut self.0.sourceNodeId);
+        self
+    }
+
+    #[must_use]
+    pub fn with_reference_type_id(mut self, reference_type_id: &ua::NodeId) -> Self {
+        reference_type_id.clone_into_raw(&mut self.0.referenceTypeId);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_is_forward(mut self, is_forward: bool) -> Self {
+        self.0.isForward = is_forward;
+        self
+    }
+
+    /// Sets URI of server that holds the target node.
+    ///
+    /// This is only needed when the target node is on a different server.
+    #[must_use]
+    pub fn with_target_server_uri(mut self, target_server_uri: &ua::String) -> Self {
+        target_server_uri.clone_into_raw(&mut self.0.targetServerUri);
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_node_id(mut self, target_node_id: &ua::ExpandedNodeId) -> Self {
+        target_node_id.clone_into_raw(&mut self.0.targetNodeId);
+        self
+    }
+
+    /// Sets node class of target node.
+    ///
+    /// Servers may check this against the node class of the target node.
+    #[must_use]
+    pub fn with_target_node_class(mut self, target_node_class: &ua::NodeClass) -> Self {
+        target_node_class.clone_into_raw(&mut self.0.targetNodeClass);
+        self
+    }
+
+    #[must_use]
+    pub fn source_node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.sourceNodeId)
+    }
+
+    #[must_use]
+    pub fn reference_type_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.referenceTypeId)
+    }
+
+    #[must_use]
+    pub const fn is_forward(&self) -> bool {
+        self.0.isForward
+    }
+
+    #[must_use]
+    pub fn target_server_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.targetServerUri)
+    }
+
+    #[must_use]
+    pub fn target_node_id(&self) -> &ua::ExpandedNodeId {
+        ua::ExpandedNodeId::raw_ref(&self.0.targetNodeId)
+    }
+
+    #[must_use]
+    pub fn target_node_class(&self) -> &ua::NodeClass {
+        ua::NodeClass::raw_ref(&self.0.targetNodeClass)
+    }
+}
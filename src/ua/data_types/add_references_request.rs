@@ -0,0 +1,16 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(AddReferencesRequest);
+
+impl AddReferencesRequest {
+    #[must_use]
+    pub fn with_references_to_add(mut self, references_to_add: &[ua::AddReferencesItem]) -> Self {
+        let array = ua::Array::from_slice(references_to_add);
+        array.move_into_raw(&mut self.0.referencesToAddSize, &mut self.0.referencesToAdd);
+        self
+    }
+}
+
+impl ServiceRequest for AddReferencesRequest {
+    type Response = ua::AddReferencesResponse;
+}
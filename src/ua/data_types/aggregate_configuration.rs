@@ -0,0 +1,43 @@
+crate::data_type!(AggregateConfiguration);
+
+impl AggregateConfiguration {
+    /// Sets whether to use the server's default configuration for aggregates.
+    ///
+    /// When `true`, the other settings are ignored.
+    #[must_use]
+    pub const fn with_use_server_capabilities_defaults(
+        mut self,
+        use_server_capabilities_defaults: bool,
+    ) -> Self {
+        self.0.useServerCapabilitiesDefaults = use_server_capabilities_defaults;
+        self
+    }
+
+    /// Sets whether values with uncertain status are treated as bad values.
+    #[must_use]
+    pub const fn with_treat_uncertain_as_bad(mut self, treat_uncertain_as_bad: bool) -> Self {
+        self.0.treatUncertainAsBad = treat_uncertain_as_bad;
+        self
+    }
+
+    /// Sets minimum percentage of bad data that makes an interval bad.
+    #[must_use]
+    pub const fn with_percent_data_bad(mut self, percent_data_bad: u8) -> Self {
+        self.0.percentDataBad = percent_data_bad;
+        self
+    }
+
+    /// Sets minimum percentage of good data that makes an interval good.
+    #[must_use]
+    pub const fn with_percent_data_good(mut self, percent_data_good: u8) -> Self {
+        self.0.percentDataGood = percent_data_good;
+        self
+    }
+
+    /// Sets whether to use sloped extrapolation instead of stepped extrapolation.
+    #[must_use]
+    pub const fn with_use_sloped_extrapolation(mut self, use_sloped_extrapolation: bool) -> Self {
+        self.0.useSlopedExtrapolation = use_sloped_extrapolation;
+        self
+    }
+}
@@ -1,5 +1,7 @@
 use std::hash;
 
+use open62541_sys::UA_AttributeId;
+
 crate::data_type!(AttributeId, UInt32);
 
 crate::enum_variants!(
@@ -36,6 +38,15 @@ crate::enum_variants!(
     ],
 );
 
+impl AttributeId {
+    pub(crate) fn from_u32(attribute_id: u32) -> Self {
+        // This conversion is necessary on Windows builds with inner type `i32`.
+        #[allow(clippy::useless_conversion)]
+        let attribute_id = attribute_id.try_into().expect("should convert from u32");
+        Self(UA_AttributeId(attribute_id))
+    }
+}
+
 impl hash::Hash for AttributeId {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         self.0.hash(state);
@@ -1,5 +1,3 @@
-use open62541_sys::UA_NS0ID_HIERARCHICALREFERENCES;
-
 use crate::{ua, DataType};
 
 crate::data_type!(BrowseDescription);
@@ -41,18 +39,42 @@ impl BrowseDescription {
         self
     }
 
-    #[allow(dead_code)] // --no-default-features
     #[must_use]
-    pub(crate) fn node_id(&self) -> &ua::NodeId {
+    pub fn node_id(&self) -> &ua::NodeId {
         ua::NodeId::raw_ref(&self.0.nodeId)
     }
+
+    #[must_use]
+    pub fn browse_direction(&self) -> &ua::BrowseDirection {
+        ua::BrowseDirection::raw_ref(&self.0.browseDirection)
+    }
+
+    #[must_use]
+    pub fn reference_type_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.referenceTypeId)
+    }
+
+    #[must_use]
+    pub const fn include_subtypes(&self) -> bool {
+        self.0.includeSubtypes
+    }
+
+    #[must_use]
+    pub const fn node_class_mask(&self) -> ua::NodeClassMask {
+        ua::NodeClassMask::from_u32(self.0.nodeClassMask)
+    }
+
+    #[must_use]
+    pub const fn result_mask(&self) -> ua::BrowseResultMask {
+        ua::BrowseResultMask::from_u32(self.0.resultMask)
+    }
 }
 
 impl Default for BrowseDescription {
     fn default() -> Self {
         Self::init()
             .with_browse_direction(&ua::BrowseDirection::FORWARD)
-            .with_reference_type_id(&ua::NodeId::numeric(0, UA_NS0ID_HIERARCHICALREFERENCES))
+            .with_reference_type_id(&ua::NodeId::ns0_hierarchical_references())
             .with_include_subtypes(true)
             .with_result_mask(&ua::BrowseResultMask::ALL)
     }
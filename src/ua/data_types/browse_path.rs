@@ -3,6 +3,21 @@ use crate::{ua, DataType as _};
 crate::data_type!(BrowsePath);
 
 impl BrowsePath {
+    /// Creates browse path from starting node and path elements.
+    ///
+    /// See [`ua::RelativePathElement::hierarchical()`] and related methods for creating elements.
+    #[must_use]
+    pub fn from_segments(
+        starting_node: &ua::NodeId,
+        elements: impl IntoIterator<Item = ua::RelativePathElement>,
+    ) -> Self {
+        let elements: Vec<_> = elements.into_iter().collect();
+
+        Self::init()
+            .with_starting_node(starting_node)
+            .with_relative_path(&ua::RelativePath::init().with_elements(&elements))
+    }
+
     #[must_use]
     pub fn with_starting_node(mut self, node_id: &ua::NodeId) -> Self {
         node_id.clone_into_raw(&mut self.0.startingNode);
@@ -14,4 +29,14 @@ impl BrowsePath {
         relative_path.clone_into_raw(&mut self.0.relativePath);
         self
     }
+
+    #[must_use]
+    pub fn starting_node(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.startingNode)
+    }
+
+    #[must_use]
+    pub fn relative_path(&self) -> &ua::RelativePath {
+        ua::RelativePath::raw_ref(&self.0.relativePath)
+    }
 }
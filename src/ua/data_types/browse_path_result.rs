@@ -1,4 +1,4 @@
-use crate::ua;
+use crate::{ua, BrowsePathError, Error, Result};
 
 crate::data_type!(BrowsePathResult);
 
@@ -13,4 +13,43 @@ impl BrowsePathResult {
         // TODO: Adjust signature to return non-owned value instead.
         ua::Array::from_raw_parts(self.0.targetsSize, self.0.targets)
     }
+
+    /// Gets node ID that the browse path was fully resolved to.
+    ///
+    /// The browse path must be the one that was translated into this result. When there are several
+    /// targets, the first complete target on the local server is returned.
+    ///
+    /// # Errors
+    ///
+    /// This fails with the status code of the result when the translation was not successful. When
+    /// the path was resolved only partially (e.g. because it continues on another server), this
+    /// fails with [`Error::BrowsePath`] carrying the remaining path.
+    pub fn to_resolved_node_id(&self, browse_path: &ua::BrowsePath) -> Result<ua::NodeId> {
+        let status_code = self.status_code();
+        if status_code.is_bad() {
+            return Err(Error::new(status_code));
+        }
+
+        let targets = self
+            .targets()
+            .ok_or(Error::internal("translation should return targets"))?;
+
+        if let Some(node_id) = targets
+            .iter()
+            .filter(|target| target.is_complete())
+            .find_map(ua::BrowsePathTarget::resolved_node_id)
+        {
+            return Ok(node_id.clone());
+        }
+
+        let Some(target) = targets.as_slice().first() else {
+            return Err(Error::internal("translation should return targets"));
+        };
+
+        Err(Error::BrowsePath(Box::new(BrowsePathError::new(
+            status_code,
+            target.target_id().clone(),
+            target.remaining_path(browse_path),
+        ))))
+    }
 }
@@ -31,4 +31,43 @@ impl BrowsePathTarget {
                 .expect("remaining path index should be in range of usize"),
         )
     }
+
+    /// Checks if all elements of the [`ua::RelativePath`] were processed.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.remaining_path_index().is_none()
+    }
+
+    /// Gets target node ID when it is local to the server.
+    ///
+    /// This returns `None` when the target lives on another server, i.e. when the target ID has a
+    /// server index or namespace URI. Use [`is_complete()`](Self::is_complete) to check that the
+    /// target is the end of the path.
+    #[must_use]
+    pub fn resolved_node_id(&self) -> Option<&ua::NodeId> {
+        let target_id = self.target_id();
+        if target_id.server_index() != 0 || !target_id.namespace_uri().is_empty() {
+            return None;
+        }
+        Some(target_id.node_id())
+    }
+
+    /// Gets target names of elements that remain to be processed.
+    ///
+    /// The browse path must be the one that was translated into this target. Returns an empty list
+    /// when the target is [complete](Self::is_complete).
+    #[must_use]
+    pub fn remaining_path(&self, browse_path: &ua::BrowsePath) -> Vec<ua::QualifiedName> {
+        let Some(remaining_path_index) = self.remaining_path_index() else {
+            return Vec::new();
+        };
+        browse_path
+            .relative_path()
+            .elements()
+            .unwrap_or_default()
+            .iter()
+            .skip(remaining_path_index)
+            .map(|element| element.target_name().clone())
+            .collect()
+    }
 }
@@ -1,8 +1,17 @@
-use crate::{ua, ServiceRequest};
+use crate::{ua, DataType as _, ServiceRequest};
 
 crate::data_type!(BrowseRequest);
 
 impl BrowseRequest {
+    /// Sets view to restrict browsing to.
+    ///
+    /// By default, the entire address space is browsed.
+    #[must_use]
+    pub fn with_view(mut self, view: &ua::ViewDescription) -> Self {
+        view.clone_into_raw(&mut self.0.view);
+        self
+    }
+
     #[must_use]
     pub fn with_nodes_to_browse(mut self, nodes_to_browse: &[ua::BrowseDescription]) -> Self {
         let array = ua::Array::from_slice(nodes_to_browse);
@@ -8,6 +8,17 @@ impl BrowseResponse {
         // TODO: Adjust signature to return non-owned value instead.
         ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
     }
+
+    /// Gets diagnostic infos of individual results.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+        }
+    }
 }
 
 impl ServiceResponse for BrowseResponse {
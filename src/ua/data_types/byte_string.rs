@@ -38,8 +38,7 @@ impl ByteString {
         dst
     }
 
-    #[allow(dead_code)] // --no-default-features
-    fn clear(&mut self) {
+    pub(crate) fn clear(&mut self) {
         unsafe {
             // Clearing frees the referenced heap memory and resets length and data pointer to all
             // zeroes, i.e. the string becomes an "invalid" string (as defined by OPC UA).
@@ -47,8 +46,7 @@ impl ByteString {
         }
     }
 
-    #[allow(dead_code)] // --no-default-features
-    fn mem_zero(&mut self) {
+    pub(crate) fn mem_zero(&mut self) {
         unsafe {
             // This zeroizes the string contents, i.e. characters, leaving the string object itself
             // intact. The string has the same length as before but is all `\0`.
@@ -21,4 +21,19 @@ impl CallMethodRequest {
         array.move_into_raw(&mut self.0.inputArgumentsSize, &mut self.0.inputArguments);
         self
     }
+
+    #[must_use]
+    pub fn object_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.objectId)
+    }
+
+    #[must_use]
+    pub fn method_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.methodId)
+    }
+
+    #[must_use]
+    pub fn input_arguments(&self) -> Option<&[ua::Variant]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.inputArgumentsSize, self.0.inputArguments) }
+    }
 }
@@ -1,8 +1,21 @@
-use crate::ua;
+use crate::{ua, DataType as _};
 
 crate::data_type!(CallMethodResult);
 
 impl CallMethodResult {
+    #[must_use]
+    pub fn with_status_code(mut self, status_code: ua::StatusCode) -> Self {
+        status_code.move_into_raw(&mut self.0.statusCode);
+        self
+    }
+
+    #[must_use]
+    pub fn with_output_arguments(mut self, output_arguments: &[ua::Variant]) -> Self {
+        let array = ua::Array::from_slice(output_arguments);
+        array.move_into_raw(&mut self.0.outputArgumentsSize, &mut self.0.outputArguments);
+        self
+    }
+
     #[must_use]
     pub const fn status_code(&self) -> ua::StatusCode {
         ua::StatusCode::new(self.0.statusCode)
@@ -0,0 +1,16 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(CancelRequest);
+
+impl CancelRequest {
+    /// Sets request handle of requests to cancel.
+    #[must_use]
+    pub const fn with_request_handle(mut self, request_handle: u32) -> Self {
+        self.0.requestHandle = request_handle;
+        self
+    }
+}
+
+impl ServiceRequest for CancelRequest {
+    type Response = ua::CancelResponse;
+}
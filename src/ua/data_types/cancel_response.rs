@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(CancelResponse);
+
+impl CancelResponse {
+    /// Gets number of requests that have been cancelled.
+    #[must_use]
+    pub const fn cancel_count(&self) -> u32 {
+        self.0.cancelCount
+    }
+}
+
+impl ServiceResponse for CancelResponse {
+    type Request = ua::CancelRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
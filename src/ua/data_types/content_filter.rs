@@ -1,8 +1,26 @@
-use crate::ua;
+use crate::{ua, DataType as _};
 
 crate::data_type!(ContentFilter);
 
 impl ContentFilter {
+    /// Creates filter that matches events of the given type, including its subtypes.
+    ///
+    /// This is a single `OfType` element, e.g. for the where clause of [`ua::EventFilter`].
+    #[must_use]
+    pub fn of_type(type_definition_id: &ua::NodeId) -> Self {
+        let element = ua::ContentFilterElement::init()
+            .with_filter_operator(ua::FilterOperator::OFTYPE)
+            .with_filter_operands(&[ua::LiteralOperand::new(ua::Variant::scalar(
+                type_definition_id.clone(),
+            ))]);
+        Self::init().with_elements(&[element])
+    }
+
+    #[must_use]
+    pub fn elements(&self) -> Option<&[ua::ContentFilterElement]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.elementsSize, self.0.elements) }
+    }
+
     #[must_use]
     pub fn with_elements(mut self, elements: &[ua::ContentFilterElement]) -> Self {
         let array = ua::Array::from_slice(elements);
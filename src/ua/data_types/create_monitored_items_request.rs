@@ -1,4 +1,4 @@
-use crate::ua;
+use crate::{ua, DataType as _};
 
 crate::data_type!(CreateMonitoredItemsRequest);
 
@@ -9,6 +9,15 @@ impl CreateMonitoredItemsRequest {
         self
     }
 
+    #[must_use]
+    pub fn with_timestamps_to_return(
+        mut self,
+        timestamps_to_return: &ua::TimestampsToReturn,
+    ) -> Self {
+        timestamps_to_return.clone_into_raw(&mut self.0.timestampsToReturn);
+        self
+    }
+
     #[must_use]
     pub fn with_items_to_create(
         mut self,
@@ -24,4 +33,10 @@ impl CreateMonitoredItemsRequest {
     pub(crate) fn items_to_create(&self) -> Option<&[ua::MonitoredItemCreateRequest]> {
         unsafe { ua::Array::slice_from_raw_parts(self.0.itemsToCreateSize, self.0.itemsToCreate) }
     }
+
+    #[allow(dead_code)] // --no-default-features
+    #[must_use]
+    pub(crate) fn timestamps_to_return(&self) -> &ua::TimestampsToReturn {
+        ua::TimestampsToReturn::raw_ref(&self.0.timestampsToReturn)
+    }
 }
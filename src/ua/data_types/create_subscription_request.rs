@@ -23,6 +23,22 @@ impl CreateSubscriptionRequest {
         self
     }
 
+    /// Gets requested publishing interval.
+    ///
+    /// Returns `None` when the server shall revise with the fastest supported publishing interval.
+    #[must_use]
+    pub fn requested_publishing_interval(&self) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.0.requestedPublishingInterval / 1e3)
+            .ok()
+            .filter(|requested_publishing_interval| !requested_publishing_interval.is_zero())
+    }
+
+    /// Gets requested lifetime count.
+    #[must_use]
+    pub const fn requested_lifetime_count(&self) -> u32 {
+        self.0.requestedLifetimeCount
+    }
+
     /// Sets requested lifetime count.
     #[must_use]
     pub const fn with_requested_lifetime_count(mut self, requested_lifetime_count: u32) -> Self {
@@ -46,6 +62,14 @@ impl CreateSubscriptionRequest {
         self
     }
 
+    /// Gets requested maximum keep-alive count.
+    ///
+    /// Returns `None` when the server shall revise with the smallest supported keep-alive count.
+    #[must_use]
+    pub const fn requested_max_keep_alive_count(&self) -> Option<NonZeroU32> {
+        NonZeroU32::new(self.0.requestedMaxKeepAliveCount)
+    }
+
     /// Sets maximum number of notifications that the client wishes to receive in a single publish
     /// response.
     ///
@@ -61,6 +85,14 @@ impl CreateSubscriptionRequest {
         self
     }
 
+    /// Gets maximum number of notifications per publish response.
+    ///
+    /// Returns `None` when there is no limit.
+    #[must_use]
+    pub const fn max_notifications_per_publish(&self) -> Option<NonZeroU32> {
+        NonZeroU32::new(self.0.maxNotificationsPerPublish)
+    }
+
     /// Enables or disables publishing.
     #[must_use]
     pub const fn with_publishing_enabled(mut self, publishing_enabled: bool) -> Self {
@@ -68,12 +100,24 @@ impl CreateSubscriptionRequest {
         self
     }
 
+    /// Gets whether publishing is enabled.
+    #[must_use]
+    pub const fn publishing_enabled(&self) -> bool {
+        self.0.publishingEnabled
+    }
+
     /// Sets relative priority of the subscription.
     #[must_use]
     pub const fn with_priority(mut self, priority: u8) -> Self {
         self.0.priority = priority;
         self
     }
+
+    /// Gets relative priority of the subscription.
+    #[must_use]
+    pub const fn priority(&self) -> u8 {
+        self.0.priority
+    }
 }
 
 impl Default for CreateSubscriptionRequest {
@@ -1,7 +1,59 @@
-use crate::{ua, MonitoringFilter};
+use crate::{ua, DataType as _, MonitoringFilter};
 
 crate::data_type!(DataChangeFilter);
 
+impl DataChangeFilter {
+    /// Gets condition that triggers data change notifications.
+    #[must_use]
+    pub fn trigger(&self) -> &ua::DataChangeTrigger {
+        ua::DataChangeTrigger::raw_ref(&self.0.trigger)
+    }
+
+    /// Sets condition that triggers data change notifications.
+    ///
+    /// This defaults to [`ua::DataChangeTrigger::STATUS`]. Use
+    /// [`ua::DataChangeTrigger::STATUSVALUE`] to be notified of value changes.
+    #[must_use]
+    pub fn with_trigger(mut self, trigger: &ua::DataChangeTrigger) -> Self {
+        trigger.clone_into_raw(&mut self.0.trigger);
+        self
+    }
+
+    /// Gets deadband value.
+    ///
+    /// The value is interpreted according to the deadband type, see
+    /// [`with_deadband_absolute()`](Self::with_deadband_absolute) and
+    /// [`with_deadband_percent()`](Self::with_deadband_percent).
+    #[must_use]
+    pub const fn deadband_value(&self) -> f64 {
+        self.0.deadbandValue
+    }
+
+    /// Sets absolute deadband.
+    ///
+    /// Value changes are only reported when the value differs from the last reported value by more
+    /// than `deadband`. This applies to numeric values, and to each element of numeric arrays.
+    #[must_use]
+    pub const fn with_deadband_absolute(mut self, deadband: f64) -> Self {
+        self.0.deadbandType = ua::DeadbandType::ABSOLUTE_U32;
+        self.0.deadbandValue = deadband;
+        self
+    }
+
+    /// Sets percent deadband.
+    ///
+    /// Value changes are only reported when the value differs from the last reported value by more
+    /// than `deadband` percent (0.0 to 100.0) of the node's `EURange`. Servers that do not support
+    /// this reject the monitored item with [`ua::StatusCode::BADFILTERNOTALLOWED`] or
+    /// [`ua::StatusCode::BADMONITOREDITEMFILTERUNSUPPORTED`].
+    #[must_use]
+    pub const fn with_deadband_percent(mut self, deadband: f64) -> Self {
+        self.0.deadbandType = ua::DeadbandType::PERCENT_U32;
+        self.0.deadbandValue = deadband;
+        self
+    }
+}
+
 impl MonitoringFilter for DataChangeFilter {
     fn to_extension_object(&self) -> ua::ExtensionObject {
         ua::ExtensionObject::new(self)
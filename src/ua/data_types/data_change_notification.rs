@@ -0,0 +1,10 @@
+use crate::ua;
+
+crate::data_type!(DataChangeNotification);
+
+impl DataChangeNotification {
+    #[must_use]
+    pub fn monitored_items(&self) -> Option<&[ua::MonitoredItemNotification]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.monitoredItemsSize, self.0.monitoredItems) }
+    }
+}
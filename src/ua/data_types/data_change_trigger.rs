@@ -0,0 +1,7 @@
+crate::data_type!(DataChangeTrigger);
+
+crate::enum_variants!(
+    DataChangeTrigger,
+    UA_DataChangeTrigger,
+    [STATUS, STATUSVALUE, STATUSVALUETIMESTAMP]
+);
@@ -51,6 +51,17 @@ impl DataValue {
         self
     }
 
+    /// Removes server timestamp.
+    ///
+    /// This also removes the server picoseconds. Use this before writing values that have been
+    /// received from another server, to have the local server set its own timestamp.
+    #[must_use]
+    pub fn without_server_timestamp(mut self) -> Self {
+        self.0.set_hasServerTimestamp(false);
+        self.0.set_hasServerPicoseconds(false);
+        self
+    }
+
     #[must_use]
     pub fn with_status(mut self, status: &ua::StatusCode) -> Self {
         status.clone_into_raw(&mut self.0.status);
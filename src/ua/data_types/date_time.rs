@@ -1,6 +1,28 @@
+use std::time::{Duration, SystemTime};
+
 crate::data_type!(DateTime);
 
 impl DateTime {
+    /// Gets time that has elapsed since this date/time.
+    ///
+    /// This compares against the local system clock. Returns `None` when the date/time lies in the
+    /// future.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn elapsed(&self) -> Option<Duration> {
+        use open62541_sys::{UA_DATETIME_UNIX_EPOCH, UA_DATETIME_USEC};
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()?;
+        let nanos_unix = i128::try_from(now.as_nanos()).ok()?;
+        let ticks_now =
+            nanos_unix / i128::from(1000 / UA_DATETIME_USEC) + i128::from(UA_DATETIME_UNIX_EPOCH);
+
+        let ticks_elapsed = ticks_now - i128::from(self.0);
+        let nanos_elapsed = ticks_elapsed * i128::from(1000 / UA_DATETIME_USEC);
+        u64::try_from(nanos_elapsed).ok().map(Duration::from_nanos)
+    }
+
     #[cfg(feature = "time")]
     #[must_use]
     pub fn to_utc(&self) -> Option<time::OffsetDateTime> {
@@ -0,0 +1,28 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DeleteNodesItem);
+
+impl DeleteNodesItem {
+    #[must_use]
+    pub fn with_node_id(mut self, node_id: &ua::NodeId) -> Self {
+        node_id.clone_into_raw(&mut self.0.nodeId);
+        self
+    }
+
+    /// Sets whether references that point to the node from other nodes are deleted too.
+    #[must_use]
+    pub const fn with_delete_target_references(mut self, delete_target_references: bool) -> Self {
+        self.0.deleteTargetReferences = delete_target_references;
+        self
+    }
+
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.nodeId)
+    }
+
+    #[must_use]
+    pub const fn delete_target_references(&self) -> bool {
+        self.0.deleteTargetReferences
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(DeleteNodesRequest);
+
+impl DeleteNodesRequest {
+    #[must_use]
+    pub fn with_nodes_to_delete(mut self, nodes_to_delete: &[ua::DeleteNodesItem]) -> Self {
+        let array = ua::Array::from_slice(nodes_to_delete);
+        array.move_into_raw(&mut self.0.nodesToDeleteSize, &mut self.0.nodesToDelete);
+        self
+    }
+}
+
+impl ServiceRequest for DeleteNodesRequest {
+    type Response = ua::DeleteNodesResponse;
+}
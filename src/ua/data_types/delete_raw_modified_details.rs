@@ -0,0 +1,30 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DeleteRawModifiedDetails);
+
+impl DeleteRawModifiedDetails {
+    #[must_use]
+    pub fn with_node_id(mut self, node_id: &ua::NodeId) -> Self {
+        node_id.clone_into_raw(&mut self.0.nodeId);
+        self
+    }
+
+    /// Sets whether to delete modified values instead of raw values.
+    #[must_use]
+    pub const fn with_is_delete_modified(mut self, is_delete_modified: bool) -> Self {
+        self.0.isDeleteModified = is_delete_modified;
+        self
+    }
+
+    #[must_use]
+    pub fn with_start_time(mut self, start_time: &ua::DateTime) -> Self {
+        start_time.clone_into_raw(&mut self.0.startTime);
+        self
+    }
+
+    #[must_use]
+    pub fn with_end_time(mut self, end_time: &ua::DateTime) -> Self {
+        end_time.clone_into_raw(&mut self.0.endTime);
+        self
+    }
+}
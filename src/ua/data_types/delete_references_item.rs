@@ -0,0 +1,61 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DeleteReferencesItem);
+
+impl DeleteReferencesItem {
+    #[must_use]
+    pub fn with_source_node_id(mut self, source_node_id: &ua::NodeId) -> Self {
+        source_node_id.clone_into_raw(&mut self.0.sourceNodeId);
+        self
+    }
+
+    #[must_use]
+    pub fn with_reference_type_id(mut self, reference_type_id: &ua::NodeId) -> Self {
+        reference_type_id.clone_into_raw(&mut self.0.referenceTypeId);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_is_forward(mut self, is_forward: bool) -> Self {
+        self.0.isForward = is_forward;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_node_id(mut self, target_node_id: &ua::ExpandedNodeId) -> Self {
+        target_node_id.clone_into_raw(&mut self.0.targetNodeId);
+        self
+    }
+
+    /// Sets whether the opposite reference from the target node is deleted too.
+    #[must_use]
+    pub const fn with_delete_bidirectional(mut self, delete_bidirectional: bool) -> Self {
+        self.0.deleteBidirectional = delete_bidirectional;
+        self
+    }
+
+    #[must_use]
+    pub fn source_node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.sourceNodeId)
+    }
+
+    #[must_use]
+    pub fn reference_type_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.referenceTypeId)
+    }
+
+    #[must_use]
+    pub const fn is_forward(&self) -> bool {
+        self.0.isForward
+    }
+
+    #[must_use]
+    pub fn target_node_id(&self) -> &ua::ExpandedNodeId {
+        ua::ExpandedNodeId::raw_ref(&self.0.targetNodeId)
+    }
+
+    #[must_use]
+    pub const fn delete_bidirectional(&self) -> bool {
+        self.0.deleteBidirectional
+    }
+}
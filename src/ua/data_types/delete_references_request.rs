@@ -0,0 +1,22 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(DeleteReferencesRequest);
+
+impl DeleteReferencesRequest {
+    #[must_use]
+    pub fn with_references_to_delete(
+        mut self,
+        references_to_delete: &[ua::DeleteReferencesItem],
+    ) -> Self {
+        let array = ua::Array::from_slice(references_to_delete);
+        array.move_into_raw(
+            &mut self.0.referencesToDeleteSize,
+            &mut self.0.referencesToDelete,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for DeleteReferencesRequest {
+    type Response = ua::DeleteReferencesResponse;
+}
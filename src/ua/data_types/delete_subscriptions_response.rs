@@ -1 +1,31 @@
+use crate::{ua, DataType as _};
+
 crate::data_type!(DeleteSubscriptionsResponse);
+
+impl DeleteSubscriptionsResponse {
+    /// Gets results of individual subscriptions.
+    #[must_use]
+    pub fn results(&self) -> Option<Vec<ua::StatusCode>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        let array: ua::Array<ua::UInt32> =
+            ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)?;
+        Some(
+            array
+                .as_slice()
+                .iter()
+                .map(|status_code| ua::StatusCode::new(status_code.clone().into_raw()))
+                .collect(),
+        )
+    }
+
+    /// Gets diagnostic infos of individual results.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+        }
+    }
+}
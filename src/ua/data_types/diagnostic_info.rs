@@ -0,0 +1,74 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(DiagnosticInfo);
+
+impl DiagnosticInfo {
+    /// Gets index of symbolic ID in string table.
+    ///
+    /// The string table is part of the response that includes the diagnostic info. To get resolved
+    /// strings, use [`crate::DiagnosticInfo`] instead.
+    #[must_use]
+    pub fn symbolic_id(&self) -> Option<i32> {
+        self.0.hasSymbolicId().then_some(self.0.symbolicId)
+    }
+
+    /// Gets index of namespace URI of symbolic ID in string table.
+    #[must_use]
+    pub fn namespace_uri(&self) -> Option<i32> {
+        self.0.hasNamespaceUri().then_some(self.0.namespaceUri)
+    }
+
+    /// Gets index of localized text in string table.
+    #[must_use]
+    pub fn localized_text(&self) -> Option<i32> {
+        self.0.hasLocalizedText().then_some(self.0.localizedText)
+    }
+
+    /// Gets index of locale of localized text in string table.
+    #[must_use]
+    pub fn locale(&self) -> Option<i32> {
+        self.0.hasLocale().then_some(self.0.locale)
+    }
+
+    /// Gets additional (vendor-specific) information.
+    #[must_use]
+    pub fn additional_info(&self) -> Option<&ua::String> {
+        self.0
+            .hasAdditionalInfo()
+            .then(|| ua::String::raw_ref(&self.0.additionalInfo))
+    }
+
+    /// Gets status code from underlying system.
+    #[must_use]
+    pub fn inner_status_code(&self) -> Option<ua::StatusCode> {
+        self.0
+            .hasInnerStatusCode()
+            .then(|| ua::StatusCode::new(self.0.innerStatusCode))
+    }
+
+    /// Gets diagnostic info associated with inner status code.
+    #[must_use]
+    pub fn inner_diagnostic_info(&self) -> Option<&DiagnosticInfo> {
+        if !self.0.hasInnerDiagnosticInfo() {
+            return None;
+        }
+        // SAFETY: When set, the pointer is owned by this diagnostic info and valid for its lifetime.
+        let inner = unsafe { self.0.innerDiagnosticInfo.as_ref() }?;
+        Some(DiagnosticInfo::raw_ref(inner))
+    }
+
+    /// Checks whether diagnostic info is empty.
+    ///
+    /// Servers return empty diagnostic infos for operations without diagnostics when others in the
+    /// same request have some.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !(self.0.hasSymbolicId()
+            || self.0.hasNamespaceUri()
+            || self.0.hasLocalizedText()
+            || self.0.hasLocale()
+            || self.0.hasAdditionalInfo()
+            || self.0.hasInnerStatusCode()
+            || self.0.hasInnerDiagnosticInfo())
+    }
+}
@@ -3,6 +3,16 @@ use crate::{ua, DataType};
 crate::data_type!(EndpointDescription);
 
 impl EndpointDescription {
+    /// Sets endpoint URL.
+    ///
+    /// Use this to replace the URL that the server advertises, e.g. when it is not reachable from
+    /// the client (behind NAT).
+    #[must_use]
+    pub fn with_endpoint_url(mut self, endpoint_url: &ua::String) -> Self {
+        endpoint_url.clone_into_raw(&mut self.0.endpointUrl);
+        self
+    }
+
     #[must_use]
     pub fn endpoint_url(&self) -> &ua::String {
         ua::String::raw_ref(&self.0.endpointUrl)
@@ -37,4 +47,36 @@ impl EndpointDescription {
     pub const fn security_level(&self) -> ua::SecurityLevel {
         ua::SecurityLevel::new(self.0.securityLevel)
     }
+
+    /// Gets user identity token policies supported by endpoint.
+    #[must_use]
+    pub fn user_identity_tokens(&self) -> Option<&[ua::UserTokenPolicy]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.userIdentityTokensSize,
+                self.0.userIdentityTokens,
+            )
+        }
+    }
+
+    /// Selects user token policy for token type.
+    ///
+    /// This returns the first policy of the endpoint that accepts user identity tokens of the given
+    /// type. Its [policy ID](ua::UserTokenPolicy::policy_id) must be set in the token when creating
+    /// a session.
+    ///
+    /// Note that [`ClientBuilder::connect()`] selects the policy and sets the policy ID of the token
+    /// given in [`ClientBuilder::user_identity_token()`] automatically.
+    ///
+    /// [`ClientBuilder::connect()`]: crate::ClientBuilder::connect
+    /// [`ClientBuilder::user_identity_token()`]: crate::ClientBuilder::user_identity_token
+    #[must_use]
+    pub fn select_user_token_policy(
+        &self,
+        token_type: &ua::UserTokenType,
+    ) -> Option<&ua::UserTokenPolicy> {
+        self.user_identity_tokens()?
+            .iter()
+            .find(|policy| policy.token_type() == token_type)
+    }
 }
@@ -0,0 +1,22 @@
+use crate::ua;
+
+crate::data_type!(EventFieldList);
+
+impl EventFieldList {
+    /// Gets client handle of monitored item.
+    ///
+    /// This is the handle that the client has assigned when creating the monitored item, see
+    /// [`ua::MonitoringParameters`].
+    #[must_use]
+    pub const fn client_handle(&self) -> u32 {
+        self.0.clientHandle
+    }
+
+    /// Gets event fields.
+    ///
+    /// The fields are in the order of the select clauses of the [`ua::EventFilter`].
+    #[must_use]
+    pub fn event_fields(&self) -> Option<&[ua::Variant]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.eventFieldsSize, self.0.eventFields) }
+    }
+}
@@ -3,6 +3,15 @@ use crate::{ua, DataType as _, MonitoringFilter};
 crate::data_type!(EventFilter);
 
 impl EventFilter {
+    /// Gets select clauses.
+    ///
+    /// The fields of events are returned in the order of the select clauses, see
+    /// [`ua::EventFieldList::event_fields()`].
+    #[must_use]
+    pub fn select_clauses(&self) -> Option<&[ua::SimpleAttributeOperand]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.selectClausesSize, self.0.selectClauses) }
+    }
+
     #[must_use]
     pub fn with_select_clauses(mut self, select_clauses: &[ua::SimpleAttributeOperand]) -> Self {
         let array = ua::Array::from_slice(select_clauses);
@@ -10,6 +19,54 @@ impl EventFilter {
         self
     }
 
+    /// Adds select clause for event field.
+    ///
+    /// The field is given by its browse path, relative to `BaseEventType`. Select clauses are kept
+    /// in the order in which they are added.
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let filter = ua::EventFilter::init()
+    ///     .with_select(
+    ///         &[ua::QualifiedName::new(0, "EventType")],
+    ///         &ua::AttributeId::VALUE,
+    ///     )
+    ///     .with_select_simple("Message")
+    ///     .with_where_clause(ua::ContentFilter::of_type(&ua::NodeId::ns0_base_event_type()));
+    ///
+    /// assert_eq!(filter.select_clauses().map(<[_]>::len), Some(2));
+    /// ```
+    #[must_use]
+    pub fn with_select(
+        self,
+        browse_path: &[ua::QualifiedName],
+        attribute_id: &ua::AttributeId,
+    ) -> Self {
+        let select_clause = ua::SimpleAttributeOperand::init()
+            .with_type_definition_id(ua::NodeId::ns0_base_event_type())
+            .with_browse_path(browse_path)
+            .with_attribute_id(attribute_id);
+
+        let mut select_clauses = self.select_clauses().map(<[_]>::to_vec).unwrap_or_default();
+        select_clauses.push(select_clause);
+        self.with_select_clauses(&select_clauses)
+    }
+
+    /// Adds select clause for value of event field with the given name.
+    ///
+    /// This selects a property of `BaseEventType` in namespace 0, e.g. `Message` or `Severity`. See
+    /// [`with_select()`](Self::with_select) for other fields.
+    #[must_use]
+    pub fn with_select_simple(self, name: &str) -> Self {
+        self.with_select(&[ua::QualifiedName::new(0, name)], &ua::AttributeId::VALUE)
+    }
+
+    #[must_use]
+    pub fn where_clause(&self) -> &ua::ContentFilter {
+        ua::ContentFilter::raw_ref(&self.0.whereClause)
+    }
+
     #[must_use]
     pub fn with_where_clause(mut self, where_clause: ua::ContentFilter) -> Self {
         where_clause.move_into_raw(&mut self.0.whereClause);
@@ -22,3 +79,59 @@ impl MonitoringFilter for EventFilter {
         ua::ExtensionObject::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn select_clauses_keep_order() {
+        let filter = ua::EventFilter::init()
+            .with_select_simple("Message")
+            .with_select(
+                &[
+                    ua::QualifiedName::new(0, "EnabledState"),
+                    ua::QualifiedName::new(0, "Id"),
+                ],
+                &ua::AttributeId::VALUE,
+            )
+            .with_select_simple("Severity")
+            .with_where_clause(ua::ContentFilter::of_type(
+                &ua::NodeId::ns0_base_event_type(),
+            ));
+
+        let select_clauses = filter.select_clauses().expect("should have select clauses");
+        let browse_paths: Vec<Vec<_>> = select_clauses
+            .iter()
+            .map(|select_clause| {
+                select_clause
+                    .browse_path()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|name| name.name().as_str().unwrap_or_default().to_owned())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            browse_paths,
+            [
+                vec!["Message"],
+                vec!["EnabledState", "Id"],
+                vec!["Severity"]
+            ]
+        );
+        for select_clause in select_clauses {
+            assert_eq!(
+                select_clause.type_definition_id(),
+                &ua::NodeId::ns0_base_event_type()
+            );
+            assert_eq!(select_clause.attribute_id(), ua::AttributeId::VALUE);
+        }
+
+        let elements = filter
+            .where_clause()
+            .elements()
+            .expect("should have elements");
+        assert_eq!(elements.len(), 1);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::ua;
+
+crate::data_type!(EventNotificationList);
+
+impl EventNotificationList {
+    #[must_use]
+    pub fn events(&self) -> Option<&[ua::EventFieldList]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.eventsSize, self.0.events) }
+    }
+}
@@ -74,4 +74,22 @@ impl ExtensionObject {
 
         unsafe { decoded_content.data.cast::<T::Inner>().as_ref() }.map(T::raw_ref)
     }
+
+    /// Gets mutable decoded content.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn decoded_content_mut<T: DataType>(&mut self) -> Option<&mut T> {
+        match self.0.encoding {
+            UA_ExtensionObjectEncoding::UA_EXTENSIONOBJECT_DECODED
+            | UA_ExtensionObjectEncoding::UA_EXTENSIONOBJECT_DECODED_NODELETE => {}
+            _ => return None,
+        }
+
+        let decoded_content = unsafe { self.0.content.decoded.as_mut() };
+
+        if decoded_content.type_ != T::data_type() {
+            return None;
+        }
+
+        unsafe { decoded_content.data.cast::<T::Inner>().as_mut() }.map(T::raw_mut)
+    }
 }
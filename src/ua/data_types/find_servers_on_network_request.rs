@@ -0,0 +1,41 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(FindServersOnNetworkRequest);
+
+impl FindServersOnNetworkRequest {
+    /// Sets record ID to start at.
+    ///
+    /// Only servers with this or a higher record ID are returned.
+    #[must_use]
+    pub const fn with_starting_record_id(mut self, starting_record_id: u32) -> Self {
+        self.0.startingRecordId = starting_record_id;
+        self
+    }
+
+    /// Sets maximum number of records to return.
+    ///
+    /// With `0` (the default), all records are returned.
+    #[must_use]
+    pub const fn with_max_records_to_return(mut self, max_records_to_return: u32) -> Self {
+        self.0.maxRecordsToReturn = max_records_to_return;
+        self
+    }
+
+    /// Sets capabilities that returned servers must have.
+    #[must_use]
+    pub fn with_server_capability_filter(
+        mut self,
+        server_capability_filter: &[ua::String],
+    ) -> Self {
+        let array = ua::Array::from_slice(server_capability_filter);
+        array.move_into_raw(
+            &mut self.0.serverCapabilityFilterSize,
+            &mut self.0.serverCapabilityFilter,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for FindServersOnNetworkRequest {
+    type Response = ua::FindServersOnNetworkResponse;
+}
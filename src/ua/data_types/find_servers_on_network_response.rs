@@ -0,0 +1,27 @@
+use crate::{ua, DataType as _, ServiceResponse};
+
+crate::data_type!(FindServersOnNetworkResponse);
+
+impl FindServersOnNetworkResponse {
+    /// Gets time when record IDs were last reset.
+    ///
+    /// When this changes between requests, record IDs from earlier requests are no longer valid.
+    #[must_use]
+    pub fn last_counter_reset_time(&self) -> &ua::DateTime {
+        ua::DateTime::raw_ref(&self.0.lastCounterResetTime)
+    }
+
+    #[must_use]
+    pub fn servers(&self) -> Option<ua::Array<ua::ServerOnNetwork>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.serversSize, self.0.servers)
+    }
+}
+
+impl ServiceResponse for FindServersOnNetworkResponse {
+    type Request = ua::FindServersOnNetworkRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
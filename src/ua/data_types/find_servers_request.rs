@@ -0,0 +1,34 @@
+use crate::{ua, DataType as _, ServiceRequest};
+
+crate::data_type!(FindServersRequest);
+
+impl FindServersRequest {
+    /// Sets endpoint URL that the client used to reach the server.
+    #[must_use]
+    pub fn with_endpoint_url(mut self, endpoint_url: &ua::String) -> Self {
+        endpoint_url.clone_into_raw(&mut self.0.endpointUrl);
+        self
+    }
+
+    /// Sets preferred locales for application names, in order of preference.
+    #[must_use]
+    pub fn with_locale_ids(mut self, locale_ids: &[ua::String]) -> Self {
+        let array = ua::Array::from_slice(locale_ids);
+        array.move_into_raw(&mut self.0.localeIdsSize, &mut self.0.localeIds);
+        self
+    }
+
+    /// Sets application URIs of servers to return.
+    ///
+    /// When empty, all known servers are returned.
+    #[must_use]
+    pub fn with_server_uris(mut self, server_uris: &[ua::String]) -> Self {
+        let array = ua::Array::from_slice(server_uris);
+        array.move_into_raw(&mut self.0.serverUrisSize, &mut self.0.serverUris);
+        self
+    }
+}
+
+impl ServiceRequest for FindServersRequest {
+    type Response = ua::FindServersResponse;
+}
@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(FindServersResponse);
+
+impl FindServersResponse {
+    #[must_use]
+    pub fn servers(&self) -> Option<ua::Array<ua::ApplicationDescription>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.serversSize, self.0.servers)
+    }
+}
+
+impl ServiceResponse for FindServersResponse {
+    type Request = ua::FindServersRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
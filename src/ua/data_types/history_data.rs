@@ -0,0 +1,10 @@
+use crate::ua;
+
+crate::data_type!(HistoryData);
+
+impl HistoryData {
+    #[must_use]
+    pub fn data_values(&self) -> Option<&[ua::DataValue]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.dataValuesSize, self.0.dataValues) }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{ua, DataType as _, ServiceRequest};
+
+crate::data_type!(HistoryReadRequest);
+
+impl HistoryReadRequest {
+    #[must_use]
+    pub fn with_read_raw_modified_details(
+        mut self,
+        read_raw_modified_details: &ua::ReadRawModifiedDetails,
+    ) -> Self {
+        ua::ExtensionObject::new(read_raw_modified_details)
+            .move_into_raw(&mut self.0.historyReadDetails);
+        self
+    }
+
+    #[must_use]
+    pub fn with_read_processed_details(
+        mut self,
+        read_processed_details: &ua::ReadProcessedDetails,
+    ) -> Self {
+        ua::ExtensionObject::new(read_processed_details)
+            .move_into_raw(&mut self.0.historyReadDetails);
+        self
+    }
+
+    #[must_use]
+    pub fn with_timestamps_to_return(
+        mut self,
+        timestamps_to_return: &ua::TimestampsToReturn,
+    ) -> Self {
+        timestamps_to_return.clone_into_raw(&mut self.0.timestampsToReturn);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_release_continuation_points(
+        mut self,
+        release_continuation_points: bool,
+    ) -> Self {
+        self.0.releaseContinuationPoints = release_continuation_points;
+        self
+    }
+
+    #[must_use]
+    pub fn with_nodes_to_read(mut self, nodes_to_read: &[ua::HistoryReadValueId]) -> Self {
+        let array = ua::Array::from_slice(nodes_to_read);
+        array.move_into_raw(&mut self.0.nodesToReadSize, &mut self.0.nodesToRead);
+        self
+    }
+}
+
+impl ServiceRequest for HistoryReadRequest {
+    type Response = ua::HistoryReadResponse;
+}
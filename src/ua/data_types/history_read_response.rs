@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(HistoryReadResponse);
+
+impl HistoryReadResponse {
+    #[must_use]
+    pub fn results(&self) -> Option<ua::Array<ua::HistoryReadResult>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
+    }
+}
+
+impl ServiceResponse for HistoryReadResponse {
+    type Request = ua::HistoryReadRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(HistoryReadResult);
+
+impl HistoryReadResult {
+    #[must_use]
+    pub const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
+    /// Gets continuation point.
+    ///
+    /// History read results include a continuation point when not all values could be returned.
+    /// Pass it to [`ua::HistoryReadValueId::with_continuation_point()`] in a follow-up request to
+    /// get the remaining values.
+    #[must_use]
+    pub fn continuation_point(&self) -> Option<ua::ContinuationPoint> {
+        ua::ContinuationPoint::new(ua::ByteString::raw_ref(&self.0.continuationPoint).clone())
+    }
+
+    /// Gets history data.
+    ///
+    /// This returns `None` when the result holds a different kind of data, e.g. for events.
+    #[must_use]
+    pub fn history_data(&self) -> Option<&ua::HistoryData> {
+        ua::ExtensionObject::raw_ref(&self.0.historyData).decoded_content::<ua::HistoryData>()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(HistoryReadValueId);
+
+impl HistoryReadValueId {
+    #[must_use]
+    pub fn with_node_id(mut self, node_id: &ua::NodeId) -> Self {
+        node_id.clone_into_raw(&mut self.0.nodeId);
+        self
+    }
+
+    /// Sets index range to read only part of array values, e.g. `1:3`.
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::String) -> Self {
+        index_range.clone_into_raw(&mut self.0.indexRange);
+        self
+    }
+
+    /// Sets data encoding to request structured values in.
+    #[must_use]
+    pub fn with_data_encoding(mut self, data_encoding: &ua::QualifiedName) -> Self {
+        data_encoding.clone_into_raw(&mut self.0.dataEncoding);
+        self
+    }
+
+    /// Sets continuation point to continue earlier request.
+    ///
+    /// See [`ua::HistoryReadResult::continuation_point()`].
+    #[must_use]
+    pub fn with_continuation_point(mut self, continuation_point: &ua::ContinuationPoint) -> Self {
+        continuation_point
+            .as_byte_string()
+            .clone_into_raw(&mut self.0.continuationPoint);
+        self
+    }
+
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.nodeId)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(HistoryUpdateRequest);
+
+impl HistoryUpdateRequest {
+    /// Sets update details.
+    ///
+    /// Each entry holds details such as [`ua::UpdateDataDetails`] or
+    /// [`ua::DeleteRawModifiedDetails`], wrapped in [`ua::ExtensionObject`].
+    #[must_use]
+    pub fn with_history_update_details(
+        mut self,
+        history_update_details: &[ua::ExtensionObject],
+    ) -> Self {
+        let array = ua::Array::from_slice(history_update_details);
+        array.move_into_raw(
+            &mut self.0.historyUpdateDetailsSize,
+            &mut self.0.historyUpdateDetails,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for HistoryUpdateRequest {
+    type Response = ua::HistoryUpdateResponse;
+}
@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(HistoryUpdateResponse);
+
+impl HistoryUpdateResponse {
+    #[must_use]
+    pub fn results(&self) -> Option<ua::Array<ua::HistoryUpdateResult>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.resultsSize, self.0.results)
+    }
+}
+
+impl ServiceResponse for HistoryUpdateResponse {
+    type Request = ua::HistoryUpdateRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(HistoryUpdateResult);
+
+impl HistoryUpdateResult {
+    #[must_use]
+    pub const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
+    /// Gets results of individual operations.
+    ///
+    /// For [`ua::UpdateDataDetails`], this has one entry per value.
+    #[must_use]
+    pub fn operation_results(&self) -> Option<Vec<ua::StatusCode>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        let array: ua::Array<ua::UInt32> =
+            ua::Array::from_raw_parts(self.0.operationResultsSize, self.0.operationResults)?;
+        // TODO: Simplify this. Think about what should be in `ua` and what should not.
+        Some(
+            array
+                .as_slice()
+                .iter()
+                .map(|status_code| ua::StatusCode::new(status_code.clone().into_raw()))
+                .collect(),
+        )
+    }
+}
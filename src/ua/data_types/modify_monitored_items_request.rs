@@ -0,0 +1,35 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ModifyMonitoredItemsRequest);
+
+impl ModifyMonitoredItemsRequest {
+    #[must_use]
+    pub const fn with_subscription_id(mut self, subscription_id: ua::SubscriptionId) -> Self {
+        self.0.subscriptionId = subscription_id.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_timestamps_to_return(
+        mut self,
+        timestamps_to_return: &ua::TimestampsToReturn,
+    ) -> Self {
+        timestamps_to_return.clone_into_raw(&mut self.0.timestampsToReturn);
+        self
+    }
+
+    #[must_use]
+    pub fn with_items_to_modify(
+        mut self,
+        items_to_modify: &[ua::MonitoredItemModifyRequest],
+    ) -> Self {
+        let array = ua::Array::from_slice(items_to_modify);
+        array.move_into_raw(&mut self.0.itemsToModifySize, &mut self.0.itemsToModify);
+        self
+    }
+
+    #[must_use]
+    pub fn items_to_modify(&self) -> Option<&[ua::MonitoredItemModifyRequest]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.itemsToModifySize, self.0.itemsToModify) }
+    }
+}
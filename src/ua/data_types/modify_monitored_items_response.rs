@@ -0,0 +1,22 @@
+use crate::ua;
+
+crate::data_type!(ModifyMonitoredItemsResponse);
+
+impl ModifyMonitoredItemsResponse {
+    /// Gets results of individual monitored items.
+    #[must_use]
+    pub fn results(&self) -> Option<&[ua::MonitoredItemModifyResult]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.resultsSize, self.0.results) }
+    }
+
+    /// Gets diagnostic infos of individual results.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::{num::NonZeroU32, time::Duration};
+
+use crate::ua;
+
+crate::data_type!(ModifySubscriptionRequest);
+
+impl ModifySubscriptionRequest {
+    #[must_use]
+    pub const fn with_subscription_id(mut self, subscription_id: ua::SubscriptionId) -> Self {
+        self.0.subscriptionId = subscription_id.as_u32();
+        self
+    }
+
+    /// Sets requested publishing interval.
+    ///
+    /// The value `None` indicates that the server shall revise with the fastest supported
+    /// publishing interval.
+    #[must_use]
+    pub fn with_requested_publishing_interval(
+        mut self,
+        requested_publishing_interval: Option<Duration>,
+    ) -> Self {
+        // See `ua::CreateSubscriptionRequest::with_requested_publishing_interval()`.
+        self.0.requestedPublishingInterval = requested_publishing_interval
+            .map_or(0.0, |requested_publishing_interval| {
+                requested_publishing_interval.as_secs_f64() * 1e3
+            });
+        self
+    }
+
+    /// Sets requested lifetime count.
+    #[must_use]
+    pub const fn with_requested_lifetime_count(mut self, requested_lifetime_count: u32) -> Self {
+        self.0.requestedLifetimeCount = requested_lifetime_count;
+        self
+    }
+
+    /// Sets requested maximum keep-alive count.
+    ///
+    /// The value `None` indicates that the server shall revise with the smallest supported
+    /// keep-alive count.
+    #[must_use]
+    pub fn with_requested_max_keep_alive_count(
+        mut self,
+        requested_max_keep_alive_count: Option<NonZeroU32>,
+    ) -> Self {
+        self.0.requestedMaxKeepAliveCount =
+            requested_max_keep_alive_count.map_or(0, NonZeroU32::get);
+        self
+    }
+
+    /// Sets maximum number of notifications that the client wishes to receive in a single publish
+    /// response.
+    ///
+    /// The value `None` indicates that there is no limit.
+    #[must_use]
+    pub fn with_max_notifications_per_publish(
+        mut self,
+        max_notifications_per_publish: Option<NonZeroU32>,
+    ) -> Self {
+        self.0.maxNotificationsPerPublish =
+            max_notifications_per_publish.map_or(0, NonZeroU32::get);
+        self
+    }
+
+    /// Sets relative priority of the subscription.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.0.priority = priority;
+        self
+    }
+
+    #[must_use]
+    pub const fn subscription_id(&self) -> ua::SubscriptionId {
+        ua::SubscriptionId::new(self.0.subscriptionId)
+    }
+}
@@ -14,6 +14,18 @@ impl MonitoredItemCreateRequest {
         self
     }
 
+    /// Gets item to monitor.
+    #[must_use]
+    pub fn item_to_monitor(&self) -> &ua::ReadValueId {
+        ua::ReadValueId::raw_ref(&self.0.itemToMonitor)
+    }
+
+    /// Gets node ID of item to monitor.
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.itemToMonitor.nodeId)
+    }
+
     /// Shortcut for setting node ID.
     ///
     /// See [`ua::ReadValueId::with_node_id()`].
@@ -32,6 +44,21 @@ impl MonitoredItemCreateRequest {
         self
     }
 
+    /// Shortcut for setting index range.
+    ///
+    /// See [`ua::ReadValueId::with_index_range()`].
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::String) -> Self {
+        index_range.clone_into_raw(&mut self.0.itemToMonitor.indexRange);
+        self
+    }
+
+    /// Gets monitoring mode.
+    #[must_use]
+    pub fn monitoring_mode(&self) -> &ua::MonitoringMode {
+        ua::MonitoringMode::raw_ref(&self.0.monitoringMode)
+    }
+
     /// Sets monitoring mode.
     #[must_use]
     pub fn with_monitoring_mode(mut self, monitoring_mode: &ua::MonitoringMode) -> Self {
@@ -39,6 +66,12 @@ impl MonitoredItemCreateRequest {
         self
     }
 
+    /// Gets requested parameters.
+    #[must_use]
+    pub fn requested_parameters(&self) -> &ua::MonitoringParameters {
+        ua::MonitoringParameters::raw_ref(&self.0.requestedParameters)
+    }
+
     /// Sets requested parameters.
     #[must_use]
     pub fn with_requested_parameters(
@@ -49,6 +82,14 @@ impl MonitoredItemCreateRequest {
         self
     }
 
+    /// Gets requested sampling interval.
+    ///
+    /// Returns `None` when the publishing interval of the subscription is used as sampling interval.
+    #[must_use]
+    pub fn sampling_interval(&self) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.0.requestedParameters.samplingInterval / 1e3).ok()
+    }
+
     /// Shortcut for setting sampling interval.
     ///
     /// See [`ua::MonitoringParameters::with_sampling_interval()`].
@@ -81,6 +122,12 @@ impl MonitoredItemCreateRequest {
         self
     }
 
+    /// Gets requested discard policy.
+    #[must_use]
+    pub const fn discard_oldest(&self) -> bool {
+        self.0.requestedParameters.discardOldest
+    }
+
     /// Shortcut for setting discard policy.
     ///
     /// See [`ua::MonitoringParameters::with_discard_oldest()`].
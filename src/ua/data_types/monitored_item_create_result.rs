@@ -11,9 +11,9 @@ impl MonitoredItemCreateResult {
         ua::StatusCode::new(self.0.statusCode)
     }
 
-    #[allow(dead_code)] // --no-default-features
+    /// Gets server-assigned monitored item ID.
     #[must_use]
-    pub(crate) const fn monitored_item_id(&self) -> ua::MonitoredItemId {
+    pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
         ua::MonitoredItemId::new(self.0.monitoredItemId)
     }
 
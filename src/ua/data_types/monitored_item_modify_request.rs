@@ -0,0 +1,37 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(MonitoredItemModifyRequest);
+
+impl MonitoredItemModifyRequest {
+    /// Gets server-assigned ID of monitored item to modify.
+    #[must_use]
+    pub const fn monitored_item_id(&self) -> ua::MonitoredItemId {
+        ua::MonitoredItemId::new(self.0.monitoredItemId)
+    }
+
+    /// Sets server-assigned ID of monitored item to modify.
+    #[must_use]
+    pub const fn with_monitored_item_id(mut self, monitored_item_id: ua::MonitoredItemId) -> Self {
+        self.0.monitoredItemId = monitored_item_id.as_u32();
+        self
+    }
+
+    /// Gets requested parameters.
+    #[must_use]
+    pub fn requested_parameters(&self) -> &ua::MonitoringParameters {
+        ua::MonitoringParameters::raw_ref(&self.0.requestedParameters)
+    }
+
+    /// Sets requested parameters.
+    ///
+    /// The client handle is set by the client, so that values are still delivered to the monitored
+    /// item afterwards.
+    #[must_use]
+    pub fn with_requested_parameters(
+        mut self,
+        requested_parameters: &ua::MonitoringParameters,
+    ) -> Self {
+        requested_parameters.clone_into_raw(&mut self.0.requestedParameters);
+        self
+    }
+}
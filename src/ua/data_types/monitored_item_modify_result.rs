@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use crate::{ua, DataType as _, Error, Result};
+
+crate::data_type!(MonitoredItemModifyResult);
+
+impl MonitoredItemModifyResult {
+    #[allow(dead_code)] // --no-default-features
+    #[must_use]
+    pub(crate) const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
+    /// Gets revised sampling interval.
+    ///
+    /// # Errors
+    ///
+    /// This fails when the returned value is negative.
+    pub fn revised_sampling_interval(&self) -> Result<Duration> {
+        Duration::try_from_secs_f64(self.0.revisedSamplingInterval / 1e3)
+            .map_err(|_| Error::internal("invalid revised sampling interval"))
+    }
+
+    /// Gets revised queue size.
+    #[must_use]
+    pub const fn revised_queue_size(&self) -> u32 {
+        self.0.revisedQueueSize
+    }
+
+    /// Gets filter result.
+    ///
+    /// Servers return this for filters that they have revised, e.g. for event filters with
+    /// select clauses that are invalid.
+    #[must_use]
+    pub fn filter_result(&self) -> &ua::ExtensionObject {
+        ua::ExtensionObject::raw_ref(&self.0.filterResult)
+    }
+}
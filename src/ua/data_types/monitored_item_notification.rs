@@ -0,0 +1,19 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(MonitoredItemNotification);
+
+impl MonitoredItemNotification {
+    /// Gets client handle of monitored item.
+    ///
+    /// This is the handle that the client has assigned when creating the monitored item, see
+    /// [`ua::MonitoringParameters`].
+    #[must_use]
+    pub const fn client_handle(&self) -> u32 {
+        self.0.clientHandle
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &ua::DataValue {
+        ua::DataValue::raw_ref(&self.0.value)
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::{DataType as _, MonitoringFilter};
+use crate::{ua, DataType as _, MonitoringFilter};
 
 crate::data_type!(MonitoringParameters);
 
@@ -22,6 +22,12 @@ impl MonitoringParameters {
         self
     }
 
+    /// Gets filter.
+    #[must_use]
+    pub fn filter(&self) -> &ua::ExtensionObject {
+        ua::ExtensionObject::raw_ref(&self.0.filter)
+    }
+
     /// Sets filter.
     #[must_use]
     pub fn with_filter(mut self, filter: &impl MonitoringFilter) -> Self {
@@ -1,5 +1,10 @@
+mod data_type_attributes;
 mod method_attributes;
+mod object_type_attributes;
+mod reference_type_attributes;
 mod variable_attributes;
+mod variable_type_attributes;
+mod view_attributes;
 
 use open62541_sys::{UA_DataType, UA_NodeAttributes};
 
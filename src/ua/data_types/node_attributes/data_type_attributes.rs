@@ -0,0 +1,10 @@
+use crate::ua;
+
+impl super::DataTypeAttributes {
+    #[must_use]
+    pub const fn with_is_abstract(mut self, is_abstract: bool) -> Self {
+        self.0.isAbstract = is_abstract;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::ISABSTRACT.as_u32();
+        self
+    }
+}
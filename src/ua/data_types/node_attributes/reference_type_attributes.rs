@@ -0,0 +1,28 @@
+use crate::{ua, DataType as _};
+
+impl super::ReferenceTypeAttributes {
+    #[must_use]
+    pub const fn with_is_abstract(mut self, is_abstract: bool) -> Self {
+        self.0.isAbstract = is_abstract;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::ISABSTRACT.as_u32();
+        self
+    }
+
+    /// Sets whether reference type is symmetric.
+    ///
+    /// Symmetric reference types have the same meaning in both directions and no inverse name.
+    #[must_use]
+    pub const fn with_symmetric(mut self, symmetric: bool) -> Self {
+        self.0.symmetric = symmetric;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::SYMMETRIC.as_u32();
+        self
+    }
+
+    /// Sets name of inverse direction, e.g. `ComponentOf` for `HasComponent`.
+    #[must_use]
+    pub fn with_inverse_name(mut self, inverse_name: &ua::LocalizedText) -> Self {
+        inverse_name.clone_into_raw(&mut self.0.inverseName);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::INVERSENAME.as_u32();
+        self
+    }
+}
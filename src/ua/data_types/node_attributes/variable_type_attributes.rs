@@ -0,0 +1,42 @@
+use crate::{ua, DataType as _};
+
+impl super::VariableTypeAttributes {
+    /// Sets default value.
+    ///
+    /// Variables of this type that do not have their own value start with this value.
+    #[must_use]
+    pub fn with_value(mut self, value: &ua::Variant) -> Self {
+        value.clone_into_raw(&mut self.0.value);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::VALUE.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_type(mut self, data_type: &ua::NodeId) -> Self {
+        data_type.clone_into_raw(&mut self.0.dataType);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::DATATYPE.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_value_rank(mut self, rank: i32) -> Self {
+        self.0.valueRank = rank;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::VALUERANK.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_array_dimensions(mut self, array_dimensions: &[u32]) -> Self {
+        let array = ua::Array::from_iter(array_dimensions.iter().copied().map(ua::UInt32::new));
+        array.move_into_raw(&mut self.0.arrayDimensionsSize, &mut self.0.arrayDimensions);
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::ARRAYDIMENSIONS.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_is_abstract(mut self, is_abstract: bool) -> Self {
+        self.0.isAbstract = is_abstract;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::ISABSTRACT.as_u32();
+        self
+    }
+}
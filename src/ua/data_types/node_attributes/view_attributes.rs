@@ -0,0 +1,21 @@
+use crate::ua;
+
+impl super::ViewAttributes {
+    /// Sets whether following the view's references from its root never loops back.
+    #[must_use]
+    pub const fn with_contains_no_loops(mut self, contains_no_loops: bool) -> Self {
+        self.0.containsNoLoops = contains_no_loops;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::CONTAINSNOLOOPS.as_u32();
+        self
+    }
+
+    /// Sets event notifier.
+    ///
+    /// Bit 0 indicates that the view can be subscribed to for events.
+    #[must_use]
+    pub const fn with_event_notifier(mut self, event_notifier: u8) -> Self {
+        self.0.eventNotifier = event_notifier;
+        self.0.specifiedAttributes |= ua::SpecifiedAttributes::EVENTNOTIFIER.as_u32();
+        self
+    }
+}
@@ -1,4 +1,4 @@
-use std::{ffi::CString, fmt, hash, str};
+use std::{fmt, hash, str};
 
 use open62541_sys::{
     UA_NodeIdType, UA_NodeId_hash, UA_NodeId_parse, UA_NodeId_print, UA_NODEID_NULL,
@@ -39,7 +39,8 @@ impl NodeId {
     /// The string identifier must not contain any NUL bytes.
     #[must_use]
     pub fn string(ns_index: u16, string: &str) -> Self {
-        let string = CString::new(string).expect("node ID string does not contain NUL bytes");
+        let string =
+            Error::c_string(string, "node ID string").expect("should not contain NUL bytes");
 
         // Technically, string allocation may fail but `UA_NODEID_STRING_ALLOC` doesn't tell us that
         // when it happens. Instead, we end up with a well-defined node ID that has an empty string.
@@ -115,6 +116,118 @@ impl NodeId {
     }
 }
 
+/// Defines well-known node IDs in namespace 0.
+///
+/// This adds constructors to [`NodeId`] for the given constants from [`open62541_sys`]. Deriving
+/// them from the constants (instead of hardcoding numeric identifiers) keeps them in sync.
+macro_rules! ns0_node_ids {
+    ($( $name:ident: $id:ident ),* $(,)?) => {
+        impl NodeId {
+            $(
+                /// Creates well-known node ID
+                #[doc = paste::paste! { concat!("[`", stringify!([<UA_NS0ID_ $id>]), "`](open62541_sys::", stringify!([<UA_NS0ID_ $id>]), ")") }]
+                /// in namespace 0.
+                #[must_use]
+                pub fn $name() -> Self {
+                    Self::ns0(paste::paste! { open62541_sys::[<UA_NS0ID_ $id>] })
+                }
+            )*
+        }
+    };
+}
+
+ns0_node_ids!(
+    // Folders and objects.
+    ns0_root_folder: ROOTFOLDER,
+    ns0_objects_folder: OBJECTSFOLDER,
+    ns0_types_folder: TYPESFOLDER,
+    ns0_views_folder: VIEWSFOLDER,
+    ns0_server: SERVER,
+    ns0_server_namespace_array: SERVER_NAMESPACEARRAY,
+    ns0_server_server_array: SERVER_SERVERARRAY,
+    ns0_server_status: SERVER_SERVERSTATUS,
+    ns0_server_status_start_time: SERVER_SERVERSTATUS_STARTTIME,
+    ns0_server_status_current_time: SERVER_SERVERSTATUS_CURRENTTIME,
+    ns0_server_status_state: SERVER_SERVERSTATUS_STATE,
+    ns0_server_status_build_info: SERVER_SERVERSTATUS_BUILDINFO,
+    ns0_server_status_build_info_product_uri: SERVER_SERVERSTATUS_BUILDINFO_PRODUCTURI,
+    ns0_server_status_build_info_manufacturer_name: SERVER_SERVERSTATUS_BUILDINFO_MANUFACTURERNAME,
+    ns0_server_status_build_info_product_name: SERVER_SERVERSTATUS_BUILDINFO_PRODUCTNAME,
+    ns0_server_status_build_info_software_version: SERVER_SERVERSTATUS_BUILDINFO_SOFTWAREVERSION,
+    ns0_server_status_build_info_build_number: SERVER_SERVERSTATUS_BUILDINFO_BUILDNUMBER,
+    ns0_server_status_build_info_build_date: SERVER_SERVERSTATUS_BUILDINFO_BUILDDATE,
+    ns0_server_status_seconds_till_shutdown: SERVER_SERVERSTATUS_SECONDSTILLSHUTDOWN,
+    ns0_server_status_shutdown_reason: SERVER_SERVERSTATUS_SHUTDOWNREASON,
+    ns0_server_diagnostics_summary: SERVER_SERVERDIAGNOSTICS_SERVERDIAGNOSTICSSUMMARY,
+    ns0_server_get_monitored_items: SERVER_GETMONITOREDITEMS,
+    // Reference types.
+    ns0_references: REFERENCES,
+    ns0_hierarchical_references: HIERARCHICALREFERENCES,
+    ns0_non_hierarchical_references: NONHIERARCHICALREFERENCES,
+    ns0_aggregates: AGGREGATES,
+    ns0_organizes: ORGANIZES,
+    ns0_has_component: HASCOMPONENT,
+    ns0_has_ordered_component: HASORDEREDCOMPONENT,
+    ns0_has_property: HASPROPERTY,
+    ns0_has_subtype: HASSUBTYPE,
+    ns0_has_type_definition: HASTYPEDEFINITION,
+    ns0_has_modelling_rule: HASMODELLINGRULE,
+    ns0_has_event_source: HASEVENTSOURCE,
+    ns0_has_notifier: HASNOTIFIER,
+    // Object types, variable types, and modelling rules.
+    ns0_base_object_type: BASEOBJECTTYPE,
+    ns0_folder_type: FOLDERTYPE,
+    ns0_server_type: SERVERTYPE,
+    ns0_base_variable_type: BASEVARIABLETYPE,
+    ns0_base_data_variable_type: BASEDATAVARIABLETYPE,
+    ns0_property_type: PROPERTYTYPE,
+    ns0_modelling_rule_mandatory: MODELLINGRULE_MANDATORY,
+    ns0_modelling_rule_optional: MODELLINGRULE_OPTIONAL,
+    // Data types.
+    ns0_base_data_type: BASEDATATYPE,
+    ns0_number: NUMBER,
+    ns0_integer: INTEGER,
+    ns0_uinteger: UINTEGER,
+    ns0_enumeration: ENUMERATION,
+    ns0_structure: STRUCTURE,
+    ns0_boolean: BOOLEAN,
+    ns0_sbyte: SBYTE,
+    ns0_byte: BYTE,
+    ns0_int16: INT16,
+    ns0_uint16: UINT16,
+    ns0_int32: INT32,
+    ns0_uint32: UINT32,
+    ns0_int64: INT64,
+    ns0_uint64: UINT64,
+    ns0_float: FLOAT,
+    ns0_double: DOUBLE,
+    ns0_string: STRING,
+    ns0_date_time: DATETIME,
+    ns0_guid: GUID,
+    ns0_byte_string: BYTESTRING,
+    ns0_xml_element: XMLELEMENT,
+    ns0_node_id: NODEID,
+    ns0_expanded_node_id: EXPANDEDNODEID,
+    ns0_status_code: STATUSCODE,
+    ns0_qualified_name: QUALIFIEDNAME,
+    ns0_localized_text: LOCALIZEDTEXT,
+    ns0_data_value: DATAVALUE,
+    ns0_diagnostic_info: DIAGNOSTICINFO,
+    // Event types.
+    ns0_base_event_type: BASEEVENTTYPE,
+    ns0_system_event_type: SYSTEMEVENTTYPE,
+    ns0_audit_event_type: AUDITEVENTTYPE,
+    ns0_progress_event_type: PROGRESSEVENTTYPE,
+    ns0_base_model_change_event_type: BASEMODELCHANGEEVENTTYPE,
+    ns0_general_model_change_event_type: GENERALMODELCHANGEEVENTTYPE,
+    ns0_condition_type: CONDITIONTYPE,
+    ns0_alarm_condition_type: ALARMCONDITIONTYPE,
+    // Aggregate functions.
+    ns0_aggregate_function_average: AGGREGATEFUNCTION_AVERAGE,
+    ns0_aggregate_function_minimum: AGGREGATEFUNCTION_MINIMUM,
+    ns0_aggregate_function_maximum: AGGREGATEFUNCTION_MAXIMUM,
+);
+
 impl hash::Hash for NodeId {
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         let hash = unsafe { UA_NodeId_hash(self.as_ptr()) };
@@ -247,4 +360,14 @@ mod tests {
         //
         let _node_id: ua::NodeId = "ns=0;i=2258".parse().expect("should be valid node ID");
     }
+
+    #[test]
+    fn well_known_node_ids() {
+        assert_eq!(ua::NodeId::ns0_objects_folder(), ua::NodeId::ns0(85));
+        assert_eq!(
+            ua::NodeId::ns0_server_status_current_time().to_string(),
+            "i=2258"
+        );
+        assert_eq!(ua::NodeId::ns0_string().as_ns0(), Some(12));
+    }
 }
@@ -0,0 +1,67 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(NotificationMessage);
+
+impl NotificationMessage {
+    /// Gets sequence number of message.
+    ///
+    /// Keep-alive messages carry no notifications and have the sequence number of the next message
+    /// that is to be sent.
+    #[must_use]
+    pub const fn sequence_number(&self) -> u32 {
+        self.0.sequenceNumber
+    }
+
+    /// Gets time that message was sent at.
+    #[must_use]
+    pub fn publish_time(&self) -> &ua::DateTime {
+        ua::DateTime::raw_ref(&self.0.publishTime)
+    }
+
+    /// Gets notifications as extension objects.
+    ///
+    /// Use [`data_change_notifications()`](Self::data_change_notifications) and related methods
+    /// to get the decoded notifications.
+    #[must_use]
+    pub fn notification_data(&self) -> Option<&[ua::ExtensionObject]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(self.0.notificationDataSize, self.0.notificationData)
+        }
+    }
+
+    /// Checks if this is a keep-alive message, i.e. without notifications.
+    #[must_use]
+    pub fn is_keep_alive(&self) -> bool {
+        self.notification_data().unwrap_or_default().is_empty()
+    }
+
+    /// Gets data change notifications.
+    #[must_use]
+    pub fn data_change_notifications(&self) -> Vec<&ua::DataChangeNotification> {
+        self.notification_data()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(ua::ExtensionObject::decoded_content)
+            .collect()
+    }
+
+    /// Gets event notifications.
+    #[must_use]
+    pub fn event_notification_lists(&self) -> Vec<&ua::EventNotificationList> {
+        self.notification_data()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(ua::ExtensionObject::decoded_content)
+            .collect()
+    }
+
+    /// Gets status change notifications.
+    #[must_use]
+    pub fn status_change_notifications(&self) -> Vec<&ua::StatusChangeNotification> {
+        self.notification_data()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(ua::ExtensionObject::decoded_content)
+            .collect()
+    }
+}
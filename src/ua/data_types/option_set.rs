@@ -0,0 +1,172 @@
+use std::ptr;
+
+use open62541_sys::UA_decodeBinary;
+
+use crate::{ua, DataType};
+
+crate::data_type!(OptionSet);
+
+impl OptionSet {
+    /// Creates option set from bits.
+    ///
+    /// Bit 0 is the least significant bit of the first byte.
+    #[must_use]
+    pub fn new(bits: &[u8], valid_bits: &[u8]) -> Self {
+        let mut option_set = Self::init();
+        ua::ByteString::new(bits).move_into_raw(&mut option_set.0.value);
+        ua::ByteString::new(valid_bits).move_into_raw(&mut option_set.0.validBits);
+        option_set
+    }
+
+    /// Creates option set from integer bits.
+    ///
+    /// This uses the lowest `len` bytes of `bits` and `valid_bits`. Returns `None` when `len` is
+    /// larger than 8 or when bits outside of this range are set.
+    #[must_use]
+    pub fn from_u64(bits: u64, valid_bits: u64, len: usize) -> Option<Self> {
+        let to_bytes = |value: u64| {
+            let bytes = value.to_le_bytes();
+            let (bytes, rest) = bytes.split_at_checked(len)?;
+            rest.iter().all(|&byte| byte == 0).then(|| bytes.to_vec())
+        };
+
+        Some(Self::new(&to_bytes(bits)?, &to_bytes(valid_bits)?))
+    }
+
+    /// Decodes option set from extension object.
+    ///
+    /// Subtypes of `OptionSet` are not known to `open62541` and are received as extension objects
+    /// with binary encoded content. Use this when the data type of the value is known to be such a
+    /// subtype. Returns `None` when the content cannot be decoded.
+    #[must_use]
+    pub fn from_extension_object(extension_object: &ua::ExtensionObject) -> Option<Self> {
+        if let Some(option_set) = extension_object.decoded_content::<Self>() {
+            return Some(option_set.clone());
+        }
+
+        let (_, body) = extension_object.encoded_content_bytestring()?;
+
+        let mut option_set = Self::init();
+        let status_code = ua::StatusCode::new(unsafe {
+            UA_decodeBinary(
+                body.as_ptr(),
+                option_set.as_mut_ptr().cast(),
+                Self::data_type(),
+                ptr::null(),
+            )
+        });
+        status_code.is_good().then_some(option_set)
+    }
+
+    /// Gets bits of option set.
+    ///
+    /// Bit 0 is the least significant bit of the first byte.
+    #[must_use]
+    pub fn bits(&self) -> &[u8] {
+        ua::ByteString::raw_ref(&self.0.value)
+            .as_bytes()
+            .unwrap_or_default()
+    }
+
+    /// Gets bits that are valid, i.e. that have been set explicitly.
+    #[must_use]
+    pub fn valid_bits(&self) -> &[u8] {
+        ua::ByteString::raw_ref(&self.0.validBits)
+            .as_bytes()
+            .unwrap_or_default()
+    }
+
+    /// Checks if bit is set.
+    #[must_use]
+    pub fn is_set(&self, bit: usize) -> bool {
+        get_bit(self.bits(), bit)
+    }
+
+    /// Checks if bit is valid.
+    #[must_use]
+    pub fn is_valid(&self, bit: usize) -> bool {
+        get_bit(self.valid_bits(), bit)
+    }
+
+    /// Gets bits as integer.
+    ///
+    /// Returns `None` when there are more than 64 bits.
+    #[must_use]
+    pub fn to_u64(&self) -> Option<u64> {
+        to_u64(self.bits())
+    }
+
+    /// Gets valid bits as integer.
+    ///
+    /// Returns `None` when there are more than 64 bits.
+    #[must_use]
+    pub fn valid_bits_to_u64(&self) -> Option<u64> {
+        to_u64(self.valid_bits())
+    }
+
+    /// Gets names of set bits.
+    ///
+    /// The names are the values of the `OptionSetValues` property of the option set's data type,
+    /// see [`AsyncClient::read_option_set_names()`]. Set bits without name are skipped.
+    ///
+    /// [`AsyncClient::read_option_set_names()`]: crate::AsyncClient::read_option_set_names
+    #[must_use]
+    pub fn set_names<'a>(&self, names: &'a [ua::LocalizedText]) -> Vec<&'a ua::LocalizedText> {
+        names
+            .iter()
+            .enumerate()
+            .filter(|&(bit, _)| self.is_set(bit))
+            .map(|(_, name)| name)
+            .collect()
+    }
+}
+
+fn get_bit(bytes: &[u8], bit: usize) -> bool {
+    bytes
+        .get(bit / 8)
+        .is_some_and(|byte| byte & (1 << (bit % 8)) != 0)
+}
+
+fn to_u64(bytes: &[u8]) -> Option<u64> {
+    let mut buf = [0; 8];
+    buf.get_mut(..bytes.len())?.copy_from_slice(bytes);
+    Some(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ua;
+
+    #[test]
+    fn option_set_bits() {
+        let option_set = ua::OptionSet::from_u64(0b1010_0000_0001, 0xffff, 2).unwrap();
+        assert_eq!(option_set.bits(), &[0b0000_0001, 0b1010]);
+        assert!(option_set.is_set(0));
+        assert!(!option_set.is_set(1));
+        assert!(option_set.is_set(9));
+        assert!(option_set.is_set(11));
+        assert!(!option_set.is_set(16));
+        assert!(option_set.is_valid(15));
+        assert_eq!(option_set.to_u64(), Some(0b1010_0000_0001));
+
+        // Bits must fit into given length.
+        assert!(ua::OptionSet::from_u64(0x1_0000, 0, 2).is_none());
+
+        let names = ["A", "B", "C"].map(|name| ua::LocalizedText::new("", name).unwrap());
+        let option_set = ua::OptionSet::from_u64(0b101, 0b111, 1).unwrap();
+        let set_names: Vec<_> = option_set
+            .set_names(&names)
+            .into_iter()
+            .filter_map(|name| name.text().as_str())
+            .collect();
+        assert_eq!(set_names, ["A", "C"]);
+    }
+
+    #[test]
+    fn option_set_from_extension_object() {
+        let option_set = ua::OptionSet::from_u64(0b11, 0b11, 1).unwrap();
+        let extension_object = ua::ExtensionObject::new(&option_set);
+        let decoded = ua::OptionSet::from_extension_object(&extension_object).unwrap();
+        assert_eq!(decoded.bits(), &[0b11]);
+    }
+}
@@ -0,0 +1,7 @@
+crate::data_type!(PerformUpdateType);
+
+crate::enum_variants!(
+    PerformUpdateType,
+    UA_PerformUpdateType,
+    [INSERT, REPLACE, UPDATE, REMOVE],
+);
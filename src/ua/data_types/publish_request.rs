@@ -0,0 +1,23 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(PublishRequest);
+
+impl PublishRequest {
+    /// Sets acknowledgements of notification messages that have been received.
+    #[must_use]
+    pub fn with_subscription_acknowledgements(
+        mut self,
+        subscription_acknowledgements: &[ua::SubscriptionAcknowledgement],
+    ) -> Self {
+        let array = ua::Array::from_slice(subscription_acknowledgements);
+        array.move_into_raw(
+            &mut self.0.subscriptionAcknowledgementsSize,
+            &mut self.0.subscriptionAcknowledgements,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for PublishRequest {
+    type Response = ua::PublishResponse;
+}
@@ -0,0 +1,48 @@
+use crate::{ua, DataType as _, ServiceResponse};
+
+crate::data_type!(PublishResponse);
+
+impl PublishResponse {
+    /// Gets ID of subscription that the notification message belongs to.
+    #[must_use]
+    pub const fn subscription_id(&self) -> ua::SubscriptionId {
+        ua::SubscriptionId::new(self.0.subscriptionId)
+    }
+
+    /// Gets sequence numbers of notification messages that are available for retransmission.
+    ///
+    /// These have not been acknowledged yet.
+    #[must_use]
+    pub fn available_sequence_numbers(&self) -> Vec<u32> {
+        let available_sequence_numbers: Option<&[ua::UInt32]> = unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.availableSequenceNumbersSize,
+                self.0.availableSequenceNumbers,
+            )
+        };
+        available_sequence_numbers
+            .unwrap_or_default()
+            .iter()
+            .map(ua::UInt32::value)
+            .collect()
+    }
+
+    /// Checks if the server has more notifications that did not fit into this response.
+    #[must_use]
+    pub const fn more_notifications(&self) -> bool {
+        self.0.moreNotifications
+    }
+
+    #[must_use]
+    pub fn notification_message(&self) -> &ua::NotificationMessage {
+        ua::NotificationMessage::raw_ref(&self.0.notificationMessage)
+    }
+}
+
+impl ServiceResponse for PublishResponse {
+    type Request = ua::PublishRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -1,8 +1,8 @@
-use std::{ffi::CString, fmt};
+use std::fmt;
 
 use open62541_sys::UA_QUALIFIEDNAME_ALLOC;
 
-use crate::{ua, DataType as _};
+use crate::{ua, DataType as _, Error};
 
 crate::data_type!(QualifiedName);
 
@@ -14,7 +14,7 @@ impl QualifiedName {
     /// The string must not contain any NUL bytes.
     #[must_use]
     pub fn new(namespace_index: u16, name: &str) -> Self {
-        let name = CString::new(name).expect("string does not contain NUL bytes");
+        let name = Error::c_string(name, "qualified name").expect("should not contain NUL bytes");
 
         let inner = unsafe { UA_QUALIFIEDNAME_ALLOC(namespace_index, name.as_ptr()) };
         if !name.is_empty() && (inner.name.data.is_null() || inner.name.length == 0) {
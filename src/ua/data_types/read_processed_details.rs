@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use crate::{ua, DataType as _};
+
+crate::data_type!(ReadProcessedDetails);
+
+impl ReadProcessedDetails {
+    #[must_use]
+    pub fn with_start_time(mut self, start_time: &ua::DateTime) -> Self {
+        start_time.clone_into_raw(&mut self.0.startTime);
+        self
+    }
+
+    #[must_use]
+    pub fn with_end_time(mut self, end_time: &ua::DateTime) -> Self {
+        end_time.clone_into_raw(&mut self.0.endTime);
+        self
+    }
+
+    /// Sets length of intervals to compute aggregates for.
+    ///
+    /// With `Duration::ZERO`, a single aggregate is computed for the entire time range.
+    #[must_use]
+    pub fn with_processing_interval(mut self, processing_interval: Duration) -> Self {
+        self.0.processingInterval = processing_interval.as_secs_f64() * 1e3;
+        self
+    }
+
+    /// Sets aggregate functions to use.
+    ///
+    /// This list must have one entry per node to read, e.g.
+    /// [`ua::NodeId::ns0_aggregate_function_average()`].
+    #[must_use]
+    pub fn with_aggregate_type(mut self, aggregate_type: &[ua::NodeId]) -> Self {
+        let array = ua::Array::from_slice(aggregate_type);
+        array.move_into_raw(&mut self.0.aggregateTypeSize, &mut self.0.aggregateType);
+        self
+    }
+
+    #[must_use]
+    pub fn with_aggregate_configuration(
+        mut self,
+        aggregate_configuration: &ua::AggregateConfiguration,
+    ) -> Self {
+        aggregate_configuration.clone_into_raw(&mut self.0.aggregateConfiguration);
+        self
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ReadRawModifiedDetails);
+
+// Reading modified values is not supported: the server returns these as `HistoryModifiedData`.
+
+impl ReadRawModifiedDetails {
+    #[must_use]
+    pub fn with_start_time(mut self, start_time: &ua::DateTime) -> Self {
+        start_time.clone_into_raw(&mut self.0.startTime);
+        self
+    }
+
+    #[must_use]
+    pub fn with_end_time(mut self, end_time: &ua::DateTime) -> Self {
+        end_time.clone_into_raw(&mut self.0.endTime);
+        self
+    }
+
+    /// Sets maximum number of values to return per node.
+    ///
+    /// With `0` (the default), all values in the interval are returned, possibly in several
+    /// responses. See [`ua::HistoryReadValueId::with_continuation_point()`].
+    #[must_use]
+    pub const fn with_num_values_per_node(mut self, num_values_per_node: u32) -> Self {
+        self.0.numValuesPerNode = num_values_per_node;
+        self
+    }
+
+    /// Sets whether to return bounding values, i.e. values just outside of the interval.
+    #[must_use]
+    pub const fn with_return_bounds(mut self, return_bounds: bool) -> Self {
+        self.0.returnBounds = return_bounds;
+        self
+    }
+}
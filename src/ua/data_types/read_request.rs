@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{ua, DataType as _, ServiceRequest};
 
 crate::data_type!(ReadRequest);
@@ -12,6 +14,16 @@ impl ReadRequest {
         self
     }
 
+    /// Sets maximum age of values that the server may return from its cache.
+    ///
+    /// With [`Duration::ZERO`] (the default), the server reads current values from the source.
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        // OPC UA specifies the maximum age in milliseconds.
+        self.0.maxAge = max_age.as_secs_f64() * 1e3;
+        self
+    }
+
     #[must_use]
     pub fn with_nodes_to_read(mut self, nodes_to_read: &[ua::ReadValueId]) -> Self {
         let array = ua::Array::from_slice(nodes_to_read);
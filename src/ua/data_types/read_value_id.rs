@@ -14,4 +14,55 @@ impl ReadValueId {
         self.0.attributeId = attribute_id.as_u32();
         self
     }
+
+    /// Sets index range to read only part of array value, e.g. `1:3`.
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::String) -> Self {
+        index_range.clone_into_raw(&mut self.0.indexRange);
+        self
+    }
+
+    /// Sets data encoding to request structured values in.
+    #[must_use]
+    pub fn with_data_encoding(mut self, data_encoding: &ua::QualifiedName) -> Self {
+        data_encoding.clone_into_raw(&mut self.0.dataEncoding);
+        self
+    }
+
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.nodeId)
+    }
+
+    #[must_use]
+    pub fn attribute_id(&self) -> ua::AttributeId {
+        ua::AttributeId::from_u32(self.0.attributeId)
+    }
+
+    #[must_use]
+    pub fn index_range(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.indexRange)
+    }
+
+    #[must_use]
+    pub fn data_encoding(&self) -> &ua::QualifiedName {
+        ua::QualifiedName::raw_ref(&self.0.dataEncoding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, DataType as _};
+
+    #[test]
+    fn read_value_id_fields() {
+        let read_value_id = ua::ReadValueId::init()
+            .with_node_id(&ua::NodeId::ns0_server_status())
+            .with_attribute_id(&ua::AttributeId::DISPLAYNAME)
+            .with_index_range(&ua::String::new("1:3").unwrap());
+
+        assert_eq!(read_value_id.node_id(), &ua::NodeId::ns0_server_status());
+        assert_eq!(read_value_id.attribute_id(), ua::AttributeId::DISPLAYNAME);
+        assert_eq!(read_value_id.index_range().as_str(), Some("1:3"));
+    }
 }
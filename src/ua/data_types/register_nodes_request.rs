@@ -0,0 +1,16 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(RegisterNodesRequest);
+
+impl RegisterNodesRequest {
+    #[must_use]
+    pub fn with_nodes_to_register(mut self, nodes_to_register: &[ua::NodeId]) -> Self {
+        let array = ua::Array::from_slice(nodes_to_register);
+        array.move_into_raw(&mut self.0.nodesToRegisterSize, &mut self.0.nodesToRegister);
+        self
+    }
+}
+
+impl ServiceRequest for RegisterNodesRequest {
+    type Response = ua::RegisterNodesResponse;
+}
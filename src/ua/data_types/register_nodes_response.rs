@@ -0,0 +1,19 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(RegisterNodesResponse);
+
+impl RegisterNodesResponse {
+    #[must_use]
+    pub fn registered_node_ids(&self) -> Option<ua::Array<ua::NodeId>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        ua::Array::from_raw_parts(self.0.registeredNodeIdsSize, self.0.registeredNodeIds)
+    }
+}
+
+impl ServiceResponse for RegisterNodesResponse {
+    type Request = ua::RegisterNodesRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -9,4 +9,9 @@ impl RelativePath {
         array.move_into_raw(&mut self.0.elementsSize, &mut self.0.elements);
         self
     }
+
+    #[must_use]
+    pub fn elements(&self) -> Option<&[ua::RelativePathElement]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.elementsSize, self.0.elements) }
+    }
 }
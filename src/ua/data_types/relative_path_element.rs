@@ -3,6 +3,38 @@ use crate::{ua, DataType as _};
 crate::data_type!(RelativePathElement);
 
 impl RelativePathElement {
+    /// Creates element that follows hierarchical references to target.
+    ///
+    /// This follows forward references of type `HierarchicalReferences` and its subtypes.
+    #[must_use]
+    pub fn hierarchical(target_name: &ua::QualifiedName) -> Self {
+        Self::forward(&ua::NodeId::ns0_hierarchical_references(), target_name)
+    }
+
+    /// Creates element that follows aggregating references to target.
+    ///
+    /// This follows forward references of type `Aggregates` and its subtypes, i.e. `HasComponent`
+    /// and `HasProperty`.
+    #[must_use]
+    pub fn aggregates(target_name: &ua::QualifiedName) -> Self {
+        Self::forward(&ua::NodeId::ns0_aggregates(), target_name)
+    }
+
+    /// Creates element that follows inverse references to target.
+    ///
+    /// This follows inverse references of the given type and its subtypes.
+    #[must_use]
+    pub fn inverse(reference_type_id: &ua::NodeId, target_name: &ua::QualifiedName) -> Self {
+        Self::forward(reference_type_id, target_name).with_is_inverse(true)
+    }
+
+    fn forward(reference_type_id: &ua::NodeId, target_name: &ua::QualifiedName) -> Self {
+        Self::init()
+            .with_reference_type_id(reference_type_id)
+            .with_include_subtypes(true)
+            .with_target_name(target_name)
+    }
+
     #[must_use]
     pub fn with_reference_type_id(mut self, reference_type_id: &ua::NodeId) -> Self {
         reference_type_id.clone_into_raw(&mut self.0.referenceTypeId);
@@ -26,4 +58,9 @@ impl RelativePathElement {
         target_name.clone_into_raw(&mut self.0.targetName);
         self
     }
+
+    #[must_use]
+    pub fn target_name(&self) -> &ua::QualifiedName {
+        ua::QualifiedName::raw_ref(&self.0.targetName)
+    }
 }
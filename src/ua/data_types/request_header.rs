@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use crate::{ua, DataType as _};
+
+crate::data_type!(RequestHeader);
+
+impl RequestHeader {
+    /// Gets request handle.
+    ///
+    /// Unless set explicitly, this is assigned by the client when the request is sent. It is used
+    /// to cancel pending requests, see [`ua::CancelRequest`](crate::ua::CancelRequest).
+    #[must_use]
+    pub const fn request_handle(&self) -> u32 {
+        self.0.requestHandle
+    }
+
+    /// Sets audit entry ID.
+    ///
+    /// Servers may use this to identify the user or application on whose behalf the request is sent
+    /// when writing audit log entries.
+    #[must_use]
+    pub fn with_audit_entry_id(mut self, audit_entry_id: &ua::String) -> Self {
+        audit_entry_id.clone_into_raw(&mut self.0.auditEntryId);
+        self
+    }
+
+    /// Sets diagnostics to return.
+    ///
+    /// This is a bit mask of diagnostics that the server should return for the request, e.g.
+    /// `0x01` for the symbolic ID and `0x02` for the localized text of service-level diagnostics,
+    /// and `0x20` and `0x40` for the same on the operation level. See OPC UA specification, Part 4,
+    /// section 7.32.
+    #[must_use]
+    pub const fn with_return_diagnostics(mut self, return_diagnostics: u32) -> Self {
+        self.0.returnDiagnostics = return_diagnostics;
+        self
+    }
+
+    /// Gets audit entry ID.
+    #[must_use]
+    pub fn audit_entry_id(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.auditEntryId)
+    }
+
+    /// Gets diagnostics to return.
+    #[must_use]
+    pub const fn return_diagnostics(&self) -> u32 {
+        self.0.returnDiagnostics
+    }
+
+    /// Copies options from other request header.
+    ///
+    /// This takes the audit entry ID and the diagnostics to return from `options`. Other fields are
+    /// left unchanged: they are set by the client for each request.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn set_options(&mut self, options: &Self) {
+        options
+            .audit_entry_id()
+            .clone_into_raw(&mut self.0.auditEntryId);
+        self.0.returnDiagnostics = options.return_diagnostics();
+    }
+
+    /// Sets timeout hint.
+    ///
+    /// The value is rounded down to milliseconds but is at least one millisecond: zero would make
+    /// the client fall back to its default timeout.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn set_timeout_hint(&mut self, timeout_hint: Duration) {
+        self.0.timeoutHint = u32::try_from(timeout_hint.as_millis())
+            .unwrap_or(u32::MAX)
+            .max(1);
+    }
+}
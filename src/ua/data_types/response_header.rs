@@ -0,0 +1,20 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ResponseHeader);
+
+impl ResponseHeader {
+    /// Gets string table.
+    ///
+    /// Diagnostic infos in the response refer to strings in this table by index, see
+    /// [`ua::DiagnosticInfo`].
+    #[must_use]
+    pub fn string_table(&self) -> Option<&[ua::String]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.stringTableSize, self.0.stringTable) }
+    }
+
+    /// Gets diagnostic info of service result.
+    #[must_use]
+    pub fn service_diagnostics(&self) -> &ua::DiagnosticInfo {
+        ua::DiagnosticInfo::raw_ref(&self.0.serviceDiagnostics)
+    }
+}
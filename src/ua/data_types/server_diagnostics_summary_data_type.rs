@@ -0,0 +1,63 @@
+crate::data_type!(ServerDiagnosticsSummaryDataType);
+
+impl ServerDiagnosticsSummaryDataType {
+    #[must_use]
+    pub const fn server_view_count(&self) -> u32 {
+        self.0.serverViewCount
+    }
+
+    #[must_use]
+    pub const fn current_session_count(&self) -> u32 {
+        self.0.currentSessionCount
+    }
+
+    #[must_use]
+    pub const fn cumulated_session_count(&self) -> u32 {
+        self.0.cumulatedSessionCount
+    }
+
+    #[must_use]
+    pub const fn security_rejected_session_count(&self) -> u32 {
+        self.0.securityRejectedSessionCount
+    }
+
+    #[must_use]
+    pub const fn rejected_session_count(&self) -> u32 {
+        self.0.rejectedSessionCount
+    }
+
+    #[must_use]
+    pub const fn session_timeout_count(&self) -> u32 {
+        self.0.sessionTimeoutCount
+    }
+
+    #[must_use]
+    pub const fn session_abort_count(&self) -> u32 {
+        self.0.sessionAbortCount
+    }
+
+    #[must_use]
+    pub const fn current_subscription_count(&self) -> u32 {
+        self.0.currentSubscriptionCount
+    }
+
+    #[must_use]
+    pub const fn cumulated_subscription_count(&self) -> u32 {
+        self.0.cumulatedSubscriptionCount
+    }
+
+    #[must_use]
+    pub const fn publishing_interval_count(&self) -> u32 {
+        self.0.publishingIntervalCount
+    }
+
+    #[must_use]
+    pub const fn security_rejected_requests_count(&self) -> u32 {
+        self.0.securityRejectedRequestsCount
+    }
+
+    #[must_use]
+    pub const fn rejected_requests_count(&self) -> u32 {
+        self.0.rejectedRequestsCount
+    }
+}
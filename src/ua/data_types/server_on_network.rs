@@ -0,0 +1,34 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ServerOnNetwork);
+
+impl ServerOnNetwork {
+    /// Gets record ID.
+    ///
+    /// Record IDs increase with each server that is announced on the network.
+    #[must_use]
+    pub const fn record_id(&self) -> u32 {
+        self.0.recordId
+    }
+
+    #[must_use]
+    pub fn server_name(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.serverName)
+    }
+
+    #[must_use]
+    pub fn discovery_url(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.discoveryUrl)
+    }
+
+    /// Gets server capabilities, e.g. `DA` or `HD`.
+    #[must_use]
+    pub fn server_capabilities(&self) -> Option<&[ua::String]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.serverCapabilitiesSize,
+                self.0.serverCapabilities,
+            )
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(SetTriggeringRequest);
+
+impl SetTriggeringRequest {
+    #[must_use]
+    pub const fn with_subscription_id(mut self, subscription_id: ua::SubscriptionId) -> Self {
+        self.0.subscriptionId = subscription_id.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_triggering_item_id(
+        mut self,
+        triggering_item_id: ua::MonitoredItemId,
+    ) -> Self {
+        self.0.triggeringItemId = triggering_item_id.as_u32();
+        self
+    }
+
+    #[must_use]
+    pub fn with_links_to_add(mut self, links_to_add: &[ua::MonitoredItemId]) -> Self {
+        let array = ua::Array::from_iter(
+            links_to_add
+                .iter()
+                .map(|monitored_item_id| monitored_item_id.to_uint32()),
+        );
+        array.move_into_raw(&mut self.0.linksToAddSize, &mut self.0.linksToAdd);
+        self
+    }
+
+    #[must_use]
+    pub fn with_links_to_remove(mut self, links_to_remove: &[ua::MonitoredItemId]) -> Self {
+        let array = ua::Array::from_iter(
+            links_to_remove
+                .iter()
+                .map(|monitored_item_id| monitored_item_id.to_uint32()),
+        );
+        array.move_into_raw(&mut self.0.linksToRemoveSize, &mut self.0.linksToRemove);
+        self
+    }
+}
+
+impl ServiceRequest for SetTriggeringRequest {
+    type Response = ua::SetTriggeringResponse;
+}
@@ -0,0 +1,71 @@
+use crate::{ua, DataType as _, ServiceResponse};
+
+crate::data_type!(SetTriggeringResponse);
+
+impl SetTriggeringResponse {
+    /// Gets results of links to add.
+    #[must_use]
+    pub fn add_results(&self) -> Option<Vec<ua::StatusCode>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        let array: ua::Array<ua::UInt32> =
+            ua::Array::from_raw_parts(self.0.addResultsSize, self.0.addResults)?;
+        Some(
+            array
+                .as_slice()
+                .iter()
+                .map(|status_code| ua::StatusCode::new(status_code.clone().into_raw()))
+                .collect(),
+        )
+    }
+
+    /// Gets diagnostic infos of links to add.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn add_diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.addDiagnosticInfosSize,
+                self.0.addDiagnosticInfos,
+            )
+        }
+    }
+
+    /// Gets results of links to remove.
+    #[must_use]
+    pub fn remove_results(&self) -> Option<Vec<ua::StatusCode>> {
+        // TODO: Adjust signature to return non-owned value instead.
+        let array: ua::Array<ua::UInt32> =
+            ua::Array::from_raw_parts(self.0.removeResultsSize, self.0.removeResults)?;
+        Some(
+            array
+                .as_slice()
+                .iter()
+                .map(|status_code| ua::StatusCode::new(status_code.clone().into_raw()))
+                .collect(),
+        )
+    }
+
+    /// Gets diagnostic infos of links to remove.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn remove_diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.removeDiagnosticInfosSize,
+                self.0.removeDiagnosticInfos,
+            )
+        }
+    }
+}
+
+impl ServiceResponse for SetTriggeringResponse {
+    type Request = ua::SetTriggeringRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
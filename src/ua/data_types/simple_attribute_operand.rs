@@ -3,12 +3,22 @@ use crate::{ua, DataType, FilterOperand};
 crate::data_type!(SimpleAttributeOperand);
 
 impl SimpleAttributeOperand {
+    #[must_use]
+    pub fn type_definition_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.typeDefinitionId)
+    }
+
     #[must_use]
     pub fn with_type_definition_id(mut self, type_definition_id: ua::NodeId) -> Self {
         type_definition_id.move_into_raw(&mut self.0.typeDefinitionId);
         self
     }
 
+    #[must_use]
+    pub fn browse_path(&self) -> Option<&[ua::QualifiedName]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.browsePathSize, self.0.browsePath) }
+    }
+
     #[must_use]
     pub fn with_browse_path(mut self, browse_path: &[ua::QualifiedName]) -> Self {
         let array = ua::Array::from_slice(browse_path);
@@ -16,6 +26,11 @@ impl SimpleAttributeOperand {
         self
     }
 
+    #[must_use]
+    pub fn attribute_id(&self) -> ua::AttributeId {
+        ua::AttributeId::from_u32(self.0.attributeId)
+    }
+
     #[must_use]
     pub fn with_attribute_id(mut self, attribute_id: &ua::AttributeId) -> Self {
         self.0.attributeId = attribute_id.as_u32();
@@ -0,0 +1,11 @@
+use crate::ua;
+
+crate::data_type!(StatusChangeNotification);
+
+impl StatusChangeNotification {
+    /// Gets status of subscription.
+    #[must_use]
+    pub const fn status(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.status)
+    }
+}
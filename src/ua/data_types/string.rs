@@ -1,4 +1,4 @@
-use std::{ffi::CString, fmt, ptr, slice, str};
+use std::{fmt, ptr, slice, str};
 
 use open62541_sys::UA_String_fromChars;
 
@@ -18,8 +18,7 @@ impl String {
     pub fn new(s: &str) -> Result<Self, Error> {
         // We do not know for sure if `open62541` handles strings with contained NUL bytes correctly
         // in all situations. We avoid this entirely (at least for now). We may revisit this later.
-        let src =
-            CString::new(s).map_err(|_| Error::internal("string should not contain NUL bytes"))?;
+        let src = Error::c_string(s, "string")?;
         let str = unsafe { UA_String_fromChars(src.as_ptr()) };
         Ok(Self(str))
     }
@@ -154,6 +153,12 @@ mod tests {
         assert_eq!(str.to_string(), "");
     }
 
+    #[test]
+    fn nul_byte_string() {
+        let result = ua::String::new("lorem\0ipsum");
+        assert!(matches!(result, Err(crate::Error::NulByte(_))));
+    }
+
     #[test]
     fn pad_string() {
         let str = ua::String::new("hello").unwrap();
@@ -0,0 +1,24 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(SubscriptionAcknowledgement);
+
+impl SubscriptionAcknowledgement {
+    /// Creates acknowledgement of notification message.
+    #[must_use]
+    pub fn new(subscription_id: ua::SubscriptionId, sequence_number: u32) -> Self {
+        let mut subscription_acknowledgement = Self::init();
+        subscription_acknowledgement.0.subscriptionId = subscription_id.as_u32();
+        subscription_acknowledgement.0.sequenceNumber = sequence_number;
+        subscription_acknowledgement
+    }
+
+    #[must_use]
+    pub const fn subscription_id(&self) -> ua::SubscriptionId {
+        ua::SubscriptionId::new(self.0.subscriptionId)
+    }
+
+    #[must_use]
+    pub const fn sequence_number(&self) -> u32 {
+        self.0.sequenceNumber
+    }
+}
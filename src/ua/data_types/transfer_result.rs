@@ -0,0 +1,29 @@
+use crate::ua;
+
+crate::data_type!(TransferResult);
+
+impl TransferResult {
+    #[must_use]
+    pub const fn status_code(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.statusCode)
+    }
+
+    /// Gets sequence numbers of notifications that are available for retransmission.
+    ///
+    /// These may be requested with the `Republish` service to fill gaps in the notifications that
+    /// have been received before the transfer.
+    #[must_use]
+    pub fn available_sequence_numbers(&self) -> Vec<u32> {
+        let available_sequence_numbers: Option<&[ua::UInt32]> = unsafe {
+            ua::Array::slice_from_raw_parts(
+                self.0.availableSequenceNumbersSize,
+                self.0.availableSequenceNumbers,
+            )
+        };
+        available_sequence_numbers
+            .unwrap_or_default()
+            .iter()
+            .map(ua::UInt32::value)
+            .collect()
+    }
+}
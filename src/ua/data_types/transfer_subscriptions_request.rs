@@ -0,0 +1,27 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(TransferSubscriptionsRequest);
+
+impl TransferSubscriptionsRequest {
+    #[must_use]
+    pub fn with_subscription_ids(mut self, subscription_ids: &[ua::SubscriptionId]) -> Self {
+        let array = ua::Array::from_iter(
+            subscription_ids
+                .iter()
+                .map(|subscription_id| subscription_id.to_uint32()),
+        );
+        array.move_into_raw(&mut self.0.subscriptionIdsSize, &mut self.0.subscriptionIds);
+        self
+    }
+
+    /// Sets whether server sends current values of all monitored items after the transfer.
+    #[must_use]
+    pub const fn with_send_initial_values(mut self, send_initial_values: bool) -> Self {
+        self.0.sendInitialValues = send_initial_values;
+        self
+    }
+}
+
+impl ServiceRequest for TransferSubscriptionsRequest {
+    type Response = ua::TransferSubscriptionsResponse;
+}
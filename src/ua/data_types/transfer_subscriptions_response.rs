@@ -0,0 +1,30 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(TransferSubscriptionsResponse);
+
+impl TransferSubscriptionsResponse {
+    /// Gets results of individual subscriptions.
+    #[must_use]
+    pub fn results(&self) -> Option<&[ua::TransferResult]> {
+        unsafe { ua::Array::slice_from_raw_parts(self.0.resultsSize, self.0.results) }
+    }
+
+    /// Gets diagnostic infos of individual results.
+    ///
+    /// Servers return these only when requested, see
+    /// [`ua::RequestHeader::with_return_diagnostics()`].
+    #[must_use]
+    pub fn diagnostic_infos(&self) -> Option<&[ua::DiagnosticInfo]> {
+        unsafe {
+            ua::Array::slice_from_raw_parts(self.0.diagnosticInfosSize, self.0.diagnosticInfos)
+        }
+    }
+}
+
+impl ServiceResponse for TransferSubscriptionsResponse {
+    type Request = ua::TransferSubscriptionsRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{ua, ServiceRequest};
+
+crate::data_type!(UnregisterNodesRequest);
+
+impl UnregisterNodesRequest {
+    #[must_use]
+    pub fn with_nodes_to_unregister(mut self, nodes_to_unregister: &[ua::NodeId]) -> Self {
+        let array = ua::Array::from_slice(nodes_to_unregister);
+        array.move_into_raw(
+            &mut self.0.nodesToUnregisterSize,
+            &mut self.0.nodesToUnregister,
+        );
+        self
+    }
+}
+
+impl ServiceRequest for UnregisterNodesRequest {
+    type Response = ua::UnregisterNodesResponse;
+}
@@ -0,0 +1,11 @@
+use crate::{ua, ServiceResponse};
+
+crate::data_type!(UnregisterNodesResponse);
+
+impl ServiceResponse for UnregisterNodesResponse {
+    type Request = ua::UnregisterNodesRequest;
+
+    fn service_result(&self) -> ua::StatusCode {
+        ua::StatusCode::new(self.0.responseHeader.serviceResult)
+    }
+}
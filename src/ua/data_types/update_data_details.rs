@@ -0,0 +1,31 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(UpdateDataDetails);
+
+impl UpdateDataDetails {
+    #[must_use]
+    pub fn with_node_id(mut self, node_id: &ua::NodeId) -> Self {
+        node_id.clone_into_raw(&mut self.0.nodeId);
+        self
+    }
+
+    /// Sets how values are applied to the history.
+    ///
+    /// With [`ua::PerformUpdateType::INSERT`], values are only added when there is no value at the
+    /// same source timestamp yet.
+    #[must_use]
+    pub fn with_perform_insert_replace(
+        mut self,
+        perform_insert_replace: &ua::PerformUpdateType,
+    ) -> Self {
+        perform_insert_replace.clone_into_raw(&mut self.0.performInsertReplace);
+        self
+    }
+
+    #[must_use]
+    pub fn with_update_values(mut self, update_values: &[ua::DataValue]) -> Self {
+        let array = ua::Array::from_slice(update_values);
+        array.move_into_raw(&mut self.0.updateValuesSize, &mut self.0.updateValues);
+        self
+    }
+}
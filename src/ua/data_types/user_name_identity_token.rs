@@ -35,4 +35,38 @@ impl UserNameIdentityToken {
             .move_into_raw(&mut self.0.password);
         self
     }
+
+    /// Sets password from raw bytes.
+    ///
+    /// Unlike [`with_password()`](Self::with_password), this copies the password directly into
+    /// memory owned by `open62541`, without any intermediate copies.
+    #[must_use]
+    pub(crate) fn with_password_bytes(mut self, password: &[u8]) -> Self {
+        ua::ByteString::new(password).move_into_raw(&mut self.0.password);
+        self
+    }
+
+    /// Gets user name.
+    #[must_use]
+    pub fn user_name(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.userName)
+    }
+
+    /// Zeroizes password.
+    ///
+    /// This overwrites the password in memory with zeroes before it is released.
+    pub(crate) fn zeroize_password(&mut self) {
+        let password = ua::ByteString::raw_mut(&mut self.0.password);
+        password.mem_zero();
+        password.clear();
+    }
+
+    /// Zeroizes password of token in extension object.
+    ///
+    /// This does nothing when the extension object holds another type of token.
+    pub(crate) fn zeroize_password_in(user_identity_token: &mut ua::ExtensionObject) {
+        if let Some(token) = user_identity_token.decoded_content_mut::<Self>() {
+            token.zeroize_password();
+        }
+    }
 }
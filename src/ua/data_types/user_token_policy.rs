@@ -0,0 +1,37 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(UserTokenPolicy);
+
+impl UserTokenPolicy {
+    /// Gets policy ID.
+    ///
+    /// This identifies the policy on the server. User identity tokens must carry this ID.
+    #[must_use]
+    pub fn policy_id(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.policyId)
+    }
+
+    #[must_use]
+    pub fn token_type(&self) -> &ua::UserTokenType {
+        ua::UserTokenType::raw_ref(&self.0.tokenType)
+    }
+
+    #[must_use]
+    pub fn issued_token_type(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.issuedTokenType)
+    }
+
+    #[must_use]
+    pub fn issuer_endpoint_url(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.issuerEndpointUrl)
+    }
+
+    /// Gets security policy URI.
+    ///
+    /// This is used to encrypt the user identity token. When empty, the security policy of the
+    /// endpoint is used.
+    #[must_use]
+    pub fn security_policy_uri(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.securityPolicyUri)
+    }
+}
@@ -1,11 +1,12 @@
 use std::ffi::c_void;
 
 use open62541_sys::{
-    UA_Variant_clear, UA_Variant_hasArrayType, UA_Variant_hasScalarType, UA_Variant_isEmpty,
-    UA_Variant_isScalar, UA_Variant_setArray, UA_Variant_setScalar, UA_Variant_setScalarCopy,
+    UA_Variant_clear, UA_Variant_copyRange, UA_Variant_hasArrayType, UA_Variant_hasScalarType,
+    UA_Variant_isEmpty, UA_Variant_isScalar, UA_Variant_setArray, UA_Variant_setScalar,
+    UA_Variant_setScalarCopy,
 };
 
-use crate::{ua, DataType, NonScalarValue, ScalarValue, ValueType, VariantValue};
+use crate::{ua, DataType, Error, NonScalarValue, ScalarValue, ValueType, VariantValue};
 
 crate::data_type!(Variant);
 
@@ -131,6 +132,46 @@ impl Variant {
         ua::Array::from_raw_parts(self.0.arrayLength, self.0.data.cast::<T::Inner>())
     }
 
+    /// Copies part of array value.
+    ///
+    /// This returns a variant with only the elements of the array value that are selected by
+    /// `index_range`. Use this in [`DataSource::read()`] to apply the index range requested by the
+    /// client.
+    ///
+    /// [`DataSource::read()`]: crate::DataSource::read
+    ///
+    /// # Errors
+    ///
+    /// This fails when the value is not an array or when the range lies outside of its bounds, e.g.
+    /// with [`ua::StatusCode::BADINDEXRANGENODATA`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let value = ua::Variant::array(ua::Array::from_slice(&[
+    ///     ua::Int32::new(0),
+    ///     ua::Int32::new(1),
+    ///     ua::Int32::new(2),
+    ///     ua::Int32::new(3),
+    /// ]));
+    /// let range: ua::NumericRange = "1:2".parse()?;
+    ///
+    /// let slice = value.slice_by_range(&range)?;
+    /// let slice = slice.to_array::<ua::Int32>().unwrap();
+    /// assert_eq!(slice.as_slice(), &[ua::Int32::new(1), ua::Int32::new(2)]);
+    /// # Ok::<(), open62541::Error>(())
+    /// ```
+    pub fn slice_by_range(&self, index_range: &ua::NumericRange) -> Result<Self, Error> {
+        let mut variant = Self::init();
+        let status_code = ua::StatusCode::new(index_range.with_raw(|range| unsafe {
+            UA_Variant_copyRange(self.as_ptr(), variant.as_mut_ptr(), range)
+        }));
+        Error::verify_good(&status_code)?;
+        Ok(variant)
+    }
+
     #[must_use]
     pub fn to_value(&self) -> VariantValue {
         if self.is_empty() {
@@ -167,12 +208,14 @@ impl Variant {
             String,         // Data type ns=0;i=12
             DateTime,       // Data type ns=0;i=13
             ByteString,     // Data type ns=0;i=15
+            XmlElement,     // Data type ns=0;i=16
             NodeId,         // Data type ns=0;i=17
             ExpandedNodeId, // Data type ns=0;i=18
             StatusCode,     // Data type ns=0;i=19
             QualifiedName,  // Data type ns=0;i=20
             LocalizedText,  // Data type ns=0;i=21
             Argument,       // Data type ns=0;i=296
+            OptionSet,      // Data type ns=0;i=12755
         );
 
         VariantValue::Scalar(ScalarValue::Unsupported)
@@ -185,6 +228,32 @@ impl Variant {
     }
 }
 
+impl TryFrom<&str> for Variant {
+    type Error = Error;
+
+    /// Creates variant from string slice.
+    ///
+    /// This fails when the string contains NUL bytes, see [`ua::String::new()`].
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ua::String::new(value).map(Self::scalar)
+    }
+}
+
+impl TryFrom<Vec<&str>> for Variant {
+    type Error = Error;
+
+    /// Creates variant from string slices.
+    ///
+    /// This fails when any of the strings contains NUL bytes, see [`ua::String::new()`].
+    fn try_from(values: Vec<&str>) -> Result<Self, Self::Error> {
+        let values = values
+            .into_iter()
+            .map(ua::String::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::array(ua::Array::from_slice(&values)))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Variant {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -227,6 +296,7 @@ impl serde::Serialize for Variant {
                 #[cfg(feature = "time")]
                 DateTime, // Data type ns=0;i=13
                 ByteString, // Data type ns=0;i=15
+                XmlElement, // Data type ns=0;i=16
                 NodeId,  // Data type ns=0;i=17
             ],
         );
@@ -239,6 +309,7 @@ impl serde::Serialize for Variant {
         // - QualifiedName,  // Data type ns=0;i=20
         // - LocalizedText,  // Data type ns=0;i=21
         // - Argument,       // Data type ns=0;i=296
+        // - OptionSet,      // Data type ns=0;i=12755
 
         Err(serde::ser::Error::custom("non-primitive value in Variant"))
     }
@@ -323,6 +394,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_rust_values() {
+        assert_eq!(
+            ua::Variant::from(true),
+            ua::Variant::scalar(ua::Boolean::new(true))
+        );
+        assert_eq!(
+            ua::Variant::from(-5_i16),
+            ua::Variant::scalar(ua::Int16::new(-5))
+        );
+        assert_eq!(
+            ua::Variant::from(1.5_f64),
+            ua::Variant::scalar(ua::Double::new(1.5))
+        );
+        assert_eq!(
+            ua::Variant::from(vec![1_u32, 2]),
+            ua::Variant::array(ua::Array::from_slice(&[1, 2].map(ua::UInt32::new)))
+        );
+        assert_eq!(
+            ua::Variant::try_from("lorem").unwrap(),
+            ua::Variant::scalar(ua::String::new("lorem").unwrap())
+        );
+        assert_eq!(
+            ua::Variant::try_from(vec!["lorem", "ipsum"])
+                .unwrap()
+                .to_array::<ua::String>()
+                .map(ua::Array::into_vec),
+            Some(vec![
+                ua::String::new("lorem").unwrap(),
+                ua::String::new("ipsum").unwrap(),
+            ])
+        );
+        assert!(ua::Variant::try_from("lorem\0ipsum").is_err());
+    }
+
     #[test]
     fn compare_variant() {
         // Variants of same type compare as expected.
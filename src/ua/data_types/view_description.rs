@@ -0,0 +1,38 @@
+use crate::{ua, DataType as _};
+
+crate::data_type!(ViewDescription);
+
+impl ViewDescription {
+    #[must_use]
+    pub fn with_view_id(mut self, view_id: &ua::NodeId) -> Self {
+        view_id.clone_into_raw(&mut self.0.viewId);
+        self
+    }
+
+    #[must_use]
+    pub fn with_timestamp(mut self, timestamp: &ua::DateTime) -> Self {
+        timestamp.clone_into_raw(&mut self.0.timestamp);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_view_version(mut self, view_version: u32) -> Self {
+        self.0.viewVersion = view_version;
+        self
+    }
+
+    #[must_use]
+    pub fn view_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.viewId)
+    }
+
+    #[must_use]
+    pub fn timestamp(&self) -> &ua::DateTime {
+        ua::DateTime::raw_ref(&self.0.timestamp)
+    }
+
+    #[must_use]
+    pub const fn view_version(&self) -> u32 {
+        self.0.viewVersion
+    }
+}
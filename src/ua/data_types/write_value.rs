@@ -15,9 +15,36 @@ impl WriteValue {
         self
     }
 
+    /// Sets index range to write only part of array value, e.g. `1:3`.
+    #[must_use]
+    pub fn with_index_range(mut self, index_range: &ua::String) -> Self {
+        index_range.clone_into_raw(&mut self.0.indexRange);
+        self
+    }
+
     #[must_use]
     pub fn with_value(mut self, value: &ua::DataValue) -> Self {
         value.clone_into_raw(&mut self.0.value);
         self
     }
+
+    #[must_use]
+    pub fn node_id(&self) -> &ua::NodeId {
+        ua::NodeId::raw_ref(&self.0.nodeId)
+    }
+
+    #[must_use]
+    pub fn attribute_id(&self) -> ua::AttributeId {
+        ua::AttributeId::from_u32(self.0.attributeId)
+    }
+
+    #[must_use]
+    pub fn index_range(&self) -> &ua::String {
+        ua::String::raw_ref(&self.0.indexRange)
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &ua::DataValue {
+        ua::DataValue::raw_ref(&self.0.value)
+    }
 }
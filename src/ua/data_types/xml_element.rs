@@ -0,0 +1,111 @@
+use std::{fmt, slice, str};
+
+use open62541_sys::UA_String_fromChars;
+
+use crate::{ArrayValue, Error};
+
+// Technically, `open62541_sys::XmlElement` is an alias for `open62541_sys::String`. But we treat it
+// as a distinct type so that variants of this type are told apart from regular strings.
+crate::data_type!(XmlElement);
+
+// In the implementation below, remember that `self.0.data` may be `UA_EMPTY_ARRAY_SENTINEL` for any
+// strings of `length` 0. It may also be `ptr::null()` for "invalid" strings. This is similar to how
+// OPC UA treats arrays (which also distinguishes between empty and invalid instances).
+impl XmlElement {
+    /// Creates XML element from string slice.
+    ///
+    /// The contents are not checked to be well-formed XML.
+    ///
+    /// # Errors
+    ///
+    /// The string must not contain any NUL bytes.
+    pub fn new(s: &str) -> Result<Self, Error> {
+        let src = Error::c_string(s, "XML element")?;
+        let str = unsafe { UA_String_fromChars(src.as_ptr()) };
+        Ok(Self(str))
+    }
+
+    /// Checks if XML element is invalid.
+    ///
+    /// The invalid state is defined by OPC UA. It is a third state which is distinct from empty and
+    /// regular (non-empty) XML elements.
+    #[must_use]
+    pub fn is_invalid(&self) -> bool {
+        matches!(self.array_value(), ArrayValue::Invalid)
+    }
+
+    /// Checks if XML element is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        matches!(self.array_value(), ArrayValue::Empty)
+    }
+
+    /// Returns XML element contents as byte slice.
+    ///
+    /// This may return [`None`] when the XML element itself is invalid (as defined by OPC UA).
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self.array_value() {
+            ArrayValue::Invalid => None,
+            ArrayValue::Empty => Some(&[]),
+            ArrayValue::Valid(data) => {
+                // `self.0.data` is valid, so we may use `self.0.length` now.
+                Some(unsafe { slice::from_raw_parts(data.as_ptr(), self.0.length) })
+            }
+        }
+    }
+
+    /// Returns XML element contents as string slice.
+    ///
+    /// This may return [`None`] when the XML element itself is invalid (as defined by OPC UA) or
+    /// when it is not valid Unicode (UTF-8).
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_bytes().and_then(|slice| str::from_utf8(slice).ok())
+    }
+
+    fn array_value(&self) -> ArrayValue<u8> {
+        // Internally, `open62541` represents XML elements as `Byte` array and has the same special
+        // cases as regular arrays, i.e. empty and invalid states.
+        ArrayValue::from_ptr(self.0.data)
+    }
+}
+
+impl fmt::Display for XmlElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Display invalid XML elements as empty strings.
+        self.as_str().unwrap_or("").fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for XmlElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str()
+            .ok_or(serde::ser::Error::custom("XmlElement should be valid"))
+            .and_then(|str| serializer.serialize_str(str))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, ScalarValue, ValueType, VariantValue};
+
+    #[test]
+    fn xml_element_variant() {
+        let xml = ua::XmlElement::new("<a>b</a>").expect("should create XML element");
+        assert_eq!(xml.as_str(), Some("<a>b</a>"));
+
+        let variant = ua::Variant::scalar(xml);
+        assert_eq!(variant.value_type(), Some(ValueType::XmlElement));
+        // XML elements must not be mistaken for regular strings.
+        assert!(variant.as_scalar::<ua::String>().is_none());
+        let VariantValue::Scalar(ScalarValue::XmlElement(xml)) = variant.to_value() else {
+            panic!("should be XML element");
+        };
+        assert_eq!(xml.to_string(), "<a>b</a>");
+    }
+}
@@ -11,7 +11,32 @@ pub(crate) struct Logger(NonNull<UA_Logger>);
 impl Logger {
     /// Creates logger that forwards to the `log` crate.
     pub(crate) fn rust_log() -> Self {
-        rust_log::logger()
+        rust_log::logger(rust_log::LOG_TARGET)
+    }
+
+    /// Creates logger that forwards to the `log` crate, with custom target.
+    ///
+    /// The target is extended by the log category of `open62541` (e.g. `open62541::client::network`
+    /// for target `open62541::client`).
+    pub(crate) fn rust_log_with_target(target: &'static str) -> Self {
+        rust_log::logger(target)
+    }
+
+    /// Sets whether logger forwards to the `log` crate.
+    ///
+    /// When disabled, log messages are passed to the default logger of `open62541` instead, which
+    /// writes them to stdout.
+    ///
+    /// # Safety
+    ///
+    /// The logger must have been created by [`rust_log()`] or [`rust_log_with_target()`] and must
+    /// still be valid (i.e. its `clear()` callback must not have been called).
+    ///
+    /// [`rust_log()`]: Self::rust_log
+    /// [`rust_log_with_target()`]: Self::rust_log_with_target
+    pub(crate) unsafe fn set_log_to_log_crate(logger: *const UA_Logger, log_to_log_crate: bool) {
+        // SAFETY: We require the same safety guarantees from our callers.
+        unsafe { rust_log::set_log_to_log_crate(logger, log_to_log_crate) };
     }
 
     /// Creates wrapper by taking ownership of value.
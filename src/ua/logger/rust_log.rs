@@ -1,28 +1,79 @@
 use std::{
     ffi::{c_char, c_void, CStr},
-    ptr,
+    sync::atomic::{AtomicBool, Ordering},
 };
 
-use open62541_sys::{vsnprintf_va_copy, vsnprintf_va_end, UA_LogCategory, UA_LogLevel, UA_Logger};
+use open62541_sys::{
+    vsnprintf_va_copy, vsnprintf_va_end, UA_LogCategory, UA_LogLevel, UA_Log_Stdout_, UA_Logger,
+};
 
 use crate::ua;
 
-const LOG_TARGET: &str = "open62541_sys";
+/// Default target of log messages.
+pub(crate) const LOG_TARGET: &str = "open62541_sys";
+
+/// Context of logger.
+///
+/// This is owned by the `UA_Logger` structure and released in its `clear()` callback.
+struct LogContext {
+    /// Target of log messages, extended by the log category.
+    target: &'static str,
+    /// Whether to forward to the `log` crate (or to the default logger of `open62541`).
+    log_to_log_crate: AtomicBool,
+}
 
 /// Creates logger that forwards to the `log` crate.
 ///
 /// We can use this to prevent `open62541` from installing its own default logger (which outputs any
 /// logs to stdout/stderr directly).
-pub(crate) fn logger() -> ua::Logger {
+///
+/// Log messages are emitted with the given target, extended by the log category of `open62541`
+/// (e.g. `open62541::client::network`).
+pub(crate) fn logger(target: &'static str) -> ua::Logger {
     unsafe extern "C" fn log_c(
-        _log_context: *mut c_void,
+        log_context: *mut c_void,
         level: UA_LogLevel,
-        _category: UA_LogCategory,
+        category: UA_LogCategory,
         msg: *const c_char,
         args: open62541_sys::va_list_,
     ) {
+        // SAFETY: The context has been created in `logger()` and lives until `clear_c()` is called.
+        let Some(context) = (unsafe { log_context.cast::<LogContext>().as_ref() }) else {
+            return;
+        };
+
+        if !context.log_to_log_crate.load(Ordering::Relaxed) {
+            // SAFETY: The default logger is a static value without state that must be cleaned up.
+            if let Some(log) = unsafe { UA_Log_Stdout_.log } {
+                unsafe { log(UA_Log_Stdout_.context, level, category, msg, args) };
+            }
+            return;
+        }
+
+        let level = match level {
+            // Without fatal level in `log`, fall back to error.
+            UA_LogLevel::UA_LOGLEVEL_FATAL | UA_LogLevel::UA_LOGLEVEL_ERROR => log::Level::Error,
+            UA_LogLevel::UA_LOGLEVEL_WARNING => log::Level::Warn,
+            UA_LogLevel::UA_LOGLEVEL_INFO => log::Level::Info,
+            UA_LogLevel::UA_LOGLEVEL_DEBUG => log::Level::Debug,
+            UA_LogLevel::UA_LOGLEVEL_TRACE => log::Level::Trace,
+            // Handle unexpected level by escalating to error.
+            _ => log::Level::Error,
+        };
+
+        // Check level before allocating target and message. `open62541` logs a lot at lower levels
+        // that are usually disabled.
+        if level > log::max_level() {
+            return;
+        }
+        let target = format!("{}::{}", context.target, category_name(category));
+        let target = target.as_str();
+        if !log::log_enabled!(target: target, level) {
+            return;
+        }
+
         let Some(msg) = format_message(msg, args) else {
-            log::error!(target: LOG_TARGET, "Unknown log message");
+            log::error!(target: target, "Unknown log message");
             return;
         };
 
@@ -30,23 +81,7 @@ pub(crate) fn logger() -> ua::Logger {
             .unwrap_or(c"Invalid log message")
             .to_string_lossy();
 
-        if level == UA_LogLevel::UA_LOGLEVEL_FATAL {
-            // Without fatal level in `log`, fall back to error.
-            log::error!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_ERROR {
-            log::error!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_WARNING {
-            log::warn!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_INFO {
-            log::info!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_DEBUG {
-            log::debug!(target: LOG_TARGET, "{msg}");
-        } else if level == UA_LogLevel::UA_LOGLEVEL_TRACE {
-            log::trace!(target: LOG_TARGET, "{msg}");
-        } else {
-            // Handle unexpected level by escalating to error.
-            log::error!(target: LOG_TARGET, "{msg}");
-        }
+        log::log!(target: target, level, "{msg}");
     }
 
     unsafe extern "C" fn clear_c(logger: *mut UA_Logger) {
@@ -63,8 +98,9 @@ pub(crate) fn logger() -> ua::Logger {
         debug_assert!(logger.log == Some(log_c));
         debug_assert!(logger.clear == Some(clear_c));
 
-        // As long as we do not carry data, there is nothing to clean up here.
-        debug_assert!(logger.context.is_null());
+        // Release the context that has been created along with the logger.
+        debug_assert!(!logger.context.is_null());
+        drop(unsafe { Box::from_raw(logger.context.cast::<LogContext>()) });
 
         // Dropping the boxed logger cleans up allocated memory.
         drop(logger);
@@ -72,11 +108,16 @@ pub(crate) fn logger() -> ua::Logger {
 
     log::debug!("Creating `log` logger");
 
+    let context = Box::new(LogContext {
+        target,
+        log_to_log_crate: AtomicBool::new(true),
+    });
+
     // Create logger configuration. We leak the memory which is cleaned up eventually when `clear()`
     // is called (which is `clear_c()` above).
     let logger = Box::leak(Box::new(UA_Logger {
         log: Some(log_c),
-        context: ptr::null_mut(),
+        context: Box::into_raw(context).cast::<c_void>(),
         clear: Some(clear_c),
     }));
 
@@ -84,6 +125,42 @@ pub(crate) fn logger() -> ua::Logger {
     unsafe { ua::Logger::from_raw(logger) }
 }
 
+/// Sets whether logger forwards to the `log` crate.
+///
+/// # Safety
+///
+/// The logger must have been created by [`logger()`] and must not have been cleared.
+pub(crate) unsafe fn set_log_to_log_crate(logger: *const UA_Logger, log_to_log_crate: bool) {
+    // SAFETY: We require the same safety guarantees from our callers.
+    let Some(logger) = (unsafe { logger.as_ref() }) else {
+        return;
+    };
+    // SAFETY: The context has been created in `logger()` and lives as long as the logger.
+    let Some(context) = (unsafe { logger.context.cast::<LogContext>().as_ref() }) else {
+        return;
+    };
+    context
+        .log_to_log_crate
+        .store(log_to_log_crate, Ordering::Relaxed);
+}
+
+/// Gets name of log category to be used in log target.
+const fn category_name(category: UA_LogCategory) -> &'static str {
+    match category {
+        UA_LogCategory::UA_LOGCATEGORY_NETWORK => "network",
+        UA_LogCategory::UA_LOGCATEGORY_SECURECHANNEL => "secure_channel",
+        UA_LogCategory::UA_LOGCATEGORY_SESSION => "session",
+        UA_LogCategory::UA_LOGCATEGORY_SERVER => "server",
+        UA_LogCategory::UA_LOGCATEGORY_CLIENT => "client",
+        UA_LogCategory::UA_LOGCATEGORY_USERLAND => "userland",
+        UA_LogCategory::UA_LOGCATEGORY_SECURITYPOLICY => "security_policy",
+        UA_LogCategory::UA_LOGCATEGORY_EVENTLOOP => "event_loop",
+        UA_LogCategory::UA_LOGCATEGORY_PUBSUB => "pubsub",
+        UA_LogCategory::UA_LOGCATEGORY_DISCOVERY => "discovery",
+        _ => "unknown",
+    }
+}
+
 /// Initial buffer size when formatting messages.
 const FORMAT_MESSAGE_DEFAULT_BUFFER_LEN: usize = 128;
 
@@ -151,3 +228,52 @@ fn format_message(msg: *const c_char, args: open62541_sys::va_list_) -> Option<V
 
     Some(msg_buffer)
 }
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use crate::{ua, TestHarness};
+
+    use super::LOG_TARGET;
+
+    /// Captures log records of `open62541` servers, with only error to info levels enabled.
+    struct CaptureLogger(Mutex<Vec<log::Level>>);
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.level() <= log::Level::Info
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            // Other tests are run concurrently. Ignore log records from outside `open62541`.
+            if record.target().starts_with(LOG_TARGET) {
+                self.0.lock().unwrap().push(record.level());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CaptureLogger = CaptureLogger(Mutex::new(Vec::new()));
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn forward_enabled_levels() {
+        log::set_logger(&LOGGER).expect("should set logger");
+        // Do not filter by maximum level, only by `enabled()` above.
+        log::set_max_level(log::LevelFilter::Trace);
+
+        // The server logs each service request at debug level.
+        let harness = TestHarness::start(|_| {}).expect("should start harness");
+        harness
+            .client()
+            .read_value(&ua::NodeId::ns0_server_status_state())
+            .await
+            .expect("should read value");
+        harness.shutdown().await.unwrap();
+
+        let levels = LOGGER.0.lock().unwrap();
+        assert!(levels.contains(&log::Level::Info));
+        assert!(levels.iter().all(|level| *level <= log::Level::Info));
+    }
+}
@@ -1,17 +1,23 @@
+use std::{fmt, num::ParseIntError, str};
+
 use crate::ua;
 
 /// Wrapper for monitored item ID from [`open62541_sys`].
+///
+/// Monitored item IDs are assigned by the server and are unique only within their subscription.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MonitoredItemId(u32);
 
 impl MonitoredItemId {
-    #[allow(dead_code)] // --no-default-features
+    /// Creates monitored item ID from raw value.
     #[must_use]
-    pub(crate) const fn new(id: u32) -> Self {
+    pub const fn new(id: u32) -> Self {
         Self(id)
     }
 
-    pub(crate) const fn as_u32(self) -> u32 {
+    /// Gets raw value of monitored item ID.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
         self.0
     }
 
@@ -19,3 +25,47 @@ impl MonitoredItemId {
         ua::UInt32::new(self.as_u32())
     }
 }
+
+impl fmt::Display for MonitoredItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl str::FromStr for MonitoredItemId {
+    type Err = ParseIntError;
+
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let monitored_item_id: ua::MonitoredItemId = "7".parse().expect("should be valid ID");
+    /// assert_eq!(monitored_item_id, ua::MonitoredItemId::new(7));
+    /// assert_eq!(monitored_item_id.to_string(), "7");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::MonitoredItemId;
+
+    impl serde::Serialize for MonitoredItemId {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_u32(self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for MonitoredItemId {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            <u32 as serde::Deserialize>::deserialize(deserializer).map(Self)
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::{fmt, slice, str};
+
+use open62541_sys::{UA_NumericRange, UA_NumericRangeDimension};
+
+use crate::{ua, Error, Result};
+
+/// Index range to select part of array value.
+///
+/// This is used with [`AsyncClient::read_value_range()`] and [`AsyncClient::write_value_range()`]
+/// to read or write only a sub-range of array values, and with
+/// [`MonitoredItemBuilder::index_range()`] to monitor it. Each dimension is either a single index
+/// (e.g. `2`) or an inclusive range of indices (e.g. `2:5`). Multiple dimensions are separated by
+/// commas (e.g. `1:2,0:3`).
+///
+/// Index ranges are validated when they are created, so malformed ranges are rejected locally
+/// before any request is sent.
+///
+/// [`AsyncClient::read_value_range()`]: crate::AsyncClient::read_value_range
+/// [`AsyncClient::write_value_range()`]: crate::AsyncClient::write_value_range
+/// [`MonitoredItemBuilder::index_range()`]: crate::MonitoredItemBuilder::index_range
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericRange(Vec<(u32, u32)>);
+
+impl NumericRange {
+    /// Creates index range from dimensions.
+    ///
+    /// Each dimension is given as inclusive pair of minimum and maximum index. When both are the
+    /// same, the dimension selects a single index.
+    ///
+    /// # Errors
+    ///
+    /// This fails with [`Error::IndexRange`] when no dimensions are given or when the minimum of
+    /// any dimension is larger than its maximum.
+    pub fn new(dimensions: &[(u32, u32)]) -> Result<Self> {
+        if dimensions.is_empty() {
+            return Err(Error::IndexRange("should have at least one dimension"));
+        }
+        if dimensions.iter().any(|&(min, max)| min > max) {
+            return Err(Error::IndexRange(
+                "minimum should not be larger than maximum",
+            ));
+        }
+        Ok(Self(dimensions.to_vec()))
+    }
+
+    /// Gets dimensions of index range.
+    ///
+    /// Each dimension is returned as inclusive pair of minimum and maximum index.
+    #[must_use]
+    pub fn dimensions(&self) -> &[(u32, u32)] {
+        &self.0
+    }
+
+    /// Creates index range from raw value.
+    ///
+    /// This returns `None` when the raw value has no dimensions or is invalid.
+    pub(crate) fn from_raw(range: &UA_NumericRange) -> Option<Self> {
+        if range.dimensionsSize == 0 || range.dimensions.is_null() {
+            return None;
+        }
+        // SAFETY: The dimensions array is valid and has the given size.
+        let dimensions = unsafe { slice::from_raw_parts(range.dimensions, range.dimensionsSize) };
+        let dimensions: Vec<_> = dimensions
+            .iter()
+            .map(|dimension| (dimension.min, dimension.max))
+            .collect();
+        Self::new(&dimensions).ok()
+    }
+
+    /// Calls closure with raw value.
+    ///
+    /// The raw value borrows from temporary memory. It must not be used after the closure returns.
+    pub(crate) fn with_raw<R>(&self, f: impl FnOnce(UA_NumericRange) -> R) -> R {
+        let mut dimensions: Vec<_> = self
+            .0
+            .iter()
+            .map(|&(min, max)| UA_NumericRangeDimension { min, max })
+            .collect();
+        f(UA_NumericRange {
+            dimensionsSize: dimensions.len(),
+            dimensions: dimensions.as_mut_ptr(),
+        })
+    }
+
+    /// Gets string representation to be used in requests.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) fn to_ua_string(&self) -> ua::String {
+        // PANIC: The string contains only digits, colons and commas.
+        ua::String::new(&self.to_string()).unwrap()
+    }
+}
+
+impl fmt::Display for NumericRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, &(min, max)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            if min == max {
+                write!(f, "{min}")?;
+            } else {
+                write!(f, "{min}:{max}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl str::FromStr for NumericRange {
+    type Err = Error;
+
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let range: ua::NumericRange = "1:2,0:3".parse().expect("should be valid range");
+    /// assert_eq!(range.dimensions(), &[(1, 2), (0, 3)]);
+    /// assert_eq!(range.to_string(), "1:2,0:3");
+    ///
+    /// "3:1".parse::<ua::NumericRange>().expect_err("should be invalid range");
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let dimensions = s
+            .split(',')
+            .map(parse_dimension)
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(&dimensions)
+    }
+}
+
+/// Parses single dimension of index range.
+fn parse_dimension(s: &str) -> Result<(u32, u32)> {
+    if let Some((min, max)) = s.split_once(':') {
+        let (min, max) = (parse_index(min)?, parse_index(max)?);
+        // The OPC UA specification requires ranges to be given with ascending indices. Ranges of a
+        // single index must be written without colon.
+        if min >= max {
+            return Err(Error::IndexRange("range should have ascending indices"));
+        }
+        Ok((min, max))
+    } else {
+        let index = parse_index(s)?;
+        Ok((index, index))
+    }
+}
+
+/// Parses single index of index range.
+fn parse_index(s: &str) -> Result<u32> {
+    if s.is_empty() {
+        return Err(Error::IndexRange("index should not be empty"));
+    }
+    // Reject signs that would otherwise be accepted by `u32::from_str()`.
+    if !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(Error::IndexRange("index should contain only digits"));
+    }
+    s.parse()
+        .map_err(|_| Error::IndexRange("index should fit into 32 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ua, Error};
+
+    #[test]
+    fn round_trip() {
+        for range in ["0", "2:5", "1:2,0:3", "7,1:4,0"] {
+            let parsed: ua::NumericRange = range.parse().expect("should be valid range");
+            assert_eq!(parsed.to_string(), range);
+            assert_eq!(parsed.to_ua_string().as_str(), Some(range));
+        }
+
+        let range: ua::NumericRange = "1:2,0:3".parse().unwrap();
+        assert_eq!(range.dimensions(), &[(1, 2), (0, 3)]);
+        assert_eq!(ua::NumericRange::new(&[(1, 2), (0, 3)]).unwrap(), range);
+        assert_eq!(ua::NumericRange::new(&[(4, 4)]).unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        for range in [
+            "",
+            "3:1",
+            "2:2",
+            ":3",
+            "1:",
+            "1,,2",
+            "1:2:3",
+            "-1",
+            "+1",
+            "a:b",
+            " 1",
+            "4294967296",
+        ] {
+            let result = range.parse::<ua::NumericRange>();
+            assert!(
+                matches!(result, Err(Error::IndexRange(_))),
+                "{range:?} should be invalid"
+            );
+        }
+
+        assert!(matches!(
+            ua::NumericRange::new(&[]),
+            Err(Error::IndexRange(_))
+        ));
+        assert!(matches!(
+            ua::NumericRange::new(&[(3, 1)]),
+            Err(Error::IndexRange(_))
+        ));
+    }
+}
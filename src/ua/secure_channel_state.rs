@@ -1,10 +1,16 @@
 use open62541_sys::UA_SecureChannelState;
 
 /// Wrapper for [`UA_SecureChannelState`] from [`open62541_sys`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SecureChannelState(UA_SecureChannelState);
 
 impl SecureChannelState {
+    /// Creates wrapper from raw value.
+    #[must_use]
+    pub(crate) const fn new(state: UA_SecureChannelState) -> Self {
+        Self(state)
+    }
+
     /// Creates wrapper initialized with defaults.
     #[must_use]
     #[allow(dead_code)] // --no-default-features
@@ -13,6 +19,12 @@ impl SecureChannelState {
         Self(UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSED)
     }
 
+    /// Checks if secure channel has been closed.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) const fn is_closed(&self) -> bool {
+        matches!(self.0, UA_SecureChannelState::UA_SECURECHANNELSTATE_CLOSED)
+    }
+
     /// Returns mutable pointer to value.
     #[must_use]
     #[allow(dead_code)] // --no-default-features
@@ -2,7 +2,7 @@ use std::ptr::{self, NonNull};
 
 use open62541_sys::{UA_Server, UA_Server_delete, UA_Server_newWithConfig};
 
-use crate::{ua, Error};
+use crate::{server::ServerContext, ua, Error};
 
 /// Wrapper for [`UA_Server`] from [`open62541_sys`].
 ///
@@ -60,6 +60,10 @@ impl Drop for Server {
     fn drop(&mut self) {
         log::debug!("Deleting server");
 
+        // SAFETY: The server is deleted right below. Callbacks that use the context are not invoked
+        // anymore at this point, but we keep the context alive until the server is gone.
+        let context = unsafe { ServerContext::take(self.as_mut_ptr()) };
+
         let status_code = ua::StatusCode::new(unsafe {
             // SAFETY: We retain ownership of `self`.
             UA_Server_delete(self.as_mut_ptr())
@@ -67,5 +71,7 @@ impl Drop for Server {
         if let Err(error) = Error::verify_good(&status_code) {
             log::warn!("Error while dropping server: {error}");
         }
+
+        drop(context);
     }
 }
@@ -87,6 +87,34 @@ impl ServerConfig {
         Ok(config)
     }
 
+    /// Creates a default server config with security policies and certificate chain.
+    ///
+    /// See [`default_with_security_policies()`](Self::default_with_security_policies).
+    #[cfg(feature = "mbedtls")]
+    pub(crate) fn default_with_security_policies_with_chain(
+        port_number: u16,
+        certificate: &crate::Certificate,
+        intermediate_certificates: &[crate::Certificate],
+        private_key: &crate::PrivateKey,
+    ) -> Result<Self, crate::Error> {
+        let mut config =
+            Self::default_with_security_policies(port_number, certificate, private_key)?;
+
+        {
+            let config = unsafe { config.as_mut() };
+            // SAFETY: The security policies have been set up by `default_with_security_policies()`.
+            unsafe {
+                crate::ssl::append_intermediate_certificates(
+                    config.securityPolicies,
+                    config.securityPoliciesSize,
+                    intermediate_certificates,
+                )?;
+            }
+        }
+
+        Ok(config)
+    }
+
     /// Creates a default server config with secure security policies.
     // Method name refers to call of `UA_ServerConfig_setDefaultWithSecureSecurityPolicies()`.
     #[cfg(feature = "mbedtls")]
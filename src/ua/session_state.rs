@@ -1,10 +1,16 @@
 use open62541_sys::UA_SessionState;
 
 /// Wrapper for [`UA_SessionState`] from [`open62541_sys`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SessionState(UA_SessionState);
 
 impl SessionState {
+    /// Creates wrapper from raw value.
+    #[must_use]
+    pub(crate) const fn new(state: UA_SessionState) -> Self {
+        Self(state)
+    }
+
     /// Creates wrapper initialized with defaults.
     #[must_use]
     pub(crate) const fn init() -> Self {
@@ -12,6 +18,12 @@ impl SessionState {
         Self(UA_SessionState::UA_SESSIONSTATE_CLOSED)
     }
 
+    /// Checks if session has been activated.
+    #[allow(dead_code)] // --no-default-features
+    pub(crate) const fn is_activated(&self) -> bool {
+        matches!(self.0, UA_SessionState::UA_SESSIONSTATE_ACTIVATED)
+    }
+
     /// Returns mutable pointer to value.
     #[must_use]
     pub(crate) fn as_mut_ptr(&mut self) -> *mut UA_SessionState {
@@ -1,16 +1,28 @@
+use std::{fmt, num::ParseIntError, str};
+
 use crate::ua;
 
 /// Wrapper for subscription ID from [`open62541_sys`].
+///
+/// Subscription IDs are assigned by the server. They can be persisted (e.g. with [`Display`] or
+/// `serde`) and used to resume management of a subscription after it has been transferred to a new
+/// session, see [`AsyncClient::adopt_subscription()`].
+///
+/// [`Display`]: fmt::Display
+/// [`AsyncClient::adopt_subscription()`]: crate::AsyncClient::adopt_subscription
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SubscriptionId(u32);
 
 impl SubscriptionId {
+    /// Creates subscription ID from raw value.
     #[must_use]
-    pub(crate) const fn new(id: u32) -> Self {
+    pub const fn new(id: u32) -> Self {
         Self(id)
     }
 
-    pub(crate) const fn as_u32(self) -> u32 {
+    /// Gets raw value of subscription ID.
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
         self.0
     }
 
@@ -18,3 +30,66 @@ impl SubscriptionId {
         ua::UInt32::new(self.as_u32())
     }
 }
+
+impl fmt::Display for SubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl str::FromStr for SubscriptionId {
+    type Err = ParseIntError;
+
+    /// ```
+    /// use open62541::ua;
+    ///
+    /// let subscription_id: ua::SubscriptionId = "42".parse().expect("should be valid ID");
+    /// assert_eq!(subscription_id, ua::SubscriptionId::new(42));
+    /// assert_eq!(subscription_id.to_string(), "42");
+    ///
+    /// "LoremIpsum".parse::<ua::SubscriptionId>().expect_err("should be invalid ID");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::SubscriptionId;
+
+    impl serde::Serialize for SubscriptionId {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_u32(self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for SubscriptionId {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            <u32 as serde::Deserialize>::deserialize(deserializer).map(Self)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::ua;
+
+        #[test]
+        fn json_serialization() {
+            let subscription_id: ua::SubscriptionId =
+                serde_json::from_str("123").expect("should deserialize subscription ID");
+
+            assert_eq!(subscription_id, ua::SubscriptionId::new(123));
+            assert_eq!(
+                serde_json::to_string(&subscription_id).expect("should serialize subscription ID"),
+                "123"
+            );
+        }
+    }
+}
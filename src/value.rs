@@ -28,12 +28,14 @@ pub enum ValueType {
     String,         // Data type ns=0;i=12
     DateTime,       // Data type ns=0;i=13
     ByteString,     // Data type ns=0;i=15
+    XmlElement,     // Data type ns=0;i=16
     NodeId,         // Data type ns=0;i=17
     ExpandedNodeId, // Data type ns=0;i=18
     StatusCode,     // Data type ns=0;i=19
     QualifiedName,  // Data type ns=0;i=20
     LocalizedText,  // Data type ns=0;i=21
     Argument,       // Data type ns=0;i=296
+    OptionSet,      // Data type ns=0;i=12755
 }
 
 impl ValueType {
@@ -81,12 +83,14 @@ impl ValueType {
                 String,         // Data type ns=0;i=12
                 DateTime,       // Data type ns=0;i=13
                 ByteString,     // Data type ns=0;i=15
+                XmlElement,     // Data type ns=0;i=16
                 NodeId,         // Data type ns=0;i=17
                 ExpandedNodeId, // Data type ns=0;i=18
                 StatusCode,     // Data type ns=0;i=19
                 QualifiedName,  // Data type ns=0;i=20
                 LocalizedText,  // Data type ns=0;i=21
                 Argument,       // Data type ns=0;i=296
+                OptionSet,      // Data type ns=0;i=12755
             ],
         )
     }
@@ -127,12 +131,14 @@ pub enum ScalarValue {
     String(ua::String),                 // Data type ns=0;i=12
     DateTime(ua::DateTime),             // Data type ns=0;i=13
     ByteString(ua::ByteString),         // Data type ns=0;i=15
+    XmlElement(ua::XmlElement),         // Data type ns=0;i=16
     NodeId(ua::NodeId),                 // Data type ns=0;i=17
     ExpandedNodeId(ua::ExpandedNodeId), // Data type ns=0;i=18
     StatusCode(ua::StatusCode),         // Data type ns=0;i=19
     QualifiedName(ua::QualifiedName),   // Data type ns=0;i=20
     LocalizedText(ua::LocalizedText),   // Data type ns=0;i=21
     Argument(ua::Argument),             // Data type ns=0;i=296
+    OptionSet(ua::OptionSet),           // Data type ns=0;i=12755
 }
 
 // TODO: Add proper interface.
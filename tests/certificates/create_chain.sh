@@ -0,0 +1,56 @@
+#!/bin/sh
+
+# Creates two-level certificate chain for tests: root CA -> intermediate CA -> leaf certificates
+# for server and client. All certificates are in DER format.
+
+set -e
+
+cat > ca.cnf <<CNF
+basicConstraints = critical, CA:TRUE
+keyUsage = critical, keyCertSign, cRLSign
+subjectKeyIdentifier = hash
+CNF
+
+cat > server.cnf <<CNF
+basicConstraints = critical, CA:FALSE
+keyUsage = critical, digitalSignature, nonRepudiation, keyEncipherment, dataEncipherment
+extendedKeyUsage = serverAuth, clientAuth
+subjectAltName = URI:urn:open62541.server.application, DNS:localhost
+CNF
+
+cat > client.cnf <<CNF
+basicConstraints = critical, CA:FALSE
+keyUsage = critical, digitalSignature, nonRepudiation, keyEncipherment, dataEncipherment
+extendedKeyUsage = serverAuth, clientAuth
+subjectAltName = URI:urn:open62541.client.application, DNS:localhost
+CNF
+
+openssl req -x509 \
+  -newkey rsa:2048 -keyout root_ca_private_key.pem -nodes \
+  -out root_ca_certificate.der -outform DER -sha256 -days 36500 \
+  -subj "/C=DE/O=SampleOrganization/CN=Open62541 Root CA" \
+  -addext "basicConstraints = critical, CA:TRUE" \
+  -addext "keyUsage = critical, keyCertSign, cRLSign"
+
+create() {
+  name=$1
+  issuer=$2
+  subject=$3
+  extensions=$4
+
+  openssl req \
+    -newkey rsa:2048 -keyout "${name}_private_key.pem" -nodes \
+    -out "${name}.csr" -subj "$subject"
+  openssl x509 -req \
+    -in "${name}.csr" -out "${name}_certificate.der" -outform DER -sha256 -days 36500 \
+    -CA "${issuer}_certificate.der" -CAform DER -CAkey "${issuer}_private_key.pem" \
+    -CAcreateserial -extfile "$extensions"
+  rm "${name}.csr"
+}
+
+create intermediate_ca root_ca "/C=DE/O=SampleOrganization/CN=Open62541 Intermediate CA" ca.cnf
+create server intermediate_ca "/C=DE/O=SampleOrganization/CN=Open62541Server@localhost" server.cnf
+create client intermediate_ca "/C=DE/O=SampleOrganization/CN=Open62541Client@localhost" client.cnf
+
+# The CA keys are not needed by tests.
+rm ca.cnf server.cnf client.cnf ./*.srl root_ca_private_key.pem intermediate_ca_private_key.pem